@@ -29,10 +29,19 @@ for ps in "${proto_sets[@]}"; do
   cargo run -p pb-rs -- -I "$ps" -d "$ps" "$ps"/*.proto
 done
 
+# pb-rs' own descriptor.proto and plugin.proto types, used for descriptor sets and protoc-gen-quickrs
+cargo run -p pb-rs -- -I "$base_dir"/pb-rs/src/descriptor -d pb-rs/src/descriptor \
+  pb-rs/src/descriptor/google/protobuf/descriptor.proto \
+  pb-rs/src/descriptor/google/protobuf/compiler/plugin.proto
+
 for ps in "${nostd_proto_sets[@]}"; do
   cargo run -p pb-rs -- --nostd -I "$ps" -d "$ps" "$ps"/*.proto
 done
 
+# fixed capacity collections, built without an allocator
+cargo run -p pb-rs -- --nostd --heapless -I quick-protobuf/examples/pb_rs_heapless \
+  -d quick-protobuf/examples/pb_rs_heapless quick-protobuf/examples/pb_rs_heapless/*.proto
+
 rm -rf quick-protobuf/examples/pb_rs_v3/owned
 mkdir -p quick-protobuf/examples/pb_rs_v3/owned
 cargo run -p pb-rs -- quick-protobuf/examples/pb_rs_v3/*.proto \
@@ -0,0 +1,29 @@
+//! protoc plugin generating quick-protobuf modules
+//!
+//! ```sh
+//! protoc --plugin=protoc-gen-quickrs --quickrs_out=src/protos --quickrs_opt=owned foo.proto
+//! ```
+//!
+//! See [pb_rs::plugin] for the supported parameters.
+
+use pb_rs::errors::Error;
+use std::io::{Read, Write};
+
+fn run() -> Result<(), Error> {
+    let mut request = Vec::new();
+    std::io::stdin().read_to_end(&mut request)?;
+    let response = pb_rs::plugin::run(&request)?;
+    std::io::stdout().write_all(&response)?;
+    Ok(())
+}
+
+fn main() {
+    ::std::process::exit({
+        if let Err(e) = run() {
+            eprintln!("protoc-gen-quickrs fatal error {}", e);
+            1
+        } else {
+            0
+        }
+    });
+}
@@ -0,0 +1,709 @@
+//! Detection of breaking changes between two versions of a schema
+//!
+//! [compare] takes the `FileDescriptorSet`s of the old and new versions, e.g. from
+//! [file_descriptor_set](crate::descriptor_set::file_descriptor_set) or
+//! `protoc --descriptor_set_out`, and returns the changes breaking the wire format or the
+//! generated code:
+//!
+//! - removed messages, enums, services and methods
+//! - removed fields and enum values whose numbers are not reserved
+//! - changed field numbers, types, labels and oneofs, renamed fields
+//! - changed enum value numbers, renamed enum values
+//! - changed method signatures
+//! - files moved to another package
+//!
+//! Fields and enum values are matched by number, the other elements by fully qualified name.
+//! The types of a file moved to another package are matched with their new name.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::descriptor::google::protobuf::mod_FieldDescriptorProto::{Label, Type};
+use crate::descriptor::google::protobuf::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorProto,
+    FileDescriptorSet, MethodDescriptorProto, ServiceDescriptorProto,
+};
+
+/// The kind of a breaking change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    PackageChanged,
+    MessageRemoved,
+    FieldRemoved,
+    FieldNumberChanged,
+    FieldTypeChanged,
+    FieldLabelChanged,
+    FieldRenamed,
+    FieldOneofChanged,
+    EnumRemoved,
+    EnumValueRemoved,
+    EnumValueNumberChanged,
+    EnumValueRenamed,
+    ServiceRemoved,
+    MethodRemoved,
+    MethodSignatureChanged,
+}
+
+impl ChangeKind {
+    /// Name of the change in the machine-readable output, e.g. `FIELD_REMOVED`
+    pub fn name(self) -> &'static str {
+        match self {
+            ChangeKind::PackageChanged => "PACKAGE_CHANGED",
+            ChangeKind::MessageRemoved => "MESSAGE_REMOVED",
+            ChangeKind::FieldRemoved => "FIELD_REMOVED",
+            ChangeKind::FieldNumberChanged => "FIELD_NUMBER_CHANGED",
+            ChangeKind::FieldTypeChanged => "FIELD_TYPE_CHANGED",
+            ChangeKind::FieldLabelChanged => "FIELD_LABEL_CHANGED",
+            ChangeKind::FieldRenamed => "FIELD_RENAMED",
+            ChangeKind::FieldOneofChanged => "FIELD_ONEOF_CHANGED",
+            ChangeKind::EnumRemoved => "ENUM_REMOVED",
+            ChangeKind::EnumValueRemoved => "ENUM_VALUE_REMOVED",
+            ChangeKind::EnumValueNumberChanged => "ENUM_VALUE_NUMBER_CHANGED",
+            ChangeKind::EnumValueRenamed => "ENUM_VALUE_RENAMED",
+            ChangeKind::ServiceRemoved => "SERVICE_REMOVED",
+            ChangeKind::MethodRemoved => "METHOD_REMOVED",
+            ChangeKind::MethodSignatureChanged => "METHOD_SIGNATURE_CHANGED",
+        }
+    }
+
+    /// The change breaks the wire format, not only the generated code
+    ///
+    /// Messages encoded with one version may not be decoded correctly with the other one, or
+    /// rpcs may fail.
+    pub fn breaks_wire(self) -> bool {
+        !matches!(
+            self,
+            ChangeKind::MessageRemoved
+                | ChangeKind::FieldRenamed
+                | ChangeKind::EnumRemoved
+                | ChangeKind::EnumValueRenamed
+        )
+    }
+}
+
+/// A breaking change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// Fully qualified name of the changed element in the old version, or name of the file for
+    /// package changes
+    pub path: String,
+    /// The old value of the changed element, e.g. its number or type
+    pub old: Option<String>,
+    /// The new value of the changed element
+    pub new: Option<String>,
+}
+
+impl Change {
+    fn new(kind: ChangeKind, path: String, old: Option<String>, new: Option<String>) -> Self {
+        Change {
+            kind,
+            path,
+            old,
+            new,
+        }
+    }
+
+    /// The change as a single line JSON object
+    ///
+    /// e.g. `{"kind":"FIELD_REMOVED","path":"pkg.M.f","old":"3","new":null,"breaks_wire":true}`
+    pub fn to_json(&self) -> String {
+        let value = |v: &Option<String>| v.as_deref().map_or("null".to_string(), json_string);
+        format!(
+            "{{\"kind\":{},\"path\":{},\"old\":{},\"new\":{},\"breaks_wire\":{}}}",
+            json_string(self.kind.name()),
+            json_string(&self.path),
+            value(&self.old),
+            value(&self.new),
+            self.kind.breaks_wire()
+        )
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let old = self.old.as_deref().unwrap_or("none");
+        let new = self.new.as_deref().unwrap_or("none");
+        write!(f, "{}: ", self.path)?;
+        match self.kind {
+            ChangeKind::PackageChanged => {
+                write!(f, "package changed from '{}' to '{}'", old, new)
+            }
+            ChangeKind::MessageRemoved => write!(f, "message removed"),
+            ChangeKind::FieldRemoved => {
+                write!(f, "field removed without reserving its number {}", old)
+            }
+            ChangeKind::FieldNumberChanged => {
+                write!(f, "field number changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldTypeChanged => write!(f, "field type changed from {} to {}", old, new),
+            ChangeKind::FieldLabelChanged => {
+                write!(f, "field label changed from {} to {}", old, new)
+            }
+            ChangeKind::FieldRenamed => write!(f, "field renamed to {}", new),
+            ChangeKind::FieldOneofChanged => {
+                write!(f, "field oneof changed from {} to {}", old, new)
+            }
+            ChangeKind::EnumRemoved => write!(f, "enum removed"),
+            ChangeKind::EnumValueRemoved => {
+                write!(f, "enum value removed without reserving its number {}", old)
+            }
+            ChangeKind::EnumValueNumberChanged => {
+                write!(f, "enum value number changed from {} to {}", old, new)
+            }
+            ChangeKind::EnumValueRenamed => write!(f, "enum value renamed to {}", new),
+            ChangeKind::ServiceRemoved => write!(f, "service removed"),
+            ChangeKind::MethodRemoved => write!(f, "method removed"),
+            ChangeKind::MethodSignatureChanged => {
+                write!(f, "method signature changed from {} to {}", old, new)
+            }
+        }
+    }
+}
+
+/// Compares two versions of a schema and returns the breaking changes of the new one
+pub fn compare(old: &FileDescriptorSet, new: &FileDescriptorSet) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut comparison = Comparison {
+        old: Schema::new(old),
+        new: Schema::new(new),
+        renames: HashMap::new(),
+    };
+    comparison.compare_packages(old, new, &mut changes);
+    comparison.compare_messages(&mut changes);
+    comparison.compare_enums(&mut changes);
+    comparison.compare_services(&mut changes);
+    changes
+}
+
+/// A type of a schema: its fully qualified name, file and descriptor
+type Item<'a, T> = (String, &'a FileDescriptorProto<'a>, &'a T);
+
+/// The messages, enums and services of a set, nested ones included
+#[derive(Default)]
+struct Schema<'a> {
+    messages: Vec<Item<'a, DescriptorProto<'a>>>,
+    enums: Vec<Item<'a, EnumDescriptorProto<'a>>>,
+    services: Vec<Item<'a, ServiceDescriptorProto<'a>>>,
+}
+
+impl<'a> Schema<'a> {
+    fn new(set: &'a FileDescriptorSet<'a>) -> Self {
+        let mut schema = Schema::default();
+        for file in &set.file {
+            let package = file.package.as_deref().unwrap_or_default();
+            for m in &file.message_type {
+                schema.add_message(package, file, m);
+            }
+            for e in &file.enum_type {
+                schema.enums.push((full_name(package, &e.name), file, e));
+            }
+            for s in &file.service {
+                schema.services.push((full_name(package, &s.name), file, s));
+            }
+        }
+        schema
+    }
+
+    fn add_message(
+        &mut self,
+        scope: &str,
+        file: &'a FileDescriptorProto<'a>,
+        m: &'a DescriptorProto<'a>,
+    ) {
+        let name = full_name(scope, &m.name);
+        for nested in &m.nested_type {
+            self.add_message(&name, file, nested);
+        }
+        for e in &m.enum_type {
+            self.enums.push((full_name(&name, &e.name), file, e));
+        }
+        self.messages.push((name, file, m));
+    }
+
+    /// The names of the types declared in `file`
+    fn type_names(&self, file: &FileDescriptorProto) -> Vec<String> {
+        let in_file = |f: &FileDescriptorProto| f.name == file.name;
+        self.messages
+            .iter()
+            .filter(|(_, f, _)| in_file(f))
+            .map(|(name, _, _)| &**name)
+            .chain(
+                self.enums
+                    .iter()
+                    .filter(|(_, f, _)| in_file(f))
+                    .map(|(name, _, _)| &**name),
+            )
+            .chain(
+                self.services
+                    .iter()
+                    .filter(|(_, f, _)| in_file(f))
+                    .map(|(name, _, _)| &**name),
+            )
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+struct Comparison<'a> {
+    old: Schema<'a>,
+    new: Schema<'a>,
+    /// New names of the types of the files moved to another package
+    renames: HashMap<String, String>,
+}
+
+impl<'a> Comparison<'a> {
+    /// The name of an old type in the new version
+    fn new_name<'n>(&'n self, old: &'n str) -> &'n str {
+        self.renames.get(old).map_or(old, |n| n)
+    }
+
+    fn compare_packages(
+        &mut self,
+        old: &FileDescriptorSet,
+        new: &FileDescriptorSet,
+        changes: &mut Vec<Change>,
+    ) {
+        for old_file in &old.file {
+            let new_file = match new.file.iter().find(|f| f.name == old_file.name) {
+                Some(f) => f,
+                None => continue,
+            };
+            let old_package = old_file.package.as_deref().unwrap_or_default();
+            let new_package = new_file.package.as_deref().unwrap_or_default();
+            if old_package == new_package {
+                continue;
+            }
+            for name in self.old.type_names(old_file) {
+                let local = match old_package {
+                    "" => &*name,
+                    _ => &name[old_package.len() + 1..],
+                };
+                let new_name = match new_package {
+                    "" => local.to_string(),
+                    _ => format!("{}.{}", new_package, local),
+                };
+                self.renames.insert(name, new_name);
+            }
+            changes.push(Change::new(
+                ChangeKind::PackageChanged,
+                old_file.name.as_deref().unwrap_or_default().to_string(),
+                Some(old_package.to_string()),
+                Some(new_package.to_string()),
+            ));
+        }
+    }
+
+    fn compare_messages(&self, changes: &mut Vec<Change>) {
+        for (name, old_file, old) in &self.old.messages {
+            let new_name = self.new_name(name);
+            match self.new.messages.iter().find(|(n, _, _)| n == new_name) {
+                Some((_, new_file, new)) => {
+                    self.compare_fields(changes, name, (old_file, old), (new_file, new))
+                }
+                None => changes.push(Change::new(
+                    ChangeKind::MessageRemoved,
+                    name.clone(),
+                    None,
+                    None,
+                )),
+            }
+        }
+    }
+
+    fn compare_fields(
+        &self,
+        changes: &mut Vec<Change>,
+        message: &str,
+        (old_file, old): (&FileDescriptorProto, &DescriptorProto),
+        (new_file, new): (&FileDescriptorProto, &DescriptorProto),
+    ) {
+        for f in &old.field {
+            let path = format!("{}.{}", message, f.name.as_deref().unwrap_or_default());
+            let number = f.number.unwrap_or_default();
+            let new_f = match new.field.iter().find(|n| n.number == f.number) {
+                Some(new_f) => new_f,
+                None => {
+                    if let Some(new_f) = new.field.iter().find(|n| n.name == f.name) {
+                        changes.push(Change::new(
+                            ChangeKind::FieldNumberChanged,
+                            path,
+                            Some(number.to_string()),
+                            Some(new_f.number.unwrap_or_default().to_string()),
+                        ));
+                    } else if !new.reserved_range.iter().any(|r| {
+                        r.start.unwrap_or_default() <= number && number < r.end.unwrap_or_default()
+                    }) {
+                        changes.push(Change::new(
+                            ChangeKind::FieldRemoved,
+                            path,
+                            Some(number.to_string()),
+                            None,
+                        ));
+                    }
+                    continue;
+                }
+            };
+            if new_f.name != f.name {
+                changes.push(Change::new(
+                    ChangeKind::FieldRenamed,
+                    path.clone(),
+                    f.name.as_ref().map(|n| n.to_string()),
+                    new_f.name.as_ref().map(|n| n.to_string()),
+                ));
+            }
+            let old_type = field_type(f);
+            let new_type = field_type(new_f);
+            if self.new_name(&old_type) != new_type {
+                changes.push(Change::new(
+                    ChangeKind::FieldTypeChanged,
+                    path.clone(),
+                    Some(old_type),
+                    Some(new_type),
+                ));
+            }
+            let old_label = label(f, old_file);
+            let new_label = label(new_f, new_file);
+            if old_label != new_label {
+                changes.push(Change::new(
+                    ChangeKind::FieldLabelChanged,
+                    path.clone(),
+                    Some(old_label.to_string()),
+                    Some(new_label.to_string()),
+                ));
+            }
+            let old_oneof = oneof(f, old);
+            let new_oneof = oneof(new_f, new);
+            if old_oneof != new_oneof {
+                changes.push(Change::new(
+                    ChangeKind::FieldOneofChanged,
+                    path,
+                    old_oneof.map(str::to_string),
+                    new_oneof.map(str::to_string),
+                ));
+            }
+        }
+    }
+
+    fn compare_enums(&self, changes: &mut Vec<Change>) {
+        for (name, _, old) in &self.old.enums {
+            let new_name = self.new_name(name);
+            let new = match self.new.enums.iter().find(|(n, _, _)| n == new_name) {
+                Some((_, _, new)) => new,
+                None => {
+                    changes.push(Change::new(
+                        ChangeKind::EnumRemoved,
+                        name.clone(),
+                        None,
+                        None,
+                    ));
+                    continue;
+                }
+            };
+            for v in &old.value {
+                if new
+                    .value
+                    .iter()
+                    .any(|n| n.name == v.name && n.number == v.number)
+                {
+                    continue;
+                }
+                let path = format!("{}.{}", name, v.name.as_deref().unwrap_or_default());
+                let number = v.number.unwrap_or_default();
+                if let Some(new_v) = new.value.iter().find(|n| n.number == v.number) {
+                    changes.push(Change::new(
+                        ChangeKind::EnumValueRenamed,
+                        path,
+                        v.name.as_ref().map(|n| n.to_string()),
+                        new_v.name.as_ref().map(|n| n.to_string()),
+                    ));
+                } else if let Some(new_v) = new.value.iter().find(|n| n.name == v.name) {
+                    changes.push(Change::new(
+                        ChangeKind::EnumValueNumberChanged,
+                        path,
+                        Some(number.to_string()),
+                        Some(new_v.number.unwrap_or_default().to_string()),
+                    ));
+                } else if !new.reserved_range.iter().any(|r| {
+                    // enum reserved ranges are inclusive
+                    r.start.unwrap_or_default() <= number && number <= r.end.unwrap_or_default()
+                }) {
+                    changes.push(Change::new(
+                        ChangeKind::EnumValueRemoved,
+                        path,
+                        Some(number.to_string()),
+                        None,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn compare_services(&self, changes: &mut Vec<Change>) {
+        for (name, _, old) in &self.old.services {
+            let new_name = self.new_name(name);
+            let new = match self.new.services.iter().find(|(n, _, _)| n == new_name) {
+                Some((_, _, new)) => new,
+                None => {
+                    changes.push(Change::new(
+                        ChangeKind::ServiceRemoved,
+                        name.clone(),
+                        None,
+                        None,
+                    ));
+                    continue;
+                }
+            };
+            for m in &old.method {
+                let path = format!("{}.{}", name, m.name.as_deref().unwrap_or_default());
+                match new.method.iter().find(|n| n.name == m.name) {
+                    Some(new_m) => {
+                        let old_signature = signature(m, |t| self.new_name(t).to_string());
+                        let new_signature = signature(new_m, str::to_string);
+                        if old_signature != new_signature {
+                            changes.push(Change::new(
+                                ChangeKind::MethodSignatureChanged,
+                                path,
+                                Some(signature(m, str::to_string)),
+                                Some(new_signature),
+                            ));
+                        }
+                    }
+                    None => changes.push(Change::new(ChangeKind::MethodRemoved, path, None, None)),
+                }
+            }
+        }
+    }
+}
+
+/// Fully qualified name, without the leading `.`
+fn full_name(scope: &str, name: &Option<Cow<str>>) -> String {
+    let name = name.as_deref().unwrap_or_default();
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+/// The scalar type of a field, e.g. `int32`, or the name of its message or enum
+fn field_type(f: &FieldDescriptorProto) -> String {
+    match (&f.type_name, f.type_pb) {
+        (Some(name), _) => name.trim_start_matches('.').to_string(),
+        (None, Some(typ)) => format!("{:?}", typ)["TYPE_".len()..].to_lowercase(),
+        (None, None) => String::new(),
+    }
+}
+
+fn label(f: &FieldDescriptorProto, file: &FileDescriptorProto) -> &'static str {
+    match f.label {
+        Some(Label::LABEL_REPEATED) => "repeated",
+        Some(Label::LABEL_REQUIRED) => "required",
+        _ if file.syntax.as_deref() != Some("proto3")
+            || f.proto3_optional == Some(true)
+            || f.type_pb == Some(Type::TYPE_MESSAGE) =>
+        {
+            "optional"
+        }
+        // proto3 fields without presence
+        _ => "implicit",
+    }
+}
+
+/// The oneof of a field, not the synthetic oneof of a proto3 `optional` field
+fn oneof<'a>(f: &FieldDescriptorProto, m: &'a DescriptorProto) -> Option<&'a str> {
+    if f.proto3_optional == Some(true) {
+        return None;
+    }
+    let o = m.oneof_decl.get(f.oneof_index? as usize)?;
+    o.name.as_deref()
+}
+
+/// e.g. `(stream a.Request) returns (a.Response)`, with the types renamed by `rename`
+fn signature<F: Fn(&str) -> String>(m: &MethodDescriptorProto, rename: F) -> String {
+    let typ = |streaming: bool, name: &Option<Cow<str>>| {
+        let name = rename(name.as_deref().unwrap_or_default().trim_start_matches('.'));
+        if streaming {
+            format!("(stream {})", name)
+        } else {
+            format!("({})", name)
+        }
+    };
+    format!(
+        "{} returns {}",
+        typ(m.client_streaming, &m.input_type),
+        typ(m.server_streaming, &m.output_type)
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::descriptor::google::protobuf::mod_EnumDescriptorProto::EnumReservedRange;
+    use crate::descriptor_set::file_descriptor_set;
+
+    fn set(name: &str, proto: &str) -> FileDescriptorSet<'static> {
+        let dir = std::env::temp_dir().join(format!("pb_rs_test_breaking_{}", name));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("a.proto");
+        std::fs::write(&file, proto).unwrap();
+        let set = file_descriptor_set(&[file], &[], false).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        set
+    }
+
+    /// The changes between `old` and `new`, described like `pb-rs breaking` does
+    fn changes(old: &FileDescriptorSet, new: &FileDescriptorSet) -> Vec<String> {
+        compare(old, new)
+            .iter()
+            .map(|c| format!("{} {}", c.kind.name(), c))
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_messages() {
+        let old = r#"syntax = "proto3";
+            package a;
+            message M {
+                int32 id = 1;
+                string name = 2;
+                repeated int64 values = 3;
+                optional bool flag = 4;
+                int32 removed = 5;
+                int32 reserved = 6;
+                oneof o {
+                    N n = 7;
+                }
+                int32 renamed = 8;
+                message N {}
+            }
+            message Removed {}
+        "#;
+        let new = r#"syntax = "proto3";
+            package a;
+            message M {
+                reserved 6;
+                int64 id = 1;
+                string name = 12;
+                int64 values = 3;
+                bool flag = 4;
+                N n = 7;
+                int32 new_name = 8;
+                message N {}
+            }
+        "#;
+        assert_eq!(
+            vec![
+                "FIELD_TYPE_CHANGED a.M.id: field type changed from int32 to int64",
+                "FIELD_NUMBER_CHANGED a.M.name: field number changed from 2 to 12",
+                "FIELD_LABEL_CHANGED a.M.values: field label changed from repeated to implicit",
+                "FIELD_LABEL_CHANGED a.M.flag: field label changed from optional to implicit",
+                "FIELD_REMOVED a.M.removed: field removed without reserving its number 5",
+                "FIELD_RENAMED a.M.renamed: field renamed to new_name",
+                "FIELD_ONEOF_CHANGED a.M.n: field oneof changed from o to none",
+                "MESSAGE_REMOVED a.Removed: message removed",
+            ],
+            changes(&set("messages_old", old), &set("messages_new", new))
+        );
+        let old = set("messages_same", old);
+        assert!(changes(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_compare_enums_and_services() {
+        let old = r#"syntax = "proto3";
+            package a;
+            enum E {
+                A = 0;
+                B = 1;
+                C = 2;
+                D = 3;
+                E_ = 4;
+            }
+            enum Removed { X = 0; }
+            message Req {}
+            message Res {}
+            service S {
+                rpc Get(Req) returns (Res);
+                rpc List(Req) returns (Res);
+                rpc Removed(Req) returns (Res);
+            }
+            service Removed {}
+        "#;
+        let new = r#"syntax = "proto3";
+            package a;
+            enum E {
+                A = 0;
+                BB = 1;
+                C = 5;
+            }
+            message Req {}
+            message Res {}
+            service S {
+                rpc Get(Req) returns (Res);
+                rpc List(Req) returns (stream Req);
+            }
+        "#;
+        // enum reserved ranges are not parsed, but are in the sets written by protoc
+        let mut new = set("enums_new", new);
+        new.file[0].enum_type[0]
+            .reserved_range
+            .push(EnumReservedRange {
+                start: Some(4),
+                end: Some(4),
+            });
+        assert_eq!(
+            vec![
+                "ENUM_VALUE_RENAMED a.E.B: enum value renamed to BB",
+                "ENUM_VALUE_NUMBER_CHANGED a.E.C: enum value number changed from 2 to 5",
+                "ENUM_VALUE_REMOVED a.E.D: enum value removed without reserving its number 3",
+                "ENUM_REMOVED a.Removed: enum removed",
+                "METHOD_SIGNATURE_CHANGED a.S.List: method signature changed from (a.Req) \
+                 returns (a.Res) to (a.Req) returns (stream a.Req)",
+                "METHOD_REMOVED a.S.Removed: method removed",
+                "SERVICE_REMOVED a.Removed: service removed",
+            ],
+            changes(&set("enums_old", old), &new)
+        );
+    }
+
+    #[test]
+    fn test_compare_packages() {
+        let old = r#"package a;
+            message M { optional M m = 1; }
+            service S { rpc Get(M) returns (M); }
+        "#;
+        let new = r#"package b.c;
+            message M { optional M m = 1; }
+            service S { rpc Get(M) returns (M); }
+        "#;
+        let (old, new) = (set("packages_old", old), set("packages_new", new));
+        assert_eq!(
+            vec!["PACKAGE_CHANGED a.proto: package changed from 'a' to 'b.c'"],
+            changes(&old, &new)
+        );
+        let change = &compare(&old, &new)[0];
+        assert_eq!(
+            r#"{"kind":"PACKAGE_CHANGED","path":"a.proto","old":"a","new":"b.c","breaks_wire":true}"#,
+            change.to_json()
+        );
+        assert_eq!(r#""a\"b\\c\n\u0001""#, json_string("a\"b\\c\n\u{1}"));
+    }
+}
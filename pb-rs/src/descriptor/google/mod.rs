@@ -0,0 +1,13 @@
+// Not generated: pb-rs writes the `google.protobuf` package to `protobuf.rs` and its
+// `google.protobuf.compiler` subpackage to `protobuf/`, which cannot both be the
+// `protobuf` module. They are merged here.
+
+#[path = "protobuf.rs"]
+mod descriptor;
+#[path = "protobuf/mod.rs"]
+mod subpackages;
+
+pub mod protobuf {
+    pub use super::descriptor::*;
+    pub use super::subpackages::compiler;
+}
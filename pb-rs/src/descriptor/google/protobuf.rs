@@ -0,0 +1,2516 @@
+// Automatically generated rust module for 'descriptor.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::super::*;
+
+/// The protocol compiler can output a FileDescriptorSet containing the .proto
+/// files it parses.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FileDescriptorSet<'a> {
+    pub file: Vec<google::protobuf::FileDescriptorProto<'a>>,
+}
+
+impl<'a> MessageRead<'a> for FileDescriptorSet<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.file.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.file.push(r.read_message::<google::protobuf::FileDescriptorProto>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FileDescriptorSet<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.file.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.file { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a complete .proto file.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FileDescriptorProto<'a> {
+    /// file name, relative to root of source tree
+    pub name: Option<Cow<'a, str>>,
+    /// e.g. "foo", "foo.bar", etc.
+    pub package: Option<Cow<'a, str>>,
+    /// Names of files imported by this file.
+    pub dependency: Vec<Cow<'a, str>>,
+    /// Indexes of the public imported files in the dependency list above.
+    pub public_dependency: Vec<i32>,
+    /// Indexes of the weak imported files in the dependency list.
+    /// For Google-internal migration only. Do not use.
+    pub weak_dependency: Vec<i32>,
+    /// All top-level definitions in this file.
+    pub message_type: Vec<google::protobuf::DescriptorProto<'a>>,
+    pub enum_type: Vec<google::protobuf::EnumDescriptorProto<'a>>,
+    pub service: Vec<google::protobuf::ServiceDescriptorProto<'a>>,
+    pub extension: Vec<google::protobuf::FieldDescriptorProto<'a>>,
+    pub options: Option<google::protobuf::FileOptions<'a>>,
+    /// This field contains optional information about the original source code.
+    /// You may safely remove this entire field without harming runtime
+    /// functionality of the descriptors -- the information is needed only by
+    /// development tools.
+    pub source_code_info: Option<google::protobuf::SourceCodeInfo<'a>>,
+    /// The syntax of the proto file.
+    /// The supported values are "proto2" and "proto3".
+    pub syntax: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for FileDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.package = Default::default();
+        self.dependency.clear();
+        self.public_dependency.clear();
+        self.weak_dependency.clear();
+        self.message_type.clear();
+        self.enum_type.clear();
+        self.service.clear();
+        self.extension.clear();
+        self.options = Default::default();
+        self.source_code_info = Default::default();
+        self.syntax = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.package = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => self.dependency.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(80) => self.public_dependency.push(r.read_int32(bytes)?),
+                Ok(88) => self.weak_dependency.push(r.read_int32(bytes)?),
+                Ok(34) => self.message_type.push(r.read_message::<google::protobuf::DescriptorProto>(bytes)?),
+                Ok(42) => self.enum_type.push(r.read_message::<google::protobuf::EnumDescriptorProto>(bytes)?),
+                Ok(50) => self.service.push(r.read_message::<google::protobuf::ServiceDescriptorProto>(bytes)?),
+                Ok(58) => self.extension.push(r.read_message::<google::protobuf::FieldDescriptorProto>(bytes)?),
+                Ok(66) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(74) => r.merge_message(bytes, self.source_code_info.get_or_insert_with(Default::default))?,
+                Ok(98) => self.syntax = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FileDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.package.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.dependency.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.public_dependency.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+        + self.weak_dependency.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+        + self.message_type.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.enum_type.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.service.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extension.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.source_code_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.syntax.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.package { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        for s in &self.dependency { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.public_dependency { w.write_with_tag(80, |w| w.write_int32(*s))?; }
+        for s in &self.weak_dependency { w.write_with_tag(88, |w| w.write_int32(*s))?; }
+        for s in &self.message_type { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.enum_type { w.write_with_tag(42, |w| w.write_message(s))?; }
+        for s in &self.service { w.write_with_tag(50, |w| w.write_message(s))?; }
+        for s in &self.extension { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.source_code_info { w.write_with_tag(74, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.syntax { w.write_with_tag(98, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a message type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub field: Vec<google::protobuf::FieldDescriptorProto<'a>>,
+    pub extension: Vec<google::protobuf::FieldDescriptorProto<'a>>,
+    pub nested_type: Vec<google::protobuf::DescriptorProto<'a>>,
+    pub enum_type: Vec<google::protobuf::EnumDescriptorProto<'a>>,
+    pub extension_range: Vec<google::protobuf::mod_DescriptorProto::ExtensionRange<'a>>,
+    pub oneof_decl: Vec<google::protobuf::OneofDescriptorProto<'a>>,
+    pub options: Option<google::protobuf::MessageOptions<'a>>,
+    pub reserved_range: Vec<google::protobuf::mod_DescriptorProto::ReservedRange>,
+    /// Reserved field names, which may not be used by fields in the same message.
+    /// A given name may only be reserved once.
+    pub reserved_name: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for DescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.field.clear();
+        self.extension.clear();
+        self.nested_type.clear();
+        self.enum_type.clear();
+        self.extension_range.clear();
+        self.oneof_decl.clear();
+        self.options = Default::default();
+        self.reserved_range.clear();
+        self.reserved_name.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.field.push(r.read_message::<google::protobuf::FieldDescriptorProto>(bytes)?),
+                Ok(50) => self.extension.push(r.read_message::<google::protobuf::FieldDescriptorProto>(bytes)?),
+                Ok(26) => self.nested_type.push(r.read_message::<google::protobuf::DescriptorProto>(bytes)?),
+                Ok(34) => self.enum_type.push(r.read_message::<google::protobuf::EnumDescriptorProto>(bytes)?),
+                Ok(42) => self.extension_range.push(r.read_message::<google::protobuf::mod_DescriptorProto::ExtensionRange>(bytes)?),
+                Ok(66) => self.oneof_decl.push(r.read_message::<google::protobuf::OneofDescriptorProto>(bytes)?),
+                Ok(58) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(74) => self.reserved_range.push(r.read_message::<google::protobuf::mod_DescriptorProto::ReservedRange>(bytes)?),
+                Ok(82) => self.reserved_name.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.field.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extension.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.nested_type.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.enum_type.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extension_range.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.oneof_decl.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.reserved_range.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.reserved_name.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        for s in &self.field { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.extension { w.write_with_tag(50, |w| w.write_message(s))?; }
+        for s in &self.nested_type { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.enum_type { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.extension_range { w.write_with_tag(42, |w| w.write_message(s))?; }
+        for s in &self.oneof_decl { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(58, |w| w.write_message(s))?; }
+        for s in &self.reserved_range { w.write_with_tag(74, |w| w.write_message(s))?; }
+        for s in &self.reserved_name { w.write_with_tag(82, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DescriptorProto`
+pub mod mod_DescriptorProto {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ExtensionRange<'a> {
+    /// Inclusive.
+    pub start: Option<i32>,
+    /// Exclusive.
+    pub end: Option<i32>,
+    pub options: Option<google::protobuf::ExtensionRangeOptions<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ExtensionRange<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.start = Default::default();
+        self.end = Default::default();
+        self.options = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.start = Some(r.read_int32(bytes)?),
+                Ok(16) => self.end = Some(r.read_int32(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ExtensionRange<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.start.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.end.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.start { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.end { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Range of reserved tag numbers. Reserved tag numbers may not be used by
+/// fields or extension ranges in the same message. Reserved ranges may
+/// not overlap.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ReservedRange {
+    /// Inclusive.
+    pub start: Option<i32>,
+    /// Exclusive.
+    pub end: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for ReservedRange {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.start = Default::default();
+        self.end = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.start = Some(r.read_int32(bytes)?),
+                Ok(16) => self.end = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for ReservedRange {
+    fn get_size(&self) -> usize {
+        0
+        + self.start.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.end.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.start { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.end { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ExtensionRangeOptions<'a> {
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for ExtensionRangeOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ExtensionRangeOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for ExtensionRangeOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Describes a field within a message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FieldDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub number: Option<i32>,
+    pub label: Option<google::protobuf::mod_FieldDescriptorProto::Label>,
+    /// If type_name is set, this need not be set.  If both this and type_name
+    /// are set, this must be one of TYPE_ENUM, TYPE_MESSAGE or TYPE_GROUP.
+    pub type_pb: Option<google::protobuf::mod_FieldDescriptorProto::Type>,
+    /// For message and enum types, this is the name of the type.  If the name
+    /// starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
+    /// rules are used to find the type (i.e. first the nested types within this
+    /// message are searched, then within the parent, on up to the root
+    /// namespace).
+    pub type_name: Option<Cow<'a, str>>,
+    /// For extensions, this is the name of the type being extended.  It is
+    /// resolved in the same manner as type_name.
+    pub extendee: Option<Cow<'a, str>>,
+    /// For numeric types, contains the original text representation of the value.
+    /// For booleans, "true" or "false".
+    /// For strings, contains the default text contents (not escaped in any way).
+    /// For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+    /// TODO(kenton):  Base-64 encode?
+    pub default_value: Option<Cow<'a, str>>,
+    /// If set, gives the index of a oneof in the containing type's oneof_decl
+    /// list.  This field is a member of that oneof.
+    pub oneof_index: Option<i32>,
+    /// JSON name of this field. The value is set by protocol compiler. If the
+    /// user has set a "json_name" option on this field, that option's value
+    /// will be used. Otherwise, it's deduced from the field's name by converting
+    /// it to camelCase.
+    pub json_name: Option<Cow<'a, str>>,
+    pub options: Option<google::protobuf::FieldOptions<'a>>,
+    /// If true, this is a proto3 "optional". When a proto3 field is optional, it
+    /// tracks presence regardless of field type.
+    ///
+    /// When proto3_optional is true, this field must be belong to a oneof to
+    /// signal to old proto3 clients that presence is tracked for this field. This
+    /// oneof is known as a "synthetic" oneof, and this field must be its sole
+    /// member (each proto3 optional field gets its own synthetic oneof). Synthetic
+    /// oneofs exist in the descriptor only, and do not generate any API. Synthetic
+    /// oneofs must be ordered after all "real" oneofs.
+    ///
+    /// For message fields, proto3_optional doesn't create any semantic change,
+    /// since non-repeated message fields always track presence. However it still
+    /// indicates the semantic detail of whether the user wrote "optional" or not.
+    /// This can be useful for round-tripping the .proto file. For consistency we
+    /// give message fields a synthetic oneof also, even though it is not required
+    /// to track presence. This is especially important because the parser can't
+    /// tell if a field is a message or an enum, so it must always create a
+    /// synthetic oneof.
+    ///
+    /// Proto2 optional fields do not set this flag, because they already indicate
+    /// optional with `LABEL_OPTIONAL`.
+    pub proto3_optional: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for FieldDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.number = Default::default();
+        self.label = Default::default();
+        self.type_pb = Default::default();
+        self.type_name = Default::default();
+        self.extendee = Default::default();
+        self.default_value = Default::default();
+        self.oneof_index = Default::default();
+        self.json_name = Default::default();
+        self.options = Default::default();
+        self.proto3_optional = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(24) => self.number = Some(r.read_int32(bytes)?),
+                Ok(32) => self.label = Some(r.read_enum(bytes)?),
+                Ok(40) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(50) => self.type_name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.extendee = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => self.default_value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(72) => self.oneof_index = Some(r.read_int32(bytes)?),
+                Ok(82) => self.json_name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(66) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(136) => self.proto3_optional = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FieldDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.number.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.label.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.type_name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.extendee.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.default_value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.oneof_index.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.json_name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.proto3_optional.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.number { w.write_with_tag(24, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.label { w.write_with_tag(32, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.type_pb { w.write_with_tag(40, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.type_name { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.extendee { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.default_value { w.write_with_tag(58, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.oneof_index { w.write_with_tag(72, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.json_name { w.write_with_tag(82, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.proto3_optional { w.write_with_tag(136, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `FieldDescriptorProto`
+pub mod mod_FieldDescriptorProto {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    /// 0 is reserved for errors.
+    /// Order is weird for historical reasons.
+    TYPE_DOUBLE = 1,
+    TYPE_FLOAT = 2,
+    /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT64 if
+    /// negative values are likely.
+    TYPE_INT64 = 3,
+    TYPE_UINT64 = 4,
+    /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT32 if
+    /// negative values are likely.
+    TYPE_INT32 = 5,
+    TYPE_FIXED64 = 6,
+    TYPE_FIXED32 = 7,
+    TYPE_BOOL = 8,
+    TYPE_STRING = 9,
+    /// Tag-delimited aggregate.
+    /// Group type is deprecated and not supported in proto3. However, Proto3
+    /// implementations should still be able to parse the group wire format and
+    /// treat group fields as unknown fields.
+    TYPE_GROUP = 10,
+    /// Length-delimited aggregate.
+    TYPE_MESSAGE = 11,
+    /// New in version 2.
+    TYPE_BYTES = 12,
+    TYPE_UINT32 = 13,
+    TYPE_ENUM = 14,
+    TYPE_SFIXED32 = 15,
+    TYPE_SFIXED64 = 16,
+    /// Uses ZigZag encoding.
+    TYPE_SINT32 = 17,
+    /// Uses ZigZag encoding.
+    TYPE_SINT64 = 18,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::TYPE_DOUBLE
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Type::TYPE_DOUBLE,
+            2 => Type::TYPE_FLOAT,
+            3 => Type::TYPE_INT64,
+            4 => Type::TYPE_UINT64,
+            5 => Type::TYPE_INT32,
+            6 => Type::TYPE_FIXED64,
+            7 => Type::TYPE_FIXED32,
+            8 => Type::TYPE_BOOL,
+            9 => Type::TYPE_STRING,
+            10 => Type::TYPE_GROUP,
+            11 => Type::TYPE_MESSAGE,
+            12 => Type::TYPE_BYTES,
+            13 => Type::TYPE_UINT32,
+            14 => Type::TYPE_ENUM,
+            15 => Type::TYPE_SFIXED32,
+            16 => Type::TYPE_SFIXED64,
+            17 => Type::TYPE_SINT32,
+            18 => Type::TYPE_SINT64,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "TYPE_DOUBLE" => Type::TYPE_DOUBLE,
+            "TYPE_FLOAT" => Type::TYPE_FLOAT,
+            "TYPE_INT64" => Type::TYPE_INT64,
+            "TYPE_UINT64" => Type::TYPE_UINT64,
+            "TYPE_INT32" => Type::TYPE_INT32,
+            "TYPE_FIXED64" => Type::TYPE_FIXED64,
+            "TYPE_FIXED32" => Type::TYPE_FIXED32,
+            "TYPE_BOOL" => Type::TYPE_BOOL,
+            "TYPE_STRING" => Type::TYPE_STRING,
+            "TYPE_GROUP" => Type::TYPE_GROUP,
+            "TYPE_MESSAGE" => Type::TYPE_MESSAGE,
+            "TYPE_BYTES" => Type::TYPE_BYTES,
+            "TYPE_UINT32" => Type::TYPE_UINT32,
+            "TYPE_ENUM" => Type::TYPE_ENUM,
+            "TYPE_SFIXED32" => Type::TYPE_SFIXED32,
+            "TYPE_SFIXED64" => Type::TYPE_SFIXED64,
+            "TYPE_SINT32" => Type::TYPE_SINT32,
+            "TYPE_SINT64" => Type::TYPE_SINT64,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Label {
+    /// 0 is reserved for errors
+    LABEL_OPTIONAL = 1,
+    LABEL_REQUIRED = 2,
+    LABEL_REPEATED = 3,
+}
+
+impl Default for Label {
+    fn default() -> Self {
+        Label::LABEL_OPTIONAL
+    }
+}
+
+impl From<i32> for Label {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Label::LABEL_OPTIONAL,
+            2 => Label::LABEL_REQUIRED,
+            3 => Label::LABEL_REPEATED,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Label {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "LABEL_OPTIONAL" => Label::LABEL_OPTIONAL,
+            "LABEL_REQUIRED" => Label::LABEL_REQUIRED,
+            "LABEL_REPEATED" => Label::LABEL_REPEATED,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a oneof.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OneofDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub options: Option<google::protobuf::OneofOptions<'a>>,
+}
+
+impl<'a> MessageRead<'a> for OneofDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.options = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for OneofDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes an enum type.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub value: Vec<google::protobuf::EnumValueDescriptorProto<'a>>,
+    pub options: Option<google::protobuf::EnumOptions<'a>>,
+    /// Range of reserved numeric values. Reserved numeric values may not be used
+    /// by enum values in the same enum declaration. Reserved ranges may not
+    /// overlap.
+    pub reserved_range: Vec<google::protobuf::mod_EnumDescriptorProto::EnumReservedRange>,
+    /// Reserved enum value names, which may not be reused. A given name may only
+    /// be reserved once.
+    pub reserved_name: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for EnumDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.value.clear();
+        self.options = Default::default();
+        self.reserved_range.clear();
+        self.reserved_name.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.value.push(r.read_message::<google::protobuf::EnumValueDescriptorProto>(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(34) => self.reserved_range.push(r.read_message::<google::protobuf::mod_EnumDescriptorProto::EnumReservedRange>(bytes)?),
+                Ok(42) => self.reserved_name.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for EnumDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.value.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.reserved_range.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.reserved_name.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        for s in &self.value { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.reserved_range { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.reserved_name { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `EnumDescriptorProto`
+pub mod mod_EnumDescriptorProto {
+
+use super::*;
+
+/// Range of reserved numeric values. Reserved values may not be used by
+/// entries in the same enum. Reserved ranges may not overlap.
+///
+/// Note that this is distinct from DescriptorProto.ReservedRange in that it
+/// is inclusive such that it can appropriately represent the entire int32
+/// domain.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumReservedRange {
+    /// Inclusive.
+    pub start: Option<i32>,
+    /// Inclusive.
+    pub end: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for EnumReservedRange {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.start = Default::default();
+        self.end = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.start = Some(r.read_int32(bytes)?),
+                Ok(16) => self.end = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for EnumReservedRange {
+    fn get_size(&self) -> usize {
+        0
+        + self.start.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.end.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.start { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.end { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
+/// Describes a value within an enum.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumValueDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub number: Option<i32>,
+    pub options: Option<google::protobuf::EnumValueOptions<'a>>,
+}
+
+impl<'a> MessageRead<'a> for EnumValueDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.number = Default::default();
+        self.options = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(16) => self.number = Some(r.read_int32(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for EnumValueDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.number.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.number { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ServiceDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    pub method: Vec<google::protobuf::MethodDescriptorProto<'a>>,
+    pub options: Option<google::protobuf::ServiceOptions<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ServiceDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.method.clear();
+        self.options = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.method.push(r.read_message::<google::protobuf::MethodDescriptorProto>(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ServiceDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.method.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        for s in &self.method { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a method of a service.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MethodDescriptorProto<'a> {
+    pub name: Option<Cow<'a, str>>,
+    /// Input and output type names.  These are resolved in the same way as
+    /// FieldDescriptorProto.type_name, but must refer to a message type.
+    pub input_type: Option<Cow<'a, str>>,
+    pub output_type: Option<Cow<'a, str>>,
+    pub options: Option<google::protobuf::MethodOptions<'a>>,
+    /// Identifies if client streams multiple client messages
+    pub client_streaming: bool,
+    /// Identifies if server streams multiple server messages
+    pub server_streaming: bool,
+}
+
+impl<'a> MessageRead<'a> for MethodDescriptorProto<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.input_type = Default::default();
+        self.output_type = Default::default();
+        self.options = Default::default();
+        self.client_streaming = Default::default();
+        self.server_streaming = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.input_type = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => self.output_type = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => r.merge_message(bytes, self.options.get_or_insert_with(Default::default))?,
+                Ok(40) => self.client_streaming = r.read_bool(bytes)?,
+                Ok(48) => self.server_streaming = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MethodDescriptorProto<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.input_type.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.output_type.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.client_streaming == false { 0 } else { 1 + sizeof_varint(*(&self.client_streaming) as u64) }
+        + if self.server_streaming == false { 0 } else { 1 + sizeof_varint(*(&self.server_streaming) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.input_type { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.output_type { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.options { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if self.client_streaming != false { w.write_with_tag(40, |w| w.write_bool(*&self.client_streaming))?; }
+        if self.server_streaming != false { w.write_with_tag(48, |w| w.write_bool(*&self.server_streaming))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FileOptions<'a> {
+    /// Sets the Java package where classes generated from this .proto will be
+    /// placed.  By default, the proto package is used, but this is often
+    /// inappropriate because proto packages do not normally start with backwards
+    /// domain names.
+    pub java_package: Option<Cow<'a, str>>,
+    /// If set, all the classes from the .proto file are wrapped in a single
+    /// outer class with the given name.  This applies to both Proto1
+    /// (equivalent to the old "--one_java_file" option) and Proto2 (where
+    /// a .proto always translates to a single class, but you may want to
+    /// explicitly choose the class name).
+    pub java_outer_classname: Option<Cow<'a, str>>,
+    /// If set true, then the Java code generator will generate a separate .java
+    /// file for each top-level message, enum, and service defined in the .proto
+    /// file.  Thus, these types will *not* be nested inside the outer class
+    /// named by java_outer_classname.  However, the outer class will still be
+    /// generated to contain the file's getDescriptor() method as well as any
+    /// top-level extensions defined in the file.
+    pub java_multiple_files: bool,
+    /// If set true, then the Java2 code generator will generate code that
+    /// throws an exception whenever an attempt is made to assign a non-UTF-8
+    /// byte sequence to a string field.
+    /// Message reflection will do the same.
+    /// However, an extension field still accepts non-UTF-8 byte sequences.
+    /// This option has no effect on when used with the lite runtime.
+    pub java_string_check_utf8: bool,
+    pub optimize_for: google::protobuf::mod_FileOptions::OptimizeMode,
+    /// Sets the Go package where structs generated from this .proto will be
+    /// placed. If omitted, the Go package will be derived from the following:
+    ///   - The basename of the package import path, if provided.
+    ///   - Otherwise, the package statement in the .proto file, if present.
+    ///   - Otherwise, the basename of the .proto file, without extension.
+    pub go_package: Option<Cow<'a, str>>,
+    /// Should generic services be generated in each language?  "Generic" services
+    /// are not specific to any particular RPC system.  They are generated by the
+    /// main code generators in each language (without additional plugins).
+    /// Generic services were the only kind of service generation supported by
+    /// early versions of google.protobuf.
+    ///
+    /// Generic services are now considered deprecated in favor of using plugins
+    /// that generate code specific to your particular RPC system.  Therefore,
+    /// these default to false.  Old code which depends on generic services should
+    /// explicitly set them to true.
+    pub cc_generic_services: bool,
+    pub java_generic_services: bool,
+    pub py_generic_services: bool,
+    pub php_generic_services: bool,
+    /// Is this file deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for everything in the file, or it will be completely ignored; in the very
+    /// least, this is a formalization for deprecating files.
+    pub deprecated: bool,
+    /// Enables the use of arenas for the proto messages in this file. This applies
+    /// only to generated classes for C++.
+    pub cc_enable_arenas: bool,
+    /// Sets the objective c class prefix which is prepended to all objective c
+    /// generated classes from this .proto. There is no default.
+    pub objc_class_prefix: Option<Cow<'a, str>>,
+    /// Namespace for generated classes; defaults to the package.
+    pub csharp_namespace: Option<Cow<'a, str>>,
+    /// By default Swift generators will take the proto package and CamelCase it
+    /// replacing '.' with underscore and use that to prefix the types/symbols
+    /// defined. When this options is provided, they will use this value instead
+    /// to prefix the types/symbols defined.
+    pub swift_prefix: Option<Cow<'a, str>>,
+    /// Sets the php class prefix which is prepended to all php generated classes
+    /// from this .proto. Default is empty.
+    pub php_class_prefix: Option<Cow<'a, str>>,
+    /// Use this option to change the namespace of php generated classes. Default
+    /// is empty. When this option is empty, the package name will be used for
+    /// determining the namespace.
+    pub php_namespace: Option<Cow<'a, str>>,
+    /// Use this option to change the namespace of php generated metadata classes.
+    /// Default is empty. When this option is empty, the proto file name will be
+    /// used for determining the namespace.
+    pub php_metadata_namespace: Option<Cow<'a, str>>,
+    /// Use this option to change the package of ruby generated classes. Default
+    /// is empty. When this option is not set, the package name will be used for
+    /// determining the ruby package.
+    pub ruby_package: Option<Cow<'a, str>>,
+    /// The parser stores options it doesn't recognize here.
+    /// See the documentation for the "Options" section above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for FileOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = FileOptions {
+            cc_enable_arenas: true,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.java_package = Default::default();
+        self.java_outer_classname = Default::default();
+        self.java_multiple_files = Default::default();
+        self.java_string_check_utf8 = Default::default();
+        self.optimize_for = Default::default();
+        self.go_package = Default::default();
+        self.cc_generic_services = Default::default();
+        self.java_generic_services = Default::default();
+        self.py_generic_services = Default::default();
+        self.php_generic_services = Default::default();
+        self.deprecated = Default::default();
+        self.cc_enable_arenas = true;
+        self.objc_class_prefix = Default::default();
+        self.csharp_namespace = Default::default();
+        self.swift_prefix = Default::default();
+        self.php_class_prefix = Default::default();
+        self.php_namespace = Default::default();
+        self.php_metadata_namespace = Default::default();
+        self.ruby_package = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.java_package = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(66) => self.java_outer_classname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(80) => self.java_multiple_files = r.read_bool(bytes)?,
+                Ok(216) => self.java_string_check_utf8 = r.read_bool(bytes)?,
+                Ok(72) => self.optimize_for = r.read_enum(bytes)?,
+                Ok(90) => self.go_package = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(128) => self.cc_generic_services = r.read_bool(bytes)?,
+                Ok(136) => self.java_generic_services = r.read_bool(bytes)?,
+                Ok(144) => self.py_generic_services = r.read_bool(bytes)?,
+                Ok(336) => self.php_generic_services = r.read_bool(bytes)?,
+                Ok(184) => self.deprecated = r.read_bool(bytes)?,
+                Ok(248) => self.cc_enable_arenas = r.read_bool(bytes)?,
+                Ok(290) => self.objc_class_prefix = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(298) => self.csharp_namespace = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(314) => self.swift_prefix = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(322) => self.php_class_prefix = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(330) => self.php_namespace = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(354) => self.php_metadata_namespace = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(362) => self.ruby_package = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FileOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.java_package.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.java_outer_classname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + if self.java_multiple_files == false { 0 } else { 1 + sizeof_varint(*(&self.java_multiple_files) as u64) }
+        + if self.java_string_check_utf8 == false { 0 } else { 2 + sizeof_varint(*(&self.java_string_check_utf8) as u64) }
+        + if self.optimize_for == google::protobuf::mod_FileOptions::OptimizeMode::SPEED { 0 } else { 1 + sizeof_varint(*(&self.optimize_for) as u64) }
+        + self.go_package.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + if self.cc_generic_services == false { 0 } else { 2 + sizeof_varint(*(&self.cc_generic_services) as u64) }
+        + if self.java_generic_services == false { 0 } else { 2 + sizeof_varint(*(&self.java_generic_services) as u64) }
+        + if self.py_generic_services == false { 0 } else { 2 + sizeof_varint(*(&self.py_generic_services) as u64) }
+        + if self.php_generic_services == false { 0 } else { 2 + sizeof_varint(*(&self.php_generic_services) as u64) }
+        + if self.deprecated == false { 0 } else { 2 + sizeof_varint(*(&self.deprecated) as u64) }
+        + if self.cc_enable_arenas == true { 0 } else { 2 + sizeof_varint(*(&self.cc_enable_arenas) as u64) }
+        + self.objc_class_prefix.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.csharp_namespace.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.swift_prefix.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.php_class_prefix.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.php_namespace.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.php_metadata_namespace.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.ruby_package.as_ref().map_or(0, |m| 2 + sizeof_len((m).len()))
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.java_package { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.java_outer_classname { w.write_with_tag(66, |w| w.write_string(&**s))?; }
+        if self.java_multiple_files != false { w.write_with_tag(80, |w| w.write_bool(*&self.java_multiple_files))?; }
+        if self.java_string_check_utf8 != false { w.write_with_tag(216, |w| w.write_bool(*&self.java_string_check_utf8))?; }
+        if self.optimize_for != google::protobuf::mod_FileOptions::OptimizeMode::SPEED { w.write_with_tag(72, |w| w.write_enum(*&self.optimize_for as i32))?; }
+        if let Some(ref s) = self.go_package { w.write_with_tag(90, |w| w.write_string(&**s))?; }
+        if self.cc_generic_services != false { w.write_with_tag(128, |w| w.write_bool(*&self.cc_generic_services))?; }
+        if self.java_generic_services != false { w.write_with_tag(136, |w| w.write_bool(*&self.java_generic_services))?; }
+        if self.py_generic_services != false { w.write_with_tag(144, |w| w.write_bool(*&self.py_generic_services))?; }
+        if self.php_generic_services != false { w.write_with_tag(336, |w| w.write_bool(*&self.php_generic_services))?; }
+        if self.deprecated != false { w.write_with_tag(184, |w| w.write_bool(*&self.deprecated))?; }
+        if self.cc_enable_arenas != true { w.write_with_tag(248, |w| w.write_bool(*&self.cc_enable_arenas))?; }
+        if let Some(ref s) = self.objc_class_prefix { w.write_with_tag(290, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.csharp_namespace { w.write_with_tag(298, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.swift_prefix { w.write_with_tag(314, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.php_class_prefix { w.write_with_tag(322, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.php_namespace { w.write_with_tag(330, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.php_metadata_namespace { w.write_with_tag(354, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.ruby_package { w.write_with_tag(362, |w| w.write_string(&**s))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for FileOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Nested messages, enums and oneofs of `FileOptions`
+pub mod mod_FileOptions {
+
+
+/// Generated classes can be optimized for speed or code size.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OptimizeMode {
+    /// Generate complete code for parsing, serialization,
+    SPEED = 1,
+    /// etc.
+    ///
+    /// Use ReflectionOps to implement these methods.
+    CODE_SIZE = 2,
+    /// Generate code using MessageLite and the lite runtime.
+    LITE_RUNTIME = 3,
+}
+
+impl Default for OptimizeMode {
+    fn default() -> Self {
+        OptimizeMode::SPEED
+    }
+}
+
+impl From<i32> for OptimizeMode {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => OptimizeMode::SPEED,
+            2 => OptimizeMode::CODE_SIZE,
+            3 => OptimizeMode::LITE_RUNTIME,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OptimizeMode {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "SPEED" => OptimizeMode::SPEED,
+            "CODE_SIZE" => OptimizeMode::CODE_SIZE,
+            "LITE_RUNTIME" => OptimizeMode::LITE_RUNTIME,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MessageOptions<'a> {
+    /// Set true to use the old proto1 MessageSet wire format for extensions.
+    /// This is provided for backwards-compatibility with the MessageSet wire
+    /// format.  You should not use this for any other reason:  It's less
+    /// efficient, has fewer features, and is more complicated.
+    ///
+    /// The message must be defined exactly as follows:
+    ///   message Foo {
+    ///     option message_set_wire_format = true;
+    ///     extensions 4 to max;
+    ///   }
+    /// Note that the message cannot have any defined fields; MessageSets only
+    /// have extensions.
+    ///
+    /// All extensions of your type must be singular messages; e.g. they cannot
+    /// be int32s, enums, or repeated messages.
+    ///
+    /// Because this is an option, the above two restrictions are not enforced by
+    /// the protocol compiler.
+    pub message_set_wire_format: bool,
+    /// Disables the generation of the standard "descriptor()" accessor, which can
+    /// conflict with a field of the same name.  This is meant to make migration
+    /// from proto1 easier; new code should avoid fields named "descriptor".
+    pub no_standard_descriptor_accessor: bool,
+    /// Is this message deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for the message, or it will be completely ignored; in the very least,
+    /// this is a formalization for deprecating messages.
+    pub deprecated: bool,
+    /// Whether the message is an automatically generated map entry type for the
+    /// maps field.
+    ///
+    /// For maps fields:
+    ///     map<KeyType, ValueType> map_field = 1;
+    /// The parsed descriptor looks like:
+    ///     message MapFieldEntry {
+    ///         option map_entry = true;
+    ///         optional KeyType key = 1;
+    ///         optional ValueType value = 2;
+    ///     }
+    ///     repeated MapFieldEntry map_field = 1;
+    ///
+    /// Implementations may choose not to generate the map_entry=true message, but
+    /// use a native map in the target language to hold the keys and values.
+    /// The reflection APIs in such implementations still need to work as
+    /// if the field is a repeated message field.
+    ///
+    /// NOTE: Do not set the option in .proto files. Always use the maps syntax
+    /// instead. The option should only be implicitly set by the proto compiler
+    /// parser.
+    pub map_entry: Option<bool>,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for MessageOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.message_set_wire_format = Default::default();
+        self.no_standard_descriptor_accessor = Default::default();
+        self.deprecated = Default::default();
+        self.map_entry = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.message_set_wire_format = r.read_bool(bytes)?,
+                Ok(16) => self.no_standard_descriptor_accessor = r.read_bool(bytes)?,
+                Ok(24) => self.deprecated = r.read_bool(bytes)?,
+                Ok(56) => self.map_entry = Some(r.read_bool(bytes)?),
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MessageOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.message_set_wire_format == false { 0 } else { 1 + sizeof_varint(*(&self.message_set_wire_format) as u64) }
+        + if self.no_standard_descriptor_accessor == false { 0 } else { 1 + sizeof_varint(*(&self.no_standard_descriptor_accessor) as u64) }
+        + if self.deprecated == false { 0 } else { 1 + sizeof_varint(*(&self.deprecated) as u64) }
+        + self.map_entry.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.message_set_wire_format != false { w.write_with_tag(8, |w| w.write_bool(*&self.message_set_wire_format))?; }
+        if self.no_standard_descriptor_accessor != false { w.write_with_tag(16, |w| w.write_bool(*&self.no_standard_descriptor_accessor))?; }
+        if self.deprecated != false { w.write_with_tag(24, |w| w.write_bool(*&self.deprecated))?; }
+        if let Some(ref s) = self.map_entry { w.write_with_tag(56, |w| w.write_bool(*s))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for MessageOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FieldOptions<'a> {
+    /// The ctype option instructs the C++ code generator to use a different
+    /// representation of the field than it normally would.  See the specific
+    /// options below.  This option is not yet implemented in the open source
+    /// release -- sorry, we'll try to include it in a future version!
+    pub ctype: google::protobuf::mod_FieldOptions::CType,
+    /// The packed option can be enabled for repeated primitive fields to enable
+    /// a more efficient representation on the wire. Rather than repeatedly
+    /// writing the tag and type for each element, the entire array is encoded as
+    /// a single length-delimited blob. In proto3, only explicit setting it to
+    /// false will avoid using packed encoding.
+    pub packed: Option<bool>,
+    /// The jstype option determines the JavaScript type used for values of the
+    /// field.  The option is permitted only for 64 bit integral and fixed types
+    /// (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
+    /// is represented as JavaScript string, which avoids loss of precision that
+    /// can happen when a large value is converted to a floating point JavaScript.
+    /// Specifying JS_NUMBER for the jstype causes the generated JavaScript code to
+    /// use the JavaScript "number" type.  The behavior of the default option
+    /// JS_NORMAL is implementation dependent.
+    ///
+    /// This option is an enum to permit additional types to be added, e.g.
+    /// goog.math.Integer.
+    pub jstype: google::protobuf::mod_FieldOptions::JSType,
+    /// Should this field be parsed lazily?  Lazy applies only to message-type
+    /// fields.  It means that when the outer message is initially parsed, the
+    /// inner message's contents will not be parsed but instead stored in encoded
+    /// form.  The inner message will actually be parsed when it is first accessed.
+    ///
+    /// This is only a hint.  Implementations are free to choose whether to use
+    /// eager or lazy parsing regardless of the value of this option.  However,
+    /// setting this option true suggests that the protocol author believes that
+    /// using lazy parsing on this field is worth the additional bookkeeping
+    /// overhead typically needed to implement it.
+    ///
+    /// This option does not affect the public interface of any generated code;
+    /// all method signatures remain the same.  Furthermore, thread-safety of the
+    /// interface is not affected by this option; const methods remain safe to
+    /// call from multiple threads concurrently, while non-const methods continue
+    /// to require exclusive access.
+    ///
+    ///
+    /// Note that implementations may choose not to check required fields within
+    /// a lazy sub-message.  That is, calling IsInitialized() on the outer message
+    /// may return true even if the inner message has missing required fields.
+    /// This is necessary because otherwise the inner message would have to be
+    /// parsed in order to perform the check, defeating the purpose of lazy
+    /// parsing.  An implementation which chooses not to check required fields
+    /// must be consistent about it.  That is, for any particular sub-message, the
+    /// implementation must either *always* check its required fields, or *never*
+    /// check its required fields, regardless of whether or not the message has
+    /// been parsed.
+    pub lazy: bool,
+    /// Is this field deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for accessors, or it will be completely ignored; in the very least, this
+    /// is a formalization for deprecating fields.
+    pub deprecated: bool,
+    /// For Google-internal migration only. Do not use.
+    pub weak: bool,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for FieldOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.ctype = Default::default();
+        self.packed = Default::default();
+        self.jstype = Default::default();
+        self.lazy = Default::default();
+        self.deprecated = Default::default();
+        self.weak = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.ctype = r.read_enum(bytes)?,
+                Ok(16) => self.packed = Some(r.read_bool(bytes)?),
+                Ok(48) => self.jstype = r.read_enum(bytes)?,
+                Ok(40) => self.lazy = r.read_bool(bytes)?,
+                Ok(24) => self.deprecated = r.read_bool(bytes)?,
+                Ok(80) => self.weak = r.read_bool(bytes)?,
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FieldOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.ctype == google::protobuf::mod_FieldOptions::CType::STRING { 0 } else { 1 + sizeof_varint(*(&self.ctype) as u64) }
+        + self.packed.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + if self.jstype == google::protobuf::mod_FieldOptions::JSType::JS_NORMAL { 0 } else { 1 + sizeof_varint(*(&self.jstype) as u64) }
+        + if self.lazy == false { 0 } else { 1 + sizeof_varint(*(&self.lazy) as u64) }
+        + if self.deprecated == false { 0 } else { 1 + sizeof_varint(*(&self.deprecated) as u64) }
+        + if self.weak == false { 0 } else { 1 + sizeof_varint(*(&self.weak) as u64) }
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.ctype != google::protobuf::mod_FieldOptions::CType::STRING { w.write_with_tag(8, |w| w.write_enum(*&self.ctype as i32))?; }
+        if let Some(ref s) = self.packed { w.write_with_tag(16, |w| w.write_bool(*s))?; }
+        if self.jstype != google::protobuf::mod_FieldOptions::JSType::JS_NORMAL { w.write_with_tag(48, |w| w.write_enum(*&self.jstype as i32))?; }
+        if self.lazy != false { w.write_with_tag(40, |w| w.write_bool(*&self.lazy))?; }
+        if self.deprecated != false { w.write_with_tag(24, |w| w.write_bool(*&self.deprecated))?; }
+        if self.weak != false { w.write_with_tag(80, |w| w.write_bool(*&self.weak))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for FieldOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Nested messages, enums and oneofs of `FieldOptions`
+pub mod mod_FieldOptions {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CType {
+    /// Default mode.
+    STRING = 0,
+    CORD = 1,
+    STRING_PIECE = 2,
+}
+
+impl Default for CType {
+    fn default() -> Self {
+        CType::STRING
+    }
+}
+
+impl From<i32> for CType {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => CType::STRING,
+            1 => CType::CORD,
+            2 => CType::STRING_PIECE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CType {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "STRING" => CType::STRING,
+            "CORD" => CType::CORD,
+            "STRING_PIECE" => CType::STRING_PIECE,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum JSType {
+    /// Use the default type.
+    JS_NORMAL = 0,
+    /// Use JavaScript strings.
+    JS_STRING = 1,
+    /// Use JavaScript numbers.
+    JS_NUMBER = 2,
+}
+
+impl Default for JSType {
+    fn default() -> Self {
+        JSType::JS_NORMAL
+    }
+}
+
+impl From<i32> for JSType {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => JSType::JS_NORMAL,
+            1 => JSType::JS_STRING,
+            2 => JSType::JS_NUMBER,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JSType {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "JS_NORMAL" => JSType::JS_NORMAL,
+            "JS_STRING" => JSType::JS_STRING,
+            "JS_NUMBER" => JSType::JS_NUMBER,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OneofOptions<'a> {
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for OneofOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for OneofOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for OneofOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumOptions<'a> {
+    /// Set this option to true to allow mapping different tag names to the same
+    /// value.
+    pub allow_alias: Option<bool>,
+    /// Is this enum deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for the enum, or it will be completely ignored; in the very least, this
+    /// is a formalization for deprecating enums.
+    pub deprecated: bool,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for EnumOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.allow_alias = Default::default();
+        self.deprecated = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(16) => self.allow_alias = Some(r.read_bool(bytes)?),
+                Ok(24) => self.deprecated = r.read_bool(bytes)?,
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for EnumOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.allow_alias.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + if self.deprecated == false { 0 } else { 1 + sizeof_varint(*(&self.deprecated) as u64) }
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.allow_alias { w.write_with_tag(16, |w| w.write_bool(*s))?; }
+        if self.deprecated != false { w.write_with_tag(24, |w| w.write_bool(*&self.deprecated))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for EnumOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumValueOptions<'a> {
+    /// Is this enum value deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for the enum value, or it will be completely ignored; in the very least,
+    /// this is a formalization for deprecating enum values.
+    pub deprecated: bool,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for EnumValueOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.deprecated = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.deprecated = r.read_bool(bytes)?,
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for EnumValueOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.deprecated == false { 0 } else { 1 + sizeof_varint(*(&self.deprecated) as u64) }
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.deprecated != false { w.write_with_tag(8, |w| w.write_bool(*&self.deprecated))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for EnumValueOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ServiceOptions<'a> {
+    /// Is this service deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for the service, or it will be completely ignored; in the very least,
+    /// this is a formalization for deprecating services.
+    pub deprecated: bool,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for ServiceOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.deprecated = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(264) => self.deprecated = r.read_bool(bytes)?,
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ServiceOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.deprecated == false { 0 } else { 2 + sizeof_varint(*(&self.deprecated) as u64) }
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.deprecated != false { w.write_with_tag(264, |w| w.write_bool(*&self.deprecated))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for ServiceOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MethodOptions<'a> {
+    /// Is this method deprecated?
+    /// Depending on the target platform, this can emit Deprecated annotations
+    /// for the method, or it will be completely ignored; in the very least,
+    /// this is a formalization for deprecating methods.
+    pub deprecated: bool,
+    pub idempotency_level: google::protobuf::mod_MethodOptions::IdempotencyLevel,
+    /// The parser stores options it doesn't recognize here. See above.
+    pub uninterpreted_option: Vec<google::protobuf::UninterpretedOption<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for MethodOptions<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.deprecated = Default::default();
+        self.idempotency_level = Default::default();
+        self.uninterpreted_option.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(264) => self.deprecated = r.read_bool(bytes)?,
+                Ok(272) => self.idempotency_level = r.read_enum(bytes)?,
+                Ok(7994) => self.uninterpreted_option.push(r.read_message::<google::protobuf::UninterpretedOption>(bytes)?),
+                Ok(t) if matches!(t >> 3, 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MethodOptions<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.deprecated == false { 0 } else { 2 + sizeof_varint(*(&self.deprecated) as u64) }
+        + if self.idempotency_level == google::protobuf::mod_MethodOptions::IdempotencyLevel::IDEMPOTENCY_UNKNOWN { 0 } else { 2 + sizeof_varint(*(&self.idempotency_level) as u64) }
+        + self.uninterpreted_option.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.deprecated != false { w.write_with_tag(264, |w| w.write_bool(*&self.deprecated))?; }
+        if self.idempotency_level != google::protobuf::mod_MethodOptions::IdempotencyLevel::IDEMPOTENCY_UNKNOWN { w.write_with_tag(272, |w| w.write_enum(*&self.idempotency_level as i32))?; }
+        for s in &self.uninterpreted_option { w.write_with_tag(7994, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for MethodOptions<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Nested messages, enums and oneofs of `MethodOptions`
+pub mod mod_MethodOptions {
+
+
+/// Is this method side-effect-free (or safe in HTTP parlance), or idempotent,
+/// or neither? HTTP based RPC implementation may choose GET verb for safe
+/// methods, and PUT verb for idempotent methods instead of the default POST.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IdempotencyLevel {
+    IDEMPOTENCY_UNKNOWN = 0,
+    /// implies idempotent
+    NO_SIDE_EFFECTS = 1,
+    /// idempotent, but may have side effects
+    IDEMPOTENT = 2,
+}
+
+impl Default for IdempotencyLevel {
+    fn default() -> Self {
+        IdempotencyLevel::IDEMPOTENCY_UNKNOWN
+    }
+}
+
+impl From<i32> for IdempotencyLevel {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => IdempotencyLevel::IDEMPOTENCY_UNKNOWN,
+            1 => IdempotencyLevel::NO_SIDE_EFFECTS,
+            2 => IdempotencyLevel::IDEMPOTENT,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IdempotencyLevel {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "IDEMPOTENCY_UNKNOWN" => IdempotencyLevel::IDEMPOTENCY_UNKNOWN,
+            "NO_SIDE_EFFECTS" => IdempotencyLevel::NO_SIDE_EFFECTS,
+            "IDEMPOTENT" => IdempotencyLevel::IDEMPOTENT,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// A message representing a option the parser does not recognize. This only
+/// appears in options protos created by the compiler::Parser class.
+/// DescriptorPool resolves these when building Descriptor objects. Therefore,
+/// options protos in descriptor objects (e.g. returned by Descriptor::options(),
+/// or produced by Descriptor::CopyTo()) will never have UninterpretedOptions
+/// in them.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct UninterpretedOption<'a> {
+    pub name: Vec<google::protobuf::mod_UninterpretedOption::NamePart<'a>>,
+    /// The value of the uninterpreted option, in whatever type the tokenizer
+    /// identified it as during parsing. Exactly one of these should be set.
+    pub identifier_value: Option<Cow<'a, str>>,
+    pub positive_int_value: Option<u64>,
+    pub negative_int_value: Option<i64>,
+    pub double_value: Option<f64>,
+    pub string_value: Option<Cow<'a, [u8]>>,
+    pub aggregate_value: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for UninterpretedOption<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.identifier_value = Default::default();
+        self.positive_int_value = Default::default();
+        self.negative_int_value = Default::default();
+        self.double_value = Default::default();
+        self.string_value = Default::default();
+        self.aggregate_value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(18) => self.name.push(r.read_message::<google::protobuf::mod_UninterpretedOption::NamePart>(bytes)?),
+                Ok(26) => self.identifier_value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(32) => self.positive_int_value = Some(r.read_uint64(bytes)?),
+                Ok(40) => self.negative_int_value = Some(r.read_int64(bytes)?),
+                Ok(49) => self.double_value = Some(r.read_double(bytes)?),
+                Ok(58) => self.string_value = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(66) => self.aggregate_value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for UninterpretedOption<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.identifier_value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.positive_int_value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.negative_int_value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.double_value.as_ref().map_or(0, |_| 1 + 8)
+        + self.string_value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.aggregate_value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.name { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.identifier_value { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.positive_int_value { w.write_with_tag(32, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.negative_int_value { w.write_with_tag(40, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.double_value { w.write_with_tag(49, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.string_value { w.write_with_tag(58, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.aggregate_value { w.write_with_tag(66, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `UninterpretedOption`
+pub mod mod_UninterpretedOption {
+
+use std::borrow::Cow;
+use super::*;
+
+/// The name of the uninterpreted option.  Each string represents a segment in
+/// a dot-separated name.  is_extension is true iff a segment represents an
+/// extension (denoted with parentheses in options specs in .proto files).
+/// E.g.,{ ["foo", false], ["bar.baz", true], ["qux", false] } represents
+/// "foo.(bar.baz).qux".
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NamePart<'a> {
+    pub name_part: Cow<'a, str>,
+    pub is_extension: bool,
+}
+
+impl<'a> MessageRead<'a> for NamePart<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name_part = Default::default();
+        self.is_extension = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name_part = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.is_extension = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for NamePart<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name_part).len())
+        + 1 + sizeof_varint(*(&self.is_extension) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name_part))?;
+        w.write_with_tag(16, |w| w.write_bool(*&self.is_extension))?;
+        Ok(())
+    }
+}
+
+}
+
+/// Encapsulates information about the original source file from which a
+/// FileDescriptorProto was generated.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SourceCodeInfo<'a> {
+    /// A Location identifies a piece of source code in a .proto file which
+    /// corresponds to a particular definition.  This information is intended
+    /// to be useful to IDEs, code indexers, documentation generators, and similar
+    /// tools.
+    ///
+    /// For example, say we have a file like:
+    ///   message Foo {
+    ///     optional string foo = 1;
+    ///   }
+    /// Let's look at just the field definition:
+    ///   optional string foo = 1;
+    ///   ^       ^^     ^^  ^  ^^^
+    ///   a       bc     de  f  ghi
+    /// We have the following locations:
+    ///   span   path               represents
+    ///   [a,i)  [ 4, 0, 2, 0 ]     The whole field definition.
+    ///   [a,b)  [ 4, 0, 2, 0, 4 ]  The label (optional).
+    ///   [c,d)  [ 4, 0, 2, 0, 5 ]  The type (string).
+    ///   [e,f)  [ 4, 0, 2, 0, 1 ]  The name (foo).
+    ///   [g,h)  [ 4, 0, 2, 0, 3 ]  The number (1).
+    ///
+    /// Notes:
+    /// - A location may refer to a repeated field itself (i.e. not to any
+    ///   particular index within it).  This is used whenever a set of elements are
+    ///   logically enclosed in a single code segment.  For example, an entire
+    ///   extend block (possibly containing multiple extension definitions) will
+    ///   have an outer location whose path refers to the "extensions" repeated
+    ///   field without an index.
+    /// - Multiple locations may have the same path.  This happens when a single
+    ///   logical declaration is spread out across multiple places.  The most
+    ///   obvious example is the "extend" block again -- there may be multiple
+    ///   extend blocks in the same scope, each of which will have the same path.
+    /// - A location's span is not always a subset of its parent's span.  For
+    ///   example, the "extendee" of an extension declaration appears at the
+    ///   beginning of the "extend" block and is shared by all extensions within
+    ///   the block.
+    /// - Just because a location's span is a subset of some other location's span
+    ///   does not mean that it is a descendant.  For example, a "group" defines
+    ///   both a type and a field in a single declaration.  Thus, the locations
+    ///   corresponding to the type and field and their components will overlap.
+    /// - Code which tries to interpret locations should probably be designed to
+    ///   ignore those that it doesn't understand, as more types of locations could
+    ///   be recorded in the future.
+    pub location: Vec<google::protobuf::mod_SourceCodeInfo::Location<'a>>,
+}
+
+impl<'a> MessageRead<'a> for SourceCodeInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.location.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.location.push(r.read_message::<google::protobuf::mod_SourceCodeInfo::Location>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for SourceCodeInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.location.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.location { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `SourceCodeInfo`
+pub mod mod_SourceCodeInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Location<'a> {
+    /// Identifies which part of the FileDescriptorProto was defined at this
+    /// location.
+    ///
+    /// Each element is a field number or an index.  They form a path from
+    /// the root FileDescriptorProto to the place where the definition.  For
+    /// example, this path:
+    ///   [ 4, 3, 2, 7, 1 ]
+    /// refers to:
+    ///   file.message_type(3)  // 4, 3
+    ///       .field(7)         // 2, 7
+    ///       .name()           // 1
+    /// This is because FileDescriptorProto.message_type has field number 4:
+    ///   repeated DescriptorProto message_type = 4;
+    /// and DescriptorProto.field has field number 2:
+    ///   repeated FieldDescriptorProto field = 2;
+    /// and FieldDescriptorProto.name has field number 1:
+    ///   optional string name = 1;
+    ///
+    /// Thus, the above path gives the location of a field name.  If we removed
+    /// the last element:
+    ///   [ 4, 3, 2, 7 ]
+    /// this path refers to the whole field declaration (from the beginning
+    /// of the label to the terminating semicolon).
+    pub path: Vec<i32>,
+    /// Always has exactly three or four elements: start line, start column,
+    /// end line (optional, otherwise assumed same as start line), end column.
+    /// These are packed into a single field for efficiency.  Note that line
+    /// and column numbers are zero-based -- typically you will want to add
+    /// 1 to each before displaying to a user.
+    pub span: Vec<i32>,
+    /// If this SourceCodeInfo represents a complete declaration, these are any
+    /// comments appearing before and after the declaration which appear to be
+    /// attached to the declaration.
+    ///
+    /// A series of line comments appearing on consecutive lines, with no other
+    /// tokens appearing on those lines, will be treated as a single comment.
+    ///
+    /// leading_detached_comments will keep paragraphs of comments that appear
+    /// before (but not connected to) the current element. Each paragraph,
+    /// separated by empty lines, will be one comment element in the repeated
+    /// field.
+    ///
+    /// Only the comment content is provided; comment markers (e.g. //) are
+    /// stripped out.  For block comments, leading whitespace and an asterisk
+    /// will be stripped from the beginning of each line other than the first.
+    /// Newlines are included in the output.
+    ///
+    /// Examples:
+    ///
+    ///   optional int32 foo = 1;  // Comment attached to foo.
+    ///   // Comment attached to bar.
+    ///   optional int32 bar = 2;
+    ///
+    ///   optional string baz = 3;
+    ///   // Comment attached to baz.
+    ///   // Another line attached to baz.
+    ///
+    ///   // Comment attached to qux.
+    ///   //
+    ///   // Another line attached to qux.
+    ///   optional double qux = 4;
+    ///
+    ///   // Detached comment for corge. This is not leading or trailing comments
+    ///   // to qux or corge because there are blank lines separating it from
+    ///   // both.
+    ///
+    ///   // Detached comment for corge paragraph 2.
+    ///
+    ///   optional string corge = 5;
+    ///   /* Block comment attached
+    ///    * to corge.  Leading asterisks
+    ///    * will be removed. */
+    ///   /* Block comment attached to
+    ///    * grault. */
+    ///   optional int32 grault = 6;
+    ///
+    ///   // ignored detached comments.
+    pub leading_comments: Option<Cow<'a, str>>,
+    pub trailing_comments: Option<Cow<'a, str>>,
+    pub leading_detached_comments: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Location<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.path.clear();
+        self.span.clear();
+        self.leading_comments = Default::default();
+        self.trailing_comments = Default::default();
+        self.leading_detached_comments.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.read_packed_into(bytes, &mut self.path, |r, bytes| Ok(r.read_int32(bytes)?))?,
+                Ok(18) => r.read_packed_into(bytes, &mut self.span, |r, bytes| Ok(r.read_int32(bytes)?))?,
+                Ok(26) => self.leading_comments = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.trailing_comments = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => self.leading_detached_comments.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Location<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.path.is_empty() { 0 } else { 1 + sizeof_len(self.path.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + if self.span.is_empty() { 0 } else { 1 + sizeof_len(self.span.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + self.leading_comments.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.trailing_comments.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.leading_detached_comments.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_packed_with_tag(10, &self.path, |w, m| w.write_int32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        w.write_packed_with_tag(18, &self.span, |w, m| w.write_int32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        if let Some(ref s) = self.leading_comments { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.trailing_comments { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        for s in &self.leading_detached_comments { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+}
+
+/// Describes the relationship between generated code and its original source
+/// file. A GeneratedCodeInfo message is associated with only one generated
+/// source file, but may contain references to different source .proto files.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct GeneratedCodeInfo<'a> {
+    /// An Annotation connects some span of text in generated code to an element
+    /// of its generating .proto file.
+    pub annotation: Vec<google::protobuf::mod_GeneratedCodeInfo::Annotation<'a>>,
+}
+
+impl<'a> MessageRead<'a> for GeneratedCodeInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.annotation.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.annotation.push(r.read_message::<google::protobuf::mod_GeneratedCodeInfo::Annotation>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for GeneratedCodeInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.annotation.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.annotation { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `GeneratedCodeInfo`
+pub mod mod_GeneratedCodeInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Annotation<'a> {
+    /// Identifies the element in the original source .proto file. This field
+    /// is formatted the same as SourceCodeInfo.Location.path.
+    pub path: Vec<i32>,
+    /// Identifies the filesystem path to the original source .proto.
+    pub source_file: Option<Cow<'a, str>>,
+    /// Identifies the starting offset in bytes in the generated code
+    /// that relates to the identified object.
+    pub begin: Option<i32>,
+    /// Identifies the ending offset in bytes in the generated code that
+    /// relates to the identified offset. The end offset should be one past
+    /// the last relevant byte (so the length of the text = end - begin).
+    pub end: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for Annotation<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.path.clear();
+        self.source_file = Default::default();
+        self.begin = Default::default();
+        self.end = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.read_packed_into(bytes, &mut self.path, |r, bytes| Ok(r.read_int32(bytes)?))?,
+                Ok(18) => self.source_file = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(24) => self.begin = Some(r.read_int32(bytes)?),
+                Ok(32) => self.end = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Annotation<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.path.is_empty() { 0 } else { 1 + sizeof_len(self.path.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + self.source_file.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.begin.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.end.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_packed_with_tag(10, &self.path, |w, m| w.write_int32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        if let Some(ref s) = self.source_file { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.begin { w.write_with_tag(24, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.end { w.write_with_tag(32, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
@@ -0,0 +1,363 @@
+// Automatically generated rust module for 'plugin.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::super::super::*;
+
+/// The version number of protocol compiler.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Version<'a> {
+    pub major: Option<i32>,
+    pub minor: Option<i32>,
+    pub patch: Option<i32>,
+    /// A suffix for alpha, beta or rc release, e.g., "alpha-1", "rc2". It should
+    /// be empty for mainline stable releases.
+    pub suffix: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Version<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.major = Default::default();
+        self.minor = Default::default();
+        self.patch = Default::default();
+        self.suffix = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.major = Some(r.read_int32(bytes)?),
+                Ok(16) => self.minor = Some(r.read_int32(bytes)?),
+                Ok(24) => self.patch = Some(r.read_int32(bytes)?),
+                Ok(34) => self.suffix = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Version<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.major.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.minor.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.patch.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.suffix.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.major { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.minor { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.patch { w.write_with_tag(24, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.suffix { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// An encoded CodeGeneratorRequest is written to the plugin's stdin.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CodeGeneratorRequest<'a> {
+    /// The .proto files that were explicitly listed on the command-line.  The
+    /// code generator should generate code only for these files.  Each file's
+    /// descriptor will be included in proto_file, below.
+    pub file_to_generate: Vec<Cow<'a, str>>,
+    /// The generator parameter passed on the command-line.
+    pub parameter: Option<Cow<'a, str>>,
+    /// FileDescriptorProtos for all files in files_to_generate and everything
+    /// they import.  The files will appear in topological order, so each file
+    /// appears before any file that imports it.
+    ///
+    /// protoc guarantees that all proto_files will be written after
+    /// the fields above, even though this is not technically guaranteed by the
+    /// protobuf wire format.  This theoretically could allow a plugin to stream
+    /// in the FileDescriptorProtos and handle them one by one rather than read
+    /// the entire set into memory at once.  However, as of this writing, this
+    /// is not similarly optimized on protoc's end -- it will store all fields in
+    /// memory at once before sending them to the plugin.
+    ///
+    /// Type names of fields and extensions in the FileDescriptorProto are always
+    /// fully qualified.
+    pub proto_file: Vec<google::protobuf::FileDescriptorProto<'a>>,
+    /// The version number of protocol compiler.
+    pub compiler_version: Option<google::protobuf::compiler::Version<'a>>,
+}
+
+impl<'a> MessageRead<'a> for CodeGeneratorRequest<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.file_to_generate.clear();
+        self.parameter = Default::default();
+        self.proto_file.clear();
+        self.compiler_version = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.file_to_generate.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.parameter = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(122) => self.proto_file.push(r.read_message::<google::protobuf::FileDescriptorProto>(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.compiler_version.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CodeGeneratorRequest<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.file_to_generate.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.parameter.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.proto_file.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.compiler_version.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.file_to_generate { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.parameter { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        for s in &self.proto_file { w.write_with_tag(122, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.compiler_version { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// The plugin writes an encoded CodeGeneratorResponse to stdout.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CodeGeneratorResponse<'a> {
+    /// Error message.  If non-empty, code generation failed.  The plugin process
+    /// should exit with status code zero even if it reports an error in this way.
+    ///
+    /// This should be used to indicate errors in .proto files which prevent the
+    /// code generator from generating correct code.  Errors which indicate a
+    /// problem in protoc itself -- such as the input CodeGeneratorRequest being
+    /// unparseable -- should be reported by writing a message to stderr and
+    /// exiting with a non-zero status code.
+    pub error: Option<Cow<'a, str>>,
+    /// A bitmask of supported features that the code generator supports.
+    /// This is a bitwise "or" of values from the Feature enum.
+    pub supported_features: Option<u64>,
+    pub file: Vec<google::protobuf::compiler::mod_CodeGeneratorResponse::File<'a>>,
+}
+
+impl<'a> MessageRead<'a> for CodeGeneratorResponse<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.error = Default::default();
+        self.supported_features = Default::default();
+        self.file.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.error = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(16) => self.supported_features = Some(r.read_uint64(bytes)?),
+                Ok(122) => self.file.push(r.read_message::<google::protobuf::compiler::mod_CodeGeneratorResponse::File>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CodeGeneratorResponse<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.error.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.supported_features.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.file.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.error { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.supported_features { w.write_with_tag(16, |w| w.write_uint64(*s))?; }
+        for s in &self.file { w.write_with_tag(122, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CodeGeneratorResponse`
+pub mod mod_CodeGeneratorResponse {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Represents a single generated file.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct File<'a> {
+    /// The file name, relative to the output directory.  The name must not
+    /// contain "." or ".." components and must be relative, not be absolute (so,
+    /// the file cannot lie outside the output directory).  "/" must be used as
+    /// the path separator, not "\".
+    ///
+    /// If the name is omitted, the content will be appended to the previous
+    /// file.  This allows the generator to break large files into small chunks,
+    /// and allows the generated text to be streamed back to protoc so that large
+    /// files need not reside completely in memory at one time.  Note that as of
+    /// this writing protoc does not optimize for this -- it will read the entire
+    /// CodeGeneratorResponse before writing files to disk.
+    pub name: Option<Cow<'a, str>>,
+    /// If non-empty, indicates that the named file should already exist, and the
+    /// content here is to be inserted into that file at a defined insertion
+    /// point.  This feature allows a code generator to extend the output
+    /// produced by another code generator.  The original generator may provide
+    /// insertion points by placing special annotations in the file that look
+    /// like:
+    ///   @@protoc_insertion_point(NAME)
+    /// The annotation can have arbitrary text before and after it on the line,
+    /// which allows it to be placed in a comment.  NAME should be replaced with
+    /// an identifier naming the point -- this is what other generators will use
+    /// as the insertion_point.  Code inserted at this point will be placed
+    /// immediately above the line containing the insertion point (thus multiple
+    /// insertions to the same point will come out in the order they were added).
+    /// The double-@ is intended to make it unlikely that the generated code
+    /// could contain things that look like insertion points by accident.
+    ///
+    /// For example, the C++ code generator places the following line in the
+    /// .pb.h files that it generates:
+    ///   // @@protoc_insertion_point(namespace_scope)
+    /// This line appears within the scope of the file's package namespace, but
+    /// outside of any particular class.  Another plugin can then specify the
+    /// insertion_point "namespace_scope" to generate additional classes or
+    /// other declarations that should be placed in this scope.
+    ///
+    /// Note that if the line containing the insertion point begins with
+    /// whitespace, the same whitespace will be added to every line of the
+    /// inserted text.  This is useful for languages like Python, where
+    /// indentation matters.  In these languages, the insertion point comment
+    /// should be indented the same amount as any inserted code will need to be
+    /// in order to work correctly in that context.
+    ///
+    /// The code generator that generates the initial file and the one which
+    /// inserts into it must both run as part of a single invocation of protoc.
+    /// Code generators are executed in the order in which they appear on the
+    /// command line.
+    ///
+    /// If |insertion_point| is present, |name| must also be present.
+    pub insertion_point: Option<Cow<'a, str>>,
+    /// The file contents.
+    pub content: Option<Cow<'a, str>>,
+    /// Information describing the file content being inserted. If an insertion
+    /// point is used, this information will be appropriately offset and inserted
+    /// into the code generation metadata for the generated files.
+    pub generated_code_info: Option<google::protobuf::GeneratedCodeInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for File<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.insertion_point = Default::default();
+        self.content = Default::default();
+        self.generated_code_info = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.insertion_point = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(122) => self.content = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(130) => r.merge_message(bytes, self.generated_code_info.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for File<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.insertion_point.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.content.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.generated_code_info.as_ref().map_or(0, |m| 2 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.name { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.insertion_point { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.content { w.write_with_tag(122, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.generated_code_info { w.write_with_tag(130, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Sync with code_generator.h.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Feature {
+    FEATURE_NONE = 0,
+    FEATURE_PROTO3_OPTIONAL = 1,
+}
+
+impl Default for Feature {
+    fn default() -> Self {
+        Feature::FEATURE_NONE
+    }
+}
+
+impl From<i32> for Feature {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Feature::FEATURE_NONE,
+            1 => Feature::FEATURE_PROTO3_OPTIONAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Feature {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "FEATURE_NONE" => Feature::FEATURE_NONE,
+            "FEATURE_PROTO3_OPTIONAL" => Feature::FEATURE_PROTO3_OPTIONAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
@@ -0,0 +1,1623 @@
+//! Conversions between parsed .proto files and `google.protobuf.FileDescriptorSet`
+//!
+//! The set is encoded with quick-protobuf and is the same as the one written by
+//! `protoc --descriptor_set_out`, except that options pb-rs does not know are kept as
+//! `uninterpreted_option` and comments are not exported.
+//!
+//! Sets written by protoc can be used instead of .proto files to generate code.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use quick_protobuf::extensions::{self, Extension, Extensions};
+use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
+
+use crate::descriptor::google::protobuf::{
+    mod_DescriptorProto::{ExtensionRange, ReservedRange},
+    mod_FieldDescriptorProto::{Label, Type},
+    mod_UninterpretedOption::NamePart,
+    DescriptorProto, EnumDescriptorProto, EnumOptions, EnumValueDescriptorProto, EnumValueOptions,
+    FieldDescriptorProto, FieldOptions, FileDescriptorProto, FileDescriptorSet, FileOptions,
+    MessageOptions, MethodDescriptorProto, MethodOptions, OneofDescriptorProto, OneofOptions,
+    ServiceDescriptorProto, ServiceOptions, UninterpretedOption,
+};
+use crate::errors::{Error, Result};
+use crate::parser::{option_value, unescape};
+use crate::types::{
+    find_import, get_file_stem, get_option, Comments, EnumField, Enumerator, Extend, Field,
+    FieldType, FileDescriptor, Frequency, Message, MessageIndex, OneOf, OptionValue, ProtoOption,
+    RpcFunctionDeclaration, RpcService, Syntax,
+};
+
+/// Reads `in_files` and returns their `FileDescriptorSet`
+///
+/// With `include_imports`, the imported files are part of the set too, before the files
+/// importing them (like `protoc --include_imports`).
+pub fn file_descriptor_set<P: AsRef<Path>>(
+    in_files: &[P],
+    include_paths: &[P],
+    include_imports: bool,
+) -> Result<FileDescriptorSet<'static>> {
+    let mut include_paths = include_paths
+        .iter()
+        .map(|p| p.as_ref().to_path_buf())
+        .collect::<Vec<_>>();
+    let default = PathBuf::from(".");
+    if !include_paths.contains(&default) {
+        include_paths.push(default);
+    }
+
+    let mut set = FileDescriptorSet::default();
+    let mut done = HashSet::new();
+    for in_file in in_files {
+        let in_file = in_file.as_ref();
+        if !in_file.exists() {
+            return Err(Error::InputFile(format!("{}", in_file.display())));
+        }
+        let name = proto_name(in_file, &include_paths);
+        add_file(
+            in_file,
+            name,
+            &include_paths,
+            include_imports,
+            &mut done,
+            &mut set.file,
+        )?;
+    }
+    Ok(set)
+}
+
+/// Writes the encoded `FileDescriptorSet` of `in_files` to `out_file`
+pub fn write_descriptor_set<P: AsRef<Path>>(
+    in_files: &[P],
+    include_paths: &[P],
+    include_imports: bool,
+    out_file: &Path,
+) -> Result<()> {
+    let set = file_descriptor_set(in_files, include_paths, include_imports)?;
+    let mut w = BufWriter::new(File::create(out_file)?);
+    w.write_all(&encode(&set)?)?;
+    w.flush()?;
+    Ok(())
+}
+
+/// Encodes a message without its length prefix, as expected for a descriptor set file
+pub fn encode<M: MessageWrite>(message: &M) -> Result<Vec<u8>> {
+    let mut v = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut v))?;
+    Ok(v)
+}
+
+/// Name of `file` in the set, its path relative to the include path it is found in
+fn proto_name(file: &Path, include_paths: &[PathBuf]) -> String {
+    let name = include_paths
+        .iter()
+        .filter_map(|path| {
+            if path.is_absolute() {
+                file.strip_prefix(path).ok()
+            } else {
+                let dir = file.parent()?.join(path);
+                file.strip_prefix(dir).ok()
+            }
+        })
+        .next()
+        .unwrap_or(file);
+    name.to_string_lossy().replace('\\', "/")
+}
+
+fn add_file(
+    path: &Path,
+    name: String,
+    include_paths: &[PathBuf],
+    include_imports: bool,
+    done: &mut HashSet<String>,
+    files: &mut Vec<FileDescriptorProto<'static>>,
+) -> Result<()> {
+    if !done.insert(name.clone()) {
+        return Ok(());
+    }
+    let mut desc = FileDescriptor::read_proto(path, include_paths)?;
+    if include_imports {
+        for import in &desc.import_paths {
+            match find_import(path, import, include_paths) {
+                Ok(import_path) => {
+                    let import_name = import.to_string_lossy().replace('\\', "/");
+                    add_file(&import_path, import_name, include_paths, true, done, files)?;
+                }
+                // pb-rs does not need this file, it may not be on the import path
+                Err(_) if import == Path::new("rust_options.proto") => (),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    desc.resolve_types()?;
+    files.push(file_proto(&desc, name)?);
+    Ok(())
+}
+
+fn file_proto(desc: &FileDescriptor, name: String) -> Result<FileDescriptorProto<'static>> {
+    let mut file = FileDescriptorProto {
+        name: Some(name.into()),
+        package: non_empty(&desc.package),
+        dependency: desc
+            .import_paths
+            .iter()
+            .map(|p| p.to_string_lossy().replace('\\', "/").into())
+            .collect(),
+        options: file_options(&desc.options),
+        syntax: match desc.syntax {
+            Syntax::Proto2 => None,
+            Syntax::Proto3 => Some("proto3".into()),
+        },
+        ..Default::default()
+    };
+    for m in desc.messages.iter().filter(|m| !m.imported) {
+        file.message_type.push(message_proto(m, desc)?);
+    }
+    for e in desc.enums.iter().filter(|e| !e.imported) {
+        file.enum_type.push(enum_proto(e));
+    }
+    for e in &desc.extends {
+        file.extension.extend(extension_protos(e, desc)?);
+    }
+    for s in &desc.rpc_services {
+        file.service.push(service_proto(s, desc));
+    }
+    Ok(file)
+}
+
+fn message_proto(m: &Message, desc: &FileDescriptor) -> Result<DescriptorProto<'static>> {
+    let full_name = full_name(&m.package, &m.name);
+    let mut proto = DescriptorProto {
+        name: Some(m.name.clone().into()),
+        options: message_options(&m.options),
+        reserved_name: m
+            .reserved_names
+            .iter()
+            .flatten()
+            .map(|n| n.clone().into())
+            .collect(),
+        ..Default::default()
+    };
+
+    // proto3 `optional` fields are in a synthetic oneof, after the real ones
+    let mut synthetic_oneofs = Vec::new();
+    for f in &m.fields {
+        let mut field = field_proto(f, desc)?;
+        if let FieldType::Map(key, value) = &f.typ {
+            let entry_name = map_entry_name(&f.name);
+            field.type_name = Some(format!("{}.{}", full_name, entry_name).into());
+            proto
+                .nested_type
+                .push(map_entry_proto(entry_name, key, value, desc)?);
+        }
+        if f.proto3_optional {
+            field.oneof_index = Some((m.oneofs.len() + synthetic_oneofs.len()) as i32);
+            synthetic_oneofs.push(OneofDescriptorProto {
+                name: Some(format!("_{}", f.name).into()),
+                options: None,
+            });
+        }
+        proto.field.push(field);
+    }
+    for (i, o) in m.oneofs.iter().enumerate() {
+        for f in &o.fields {
+            let mut field = field_proto(f, desc)?;
+            field.oneof_index = Some(i as i32);
+            proto.field.push(field);
+        }
+        proto.oneof_decl.push(oneof_proto(o));
+    }
+    proto.oneof_decl.extend(synthetic_oneofs);
+
+    for nested in &m.messages {
+        proto.nested_type.push(message_proto(nested, desc)?);
+    }
+    for e in &m.enums {
+        proto.enum_type.push(enum_proto(e));
+    }
+    for e in &m.extends {
+        proto.extension.extend(extension_protos(e, desc)?);
+    }
+    proto.extension_range = m
+        .extension_ranges
+        .iter()
+        .map(|&(start, end)| ExtensionRange {
+            start: Some(start),
+            end: Some(end + 1),
+            options: None,
+        })
+        .collect();
+    proto.reserved_range = m
+        .reserved_nums
+        .iter()
+        .flatten()
+        .map(|&(start, end)| ReservedRange {
+            start: Some(start),
+            end: Some(end + 1),
+        })
+        .collect();
+    Ok(proto)
+}
+
+fn map_entry_proto(
+    name: String,
+    key: &FieldType,
+    value: &FieldType,
+    desc: &FileDescriptor,
+) -> Result<DescriptorProto<'static>> {
+    let entry_field =
+        |name: &str, number: i32, typ: &FieldType| -> Result<FieldDescriptorProto<'static>> {
+            let (type_pb, type_name) = field_type(typ, desc)?;
+            Ok(FieldDescriptorProto {
+                name: Some(name.to_string().into()),
+                number: Some(number),
+                label: Some(Label::LABEL_OPTIONAL),
+                type_pb: Some(type_pb),
+                type_name,
+                json_name: Some(name.to_string().into()),
+                ..Default::default()
+            })
+        };
+    Ok(DescriptorProto {
+        name: Some(name.into()),
+        field: vec![entry_field("key", 1, key)?, entry_field("value", 2, value)?],
+        options: Some(MessageOptions {
+            map_entry: Some(true),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+fn field_proto(f: &Field, desc: &FileDescriptor) -> Result<FieldDescriptorProto<'static>> {
+    let (type_pb, type_name) = field_type(&f.typ, desc)?;
+    let label = match (&f.typ, &f.frequency) {
+        (FieldType::Map(..), _) | (_, Frequency::Repeated) => Label::LABEL_REPEATED,
+        (_, Frequency::Optional) => Label::LABEL_OPTIONAL,
+        (_, Frequency::Required) => Label::LABEL_REQUIRED,
+    };
+    let default_value = get_option(&f.options, "default").map(|v| match v {
+        OptionValue::String(s) if type_pb == Type::TYPE_BYTES => c_escape(s),
+        OptionValue::String(s) => s.clone(),
+        v => v.to_string(),
+    });
+    let json_name = match get_option(&f.options, "json_name") {
+        Some(OptionValue::String(s)) => s.clone(),
+        _ => json_name(&f.name),
+    };
+    Ok(FieldDescriptorProto {
+        name: Some(f.name.clone().into()),
+        number: Some(f.number),
+        label: Some(label),
+        type_pb: Some(type_pb),
+        type_name,
+        default_value: default_value.map(Cow::Owned),
+        json_name: Some(json_name.into()),
+        options: field_options(&f.options),
+        proto3_optional: if f.proto3_optional { Some(true) } else { None },
+        ..Default::default()
+    })
+}
+
+fn field_type(typ: &FieldType, desc: &FileDescriptor) -> Result<(Type, Option<Cow<'static, str>>)> {
+    let type_pb = match typ {
+        FieldType::Int32 => Type::TYPE_INT32,
+        FieldType::Int64 => Type::TYPE_INT64,
+        FieldType::Uint32 => Type::TYPE_UINT32,
+        FieldType::Uint64 => Type::TYPE_UINT64,
+        FieldType::Sint32 => Type::TYPE_SINT32,
+        FieldType::Sint64 => Type::TYPE_SINT64,
+        FieldType::Bool => Type::TYPE_BOOL,
+        FieldType::Fixed64 => Type::TYPE_FIXED64,
+        FieldType::Sfixed64 => Type::TYPE_SFIXED64,
+        FieldType::Double => Type::TYPE_DOUBLE,
+        FieldType::Fixed32 => Type::TYPE_FIXED32,
+        FieldType::Sfixed32 => Type::TYPE_SFIXED32,
+        FieldType::Float => Type::TYPE_FLOAT,
+        FieldType::StringCow | FieldType::String_ => Type::TYPE_STRING,
+        FieldType::BytesCow | FieldType::Bytes_ => Type::TYPE_BYTES,
+        FieldType::Enum(e) => {
+            let e = e.get_enum(desc);
+            return Ok((Type::TYPE_ENUM, Some(full_name(&e.package, &e.name).into())));
+        }
+        FieldType::Message(m) => {
+            let m = m.get_message(desc);
+            return Ok((
+                Type::TYPE_MESSAGE,
+                Some(full_name(&m.package, &m.name).into()),
+            ));
+        }
+        // the entry type name is set by the message
+        FieldType::Map(..) => Type::TYPE_MESSAGE,
+        FieldType::MessageOrEnum(name) => return Err(Error::MessageOrEnumNotFound(name.clone())),
+    };
+    Ok((type_pb, None))
+}
+
+fn extension_protos(
+    e: &Extend,
+    desc: &FileDescriptor,
+) -> Result<Vec<FieldDescriptorProto<'static>>> {
+    let extendee = e.index.get_message(desc);
+    let extendee = full_name(&extendee.package, &extendee.name);
+    e.fields
+        .iter()
+        .map(|f| {
+            let mut field = field_proto(f, desc)?;
+            field.extendee = Some(extendee.clone().into());
+            Ok(field)
+        })
+        .collect()
+}
+
+fn oneof_proto(o: &OneOf) -> OneofDescriptorProto<'static> {
+    let mut options = OneofOptions::default();
+    options.uninterpreted_option = o.options.iter().map(uninterpreted_option).collect();
+    OneofDescriptorProto {
+        name: Some(o.name.clone().into()),
+        options: non_default(options),
+    }
+}
+
+fn enum_proto(e: &Enumerator) -> EnumDescriptorProto<'static> {
+    EnumDescriptorProto {
+        name: Some(e.name.clone().into()),
+        value: e
+            .fields
+            .iter()
+            .map(|v| {
+                let mut options = EnumValueOptions::default();
+                for o in &v.options {
+                    match (o.name.as_str(), o.value.as_bool()) {
+                        ("deprecated", Some(b)) => options.deprecated = b,
+                        _ => options.uninterpreted_option.push(uninterpreted_option(o)),
+                    }
+                }
+                EnumValueDescriptorProto {
+                    name: Some(v.name.clone().into()),
+                    number: Some(v.tag),
+                    options: non_default(options),
+                }
+            })
+            .collect(),
+        options: enum_options(&e.options),
+        ..Default::default()
+    }
+}
+
+fn service_proto(s: &RpcService, desc: &FileDescriptor) -> ServiceDescriptorProto<'static> {
+    let type_name = |index: &MessageIndex| {
+        let m = index.get_message(desc);
+        Some(full_name(&m.package, &m.name).into())
+    };
+    ServiceDescriptorProto {
+        name: Some(s.service_name.clone().into()),
+        method: s
+            .functions
+            .iter()
+            .map(|f| MethodDescriptorProto {
+                name: Some(f.name.clone().into()),
+                input_type: type_name(&f.arg_index),
+                output_type: type_name(&f.ret_index),
+                options: method_options(&f.options),
+                client_streaming: f.client_streaming,
+                server_streaming: f.server_streaming,
+            })
+            .collect(),
+        options: service_options(&s.options),
+    }
+}
+
+fn file_options(options: &[ProtoOption]) -> Option<FileOptions<'static>> {
+    let mut opts = FileOptions::default();
+    for o in options {
+        let string = || o.value.as_str().map(|s| Cow::Owned(s.to_string()));
+        match (o.name.as_str(), &o.value, o.value.as_bool()) {
+            ("java_package", OptionValue::String(_), _) => opts.java_package = string(),
+            ("java_outer_classname", OptionValue::String(_), _) => {
+                opts.java_outer_classname = string()
+            }
+            ("go_package", OptionValue::String(_), _) => opts.go_package = string(),
+            ("objc_class_prefix", OptionValue::String(_), _) => opts.objc_class_prefix = string(),
+            ("csharp_namespace", OptionValue::String(_), _) => opts.csharp_namespace = string(),
+            ("swift_prefix", OptionValue::String(_), _) => opts.swift_prefix = string(),
+            ("php_class_prefix", OptionValue::String(_), _) => opts.php_class_prefix = string(),
+            ("php_namespace", OptionValue::String(_), _) => opts.php_namespace = string(),
+            ("php_metadata_namespace", OptionValue::String(_), _) => {
+                opts.php_metadata_namespace = string()
+            }
+            ("ruby_package", OptionValue::String(_), _) => opts.ruby_package = string(),
+            ("optimize_for", OptionValue::Ident(v), _) => opts.optimize_for = v.as_str().into(),
+            ("java_multiple_files", _, Some(b)) => opts.java_multiple_files = b,
+            ("java_string_check_utf8", _, Some(b)) => opts.java_string_check_utf8 = b,
+            ("cc_generic_services", _, Some(b)) => opts.cc_generic_services = b,
+            ("java_generic_services", _, Some(b)) => opts.java_generic_services = b,
+            ("py_generic_services", _, Some(b)) => opts.py_generic_services = b,
+            ("php_generic_services", _, Some(b)) => opts.php_generic_services = b,
+            ("deprecated", _, Some(b)) => opts.deprecated = b,
+            ("cc_enable_arenas", _, Some(b)) => opts.cc_enable_arenas = b,
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+fn message_options(options: &[ProtoOption]) -> Option<MessageOptions<'static>> {
+    let mut opts = MessageOptions::default();
+    for o in options {
+        match (o.name.as_str(), o.value.as_bool()) {
+            ("message_set_wire_format", Some(b)) => opts.message_set_wire_format = b,
+            ("no_standard_descriptor_accessor", Some(b)) => {
+                opts.no_standard_descriptor_accessor = b
+            }
+            ("deprecated", Some(b)) => opts.deprecated = b,
+            ("map_entry", Some(b)) => opts.map_entry = Some(b),
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+fn field_options(options: &[ProtoOption]) -> Option<FieldOptions<'static>> {
+    let mut opts = FieldOptions::default();
+    for o in options {
+        match (o.name.as_str(), &o.value, o.value.as_bool()) {
+            // not options, they have their own `FieldDescriptorProto` fields
+            ("default", _, _) | ("json_name", _, _) => (),
+            ("packed", _, Some(b)) => opts.packed = Some(b),
+            ("deprecated", _, Some(b)) => opts.deprecated = b,
+            ("lazy", _, Some(b)) => opts.lazy = b,
+            ("weak", _, Some(b)) => opts.weak = b,
+            ("ctype", OptionValue::Ident(v), None) => opts.ctype = v.as_str().into(),
+            ("jstype", OptionValue::Ident(v), None) => opts.jstype = v.as_str().into(),
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+fn enum_options(options: &[ProtoOption]) -> Option<EnumOptions<'static>> {
+    let mut opts = EnumOptions::default();
+    for o in options {
+        match (o.name.as_str(), o.value.as_bool()) {
+            ("allow_alias", Some(b)) => opts.allow_alias = Some(b),
+            ("deprecated", Some(b)) => opts.deprecated = b,
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+fn service_options(options: &[ProtoOption]) -> Option<ServiceOptions<'static>> {
+    let mut opts = ServiceOptions::default();
+    for o in options {
+        match (o.name.as_str(), o.value.as_bool()) {
+            ("deprecated", Some(b)) => opts.deprecated = b,
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+fn method_options(options: &[ProtoOption]) -> Option<MethodOptions<'static>> {
+    let mut opts = MethodOptions::default();
+    for o in options {
+        match (o.name.as_str(), &o.value, o.value.as_bool()) {
+            ("deprecated", _, Some(b)) => opts.deprecated = b,
+            ("idempotency_level", OptionValue::Ident(v), _) => {
+                opts.idempotency_level = v.as_str().into()
+            }
+            _ => opts.uninterpreted_option.push(uninterpreted_option(o)),
+        }
+    }
+    non_default(opts)
+}
+
+/// An option pb-rs does not interpret, as protoc would write it before resolving it
+fn uninterpreted_option(o: &ProtoOption) -> UninterpretedOption<'static> {
+    let mut opt = UninterpretedOption {
+        name: option_name_parts(&o.name)
+            .into_iter()
+            .map(|(name_part, is_extension)| NamePart {
+                name_part: name_part.into(),
+                is_extension,
+            })
+            .collect(),
+        ..Default::default()
+    };
+    match &o.value {
+        OptionValue::Ident(s) => opt.identifier_value = Some(s.clone().into()),
+        OptionValue::Number(s) => match s.strip_prefix('-') {
+            Some(abs) => match parse_int(abs) {
+                Some(i) => opt.negative_int_value = Some((i as i64).wrapping_neg()),
+                None => opt.double_value = s.parse().ok(),
+            },
+            None => match parse_int(s) {
+                Some(i) => opt.positive_int_value = Some(i),
+                None => opt.double_value = s.parse().ok(),
+            },
+        },
+        OptionValue::String(s) => opt.string_value = Some(s.as_bytes().to_vec().into()),
+        v @ OptionValue::Aggregate(_) => {
+            let v = v.to_string();
+            opt.aggregate_value = Some(v[1..v.len() - 1].trim().to_string().into());
+        }
+        v @ OptionValue::List(_) => opt.aggregate_value = Some(v.to_string().into()),
+    }
+    opt
+}
+
+/// Splits `(my.option).field` into `[("my.option", true), ("field", false)]`
+fn option_name_parts(name: &str) -> Vec<(String, bool)> {
+    let mut parts = Vec::new();
+    let mut rest = name;
+    while !rest.is_empty() {
+        rest = rest.trim_start_matches('.');
+        if let Some(ext) = rest.strip_prefix('(') {
+            let end = ext.find(')').unwrap_or(ext.len());
+            parts.push((ext[..end].trim_start_matches('.').to_string(), true));
+            rest = ext.get(end + 1..).unwrap_or("");
+        } else {
+            let end = rest.find('.').unwrap_or(rest.len());
+            parts.push((rest[..end].to_string(), false));
+            rest = &rest[end..];
+        }
+    }
+    parts
+}
+
+fn parse_int(s: &str) -> Option<u64> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok()
+    } else if s.len() > 1 && s.starts_with('0') {
+        u64::from_str_radix(&s[1..], 8).ok()
+    } else {
+        s.parse().ok()
+    }
+}
+
+/// Fully qualified name, with a leading `.`
+fn full_name(package: &str, name: &str) -> String {
+    if package.is_empty() {
+        format!(".{}", name)
+    } else {
+        format!(".{}.{}", package, name)
+    }
+}
+
+/// Name of the message generated by protoc for a map field, `map_field` is `MapFieldEntry`
+fn map_entry_name(field: &str) -> String {
+    let mut name = String::with_capacity(field.len() + 5);
+    let mut upper = true;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            name.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name + "Entry"
+}
+
+/// Default JSON name of a field, `foo_bar` is `fooBar`
+fn json_name(field: &str) -> String {
+    let mut name = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            name.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// C escaped bytes, the representation of `bytes` default values
+fn c_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'\n' => escaped.push_str("\\n"),
+            b'\r' => escaped.push_str("\\r"),
+            b'\t' => escaped.push_str("\\t"),
+            b'"' => escaped.push_str("\\\""),
+            b'\'' => escaped.push_str("\\'"),
+            b'\\' => escaped.push_str("\\\\"),
+            0x20..=0x7E => escaped.push(b as char),
+            b => escaped.push_str(&format!("\\{:03o}", b)),
+        }
+    }
+    escaped
+}
+
+fn non_empty(s: &str) -> Option<Cow<'static, str>> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s.to_string().into())
+    }
+}
+
+fn non_default<T: Default + PartialEq>(value: T) -> Option<T> {
+    if value == T::default() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// Decodes a `FileDescriptorSet`, as written by `protoc --descriptor_set_out`
+pub fn decode(bytes: &[u8]) -> Result<FileDescriptorSet<'_>> {
+    let mut reader = BytesReader::from_bytes(bytes);
+    Ok(FileDescriptorSet::from_reader(&mut reader, bytes)?)
+}
+
+/// Converts the files of `set`, read from `path`, into the model pb-rs generates code from
+///
+/// Returns the name of each file in the set with its descriptor. Like
+/// [FileDescriptor::read_proto], the descriptors contain the messages and enums of the imported
+/// files, which must be in the set (`protoc --include_imports`).
+///
+/// Comments are read from the `source_code_info` (`protoc --include_source_info`) and
+/// `rust_options.proto` options from the extensions of the options.
+pub fn file_descriptors(
+    set: &FileDescriptorSet,
+    path: &Path,
+) -> Result<Vec<(String, FileDescriptor)>> {
+    let mut descs = HashMap::new();
+    set.file
+        .iter()
+        .map(|f| {
+            let name = f.name.as_deref().unwrap_or_default();
+            Ok((name.to_string(), with_imports(name, set, path, &mut descs)?))
+        })
+        .collect()
+}
+
+/// The descriptor of the file `name` of the set, with the messages and enums of its imports
+fn with_imports(
+    name: &str,
+    set: &FileDescriptorSet,
+    path: &Path,
+    descs: &mut HashMap<String, FileDescriptor>,
+) -> Result<FileDescriptor> {
+    if let Some(desc) = descs.get(name) {
+        return Ok(desc.clone());
+    }
+    let file = set
+        .file
+        .iter()
+        .find(|f| f.name.as_deref() == Some(name))
+        .ok_or_else(|| {
+            Error::InvalidImport(format!("file {} not found in the descriptor set", name))
+        })?;
+    let mut desc = Converter::new(file).file_descriptor(file, path)?;
+    desc.set_packages();
+    for import in desc.import_paths.clone() {
+        let import_name = import.to_string_lossy().replace('\\', "/");
+        // only declares the options applied by `apply_rust_options`
+        if import_name == "rust_options.proto" {
+            continue;
+        }
+        let f = with_imports(&import_name, set, path, descs)?;
+        desc.add_import(f, path, &import);
+    }
+    descs.insert(name.to_string(), desc.clone());
+    Ok(desc)
+}
+
+/// Converts the elements of a `FileDescriptorProto`
+struct Converter {
+    /// Comments by `SourceCodeInfo` path
+    comments: HashMap<Vec<i32>, Comments>,
+    /// The file imports `rust_options.proto`, whose extensions are decoded
+    rust_options: bool,
+}
+
+impl Converter {
+    fn new(file: &FileDescriptorProto) -> Converter {
+        let lines = |c: Option<&str>| -> Vec<String> {
+            c.map(|c| {
+                    c.strip_suffix('\n')
+                        .unwrap_or(c)
+                        .split('\n')
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let comments = file
+            .source_code_info
+            .iter()
+            .flat_map(|info| &info.location)
+            .map(|l| {
+                let comments = Comments {
+                    detached: l
+                        .leading_detached_comments
+                        .iter()
+                        .map(|c| lines(Some(c)))
+                        .collect(),
+                    leading: lines(l.leading_comments.as_deref()),
+                    trailing: lines(l.trailing_comments.as_deref()),
+                };
+                (l.path.clone(), comments)
+            })
+            .filter(|(_, c)| !c.is_empty())
+            .collect();
+        Converter {
+            comments,
+            rust_options: file.dependency.iter().any(|d| d == "rust_options.proto"),
+        }
+    }
+
+    fn comments(&self, path: &[i32]) -> Comments {
+        self.comments.get(path).cloned().unwrap_or_default()
+    }
+
+    fn file_descriptor(&self, file: &FileDescriptorProto, path: &Path) -> Result<FileDescriptor> {
+        let name = file.name.as_deref().unwrap_or_default();
+        let package = file.package.as_deref().unwrap_or_default().to_string();
+        let mut desc = FileDescriptor {
+            import_paths: file
+                .dependency
+                .iter()
+                .map(|d| PathBuf::from(&**d))
+                .collect(),
+            module: if package.is_empty() {
+                get_file_stem(Path::new(name))?
+            } else {
+                package.clone()
+            },
+            package,
+            syntax: match file.syntax.as_deref() {
+                Some("proto3") => Syntax::Proto3,
+                _ => Syntax::Proto2,
+            },
+            extends: self.extends(&file.extension, &[7])?,
+            options: self.file_options(&file.options),
+            ..Default::default()
+        };
+        for (i, m) in file.message_type.iter().enumerate() {
+            let mut m = self.message(m, &[4, i as i32])?;
+            m.path = path.to_path_buf();
+            desc.messages.push(m);
+        }
+        for (i, e) in file.enum_type.iter().enumerate() {
+            let mut e = self.enumerator(e, &[5, i as i32])?;
+            e.path = path.to_path_buf();
+            desc.enums.push(e);
+        }
+        for (i, s) in file.service.iter().enumerate() {
+            desc.rpc_services
+                .push(self.service(s, &desc.package, &[6, i as i32]));
+        }
+        desc.apply_rust_options()?;
+        Ok(desc)
+    }
+
+    fn message(&self, m: &DescriptorProto, path: &[i32]) -> Result<Message> {
+        let map_entries = m
+            .nested_type
+            .iter()
+            .filter(|n| n.options.as_ref().and_then(|o| o.map_entry) == Some(true))
+            .map(|n| (n.name.as_deref().unwrap_or_default(), n))
+            .collect::<HashMap<_, _>>();
+        let reserved_nums = m
+            .reserved_range
+            .iter()
+            .map(|r| (r.start.unwrap_or_default(), r.end.unwrap_or_default() - 1))
+            .collect::<Vec<_>>();
+        let mut msg = Message {
+            name: m.name.as_deref().unwrap_or_default().to_string(),
+            reserved_nums: Some(reserved_nums).filter(|r| !r.is_empty()),
+            reserved_names: Some(m.reserved_name.iter().map(|n| n.to_string()).collect())
+                .filter(|r: &Vec<_>| !r.is_empty()),
+            extension_ranges: m
+                .extension_range
+                .iter()
+                .map(|r| (r.start.unwrap_or_default(), r.end.unwrap_or_default() - 1))
+                .collect(),
+            extends: self.extends(&m.extension, &child(path, &[6]))?,
+            options: self.message_options(&m.options),
+            comments: self.comments(path),
+            ..Default::default()
+        };
+
+        // synthetic oneofs of proto3 `optional` fields are after the real ones
+        let real_oneofs = m
+            .oneof_decl
+            .iter()
+            .enumerate()
+            .take_while(|(i, _)| {
+                !m.field
+                    .iter()
+                    .any(|f| f.oneof_index == Some(*i as i32) && f.proto3_optional == Some(true))
+            })
+            .count();
+        for (i, o) in m.oneof_decl.iter().take(real_oneofs).enumerate() {
+            msg.oneofs.push(OneOf {
+                name: o.name.as_deref().unwrap_or_default().to_string(),
+                options: self.oneof_options(&o.options),
+                comments: self.comments(&child(path, &[8, i as i32])),
+                ..Default::default()
+            });
+        }
+        for (i, f) in m.field.iter().enumerate() {
+            let field = self.field(f, &child(path, &[2, i as i32]), &map_entries)?;
+            match f.oneof_index {
+                Some(o) if (o as usize) < real_oneofs => msg.oneofs[o as usize].fields.push(field),
+                _ => msg.fields.push(field),
+            }
+        }
+        for (i, n) in m.nested_type.iter().enumerate() {
+            if !map_entries.contains_key(n.name.as_deref().unwrap_or_default()) {
+                msg.messages
+                    .push(self.message(n, &child(path, &[3, i as i32]))?);
+            }
+        }
+        for (i, e) in m.enum_type.iter().enumerate() {
+            msg.enums
+                .push(self.enumerator(e, &child(path, &[4, i as i32]))?);
+        }
+        Ok(msg)
+    }
+
+    fn field(
+        &self,
+        f: &FieldDescriptorProto,
+        path: &[i32],
+        map_entries: &HashMap<&str, &DescriptorProto>,
+    ) -> Result<Field> {
+        let name = f.name.as_deref().unwrap_or_default().to_string();
+        let type_name = f.type_name.as_deref().unwrap_or_default();
+        let map_entry = map_entries
+            .get(type_name.rsplit('.').next().unwrap_or_default())
+            .filter(|_| f.label == Some(Label::LABEL_REPEATED));
+        let typ = match map_entry {
+            Some(entry) => {
+                let entry_type = |number| -> Result<FieldType> {
+                    let f = entry
+                        .field
+                        .iter()
+                        .find(|f| f.number == Some(number))
+                        .ok_or_else(|| {
+                            Error::InvalidMessage(format!("invalid map field {}", name))
+                        })?;
+                    model_field_type(f)
+                };
+                FieldType::Map(Box::new(entry_type(1)?), Box::new(entry_type(2)?))
+            }
+            None => model_field_type(f)?,
+        };
+        let frequency = match f.label {
+            _ if map_entry.is_some() => Frequency::Optional,
+            Some(Label::LABEL_REPEATED) => Frequency::Repeated,
+            Some(Label::LABEL_REQUIRED) => Frequency::Required,
+            _ => Frequency::Optional,
+        };
+
+        let mut options = Vec::new();
+        let default = f.default_value.as_deref().map(|v| {
+            let (default, value) = match f.type_pb {
+                Some(Type::TYPE_STRING) => (format!("{:?}", v), OptionValue::String(v.to_string())),
+                Some(Type::TYPE_BYTES) => {
+                    let v = unescape(v);
+                    (format!("{:?}", v), OptionValue::String(v))
+                }
+                _ if v.starts_with(|c: char| c == '-' || c.is_ascii_digit())
+                    && !v.ends_with("inf") =>
+                {
+                    (v.to_string(), OptionValue::Number(v.to_string()))
+                }
+                _ => (v.to_string(), OptionValue::Ident(v.to_string())),
+            };
+            options.push(ProtoOption {
+                name: "default".to_string(),
+                value,
+            });
+            default
+        });
+        options.extend(self.field_options(&f.options)?);
+        let field_options = f.options.as_ref();
+        Ok(Field {
+            name,
+            frequency,
+            proto3_optional: f.proto3_optional == Some(true),
+            typ,
+            number: f.number.unwrap_or_default(),
+            default,
+            packed: field_options.and_then(|o| o.packed),
+            boxed: false,
+            custom_type: None,
+            skip_debug: false,
+            capacity: None,
+            max_len: None,
+            deprecated: field_options.map(|o| o.deprecated) == Some(true),
+            attributes: Vec::new(),
+            options,
+            comments: self.comments(path),
+        })
+    }
+
+    fn extends(&self, fields: &[FieldDescriptorProto], path: &[i32]) -> Result<Vec<Extend>> {
+        let mut extends: Vec<Extend> = Vec::new();
+        for (i, f) in fields.iter().enumerate() {
+            let extendee = f.extendee.as_deref().unwrap_or_default();
+            let field = self.field(f, &child(path, &[i as i32]), &HashMap::new())?;
+            match extends.iter_mut().find(|e| e.extendee == extendee) {
+                Some(e) => e.fields.push(field),
+                None => extends.push(Extend {
+                    extendee: extendee.to_string(),
+                    fields: vec![field],
+                    ..Default::default()
+                }),
+            }
+        }
+        Ok(extends)
+    }
+
+    fn enumerator(&self, e: &EnumDescriptorProto, path: &[i32]) -> Result<Enumerator> {
+        let mut fields = Vec::new();
+        for (i, v) in e.value.iter().enumerate() {
+            let mut options = Vec::new();
+            if let Some(o) = &v.options {
+                push_bool(&mut options, "deprecated", o.deprecated);
+                options.extend(o.uninterpreted_option.iter().map(proto_option));
+                options.extend(self.rust_options(
+                    &o.extensions,
+                    &[(50000, "variant_attr")],
+                    &[],
+                )?);
+            }
+            fields.push(EnumField {
+                name: v.name.as_deref().unwrap_or_default().to_string(),
+                tag: v.number.unwrap_or_default(),
+                options,
+                comments: self.comments(&child(path, &[2, i as i32])),
+                ..Default::default()
+            });
+        }
+        Ok(Enumerator {
+            name: e.name.as_deref().unwrap_or_default().to_string(),
+            fields,
+            options: self.enum_options(&e.options)?,
+            comments: self.comments(path),
+            ..Default::default()
+        })
+    }
+
+    fn service(&self, s: &ServiceDescriptorProto, package: &str, path: &[i32]) -> RpcService {
+        // names relative to the package, as they would be written in the .proto file
+        let relative = |name: &Option<Cow<str>>| {
+            let name = name.as_deref().unwrap_or_default().trim_start_matches('.');
+            name.strip_prefix(package)
+                .and_then(|n| n.strip_prefix('.'))
+                .filter(|_| !package.is_empty())
+                .unwrap_or(name)
+                .to_string()
+        };
+        RpcService {
+            service_name: s.name.as_deref().unwrap_or_default().to_string(),
+            package: package.to_string(),
+            functions: s
+                .method
+                .iter()
+                .enumerate()
+                .map(|(i, m)| RpcFunctionDeclaration {
+                    name: m.name.as_deref().unwrap_or_default().to_string(),
+                    arg: relative(&m.input_type),
+                    ret: relative(&m.output_type),
+                    client_streaming: m.client_streaming,
+                    server_streaming: m.server_streaming,
+                    options: self.method_options(&m.options),
+                    comments: self.comments(&child(path, &[2, i as i32])),
+                    ..Default::default()
+                })
+                .collect(),
+            options: self.service_options(&s.options),
+            comments: self.comments(path),
+        }
+    }
+
+    fn file_options(&self, options: &Option<FileOptions>) -> Vec<ProtoOption> {
+        let o = match options {
+            Some(o) => o,
+            None => return Vec::new(),
+        };
+        let mut options = Vec::new();
+        let strings = [
+            ("java_package", &o.java_package),
+            ("java_outer_classname", &o.java_outer_classname),
+            ("go_package", &o.go_package),
+            ("objc_class_prefix", &o.objc_class_prefix),
+            ("csharp_namespace", &o.csharp_namespace),
+            ("swift_prefix", &o.swift_prefix),
+            ("php_class_prefix", &o.php_class_prefix),
+            ("php_namespace", &o.php_namespace),
+            ("php_metadata_namespace", &o.php_metadata_namespace),
+            ("ruby_package", &o.ruby_package),
+        ];
+        for (name, value) in strings.iter() {
+            if let Some(value) = value {
+                options.push(ProtoOption {
+                    name: name.to_string(),
+                    value: OptionValue::String(value.to_string()),
+                });
+            }
+        }
+        if o.optimize_for != Default::default() {
+            options.push(ProtoOption {
+                name: "optimize_for".to_string(),
+                value: OptionValue::Ident(format!("{:?}", o.optimize_for)),
+            });
+        }
+        push_bool(&mut options, "java_multiple_files", o.java_multiple_files);
+        push_bool(
+            &mut options,
+            "java_string_check_utf8",
+            o.java_string_check_utf8,
+        );
+        push_bool(&mut options, "cc_generic_services", o.cc_generic_services);
+        push_bool(
+            &mut options,
+            "java_generic_services",
+            o.java_generic_services,
+        );
+        push_bool(&mut options, "py_generic_services", o.py_generic_services);
+        push_bool(&mut options, "php_generic_services", o.php_generic_services);
+        push_bool(&mut options, "deprecated", o.deprecated);
+        push_bool(&mut options, "cc_enable_arenas", o.cc_enable_arenas);
+        options.extend(o.uninterpreted_option.iter().map(proto_option));
+        options
+    }
+
+    fn message_options(&self, options: &Option<MessageOptions>) -> Vec<ProtoOption> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            push_bool(
+                &mut opts,
+                "message_set_wire_format",
+                o.message_set_wire_format,
+            );
+            push_bool(
+                &mut opts,
+                "no_standard_descriptor_accessor",
+                o.no_standard_descriptor_accessor,
+            );
+            push_bool(&mut opts, "deprecated", o.deprecated);
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+            // errors are reported by `apply_rust_options` in the other cases
+            opts.extend(
+                self.rust_options(&o.extensions, &[(50000, "message_attr")], &[])
+                    .unwrap_or_default(),
+            );
+        }
+        opts
+    }
+
+    fn field_options(&self, options: &Option<FieldOptions>) -> Result<Vec<ProtoOption>> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            if let Some(packed) = o.packed {
+                opts.push(ProtoOption {
+                    name: "packed".to_string(),
+                    value: OptionValue::Ident(packed.to_string()),
+                });
+            }
+            push_bool(&mut opts, "deprecated", o.deprecated);
+            push_bool(&mut opts, "lazy", o.lazy);
+            push_bool(&mut opts, "weak", o.weak);
+            if o.ctype != Default::default() {
+                opts.push(ProtoOption {
+                    name: "ctype".to_string(),
+                    value: OptionValue::Ident(format!("{:?}", o.ctype)),
+                });
+            }
+            if o.jstype != Default::default() {
+                opts.push(ProtoOption {
+                    name: "jstype".to_string(),
+                    value: OptionValue::Ident(format!("{:?}", o.jstype)),
+                });
+            }
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+            opts.extend(self.rust_options(
+                &o.extensions,
+                &[(50000, "attr"), (50001, "type")],
+                &[(50002, "box"), (50003, "skip_debug")],
+            )?);
+            opts.extend(
+                self.rust_uint_options(&o.extensions, &[(50004, "capacity"), (50005, "max_len")])?,
+            );
+        }
+        Ok(opts)
+    }
+
+    fn oneof_options(&self, options: &Option<OneofOptions>) -> Vec<ProtoOption> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+            opts.extend(
+                self.rust_options(
+                    &o.extensions,
+                    &[(50000, "oneof_attr"), (50001, "oneof_field_attr")],
+                    &[],
+                )
+                .unwrap_or_default(),
+            );
+        }
+        opts
+    }
+
+    fn enum_options(&self, options: &Option<EnumOptions>) -> Result<Vec<ProtoOption>> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            if let Some(allow_alias) = o.allow_alias {
+                opts.push(ProtoOption {
+                    name: "allow_alias".to_string(),
+                    value: OptionValue::Ident(allow_alias.to_string()),
+                });
+            }
+            push_bool(&mut opts, "deprecated", o.deprecated);
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+            opts.extend(self.rust_options(&o.extensions, &[(50000, "enum_attr")], &[])?);
+        }
+        Ok(opts)
+    }
+
+    fn service_options(&self, options: &Option<ServiceOptions>) -> Vec<ProtoOption> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            push_bool(&mut opts, "deprecated", o.deprecated);
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+        }
+        opts
+    }
+
+    fn method_options(&self, options: &Option<MethodOptions>) -> Vec<ProtoOption> {
+        let mut opts = Vec::new();
+        if let Some(o) = options {
+            push_bool(&mut opts, "deprecated", o.deprecated);
+            if o.idempotency_level != Default::default() {
+                opts.push(ProtoOption {
+                    name: "idempotency_level".to_string(),
+                    value: OptionValue::Ident(format!("{:?}", o.idempotency_level)),
+                });
+            }
+            opts.extend(o.uninterpreted_option.iter().map(proto_option));
+        }
+        opts
+    }
+
+    /// Options of `rust_options.proto` set in `extensions`, by extension number
+    fn rust_options(
+        &self,
+        extensions: &Extensions,
+        strings: &[(u32, &str)],
+        bools: &[(u32, &str)],
+    ) -> Result<Vec<ProtoOption>> {
+        let mut options = Vec::new();
+        if !self.rust_options {
+            return Ok(options);
+        }
+        for &(number, name) in strings {
+            for s in extensions.get_repeated(&Extension::<(), extensions::Str>::new(number))? {
+                options.push(ProtoOption {
+                    name: format!("(rust.{})", name),
+                    value: OptionValue::String(s.to_string()),
+                });
+            }
+        }
+        for &(number, name) in bools {
+            if let Some(b) = extensions.get(&Extension::<(), extensions::Bool>::new(number))? {
+                options.push(ProtoOption {
+                    name: format!("(rust.{})", name),
+                    value: OptionValue::Ident(b.to_string()),
+                });
+            }
+        }
+        Ok(options)
+    }
+
+    /// Same as `rust_options` for `uint32` options
+    fn rust_uint_options(
+        &self,
+        extensions: &Extensions,
+        uints: &[(u32, &str)],
+    ) -> Result<Vec<ProtoOption>> {
+        let mut options = Vec::new();
+        if !self.rust_options {
+            return Ok(options);
+        }
+        for &(number, name) in uints {
+            if let Some(n) = extensions.get(&Extension::<(), extensions::Uint32>::new(number))? {
+                options.push(ProtoOption {
+                    name: format!("(rust.{})", name),
+                    value: OptionValue::Number(n.to_string()),
+                });
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn model_field_type(f: &FieldDescriptorProto) -> Result<FieldType> {
+    let name = f.name.as_deref().unwrap_or_default();
+    Ok(match f.type_pb {
+        Some(Type::TYPE_INT32) => FieldType::Int32,
+        Some(Type::TYPE_INT64) => FieldType::Int64,
+        Some(Type::TYPE_UINT32) => FieldType::Uint32,
+        Some(Type::TYPE_UINT64) => FieldType::Uint64,
+        Some(Type::TYPE_SINT32) => FieldType::Sint32,
+        Some(Type::TYPE_SINT64) => FieldType::Sint64,
+        Some(Type::TYPE_BOOL) => FieldType::Bool,
+        Some(Type::TYPE_FIXED64) => FieldType::Fixed64,
+        Some(Type::TYPE_SFIXED64) => FieldType::Sfixed64,
+        Some(Type::TYPE_DOUBLE) => FieldType::Double,
+        Some(Type::TYPE_FIXED32) => FieldType::Fixed32,
+        Some(Type::TYPE_SFIXED32) => FieldType::Sfixed32,
+        Some(Type::TYPE_FLOAT) => FieldType::Float,
+        Some(Type::TYPE_STRING) => FieldType::StringCow,
+        Some(Type::TYPE_BYTES) => FieldType::BytesCow,
+        Some(Type::TYPE_GROUP) => {
+            return Err(Error::InvalidMessage(format!(
+                "field {} is a group, groups are not supported",
+                name
+            )))
+        }
+        // resolved by `FileDescriptor::resolve_types`
+        Some(Type::TYPE_MESSAGE) | Some(Type::TYPE_ENUM) | None => {
+            FieldType::MessageOrEnum(f.type_name.as_deref().unwrap_or_default().to_string())
+        }
+    })
+}
+
+/// The option as it would be written in a .proto file
+fn proto_option(o: &UninterpretedOption) -> ProtoOption {
+    let name = o
+        .name
+        .iter()
+        .map(|p| {
+            if p.is_extension {
+                format!("({})", p.name_part)
+            } else {
+                p.name_part.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+    let value = if let Some(v) = &o.identifier_value {
+        OptionValue::Ident(v.to_string())
+    } else if let Some(v) = o.positive_int_value {
+        OptionValue::Number(v.to_string())
+    } else if let Some(v) = o.negative_int_value {
+        OptionValue::Number(v.to_string())
+    } else if let Some(v) = o.double_value.filter(|v| v.is_finite()) {
+        OptionValue::Number(format!("{:?}", v))
+    } else if let Some(v) = o.double_value {
+        OptionValue::Ident(v.to_string().to_lowercase())
+    } else if let Some(v) = &o.string_value {
+        OptionValue::String(String::from_utf8_lossy(v).into_owned())
+    } else if let Some(v) = &o.aggregate_value {
+        match option_value(&format!("{{{}}}", v)) {
+            Ok((_, value)) => value,
+            Err(_) => OptionValue::String(v.to_string()),
+        }
+    } else {
+        OptionValue::Ident(String::new())
+    };
+    ProtoOption { name, value }
+}
+
+/// Pushes a `name = true` option if `value` is set
+fn push_bool(options: &mut Vec<ProtoOption>, name: &str, value: bool) {
+    if value {
+        options.push(ProtoOption {
+            name: name.to_string(),
+            value: OptionValue::Ident("true".to_string()),
+        });
+    }
+}
+
+fn child(path: &[i32], tail: &[i32]) -> Vec<i32> {
+    path.iter().chain(tail).cloned().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_option_name_parts() {
+        assert_eq!(
+            vec![("deprecated".to_string(), false)],
+            option_name_parts("deprecated")
+        );
+        assert_eq!(
+            vec![
+                ("my.opt".to_string(), true),
+                ("sub".to_string(), false),
+                ("x".to_string(), false)
+            ],
+            option_name_parts("(my.opt).sub.x")
+        );
+        assert_eq!(
+            vec![("my.opt".to_string(), true)],
+            option_name_parts("(.my.opt)")
+        );
+    }
+
+    #[test]
+    fn test_file_proto() {
+        use crate::descriptor::google::protobuf::mod_FieldOptions::JSType;
+        use crate::descriptor::google::protobuf::mod_MethodOptions::IdempotencyLevel;
+
+        let msg = r#"syntax = "proto3";
+            package a.b;
+            option java_package = "com.example";
+            option (my.file_opt) = { x: 1 };
+            message M {
+                reserved 4, 5, 9;
+                map<string, N> map_field = 1;
+                optional int64 opt = 2 [jstype = JS_STRING];
+                oneof o {
+                    E e = 3;
+                }
+                message N {}
+            }
+            enum E {
+                option allow_alias = true;
+                X = 0;
+                Y = 0 [deprecated = true];
+            }
+            message R {}
+            service S {
+                option deprecated = true;
+                rpc F(stream M) returns (.a.b.R) {
+                    option idempotency_level = NO_SIDE_EFFECTS;
+                }
+            }
+        "#;
+        let dir = std::env::temp_dir().join("pb_rs_test_file_proto");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("b.proto");
+        std::fs::write(&path, msg).unwrap();
+        let mut set = file_descriptor_set(&[path], &[], false).unwrap();
+        assert_eq!(1, set.file.len());
+        let file = set.file.remove(0);
+
+        assert_eq!(Some("b.proto"), file.name.as_deref());
+
+        assert_eq!(Some("a.b"), file.package.as_deref());
+        assert_eq!(Some("proto3"), file.syntax.as_deref());
+        let options = file.options.as_ref().unwrap();
+        assert_eq!(Some("com.example"), options.java_package.as_deref());
+        let uninterpreted = &options.uninterpreted_option[0];
+        assert!(uninterpreted.name[0].is_extension);
+        assert_eq!("my.file_opt", uninterpreted.name[0].name_part);
+        assert_eq!(Some("x: 1"), uninterpreted.aggregate_value.as_deref());
+
+        let m = &file.message_type[0];
+        let map_field = &m.field[0];
+        assert_eq!(Some(Label::LABEL_REPEATED), map_field.label);
+        assert_eq!(Some(".a.b.M.MapFieldEntry"), map_field.type_name.as_deref());
+        assert_eq!(Some("mapField"), map_field.json_name.as_deref());
+        let entry = &m.nested_type[0];
+        assert_eq!(Some(true), entry.options.as_ref().unwrap().map_entry);
+        assert_eq!(Some(".a.b.M.N"), entry.field[1].type_name.as_deref());
+        assert_eq!(Some("N"), m.nested_type[1].name.as_deref());
+
+        let opt = &m.field[1];
+        assert_eq!(Some(true), opt.proto3_optional);
+        assert_eq!(Some(1), opt.oneof_index);
+        assert_eq!(JSType::JS_STRING, opt.options.as_ref().unwrap().jstype);
+        let e = &m.field[2];
+        assert_eq!(Some(Type::TYPE_ENUM), e.type_pb);
+        assert_eq!(Some(".a.b.E"), e.type_name.as_deref());
+        assert_eq!(Some(0), e.oneof_index);
+        assert_eq!(
+            vec![Some("o"), Some("_opt")],
+            m.oneof_decl
+                .iter()
+                .map(|o| o.name.as_deref())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(Some(4), Some(5)), (Some(5), Some(6)), (Some(9), Some(10))],
+            m.reserved_range
+                .iter()
+                .map(|r| (r.start, r.end))
+                .collect::<Vec<_>>()
+        );
+
+        let e = &file.enum_type[0];
+        assert_eq!(Some(true), e.options.as_ref().unwrap().allow_alias);
+        assert!(e.value[1].options.as_ref().unwrap().deprecated);
+
+        let s = &file.service[0];
+        assert!(s.options.as_ref().unwrap().deprecated);
+        let f = &s.method[0];
+        assert_eq!(Some(".a.b.M"), f.input_type.as_deref());
+        assert_eq!(Some(".a.b.R"), f.output_type.as_deref());
+        assert!(f.client_streaming);
+        assert!(!f.server_streaming);
+        assert_eq!(
+            IdempotencyLevel::NO_SIDE_EFFECTS,
+            f.options.as_ref().unwrap().idempotency_level
+        );
+
+        let set = FileDescriptorSet { file: vec![file] };
+        let bytes = encode(&set).unwrap();
+        assert_eq!(set, decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_file_descriptors() {
+        use crate::types::Config;
+        use crate::ConfigBuilder;
+
+        let a = r#"syntax = "proto2";
+            package a;
+            enum E {
+                X = 1;
+                Y = 2;
+            }
+            message A {
+                optional E e = 1 [default = Y];
+            }
+        "#;
+        let b = r#"syntax = "proto2";
+            import "a.proto";
+            message B {
+                reserved 3 to 5;
+                extensions 100 to 199;
+                required a.A a = 1;
+                optional string s = 2 [default = "x\"y"];
+                optional bytes data = 6 [default = "\001z"];
+                repeated float f = 7 [packed = true, deprecated = true];
+                map<string, a.E> map = 8;
+                oneof o {
+                    B b = 9;
+                    N n = 10;
+                }
+                message N {
+                    optional double d = 1 [default = -inf];
+                }
+            }
+            extend B {
+                optional int32 ext = 100;
+            }
+            service S {
+                rpc F(stream .a.A) returns (B.N) {
+                    option deprecated = true;
+                }
+            }
+        "#;
+        let dir = std::env::temp_dir().join("pb_rs_test_file_descriptors");
+        let (from_proto, from_set) = (dir.join("from_proto"), dir.join("from_set"));
+        for d in &[&from_proto, &from_set] {
+            std::fs::create_dir_all(d).unwrap();
+        }
+        std::fs::write(dir.join("a.proto"), a).unwrap();
+        std::fs::write(dir.join("b.proto"), b).unwrap();
+        let protos = [dir.join("a.proto"), dir.join("b.proto")];
+        let set_file = dir.join("set.pb");
+        let include = [dir.clone()];
+        write_descriptor_set(&protos, &include, true, &set_file).unwrap();
+
+        fn rpc_generator(rpc: &RpcService, w: &mut dyn Write) -> Result<()> {
+            for f in &rpc.functions {
+                writeln!(
+                    w,
+                    "// rpc {}({} {}) returns ({} {} {}) {:?}",
+                    f.name,
+                    f.client_streaming,
+                    f.arg_type,
+                    f.server_streaming,
+                    f.ret_type,
+                    f.ret_lifetime,
+                    get_option(&f.options, "deprecated"),
+                )?;
+            }
+            Ok(())
+        }
+        let with_rpc_generator = |mut configs: Vec<Config>| {
+            for config in &mut configs {
+                config.custom_rpc_generator = Box::new(rpc_generator);
+            }
+            configs
+        };
+        let config = ConfigBuilder::new(&protos, None, Some(&from_proto), &include).unwrap();
+        FileDescriptor::run(&with_rpc_generator(config.build())).unwrap();
+        let config = ConfigBuilder::new(&[set_file], None, Some(&from_set), &[]).unwrap();
+        FileDescriptor::run(&with_rpc_generator(config.descriptor_set(true).build())).unwrap();
+
+        for file in &["a.rs", "b.rs"] {
+            assert_eq!(
+                std::fs::read_to_string(from_proto.join(file)).unwrap(),
+                std::fs::read_to_string(from_set.join(file)).unwrap(),
+            );
+        }
+        let b = std::fs::read_to_string(from_proto.join("b.rs")).unwrap();
+        assert!(
+            b.contains("// rpc F(true a::A) returns (false mod_B::N false) Some(Ident(\"true\"))")
+        );
+    }
+
+    #[test]
+    fn test_unknown_custom_field_types() {
+        use crate::ConfigBuilder;
+
+        let dir = std::env::temp_dir().join("pb_rs_test_unknown_custom_field_types");
+        std::fs::create_dir_all(&dir).unwrap();
+        let proto = dir.join("a.proto");
+        std::fs::write(&proto, "syntax = \"proto3\"; package a; message A { bytes h = 1; }")
+            .unwrap();
+        let types = [("a.A.h", "[u8; 4]"), ("a.A.x", "String"), ("A.h", "String")]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let config = ConfigBuilder::new(&[proto], None, Some(&dir), &[])
+            .unwrap()
+            .custom_field_types(types);
+        match FileDescriptor::run(&config.build()) {
+            Err(Error::UnknownCustomFieldTypes(fields)) => assert_eq!(vec!["A.h", "a.A.x"], fields),
+            res => panic!("unknown custom field types should be rejected: {:?}", res.err()),
+        }
+    }
+
+    #[test]
+    fn test_source_code_info() {
+        use crate::descriptor::google::protobuf::mod_SourceCodeInfo::Location;
+        use crate::descriptor::google::protobuf::SourceCodeInfo;
+
+        let location = |path: Vec<i32>, comment: &'static str| Location {
+            path,
+            leading_comments: Some(Cow::Borrowed(comment)),
+            ..Default::default()
+        };
+        let file = FileDescriptorProto {
+            name: Some(Cow::Borrowed("c.proto")),
+            message_type: vec![DescriptorProto {
+                name: Some(Cow::Borrowed("M")),
+                field: vec![FieldDescriptorProto {
+                    name: Some(Cow::Borrowed("f")),
+                    number: Some(1),
+                    type_pb: Some(Type::TYPE_INT32),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            source_code_info: Some(SourceCodeInfo {
+                location: vec![
+                    location(vec![4, 0], " A message\n over two lines\n"),
+                    location(vec![4, 0, 2, 0], " A field\n"),
+                ],
+            }),
+            ..Default::default()
+        };
+        let desc = Converter::new(&file)
+            .file_descriptor(&file, Path::new("c.pb"))
+            .unwrap();
+        let m = &desc.messages[0];
+        assert_eq!(vec![" A message", " over two lines"], m.comments.leading);
+        assert_eq!(vec![" A field"], m.fields[0].comments.leading);
+        assert_eq!("c", desc.module);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!("MapFieldEntry", map_entry_name("map_field"));
+        assert_eq!("fooBar2", json_name("foo_bar_2"));
+    }
+}
@@ -1,14 +1,15 @@
 use std::io;
+use std::path::PathBuf;
 
 /// An error enum
 #[derive(Debug)]
 pub enum Error {
     /// IO error
     Io(io::Error),
-    /// Nom Error
-    Nom(nom::Err<nom::error::Error<String>>),
-    /// Nom's other failure case; giving up in the middle of a file
-    TrailingGarbage(String),
+    /// quick-protobuf error, when encoding or decoding descriptors
+    Protobuf(quick_protobuf::Error),
+    /// Syntax error in a .proto file
+    Parse(Box<ParseError>),
     /// No .proto file provided
     NoProto,
     /// Cannot read input file
@@ -21,6 +22,10 @@ pub enum Error {
     OutputMultipleInputs,
     /// Invalid message
     InvalidMessage(String),
+    /// Invalid enum
+    InvalidEnum(String),
+    /// Invalid `rust_options.proto` option
+    InvalidOption(String),
     /// Varint decoding error
     InvalidImport(String),
     /// Empty read
@@ -35,8 +40,77 @@ pub enum Error {
     Cycle(Vec<String>),
     /// `--output` and `--output_directory` both used
     OutputAndOutputDir,
+    /// Unknown protoc plugin parameter
+    PluginParameter(String),
+    /// Field or option not supported by the heapless code generation
+    Heapless(String),
+    /// Invalid `--custom_field_type`, not `<package.Message.field>=<type>`
+    CustomFieldType(String),
+    /// Custom field types of fields not found in the generated files
+    UnknownCustomFieldTypes(Vec<String>),
+    /// Comment which would be dropped by the formatting of a .proto file
+    Format(String),
 }
 
+/// A syntax error in a .proto file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The .proto file
+    pub path: PathBuf,
+    /// Line of the error, starting at 1
+    pub line: usize,
+    /// Column of the error in characters, starting at 1
+    pub column: usize,
+    /// The construct being parsed, e.g. `message` or `field`
+    pub construct: Option<&'static str>,
+    /// What could have been parsed at this position
+    pub expected: Vec<String>,
+    /// What was found instead
+    pub found: String,
+    /// The text of the line
+    pub source_line: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
+        match self.expected.len() {
+            0 => write!(f, "unexpected {}", self.found)?,
+            1 => write!(f, "expected {}, found {}", self.expected[0], self.found)?,
+            _ => write!(
+                f,
+                "expected one of {}, found {}",
+                self.expected.join(", "),
+                self.found
+            )?,
+        }
+        if let Some(construct) = self.construct {
+            write!(f, " in {}", construct)?;
+        }
+        // rustc style snippet with a caret under the error
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let caret = self
+            .source_line
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        write!(
+            f,
+            "\n{m}--> {}:{}:{}\n{m} |\n{} | {}\n{m} | {}^",
+            self.path.display(),
+            self.line,
+            self.column,
+            number,
+            self.source_line,
+            caret,
+            m = margin,
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// A wrapper for `Result<T, Error>`
 pub type Result<T> = ::std::result::Result<T, Error>;
 
@@ -46,11 +120,17 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<quick_protobuf::Error> for Error {
+    fn from(e: quick_protobuf::Error) -> Error {
+        Error::Protobuf(e)
+    }
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::Io(e) => Some(e),
-            Error::Nom(e) => Some(e),
+            Error::Protobuf(e) => Some(e),
             _ => None,
         }
     }
@@ -60,8 +140,8 @@ impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
         match self {
             Error::Io(e) => write!(f, "{}", e),
-            Error::Nom(e) => write!(f, "{}", e),
-            Error::TrailingGarbage(s) => write!(f, "parsing abandoned near: {:?}", s),
+            Error::Protobuf(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
             Error::NoProto => write!(f, "No .proto file provided"),
             Error::InputFile(file) => write!(f, "Cannot read input file '{}'", file),
             Error::OutputFile(file) => write!(f, "Cannot read output file '{}'", file),
@@ -73,6 +153,8 @@ impl std::fmt::Display for Error {
                 Proto definition might be invalid or something got wrong in the parsing",
                 msg
             ),
+            Error::InvalidEnum(e) => write!(f, "Enum checks errored: {}", e),
+            Error::InvalidOption(o) => write!(f, "Invalid option: {}", o),
             Error::InvalidImport(imp) => write!(
                 f,
                 "Cannot convert protobuf import into module import:: {}\r\n\
@@ -99,6 +181,19 @@ impl std::fmt::Display for Error {
             Error::OutputAndOutputDir => {
                 write!(f, "only one of --output or --output_directory allowed")
             }
+            Error::PluginParameter(p) => write!(f, "Unknown plugin parameter '{}'", p),
+            Error::Heapless(e) => write!(f, "Cannot generate heapless collections: {}", e),
+            Error::CustomFieldType(t) => write!(
+                f,
+                "Invalid custom field type '{}', expecting <package.Message.field>=<type>",
+                t
+            ),
+            Error::UnknownCustomFieldTypes(fields) => write!(
+                f,
+                "Custom field types of unknown fields: {}",
+                fields.join(", ")
+            ),
+            Error::Format(e) => write!(f, "Cannot format the file: {}", e),
         }
     }
 }
@@ -0,0 +1,738 @@
+//! Canonical formatting of .proto files
+//!
+//! [format] parses a file with the same parser as the code generation and prints it back with
+//! a fixed layout:
+//!
+//! - `syntax`, `package`, sorted `import`s and file options first
+//! - then enums, messages, `extend` blocks and services
+//! - in messages: options, `reserved` and `extensions` statements, fields and oneofs, then
+//!   nested enums, messages and `extend` blocks
+//! - 4 spaces indentation, one statement per line, explicit `syntax` and labels
+//!
+//! Fields keep their order, oneofs are placed before the first field with a greater number.
+//! The comments before an element, on the same line or in paragraphs separated by blank lines,
+//! are kept with it (block comments are written as line comments). The comments at the top of
+//! the file, like a license header, are kept as they are. Other comments, e.g. at the end of a
+//! block or inside a statement, are not retained by the parser: the file is not formatted
+//! (`Error::Format`) rather than losing them.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::errors::{Error, Result};
+use crate::parser::parse_file;
+use crate::types::{
+    Comments, EnumField, Enumerator, Extend, Field, FieldType, FileDescriptor, Frequency, Message,
+    OneOf, ProtoOption, RpcFunctionDeclaration, RpcService, Syntax, MAX_FIELD_NUMBER,
+};
+
+const INDENT: &str = "    ";
+
+/// Formats the content of the .proto file `path`
+///
+/// Fails if a comment of `input` would be dropped.
+pub fn format(path: &Path, input: &str) -> Result<String> {
+    let (header, header_len) = header(input);
+    // the comments of the header would be detached comments of the first element, the line
+    // numbers of the errors are kept
+    let body = input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| if i < header_len { "" } else { l })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let desc = parse_file(path, &body)?;
+    let mut printer = Printer {
+        out: String::with_capacity(input.len()),
+        indent: 0,
+        syntax: desc.syntax,
+    };
+    for l in header {
+        printer.line(l.trim_end());
+    }
+    printer.file(&desc);
+    check_comments(path, input, &printer.out)?;
+    Ok(printer.out)
+}
+
+/// Reads and formats the .proto file `in_file`
+pub fn format_file(in_file: &Path) -> Result<String> {
+    let input = std::fs::read_to_string(in_file)?;
+    format(in_file, &input)
+}
+
+struct Printer {
+    out: String,
+    indent: usize,
+    syntax: Syntax,
+}
+
+impl Printer {
+    fn line(&mut self, line: &str) {
+        if !line.is_empty() {
+            for _ in 0..self.indent {
+                self.out.push_str(INDENT);
+            }
+            self.out.push_str(line);
+        }
+        self.out.push('\n');
+    }
+
+    /// Separates two groups of statements with a blank line, unless at the start of a block
+    fn blank_line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with("{\n") && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    /// Writes the detached and leading comments and the `// rust-attribute:` comments of an
+    /// element
+    fn leading(
+        &mut self,
+        comments: &Comments,
+        attributes: &[String],
+        one_of_attributes: &[String],
+    ) {
+        for d in &comments.detached {
+            self.blank_line();
+            for l in d {
+                self.line(&format!("//{}", l));
+            }
+            self.line("");
+        }
+        for l in &comments.leading {
+            self.line(&format!("//{}", l));
+        }
+        for a in attributes {
+            self.line(&format!("// rust-attribute: {}", a));
+        }
+        for a in one_of_attributes {
+            self.line(&format!("// rust-one-of-attribute: {}", a));
+        }
+    }
+
+    /// Writes a statement followed by its trailing comment
+    fn statement(&mut self, statement: &str, comments: &Comments) {
+        match comments.trailing.len() {
+            0 => self.line(statement),
+            1 => self.line(&format!("{} //{}", statement, comments.trailing[0])),
+            _ => {
+                // a block comment, line comments would be attached to the next element
+                self.line(&format!("{} /*", statement));
+                for l in &comments.trailing {
+                    self.line(&format!(" *{}", l));
+                }
+                self.line(" */");
+            }
+        }
+    }
+
+    /// Writes `{`, the items written by `body` and `}` followed by the trailing comment
+    fn block<F: FnOnce(&mut Self)>(&mut self, header: &str, comments: &Comments, body: F) {
+        let start = self.out.len();
+        self.line(&format!("{} {{", header));
+        let content = self.out.len();
+        self.indent += 1;
+        body(self);
+        self.indent -= 1;
+        if self.out.len() == content {
+            self.out.truncate(start);
+            self.statement(&format!("{} {{}}", header), comments);
+        } else {
+            self.statement("}", comments);
+        }
+    }
+
+    fn options(&mut self, options: &[ProtoOption]) {
+        if !options.is_empty() {
+            self.blank_line();
+        }
+        for o in options {
+            self.line(&format!("option {} = {};", o.name, o.value));
+        }
+    }
+
+    fn file(&mut self, desc: &FileDescriptor) {
+        self.line(match desc.syntax {
+            Syntax::Proto2 => "syntax = \"proto2\";",
+            Syntax::Proto3 => "syntax = \"proto3\";",
+        });
+        if !desc.package.is_empty() {
+            self.blank_line();
+            self.line(&format!("package {};", desc.package));
+        }
+        let mut imports = desc
+            .import_paths
+            .iter()
+            .map(|i| i.to_string_lossy().replace('\\', "/"))
+            .collect::<Vec<_>>();
+        imports.sort();
+        imports.dedup();
+        if !imports.is_empty() {
+            self.blank_line();
+        }
+        for i in imports {
+            self.line(&format!("import \"{}\";", i));
+        }
+        self.options(&desc.options);
+        self.items(&desc.enums, &desc.messages, &desc.extends);
+        for s in &desc.rpc_services {
+            self.blank_line();
+            self.service(s);
+        }
+    }
+
+    /// Writes enums, messages and `extend` blocks, separated by blank lines
+    fn items(&mut self, enums: &[Enumerator], messages: &[Message], extends: &[Extend]) {
+        for e in enums {
+            self.blank_line();
+            self.enumerator(e);
+        }
+        for m in messages {
+            self.blank_line();
+            self.message(m);
+        }
+        for e in extends {
+            self.blank_line();
+            self.extend(e);
+        }
+    }
+
+    fn message(&mut self, m: &Message) {
+        self.leading(&m.comments, &m.attributes, &[]);
+        self.block(&format!("message {}", m.name), &m.comments, |p| {
+            p.options(&m.options);
+            let reserved_nums = m.reserved_nums.as_deref().unwrap_or_default();
+            let reserved_names = m.reserved_names.as_deref().unwrap_or_default();
+            if !reserved_nums.is_empty()
+                || !reserved_names.is_empty()
+                || !m.extension_ranges.is_empty()
+            {
+                p.blank_line();
+            }
+            if !reserved_nums.is_empty() {
+                p.line(&format!("reserved {};", ranges(reserved_nums)));
+            }
+            if !reserved_names.is_empty() {
+                let names = reserved_names
+                    .iter()
+                    .map(|n| format!("\"{}\"", n))
+                    .collect::<Vec<_>>();
+                p.line(&format!("reserved {};", names.join(", ")));
+            }
+            if !m.extension_ranges.is_empty() {
+                p.line(&format!("extensions {};", ranges(&m.extension_ranges)));
+            }
+
+            if !m.fields.is_empty() || !m.oneofs.is_empty() {
+                p.blank_line();
+            }
+            let first_number = |o: &OneOf| o.fields.iter().map(|f| f.number).min();
+            let mut oneofs = m.oneofs.iter().peekable();
+            for f in &m.fields {
+                while let Some(o) =
+                    oneofs.next_if(|o| first_number(o).is_some_and(|n| n < f.number))
+                {
+                    p.one_of(o);
+                }
+                p.field(f, false);
+            }
+            for o in oneofs {
+                p.one_of(o);
+            }
+
+            p.items(&m.enums, &m.messages, &m.extends);
+        });
+    }
+
+    fn field(&mut self, f: &Field, in_one_of: bool) {
+        self.leading(&f.comments, &f.attributes, &[]);
+        let label = match f.frequency {
+            Frequency::Repeated => "repeated ",
+            Frequency::Required => "required ",
+            Frequency::Optional if in_one_of || matches!(f.typ, FieldType::Map(..)) => "",
+            Frequency::Optional if self.syntax == Syntax::Proto3 && !f.proto3_optional => "",
+            Frequency::Optional => "optional ",
+        };
+        let mut statement = format!("{}{} {} = {}", label, type_name(&f.typ), f.name, f.number);
+        if !f.options.is_empty() {
+            let options = f
+                .options
+                .iter()
+                .map(|o| format!("{} = {}", o.name, o.value))
+                .collect::<Vec<_>>();
+            statement.push_str(&format!(" [{}]", options.join(", ")));
+        }
+        statement.push(';');
+        self.statement(&statement, &f.comments);
+    }
+
+    fn one_of(&mut self, o: &OneOf) {
+        self.leading(&o.comments, &o.field_attributes, &o.container_attributes);
+        self.block(&format!("oneof {}", o.name), &o.comments, |p| {
+            p.options(&o.options);
+            p.blank_line();
+            for f in &o.fields {
+                p.field(f, true);
+            }
+        });
+    }
+
+    fn extend(&mut self, e: &Extend) {
+        self.block(
+            &format!("extend {}", e.extendee),
+            &Comments::default(),
+            |p| {
+                for f in &e.fields {
+                    p.field(f, false);
+                }
+            },
+        );
+    }
+
+    fn enumerator(&mut self, e: &Enumerator) {
+        self.leading(&e.comments, &e.attributes, &[]);
+        self.block(&format!("enum {}", e.name), &e.comments, |p| {
+            p.options(&e.options);
+            p.blank_line();
+            for v in &e.fields {
+                p.enum_field(v);
+            }
+        });
+    }
+
+    fn enum_field(&mut self, v: &EnumField) {
+        self.leading(&v.comments, &v.attributes, &[]);
+        let mut statement = format!("{} = {}", v.name, v.tag);
+        if !v.options.is_empty() {
+            let options = v
+                .options
+                .iter()
+                .map(|o| format!("{} = {}", o.name, o.value))
+                .collect::<Vec<_>>();
+            statement.push_str(&format!(" [{}]", options.join(", ")));
+        }
+        statement.push(';');
+        self.statement(&statement, &v.comments);
+    }
+
+    fn service(&mut self, s: &RpcService) {
+        self.leading(&s.comments, &[], &[]);
+        self.block(&format!("service {}", s.service_name), &s.comments, |p| {
+            p.options(&s.options);
+            p.blank_line();
+            for f in &s.functions {
+                p.rpc(f);
+            }
+        });
+    }
+
+    fn rpc(&mut self, f: &RpcFunctionDeclaration) {
+        self.leading(&f.comments, &[], &[]);
+        let stream = |is_stream| if is_stream { "stream " } else { "" };
+        let signature = format!(
+            "rpc {}({}{}) returns ({}{})",
+            f.name,
+            stream(f.client_streaming),
+            f.arg,
+            stream(f.server_streaming),
+            f.ret
+        );
+        if f.options.is_empty() {
+            self.statement(&format!("{};", signature), &f.comments);
+        } else {
+            self.block(&signature, &f.comments, |p| p.options(&f.options));
+        }
+    }
+}
+
+/// A comment of a .proto file
+struct Comment<'a> {
+    /// Line of the start of the comment, starting at 0
+    line: usize,
+    /// Line of the end of the comment
+    end_line: usize,
+    /// Text of the comment, without `//` or `/*` and `*/`
+    text: &'a str,
+}
+
+/// The comments of a .proto file and the line of its first statement, if any
+fn comments(input: &str) -> (Vec<Comment<'_>>, Option<usize>) {
+    let mut comments = Vec::new();
+    let mut first_statement = None;
+    let mut line = 0;
+    let mut chars = input.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            '/' if matches!(chars.peek(), Some((_, '/'))) => {
+                let text = input[i + 2..].lines().next().unwrap_or_default();
+                comments.push(Comment {
+                    line,
+                    end_line: line,
+                    text,
+                });
+                // stop before the line ending
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            }
+            '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                chars.next();
+                let rest = &input[i + 2..];
+                let text = &rest[..rest.find("*/").unwrap_or(rest.len())];
+                comments.push(Comment {
+                    line,
+                    end_line: line + text.matches('\n').count(),
+                    text,
+                });
+                line += text.matches('\n').count();
+                // skip the text and `*/`
+                for _ in 0..text.chars().count() + 2 {
+                    chars.next();
+                }
+            }
+            c if c.is_whitespace() => (),
+            _ => {
+                first_statement.get_or_insert(line);
+                if c == '"' || c == '\'' {
+                    while let Some((_, s)) = chars.next() {
+                        match s {
+                            '\\' => {
+                                chars.next();
+                            }
+                            '\n' => line += 1,
+                            s if s == c => break,
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        }
+    }
+    (comments, first_statement)
+}
+
+/// The lines of the comments at the top of the file and the number of lines they span in `input`
+///
+/// All the comments before `syntax`, `package`, `import` or `option`, or else the ones separated
+/// from the first element by a blank line.
+fn header(input: &str) -> (Vec<&str>, usize) {
+    let (comments, first_statement) = comments(input);
+    let first_statement = first_statement.unwrap_or(usize::MAX);
+    let lines = input.lines().collect::<Vec<_>>();
+    let mut end = 0;
+    let statement = lines.get(first_statement).map_or("", |l| l.trim_start());
+    if statement.is_empty()
+        || ["syntax", "package", "import", "option"]
+            .iter()
+            .any(|s| statement.starts_with(s))
+    {
+        end = comments
+            .iter()
+            .rev()
+            .find(|c| c.end_line < first_statement)
+            .map_or(0, |c| c.end_line + 1);
+    } else {
+        for c in comments.iter().take_while(|c| c.end_line < first_statement) {
+            let next = lines[c.end_line + 1..]
+                .iter()
+                .position(|l| !l.trim().is_empty());
+            if next.is_some_and(|n| n > 0) {
+                end = c.end_line + 1;
+            }
+        }
+    }
+    let mut header = lines[..end.min(lines.len())].to_vec();
+    if header.iter().any(|l| !l.trim().is_empty()) {
+        while header.first().is_some_and(|l| l.trim().is_empty()) {
+            header.remove(0);
+        }
+        header.push("");
+    } else {
+        header.clear();
+    }
+    (header, end)
+}
+
+/// Lines of the comments, without the leading `*` of block comments, by line of `text`
+fn comment_lines(text: &str) -> Vec<(usize, String)> {
+    comments(text)
+        .0
+        .iter()
+        .flat_map(|c| c.text.lines().map(move |l| (c.line, l)))
+        .map(|(line, l)| (line, l.trim().trim_start_matches('*').trim().to_string()))
+        .filter(|(_, l)| !l.is_empty())
+        .collect()
+}
+
+/// Checks that all the comments of `input` are in `output`
+fn check_comments(path: &Path, input: &str, output: &str) -> Result<()> {
+    let mut kept = HashMap::new();
+    for (_, l) in comment_lines(output) {
+        *kept.entry(l).or_insert(0usize) += 1;
+    }
+    for (line, l) in comment_lines(input) {
+        match kept.get_mut(&l) {
+            Some(n) if *n > 0 => *n -= 1,
+            _ => {
+                return Err(Error::Format(format!(
+                    "{}:{}: the comment '{}' is not attached to an element and would be dropped",
+                    path.display(),
+                    line + 1,
+                    l
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The type of a field as written in a .proto file
+fn type_name(typ: &FieldType) -> String {
+    match typ {
+        FieldType::Int32 => "int32".to_string(),
+        FieldType::Int64 => "int64".to_string(),
+        FieldType::Uint32 => "uint32".to_string(),
+        FieldType::Uint64 => "uint64".to_string(),
+        FieldType::Sint32 => "sint32".to_string(),
+        FieldType::Sint64 => "sint64".to_string(),
+        FieldType::Bool => "bool".to_string(),
+        FieldType::Fixed64 => "fixed64".to_string(),
+        FieldType::Sfixed64 => "sfixed64".to_string(),
+        FieldType::Double => "double".to_string(),
+        FieldType::Fixed32 => "fixed32".to_string(),
+        FieldType::Sfixed32 => "sfixed32".to_string(),
+        FieldType::Float => "float".to_string(),
+        FieldType::StringCow | FieldType::String_ => "string".to_string(),
+        FieldType::BytesCow | FieldType::Bytes_ => "bytes".to_string(),
+        FieldType::MessageOrEnum(name) => name.clone(),
+        FieldType::Map(key, value) => format!("map<{}, {}>", type_name(key), type_name(value)),
+        FieldType::Message(_) | FieldType::Enum(_) => {
+            unreachable!("types are not resolved by the parser")
+        }
+    }
+}
+
+/// Inclusive ranges as `1, 3 to 5, 10 to max`
+fn ranges(ranges: &[(i32, i32)]) -> String {
+    ranges
+        .iter()
+        .map(|&(start, end)| match end {
+            _ if start == end => start.to_string(),
+            MAX_FIELD_NUMBER => format!("{} to max", start),
+            _ => format!("{} to {}", start, end),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fmt(input: &str) -> String {
+        format(Path::new("a.proto"), input).unwrap()
+    }
+
+    #[test]
+    fn test_format() {
+        let input = r#"
+syntax="proto3";
+import "z.proto";   import "a/b.proto";
+package   foo.bar ;
+option java_package="foo" ;
+service S{rpc Get ( stream Req ) returns(Res);
+  // with options
+  rpc Put(Req) returns (.foo.bar.Res) { option deprecated = true; } }
+message M {
+  // the id
+  // rust-attribute: #[serde(default)]
+  int32 id=1; // trailing
+  oneof kind { string name = 3; bytes data = 4 [deprecated=true]; }
+  map < string , M > children = 5;
+  optional  M parent = 2;
+  reserved 7, 10 to max;
+  reserved "old";
+  message Empty { }
+  /* block
+   * comment */
+  enum E { option allow_alias = true; A = 0; B = 0x1; C = 1; }
+}
+enum Color { COLOR_UNSPECIFIED = 0; RED = 1 [(custom) = { a: 1 }]; }
+"#;
+        let expected = r#"syntax = "proto3";
+
+package foo.bar;
+
+import "a/b.proto";
+import "z.proto";
+
+option java_package = "foo";
+
+enum Color {
+    COLOR_UNSPECIFIED = 0;
+    RED = 1 [(custom) = { a: 1 }];
+}
+
+message M {
+    reserved 7, 10 to max;
+    reserved "old";
+
+    // the id
+    // rust-attribute: #[serde(default)]
+    int32 id = 1; // trailing
+    oneof kind {
+        string name = 3;
+        bytes data = 4 [deprecated = true];
+    }
+    map<string, M> children = 5;
+    optional M parent = 2;
+
+    // block
+    // comment
+    enum E {
+        option allow_alias = true;
+
+        A = 0;
+        B = 1;
+        C = 1;
+    }
+
+    message Empty {}
+}
+
+service S {
+    rpc Get(stream Req) returns (Res);
+    // with options
+    rpc Put(Req) returns (.foo.bar.Res) {
+        option deprecated = true;
+    }
+}
+"#;
+        assert_eq!(expected, fmt(input));
+        assert_eq!(expected, fmt(expected));
+    }
+
+    #[test]
+    fn test_format_proto2() {
+        let input = r#"package a;
+            message M {
+                required int32 a = 1; /* first
+                                       * second */
+                int32 b = 2;
+                extensions 100 to 199;
+            }
+            extend M { repeated string c = 100; }
+        "#;
+        let expected = r#"syntax = "proto2";
+
+package a;
+
+message M {
+    extensions 100 to 199;
+
+    required int32 a = 1; /*
+     * first
+     * second
+     */
+    optional int32 b = 2;
+}
+
+extend M {
+    repeated string c = 100;
+}
+"#;
+        assert_eq!(expected, fmt(input));
+        assert_eq!(expected, fmt(expected));
+    }
+
+    #[test]
+    fn test_format_header() {
+        let input = r#"
+// Copyright 2024 The Authors
+//
+// Licensed under the MIT license
+
+/* Generated by hand */
+syntax = "proto3";
+// the message
+message M { string url = 1 [json_name = "http://a/*b*/"]; }
+"#;
+        let expected = r#"// Copyright 2024 The Authors
+//
+// Licensed under the MIT license
+
+/* Generated by hand */
+
+syntax = "proto3";
+
+// the message
+message M {
+    string url = 1 [json_name = "http://a/*b*/"];
+}
+"#;
+        assert_eq!(expected, fmt(input));
+        assert_eq!(expected, fmt(expected));
+
+        // without syntax, the comments attached to the first element are not in the header
+        let input = "// License\n\n// the message\nmessage M {}\n";
+        let expected = "// License\n\nsyntax = \"proto2\";\n\n// the message\nmessage M {}\n";
+        assert_eq!(expected, fmt(input));
+        assert_eq!(expected, fmt(expected));
+    }
+
+    #[test]
+    fn test_format_detached_comments() {
+        let input = r#"syntax = "proto3";
+// Messages
+
+message A {
+    int32 a = 1;
+    // Deprecated fields
+
+    // Leading comment of b
+    int32 b = 2;
+}
+
+// Other messages
+/* with a block comment */
+
+// More messages
+
+message B {}
+"#;
+        let expected = r#"syntax = "proto3";
+
+// Messages
+
+message A {
+    int32 a = 1;
+
+    // Deprecated fields
+
+    // Leading comment of b
+    int32 b = 2;
+}
+
+// Other messages
+// with a block comment
+
+// More messages
+
+message B {}
+"#;
+        assert_eq!(expected, fmt(input));
+        assert_eq!(expected, fmt(expected));
+    }
+
+    #[test]
+    fn test_format_dropped_comments() {
+        let dropped = [
+            "syntax = \"proto3\";\nmessage M {\n    int32 a = 1;\n    // TODO\n}\n",
+            "syntax = \"proto3\";\nmessage M {\n    int32 a = 1 /* inline */;\n}\n",
+        ];
+        for input in dropped {
+            let res = format(Path::new("a.proto"), input);
+            assert!(matches!(res, Err(Error::Format(_))), "{:?}", res);
+        }
+    }
+}
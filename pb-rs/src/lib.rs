@@ -1,6 +1,12 @@
+pub mod breaking;
+pub mod descriptor;
+pub mod descriptor_set;
 pub mod errors;
+pub mod format;
 mod keywords;
+pub mod lint;
 mod parser;
+pub mod plugin;
 mod scc;
 pub mod types;
 
@@ -62,11 +68,18 @@ pub struct ConfigBuilder {
     custom_repr: Option<String>,
     default_custom_struct_derive: String,
     custom_struct_derive: HashMap<String, String>,
+    custom_field_types: HashMap<String, String>,
     owned: bool,
     nostd: bool,
     hashbrown: bool,
+    strict: bool,
+    heapless: bool,
     gen_info: bool,
+    gen_builders: bool,
+    gen_accessors: bool,
     add_deprecated_fields: bool,
+    descriptor_set: bool,
+    generate_services: bool,
 }
 
 impl ConfigBuilder {
@@ -161,6 +174,16 @@ impl ConfigBuilder {
         self
     }
 
+    /// Replace the Rust types of the specified fields, by fully qualified field name (e.g.
+    /// `package.Message.field`), like the `(rust.type)` option which they take precedence over
+    ///
+    /// Values are converted from the decoded ones with `TryFrom`, e.g. a `[u8; 32]` or a `String`
+    /// for a `bytes` or `string` field which does not borrow from the input.
+    pub fn custom_field_types(mut self, val: HashMap<String, String>) -> Self {
+        self.custom_field_types = val;
+        self
+    }
+
     /// Add custom values to `#[repr(...)]` at the beginning of every structure
     pub fn custom_repr(mut self, val: Option<String>) -> Self {
         self.custom_repr = val;
@@ -193,18 +216,64 @@ impl ConfigBuilder {
         self
     }
 
+    /// Generate the detection of duplicate singular fields and oneofs, rejected when decoding
+    /// with a strict `BytesReader`
+    pub fn strict(mut self, val: bool) -> Self {
+        self.strict = val;
+        self
+    }
+
+    /// Generate fixed capacity `heapless` collections instead of `Vec`, `String` and maps, with
+    /// the capacities of the fields from their `(rust.capacity)` and `(rust.max_len)` options
+    pub fn heapless(mut self, val: bool) -> Self {
+        self.heapless = val;
+        self
+    }
+
     /// Generate `MessageInfo` implementations
     pub fn gen_info(mut self, val: bool) -> Self {
         self.gen_info = val;
         self
     }
 
+    /// Generate a builder for each message, with chainable setters and a `build` failing if a
+    /// required field is not set
+    pub fn gen_builders(mut self, val: bool) -> Self {
+        self.gen_builders = val;
+        self
+    }
+
+    /// Generate `x()`, `has_x()`, `clear_x()`, `set_x()` and `mut_x()` accessors for the
+    /// fields with a presence or a declared default, and accessors for the oneof variants
+    pub fn gen_accessors(mut self, val: bool) -> Self {
+        self.gen_accessors = val;
+        self
+    }
+
     /// Add deprecated fields and mark them as `#[deprecated]`
     pub fn add_deprecated_fields(mut self, val: bool) -> Self {
         self.add_deprecated_fields = val;
         self
     }
 
+    /// Read the input files as encoded `FileDescriptorSet`s, as written by
+    /// `protoc --descriptor_set_out --include_imports`, instead of .proto files
+    ///
+    /// A module is generated for each file of the sets, next to the output file.
+    pub fn descriptor_set(mut self, val: bool) -> Self {
+        self.descriptor_set = val;
+        self
+    }
+
+    /// Generate a trait, a server and a client stub for each service, with
+    /// [RpcService::write_service](types::RpcService::write_service) as `custom_rpc_generator`
+    ///
+    /// The generated code needs the `alloc` feature of quick-protobuf, enabled by `std`.
+    pub fn generate_services(mut self, val: bool) -> Self {
+        self.generate_services = val;
+        self
+    }
+
     /// Build [Config] from this `ConfigBuilder`
     pub fn build(self) -> Vec<Config> {
         self.in_files
@@ -231,14 +300,24 @@ impl ConfigBuilder {
                     dont_use_cow: self.dont_use_cow, //Change this to true to not use cow with ./generate.sh for v2 and v3 tests
                     default_custom_struct_derive: self.default_custom_struct_derive.clone(),
                     custom_struct_derive: self.custom_struct_derive.clone(),
+                    custom_field_types: self.custom_field_types.clone(),
                     custom_repr: self.custom_repr.clone(),
-                    custom_rpc_generator: Box::new(|_, _| Ok(())),
+                    custom_rpc_generator: if self.generate_services {
+                        Box::new(|rpc, w| rpc.write_service(w))
+                    } else {
+                        Box::new(|_, _| Ok(()))
+                    },
                     custom_includes: Vec::new(),
                     owned: self.owned,
                     nostd: self.nostd,
                     hashbrown: self.hashbrown,
+                    strict: self.strict,
+                    heapless: self.heapless,
                     gen_info: self.gen_info,
+                    gen_builders: self.gen_builders,
+                    gen_accessors: self.gen_accessors,
                     add_deprecated_fields: self.add_deprecated_fields,
+                    descriptor_set: self.descriptor_set,
                 }
             })
             .collect()
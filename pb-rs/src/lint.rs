@@ -0,0 +1,355 @@
+//! Style checks of .proto files
+//!
+//! [lint] parses a file with the same parser as the code generation and checks the usual
+//! naming conventions:
+//!
+//! - messages, enums, services and rpcs are `PascalCase`
+//! - fields and oneofs are `lower_snake_case`
+//! - enum values are `UPPER_SNAKE_CASE` and the zero value ends with `_UNSPECIFIED`
+//! - the package matches the directory of the file, e.g. `foo/v1/a.proto` for `foo.v1`
+
+use std::fmt;
+use std::path::{Component, Path};
+
+use crate::errors::Result;
+use crate::parser::parse_file;
+use crate::types::{camel_case, snake_case, Enumerator, Field, FileDescriptor, Message};
+
+/// A style rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    MessagePascalCase,
+    FieldLowerSnakeCase,
+    OneofLowerSnakeCase,
+    EnumPascalCase,
+    EnumValueUpperSnakeCase,
+    EnumZeroValueSuffix,
+    ServicePascalCase,
+    RpcPascalCase,
+    PackageDirectoryMatch,
+}
+
+impl Rule {
+    /// Name of the rule in the output, e.g. `FIELD_LOWER_SNAKE_CASE`
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::MessagePascalCase => "MESSAGE_PASCAL_CASE",
+            Rule::FieldLowerSnakeCase => "FIELD_LOWER_SNAKE_CASE",
+            Rule::OneofLowerSnakeCase => "ONEOF_LOWER_SNAKE_CASE",
+            Rule::EnumPascalCase => "ENUM_PASCAL_CASE",
+            Rule::EnumValueUpperSnakeCase => "ENUM_VALUE_UPPER_SNAKE_CASE",
+            Rule::EnumZeroValueSuffix => "ENUM_ZERO_VALUE_SUFFIX",
+            Rule::ServicePascalCase => "SERVICE_PASCAL_CASE",
+            Rule::RpcPascalCase => "RPC_PASCAL_CASE",
+            Rule::PackageDirectoryMatch => "PACKAGE_DIRECTORY_MATCH",
+        }
+    }
+}
+
+/// A violation of a rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub rule: Rule,
+    /// Fully qualified name of the element, or name of the file for the package
+    pub path: String,
+    /// The name, or the directory for the package
+    pub found: String,
+    /// A name, or directory, following the rule
+    pub expected: String,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        let (element, case) = match self.rule {
+            Rule::MessagePascalCase => ("message", "PascalCase"),
+            Rule::FieldLowerSnakeCase => ("field", "lower_snake_case"),
+            Rule::OneofLowerSnakeCase => ("oneof", "lower_snake_case"),
+            Rule::EnumPascalCase => ("enum", "PascalCase"),
+            Rule::EnumValueUpperSnakeCase => ("enum value", "UPPER_SNAKE_CASE"),
+            Rule::ServicePascalCase => ("service", "PascalCase"),
+            Rule::RpcPascalCase => ("rpc", "PascalCase"),
+            Rule::EnumZeroValueSuffix => {
+                return write!(
+                    f,
+                    "enum zero value {} should end with _UNSPECIFIED, e.g. {}",
+                    self.found, self.expected
+                )
+            }
+            Rule::PackageDirectoryMatch => {
+                return write!(
+                    f,
+                    "package should be in directory '{}', not '{}'",
+                    self.expected, self.found
+                )
+            }
+        };
+        write!(
+            f,
+            "{} name {} should be {}, e.g. {}",
+            element, self.found, case, self.expected
+        )
+    }
+}
+
+/// Checks the content of the .proto file `path`
+///
+/// The directory of the package is relative to `root`, or the end of the directory of `path`
+/// if there is no `root`.
+pub fn lint(path: &Path, input: &str, root: Option<&Path>) -> Result<Vec<Lint>> {
+    let desc = parse_file(path, input)?;
+    let mut lints = Vec::new();
+    check_package(&desc, path, root, &mut lints);
+    for m in &desc.messages {
+        check_message(m, &desc.package, &mut lints);
+    }
+    for e in &desc.enums {
+        check_enum(e, &desc.package, &mut lints);
+    }
+    for e in &desc.extends {
+        for f in &e.fields {
+            check_field(f, &desc.package, &mut lints);
+        }
+    }
+    for s in &desc.rpc_services {
+        let name = full_name(&desc.package, &s.service_name);
+        check(Rule::ServicePascalCase, &name, &s.service_name, &mut lints);
+        for f in &s.functions {
+            let path = full_name(&name, &f.name);
+            check(Rule::RpcPascalCase, &path, &f.name, &mut lints);
+        }
+    }
+    Ok(lints)
+}
+
+/// Reads and checks the .proto file `in_file`
+pub fn lint_file(in_file: &Path, root: Option<&Path>) -> Result<Vec<Lint>> {
+    let input = std::fs::read_to_string(in_file)?;
+    lint(in_file, &input, root)
+}
+
+fn check_package(desc: &FileDescriptor, path: &Path, root: Option<&Path>, lints: &mut Vec<Lint>) {
+    if desc.package.is_empty() {
+        return;
+    }
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let expected = desc.package.split('.').collect::<Vec<_>>();
+    let relative = root.and_then(|r| dir.strip_prefix(r).ok());
+    let matches = match relative {
+        Some(relative) => components(relative) == expected,
+        // outside of `root`, only the end of the directory is checked
+        None => components(dir).ends_with(&expected),
+    };
+    if !matches {
+        lints.push(Lint {
+            rule: Rule::PackageDirectoryMatch,
+            path: path.display().to_string(),
+            found: relative.unwrap_or(dir).display().to_string(),
+            expected: expected.join("/"),
+        });
+    }
+}
+
+fn components(dir: &Path) -> Vec<&str> {
+    dir.components()
+        .filter_map(|c| match c {
+            Component::Normal(c) => c.to_str(),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_message(m: &Message, scope: &str, lints: &mut Vec<Lint>) {
+    let name = full_name(scope, &m.name);
+    check(Rule::MessagePascalCase, &name, &m.name, lints);
+    for f in &m.fields {
+        check_field(f, &name, lints);
+    }
+    for o in &m.oneofs {
+        check(
+            Rule::OneofLowerSnakeCase,
+            &full_name(&name, &o.name),
+            &o.name,
+            lints,
+        );
+        for f in &o.fields {
+            check_field(f, &name, lints);
+        }
+    }
+    for e in &m.extends {
+        for f in &e.fields {
+            check_field(f, &name, lints);
+        }
+    }
+    for e in &m.enums {
+        check_enum(e, &name, lints);
+    }
+    for nested in &m.messages {
+        check_message(nested, &name, lints);
+    }
+}
+
+fn check_field(f: &Field, scope: &str, lints: &mut Vec<Lint>) {
+    check(
+        Rule::FieldLowerSnakeCase,
+        &full_name(scope, &f.name),
+        &f.name,
+        lints,
+    );
+}
+
+fn check_enum(e: &Enumerator, scope: &str, lints: &mut Vec<Lint>) {
+    let name = full_name(scope, &e.name);
+    check(Rule::EnumPascalCase, &name, &e.name, lints);
+    for v in &e.fields {
+        check(
+            Rule::EnumValueUpperSnakeCase,
+            &full_name(&name, &v.name),
+            &v.name,
+            lints,
+        );
+    }
+    if let Some(zero) = e.fields.iter().find(|v| v.tag == 0) {
+        if !zero.name.ends_with("_UNSPECIFIED") {
+            lints.push(Lint {
+                rule: Rule::EnumZeroValueSuffix,
+                path: full_name(&name, &zero.name),
+                found: zero.name.clone(),
+                expected: format!("{}_UNSPECIFIED", upper_snake_case(&e.name)),
+            });
+        }
+    }
+}
+
+/// Checks the case of `name`
+fn check(rule: Rule, path: &str, name: &str, lints: &mut Vec<Lint>) {
+    let (valid, expected) = match rule {
+        Rule::FieldLowerSnakeCase | Rule::OneofLowerSnakeCase => (
+            is_snake_case(name, char::is_ascii_lowercase),
+            lower_snake_case(name),
+        ),
+        Rule::EnumValueUpperSnakeCase => (
+            is_snake_case(name, char::is_ascii_uppercase),
+            upper_snake_case(name),
+        ),
+        _ => (is_pascal_case(name), camel_case(name)),
+    };
+    if !valid {
+        lints.push(Lint {
+            rule,
+            path: path.to_string(),
+            found: name.to_string(),
+            expected,
+        });
+    }
+}
+
+fn full_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Words of letters of the case `is_case` and digits, separated by single underscores
+fn is_snake_case(name: &str, is_case: fn(&char) -> bool) -> bool {
+    name.starts_with(|c: char| is_case(&c))
+        && name.split('_').all(|w| !w.is_empty())
+        && name
+            .chars()
+            .all(|c| c == '_' || c.is_ascii_digit() || is_case(&c))
+}
+
+fn lower_snake_case(name: &str) -> String {
+    snake_case(name)
+        .split('_')
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_lowercase()
+}
+
+fn upper_snake_case(name: &str) -> String {
+    lower_snake_case(name).to_uppercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lints(path: &str, input: &str, root: Option<&str>) -> Vec<String> {
+        lint(Path::new(path), input, root.map(Path::new))
+            .unwrap()
+            .iter()
+            .map(|l| format!("{} {}", l.rule.name(), l))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_names() {
+        let input = r#"syntax = "proto3";
+            package a;
+            message my_message {
+                int32 userId = 1;
+                oneof Kind { string name = 2; }
+                enum state { ACTIVE = 0; inactive = 1; }
+                message Nested { map<string, int32> counts = 3; }
+            }
+            enum Color {
+                COLOR_UNSPECIFIED = 0;
+                RED = 1;
+            }
+            service greeter { rpc say_hello(my_message) returns (my_message); }
+        "#;
+        assert_eq!(
+            vec![
+                "MESSAGE_PASCAL_CASE a.my_message: message name my_message should be \
+                 PascalCase, e.g. MyMessage",
+                "FIELD_LOWER_SNAKE_CASE a.my_message.userId: field name userId should be \
+                 lower_snake_case, e.g. user_id",
+                "ONEOF_LOWER_SNAKE_CASE a.my_message.Kind: oneof name Kind should be \
+                 lower_snake_case, e.g. kind",
+                "ENUM_PASCAL_CASE a.my_message.state: enum name state should be PascalCase, \
+                 e.g. State",
+                "ENUM_VALUE_UPPER_SNAKE_CASE a.my_message.state.inactive: enum value name \
+                 inactive should be UPPER_SNAKE_CASE, e.g. INACTIVE",
+                "ENUM_ZERO_VALUE_SUFFIX a.my_message.state.ACTIVE: enum zero value ACTIVE \
+                 should end with _UNSPECIFIED, e.g. STATE_UNSPECIFIED",
+                "SERVICE_PASCAL_CASE a.greeter: service name greeter should be PascalCase, \
+                 e.g. Greeter",
+                "RPC_PASCAL_CASE a.greeter.say_hello: rpc name say_hello should be PascalCase, \
+                 e.g. SayHello",
+            ],
+            lints("a/a.proto", input, None)
+        );
+    }
+
+    #[test]
+    fn test_lint_package() {
+        let input = "package foo.v1;";
+        assert!(lints("protos/foo/v1/a.proto", input, Some("protos")).is_empty());
+        assert!(lints("protos/foo/v1/a.proto", input, None).is_empty());
+        assert!(lints("foo/v1/a.proto", input, Some("other")).is_empty());
+        assert_eq!(
+            vec![
+                "PACKAGE_DIRECTORY_MATCH protos/bar/foo/v1/a.proto: package should be in \
+                 directory 'foo/v1', not 'bar/foo/v1'"
+            ],
+            lints("protos/bar/foo/v1/a.proto", input, Some("protos"))
+        );
+        assert_eq!(
+            vec![
+                "PACKAGE_DIRECTORY_MATCH foo/a.proto: package should be in directory \
+                  'foo/v1', not 'foo'"
+            ],
+            lints("foo/a.proto", input, None)
+        );
+        assert!(lints("a.proto", "message M {}", Some(".")).is_empty());
+    }
+}
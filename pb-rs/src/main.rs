@@ -1,11 +1,23 @@
-use clap::{crate_authors, crate_description, crate_name, crate_version, values_t, App, Arg};
-use pb_rs::{errors::Error, types::FileDescriptor, ConfigBuilder};
+use clap::{
+    crate_authors, crate_description, crate_name, crate_version, values_t, App, Arg, ArgMatches,
+    SubCommand,
+};
+use pb_rs::{
+    breaking,
+    descriptor::google::protobuf::FileDescriptorSet,
+    descriptor_set::{decode, file_descriptor_set, write_descriptor_set},
+    errors::Error,
+    format,
+    lint::lint_file,
+    types::FileDescriptor,
+    ConfigBuilder,
+};
 use std::{
     collections::HashMap,
     path::{Path, PathBuf},
 };
 
-fn run() -> Result<(), Error> {
+fn run() -> Result<i32, Error> {
     let matches = App::new(crate_name!())
         .about(crate_description!())
         .author(crate_authors!("\n"))
@@ -73,6 +85,14 @@ fn run() -> Result<(), Error> {
                 .multiple(true)
                 .number_of_values(1)
                 .help("The comma separated values to add to #[derive(...)] for every struct"),
+        ).arg(
+            Arg::with_name("CUSTOM_FIELD_TYPE")
+                .long("custom_field_type")
+                .required(false)
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("The Rust type of a field, as <package.Message.field>=<type>, converted with TryFrom"),
         ).arg(
             Arg::with_name("CUSTOM_REPR")
                 .long("custom_repr")
@@ -101,22 +121,148 @@ fn run() -> Result<(), Error> {
                 .long("hashrown")
                 .required(false)
                 .help("Use hashrown for HashMap implementation"),
+        ).arg(
+            Arg::with_name("STRICT")
+                .long("strict")
+                .required(false)
+                .help("Reject duplicate singular fields and oneofs when decoding with a strict BytesReader"),
+        ).arg(
+            Arg::with_name("HEAPLESS")
+                .long("heapless")
+                .required(false)
+                .help("Use fixed capacity heapless collections, sized by the (rust.capacity) and (rust.max_len) options"),
         ).arg(
             Arg::with_name("GEN_INFO")
                 .long("gen-info")
                 .required(false)
                 .help("Generate MessageInfo implementations")
+        ).arg(
+            Arg::with_name("GEN_BUILDERS")
+                .long("gen-builders")
+                .required(false)
+                .help("Generate a builder for each message, failing to build if a required field is missing")
+        ).arg(
+            Arg::with_name("GEN_ACCESSORS")
+                .long("gen-accessors")
+                .required(false)
+                .help("Generate accessors returning the declared defaults of unset fields")
         ).arg(
             Arg::with_name("ADD_DEPRECATED_FIELDS")
                 .long("add-deprecated-fields")
                 .required(false)
                 .help("Add deprecated fields and mark them as #[deprecated]")
+        ).arg(
+            Arg::with_name("GENERATE_SERVICES")
+                .long("generate_services")
+                .required(false)
+                .help("Generate a trait, a server and a client stub for each service, using quick_protobuf::rpc")
+        ).arg(
+            Arg::with_name("DESCRIPTOR_SET_OUT")
+                .long("descriptor_set_out")
+                .required(false)
+                .takes_value(true)
+                .help("Write a FileDescriptorSet of the input files to this file instead of generating code")
+        ).arg(
+            Arg::with_name("INCLUDE_IMPORTS")
+                .long("include_imports")
+                .required(false)
+                .requires("DESCRIPTOR_SET_OUT")
+                .help("Also include all the imported files in the FileDescriptorSet")
+        ).arg(
+            Arg::with_name("DESCRIPTOR_SET_IN")
+                .long("descriptor_set_in")
+                .required(false)
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .conflicts_with_all(&["INPUT", "DESCRIPTOR_SET_OUT"])
+                .help("Generate code for all the files of this FileDescriptorSet instead of .proto files")
+        ).subcommand(
+            SubCommand::with_name("breaking")
+                .about("Lists the breaking changes between two versions of a schema, fails if there are any")
+                .arg(
+                    Arg::with_name("OLD")
+                        .required(true)
+                        .help("The old version: a directory of .proto files, a .proto file or a FileDescriptorSet"),
+                ).arg(
+                    Arg::with_name("NEW")
+                        .required(true)
+                        .help("The new version, like OLD"),
+                ).arg(
+                    Arg::with_name("INCLUDE_PATH")
+                        .long("include")
+                        .short("I")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("Path to search for imported protobufs, besides the directories of OLD and NEW"),
+                ).arg(
+                    Arg::with_name("JSON")
+                        .long("json")
+                        .help("Print the changes as JSON objects, one per line"),
+                ),
+        ).subcommand(
+            SubCommand::with_name("fmt")
+                .about("Formats .proto files in place")
+                .arg(
+                    Arg::with_name("INPUT")
+                        .required(true)
+                        .multiple(true)
+                        .help("The .proto files, or directories of .proto files, to format"),
+                ).arg(
+                    Arg::with_name("CHECK")
+                        .long("check")
+                        .help("Do not write the files, list the ones which are not formatted and fail if there are any"),
+                ),
+        ).subcommand(
+            SubCommand::with_name("lint")
+                .about("Checks the style of .proto files, fails if there are any issues")
+                .arg(
+                    Arg::with_name("INPUT")
+                        .required(true)
+                        .multiple(true)
+                        .help("The .proto files, or directories of .proto files, to check"),
+                ).arg(
+                    Arg::with_name("ROOT")
+                        .long("root")
+                        .takes_value(true)
+                        .help("Directory the package directories are relative to, defaults to the INPUT directories"),
+                ),
         ).get_matches();
 
-    let in_files = path_vec(values_t!(matches, "INPUT", String));
+    if let Some(matches) = matches.subcommand_matches("breaking") {
+        return breaking(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("fmt") {
+        return fmt(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("lint") {
+        return lint(matches);
+    }
+
+    let descriptor_set_in = matches.is_present("DESCRIPTOR_SET_IN");
+    let in_files = if descriptor_set_in {
+        path_vec(values_t!(matches, "DESCRIPTOR_SET_IN", String))
+    } else {
+        path_vec(values_t!(matches, "INPUT", String))
+    };
     let include_paths = path_vec(values_t!(matches, "INCLUDE_PATH", String));
     let out_file = matches.value_of("OUTPUT").map(PathBuf::from);
     let out_dir = matches.value_of("OUTPUT_DIR").map(PathBuf::from);
+
+    if let Some(descriptor_set_out) = matches.value_of("DESCRIPTOR_SET_OUT") {
+        if in_files.is_empty() {
+            return Err(Error::NoProto);
+        }
+        write_descriptor_set(
+            &in_files,
+            &include_paths,
+            matches.is_present("INCLUDE_IMPORTS"),
+            Path::new(descriptor_set_out),
+        )?;
+        return Ok(0);
+    }
+
     let custom_repr = matches.value_of("CUSTOM_REPR").map(|o| o.into());
     let mut default_custom_struct_derive = String::new();
     let custom_struct_derive: HashMap<String, String> = matches
@@ -137,6 +283,15 @@ fn run() -> Result<(), Error> {
         })
         .collect();
 
+    let custom_field_types = matches
+        .values_of("CUSTOM_FIELD_TYPE")
+        .unwrap_or_default()
+        .map(|t| match t.find('=') {
+            Some(i) => Ok((t[..i].to_string(), t[i + 1..].to_string())),
+            None => Err(Error::CustomFieldType(t.to_string())),
+        })
+        .collect::<Result<_, _>>()?;
+
     let compiler = ConfigBuilder::new(
         &in_files,
         out_file.as_ref(),
@@ -150,14 +305,136 @@ fn run() -> Result<(), Error> {
     .dont_use_cow(matches.is_present("DONT_USE_COW"))
     .default_custom_struct_derive(default_custom_struct_derive)
     .custom_struct_derive(custom_struct_derive)
+    .custom_field_types(custom_field_types)
     .nostd(matches.is_present("NOSTD"))
     .hashbrown(matches.is_present("HASHBROWN"))
+    .strict(matches.is_present("STRICT"))
+    .heapless(matches.is_present("HEAPLESS"))
     .gen_info(matches.is_present("GEN_INFO"))
+    .gen_builders(matches.is_present("GEN_BUILDERS"))
+    .gen_accessors(matches.is_present("GEN_ACCESSORS"))
     .custom_repr(custom_repr)
     .owned(matches.is_present("OWNED"))
-    .add_deprecated_fields(matches.is_present("ADD_DEPRECATED_FIELDS"));
+    .add_deprecated_fields(matches.is_present("ADD_DEPRECATED_FIELDS"))
+    .generate_services(matches.is_present("GENERATE_SERVICES"))
+    .descriptor_set(descriptor_set_in);
+
+    FileDescriptor::run(&compiler.build())?;
+    Ok(0)
+}
+
+/// Prints the breaking changes between two versions of a schema, returns 1 if there are any
+fn breaking(matches: &ArgMatches) -> Result<i32, Error> {
+    let include_paths = path_vec(values_t!(matches, "INCLUDE_PATH", String));
+    let (mut old_bytes, mut new_bytes) = (Vec::new(), Vec::new());
+    let old = read_schema(matches.value_of("OLD"), &include_paths, &mut old_bytes)?;
+    let new = read_schema(matches.value_of("NEW"), &include_paths, &mut new_bytes)?;
+    let changes = breaking::compare(&old, &new);
+    for change in &changes {
+        if matches.is_present("JSON") {
+            println!("{}", change.to_json());
+        } else {
+            println!("{}", change);
+        }
+    }
+    Ok(if changes.is_empty() { 0 } else { 1 })
+}
 
-    FileDescriptor::run(&compiler.build())
+/// Formats .proto files in place, or returns 1 if some are not formatted with `--check`
+fn fmt(matches: &ArgMatches) -> Result<i32, Error> {
+    let mut unformatted = false;
+    for (file, _) in input_protos(matches)? {
+        let input = std::fs::read_to_string(&file)?;
+        let formatted = format::format(&file, &input)?;
+        if formatted == input {
+            continue;
+        }
+        if matches.is_present("CHECK") {
+            println!("{}", file.display());
+            unformatted = true;
+        } else {
+            std::fs::write(&file, formatted)?;
+        }
+    }
+    Ok(if unformatted { 1 } else { 0 })
+}
+
+/// Prints the style issues of .proto files, returns 1 if there are any
+fn lint(matches: &ArgMatches) -> Result<i32, Error> {
+    let root = matches.value_of("ROOT").map(PathBuf::from);
+    let mut found = false;
+    for (file, dir) in input_protos(matches)? {
+        let root = root.as_ref().or(dir.as_ref());
+        for lint in lint_file(&file, root.map(PathBuf::as_path))? {
+            println!("{}: {} {}", file.display(), lint.rule.name(), lint);
+            found = true;
+        }
+    }
+    Ok(if found { 1 } else { 0 })
+}
+
+/// The .proto files of the `INPUT` argument, with the `INPUT` directory containing them
+fn input_protos(matches: &ArgMatches) -> Result<Vec<(PathBuf, Option<PathBuf>)>, Error> {
+    let mut protos = Vec::new();
+    for input in path_vec(values_t!(matches, "INPUT", String)) {
+        if input.is_dir() {
+            let mut files = Vec::new();
+            proto_files(&input, &mut files)?;
+            files.sort();
+            protos.extend(files.into_iter().map(|f| (f, Some(input.clone()))));
+        } else if input.exists() {
+            protos.push((input, None));
+        } else {
+            return Err(Error::InputFile(format!("{}", input.display())));
+        }
+    }
+    Ok(protos)
+}
+
+/// Reads a directory of .proto files, a .proto file or an encoded `FileDescriptorSet`
+fn read_schema<'a>(
+    path: Option<&str>,
+    include_paths: &[PathBuf],
+    bytes: &'a mut Vec<u8>,
+) -> Result<FileDescriptorSet<'a>, Error> {
+    let path = Path::new(path.unwrap_or_default());
+    if !path.exists() {
+        return Err(Error::InputFile(format!("{}", path.display())));
+    }
+    // absolute, for the names of the files in the set to be relative to it
+    let path = path.canonicalize()?;
+    if path.is_dir() {
+        let mut protos = Vec::new();
+        proto_files(&path, &mut protos)?;
+        protos.sort();
+        let mut include = vec![path];
+        include.extend_from_slice(include_paths);
+        file_descriptor_set(&protos, &include, false)
+    } else if path.extension() == Some("proto".as_ref()) {
+        let mut include = path
+            .parent()
+            .into_iter()
+            .map(Path::to_path_buf)
+            .collect::<Vec<_>>();
+        include.extend_from_slice(include_paths);
+        file_descriptor_set(&[path], &include, false)
+    } else {
+        *bytes = std::fs::read(&path)?;
+        decode(bytes)
+    }
+}
+
+/// The .proto files in `dir` and its subdirectories
+fn proto_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            proto_files(&path, files)?;
+        } else if path.extension() == Some("proto".as_ref()) {
+            files.push(path);
+        }
+    }
+    Ok(())
 }
 
 fn extension_matches<P: AsRef<Path>>(path: P, expected: &str) -> std::result::Result<(), String> {
@@ -182,8 +459,9 @@ fn path_vec(maybe_vec: std::result::Result<Vec<String>, clap::Error>) -> Vec<Pat
 
 fn main() {
     env_logger::init();
-    ::std::process::exit({
-        if let Err(e) = run() {
+    ::std::process::exit(match run() {
+        Ok(code) => code,
+        Err(e) => {
             eprintln!("pb-rs fatal error {}", e);
             let mut e: &dyn std::error::Error = &e;
             while let Some(err) = e.source() {
@@ -191,8 +469,6 @@ fn main() {
                 e = err;
             }
             1
-        } else {
-            0
         }
     });
 }
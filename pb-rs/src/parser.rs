@@ -1,29 +1,248 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str;
 
+use crate::errors::{Error, ParseError};
 use crate::types::{
-    Enumerator, EnumField, Field, FieldType, FileDescriptor, Frequency, Message, OneOf,
-    RpcFunctionDeclaration, RpcService, Syntax,
+    get_option, Comments, EnumField, Enumerator, Extend, Field, FieldType, FileDescriptor,
+    Frequency, Message, OneOf, OptionValue, ProtoOption, RpcFunctionDeclaration, RpcService,
+    Syntax, MAX_FIELD_NUMBER,
 };
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_until},
-    character::complete::{alpha1, alphanumeric1, digit1, hex_digit1, multispace1, not_line_ending},
-    combinator::{map, map_res, not, opt, recognize, value, verify},
+    bytes::complete::take_until,
+    character::complete::{
+        alpha1, alphanumeric1, digit1, hex_digit1, multispace0, multispace1, not_line_ending,
+        space0,
+    },
+    combinator::{eof, map, map_res, not, opt, recognize, value, verify},
+    error::{context, ContextError, ErrorKind, FromExternalError},
     multi::{many0, many1, separated_list0, separated_list1},
     sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
-    IResult,
 };
 
+type IResult<I, O> = nom::IResult<I, O, Failure<I>>;
+
+/// Parser error, kept at the furthest position reached in the input
+///
+/// The alternatives failing at the same position are merged, so that all the expected tokens are
+/// reported.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Failure<I> {
+    /// The remaining input at the error
+    input: I,
+    /// What could have been parsed here
+    expected: Vec<String>,
+    /// The constructs being parsed, innermost first
+    constructs: Vec<&'static str>,
+}
+
+impl<'a> Failure<&'a str> {
+    fn expected(input: &'a str, expected: String) -> Self {
+        Failure {
+            input,
+            expected: vec![expected],
+            constructs: Vec::new(),
+        }
+    }
+
+    /// Locates the error in `source`, the content of the file `path`
+    fn into_parse_error(self, path: &Path, source: &str) -> ParseError {
+        let offset = source.len() - self.input.len();
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+        let found = match self.input.chars().next() {
+            None => "end of file".to_string(),
+            Some('\n') | Some('\r') => "end of line".to_string(),
+            Some(c) if c.is_alphanumeric() || c == '_' => match word_ref(self.input) {
+                Ok((_, word)) => format!("`{}`", word),
+                Err(_) => format!("`{}`", c),
+            },
+            Some(c) if c.is_whitespace() => "whitespace".to_string(),
+            Some(c) => format!("`{}`", c),
+        };
+        ParseError {
+            path: path.to_path_buf(),
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            construct: self.constructs.first().cloned(),
+            expected: self.expected,
+            found,
+            source_line: source[line_start..line_end].trim_end().to_string(),
+        }
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Failure<&'a str> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        let expected = match kind {
+            ErrorKind::Alpha | ErrorKind::AlphaNumeric => vec!["an identifier".to_string()],
+            ErrorKind::Digit | ErrorKind::HexDigit | ErrorKind::MapRes => {
+                vec!["an integer".to_string()]
+            }
+            _ => Vec::new(),
+        };
+        Failure {
+            input,
+            expected,
+            constructs: Vec::new(),
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(mut self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => {
+                for e in other.expected {
+                    if !self.expected.contains(&e) {
+                        self.expected.push(e);
+                    }
+                }
+                if self.constructs != other.constructs {
+                    self.constructs.clear();
+                }
+                self
+            }
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Failure<&'a str> {
+    fn add_context(input: &'a str, construct: &'static str, mut other: Self) -> Self {
+        // not started if it fails on its first token
+        if input.len() != other.input.len() {
+            other.constructs.push(construct);
+        }
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Failure<&'a str> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+/// Replaces the expected tokens of the errors of `f` failing on its first token by `expected`
+fn expecting<'a, O>(
+    expected: &'static str,
+    mut f: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    move |input: &'a str| {
+        f(input).map_err(|e| {
+            e.map(|mut e| {
+                if e.input.len() == input.len() {
+                    e.expected = vec![expected.to_string()];
+                }
+                e
+            })
+        })
+    }
+}
+
+/// `opt`, except that the errors of `f` after its first token are not discarded
+fn maybe<'a, O>(
+    mut f: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Option<O>> {
+    move |input: &'a str| match f(input) {
+        Ok((rem, o)) => Ok((rem, Some(o))),
+        Err(nom::Err::Error(e)) if e.input.len() == input.len() => Ok((input, None)),
+        Err(e) => Err(e),
+    }
+}
+
+/// `nom::bytes::complete::tag`, with the expected token in the error
+fn tag<'a>(token: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| match input.strip_prefix(token) {
+        Some(rem) => Ok((rem, &input[..token.len()])),
+        None => Err(nom::Err::Error(Failure::expected(
+            input,
+            format!("`{}`", token),
+        ))),
+    }
+}
+
+/// Repeats `item` until `end`, failing with the error of the item otherwise
+///
+/// Unlike `many0`, the error of an item which does not parse is kept, to be reported where it
+/// actually is and not at the start of the block.
+fn items_till<'a, O, E>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    mut end: impl FnMut(&'a str) -> IResult<&'a str, E>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+        loop {
+            let end_error = match end(input) {
+                Ok((rem, _)) => return Ok((rem, items)),
+                Err(nom::Err::Error(e)) => e,
+                Err(e) => return Err(e),
+            };
+            match item(input) {
+                Ok((rem, _)) if rem.len() == input.len() => {
+                    return Err(nom::Err::Error(end_error));
+                }
+                Ok((rem, o)) => {
+                    items.push(o);
+                    input = rem;
+                }
+                Err(nom::Err::Error(e)) => {
+                    return Err(nom::Err::Error(nom::error::ParseError::or(end_error, e)))
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// The items of a `{ ... }` block, after the opening brace
+fn block<'a, O>(
+    item: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    items_till(item, tag("}"))
+}
+
 #[derive(Debug, Clone)]
 enum MessageEvent {
     Message(Message),
     Enumerator(Enumerator),
     Field(Field),
-    ReservedNums(Vec<i32>),
+    ReservedNums(Vec<(i32, i32)>),
     ReservedNames(Vec<String>),
+    Extensions(Vec<(i32, i32)>),
+    Extend(Extend),
     OneOf(OneOf),
+    Option(ProtoOption),
+    Ignore,
+}
+
+#[derive(Debug, Clone)]
+enum EnumEvent {
+    Field(EnumField),
+    Option(ProtoOption),
+    Ignore,
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+enum OneOfEvent {
+    Field(Field),
+    Option(ProtoOption),
+    Ignore,
+}
+
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+enum ServiceEvent {
+    Function(RpcFunctionDeclaration),
+    Option(ProtoOption),
     Ignore,
 }
 
@@ -34,7 +253,9 @@ enum Event {
     Package(String),
     Message(Message),
     Enum(Enumerator),
+    Extend(Extend),
     RpcService(RpcService),
+    Option(ProtoOption),
     Ignore,
 }
 
@@ -49,17 +270,20 @@ fn qualifiable_name(input: &str) -> IResult<&str, String> {
 }
 
 fn word_ref(input: &str) -> IResult<&str, &str> {
-    recognize(pair(
-        alt((
-            // I would really rather just take in 1 alphabetic
-            // character, but just using `alpha1()` is also technically
-            // correct for our use case and is simpler to implement in
-            // nom apparently
-            alpha1,
-            tag("_"),
+    expecting(
+        "an identifier",
+        recognize(pair(
+            alt((
+                // I would really rather just take in 1 alphabetic
+                // character, but just using `alpha1()` is also technically
+                // correct for our use case and is simpler to implement in
+                // nom apparently
+                alpha1,
+                tag("_"),
+            )),
+            many0(alt((alphanumeric1, tag("_")))),
         )),
-        many0(alt((alphanumeric1, tag("_")))),
-    ))(input)
+    )(input)
 }
 
 fn word(input: &str) -> IResult<&str, String> {
@@ -85,15 +309,86 @@ fn one_of_attribute_comments(input: &str) -> IResult<&str, Vec<String>> {
     many0(map(delimited(tag("// rust-one-of-attribute: "), take_until("\n"), opt(multispace1)), str::to_owned))(input)
 }
 
-fn comment(input: &str) -> IResult<&str, ()> {
-    value(
-        (),
-        tuple((tag("//"), not(alt((tag(" rust-attribute: "), tag(" rust-one-of-attribute: ")))), not_line_ending))
+fn line_comment(input: &str) -> IResult<&str, &str> {
+    preceded(
+        pair(tag("//"), not(alt((tag(" rust-attribute: "), tag(" rust-one-of-attribute: "))))),
+        not_line_ending,
     )(input)
 }
 
+fn comment(input: &str) -> IResult<&str, ()> {
+    value((), line_comment)(input)
+}
+
+fn block_comment_text(input: &str) -> IResult<&str, &str> {
+    delimited(tag("/*"), take_until("*/"), tag("*/"))(input)
+}
+
 fn block_comment(input: &str) -> IResult<&str, ()> {
-    value((), tuple((tag("/*"), take_until("*/"), tag("*/"))))(input)
+    value((), block_comment_text)(input)
+}
+
+/// Lines of a comment, without the `//`, `/*`, `*/` and the leading `*` of block comments
+fn comment_lines(input: &str) -> IResult<&str, Vec<String>> {
+    alt((
+        map(line_comment, |l| vec![l.trim_end().to_string()]),
+        map(block_comment_text, |b| {
+            let mut lines = b
+                .lines()
+                .enumerate()
+                .map(|(i, l)| {
+                    if i == 0 {
+                        l.trim_start_matches('*')
+                    } else {
+                        l.trim_start().strip_prefix('*').unwrap_or(l)
+                    }
+                })
+                .map(|l| l.trim_end().to_string())
+                .collect::<Vec<_>>();
+            while lines.last().map(|l| l.trim().is_empty()) == Some(true) {
+                lines.pop();
+            }
+            if lines.first().map(|l| l.trim().is_empty()) == Some(true) {
+                lines.remove(0);
+            }
+            lines
+        }),
+    ))(input)
+}
+
+/// Comments right before an element
+///
+/// Comments separated from the element by a blank line are detached from it, comments following
+/// a previous element on the same line are ignored.
+fn leading_comments(input: &str) -> IResult<&str, Comments> {
+    map(
+        pair(many0(pair(multispace0, comment_lines)), multispace0),
+        |(comments, end): (Vec<(&str, Vec<String>)>, &str)| {
+            let mut detached = Vec::new();
+            let mut lines = Vec::new();
+            for (i, (space, text)) in comments.into_iter().enumerate() {
+                match space.matches('\n').count() {
+                    0 if i == 0 => (),
+                    0 | 1 => lines.extend(text),
+                    _ if lines.is_empty() => lines = text,
+                    _ => detached.push(std::mem::replace(&mut lines, text)),
+                }
+            }
+            if end.matches('\n').count() > 1 && !lines.is_empty() {
+                detached.push(std::mem::take(&mut lines));
+            }
+            Comments {
+                detached,
+                leading: lines,
+                trailing: Vec::new(),
+            }
+        },
+    )(input)
+}
+
+/// Comment after an element, starting on the same line
+fn trailing_comment(input: &str) -> IResult<&str, Vec<String>> {
+    map(opt(preceded(space0, comment_lines)), Option::unwrap_or_default)(input)
 }
 
 fn string(input: &str) -> IResult<&str, String> {
@@ -105,78 +400,344 @@ fn string(input: &str) -> IResult<&str, String> {
 
 // word break: multispace or comment
 fn br(input: &str) -> IResult<&str, ()> {
-    value((), many1(alt((value((), multispace1), comment, block_comment))))(input)
+    value(
+        (),
+        many1(alt((value((), multispace1), comment, block_comment))),
+    )(input)
+    .map_err(|e| {
+        // whitespace and comments can be everywhere, listing them is just noise
+        e.map(|mut e| {
+            e.expected.clear();
+            e
+        })
+    })
 }
 
 fn syntax(input: &str) -> IResult<&str, Syntax> {
-    delimited(
-        tuple((tag("syntax"), many0(br), tag("="), many0(br))),
-        alt((
-            value(Syntax::Proto2, tag("\"proto2\"")),
-            value(Syntax::Proto3, tag("\"proto3\"")),
-        )),
-        pair(many0(br), tag(";")),
+    context(
+        "syntax",
+        delimited(
+            tuple((tag("syntax"), many0(br), tag("="), many0(br))),
+            alt((
+                value(Syntax::Proto2, tag("\"proto2\"")),
+                value(Syntax::Proto3, tag("\"proto3\"")),
+            )),
+            pair(many0(br), tag(";")),
+        ),
     )(input)
 }
 
 fn import(input: &str) -> IResult<&str, PathBuf> {
-    delimited(
-        pair(tag("import"), many1(br)),
-        map(string, PathBuf::from),
-        pair(many0(br), tag(";")),
+    context(
+        "import",
+        delimited(
+            pair(tag("import"), many1(br)),
+            map(string, PathBuf::from),
+            pair(many0(br), tag(";")),
+        ),
     )(input)
 }
 
 fn package(input: &str) -> IResult<&str, String> {
-    delimited(
-        pair(tag("package"), many1(br)),
-        qualifiable_name,
-        pair(many0(br), tag(";")),
+    context(
+        "package",
+        delimited(
+            pair(tag("package"), many1(br)),
+            qualifiable_name,
+            pair(many0(br), tag(";")),
+        ),
     )(input)
 }
 
-fn extensions(input: &str) -> IResult<&str, ()> {
-    value((), delimited(tag("extensions"), take_until(";"), tag(";")))(input)
+/// A field number or an inclusive range of field numbers, up to `max`
+fn num_range(input: &str) -> IResult<&str, (i32, i32)> {
+    alt((
+        separated_pair(
+            integer,
+            tuple((many1(br), tag("to"), many1(br))),
+            alt((integer, value(MAX_FIELD_NUMBER, tag("max")))),
+        ),
+        map(integer, |i| (i, i)),
+    ))(input)
 }
 
-fn num_range(input: &str) -> IResult<&str, Vec<i32>> {
-    map(
-        separated_pair(integer, tuple((many1(br), tag("to"), many1(br))), integer),
-        |(from_, to)| (from_..=to).collect(),
+fn extensions(input: &str) -> IResult<&str, Vec<(i32, i32)>> {
+    context(
+        "extensions",
+        delimited(
+            pair(tag("extensions"), many1(br)),
+            separated_list1(tuple((many0(br), tag(","), many0(br))), num_range),
+            tuple((
+                many0(br),
+                // extension declarations are not supported
+                opt(tuple((tag("["), take_until("]"), tag("]"), many0(br)))),
+                tag(";"),
+            )),
+        ),
     )(input)
 }
 
-fn reserved_nums(input: &str) -> IResult<&str, Vec<i32>> {
-    map(
+fn extend(input: &str) -> IResult<&str, Extend> {
+    context(
+        "extend",
+        map(
+            pair(
+                delimited(pair(tag("extend"), many1(br)), qualifiable_name, many0(br)),
+                preceded(
+                    tag("{"),
+                    block(alt((map(message_field, Some), value(None, br)))),
+                ),
+            ),
+            |(extendee, fields)| Extend {
+                extendee,
+                fields: fields.into_iter().flatten().collect(),
+                ..Default::default()
+            },
+        ),
+    )(input)
+}
+
+fn reserved_nums(input: &str) -> IResult<&str, Vec<(i32, i32)>> {
+    context(
+        "reserved",
         delimited(
             pair(tag("reserved"), many1(br)),
-            separated_list1(
-                tuple((many0(br), tag(","), many0(br))),
-                alt((num_range, map(integer, |i| vec![i]))),
-            ),
+            separated_list1(tuple((many0(br), tag(","), many0(br))), num_range),
             pair(many0(br), tag(";")),
         ),
-        |nums| nums.into_iter().flat_map(|v| v.into_iter()).collect(),
     )(input)
 }
 
 fn reserved_names(input: &str) -> IResult<&str, Vec<String>> {
-    delimited(
-        pair(tag("reserved"), many1(br)),
-        separated_list1(tuple((many0(br), tag(","), many0(br))), string),
-        pair(many0(br), tag(";")),
+    context(
+        "reserved",
+        delimited(
+            pair(tag("reserved"), many1(br)),
+            separated_list1(tuple((many0(br), tag(","), many0(br))), string),
+            pair(many0(br), tag(";")),
+        ),
+    )(input)
+}
+
+/// A single or double quoted string, escapes included
+fn quoted(input: &str) -> IResult<&str, &str> {
+    let quote = match input.chars().next() {
+        Some(q @ '"') | Some(q @ '\'') => q,
+        _ => {
+            return Err(nom::Err::Error(Failure::expected(
+                input,
+                "a string".to_string(),
+            )))
+        }
+    };
+    let mut escaped = false;
+    for (i, c) in input.char_indices().skip(1) {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == quote {
+            return Ok((&input[i + 1..], &input[1..i]));
+        }
+    }
+    // reported where the string should have ended
+    let line_end = input.find('\n').unwrap_or(input.len());
+    Err(nom::Err::Error(Failure::expected(
+        &input[line_end..],
+        format!("closing `{}`", quote),
+    )))
+}
+
+/// Resolves the escape sequences of a string literal
+///
+/// Octal and hexadecimal escapes are bytes, they are mapped to the chars with the same code.
+pub(crate) fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        let radix_digits = |chars: &mut std::iter::Peekable<std::str::Chars>, radix, max| {
+            let mut v = 0;
+            for _ in 0..max {
+                match chars.peek().and_then(|c| c.to_digit(radix)) {
+                    Some(d) => {
+                        v = v * radix + d;
+                        chars.next();
+                    }
+                    None => break,
+                }
+            }
+            v
+        };
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('a') => out.push('\x07'),
+            Some('b') => out.push('\x08'),
+            Some('f') => out.push('\x0C'),
+            Some('v') => out.push('\x0B'),
+            Some('x') | Some('X') => out.extend(char::from_u32(radix_digits(&mut chars, 16, 2))),
+            Some('u') => out.extend(char::from_u32(radix_digits(&mut chars, 16, 4))),
+            Some('U') => out.extend(char::from_u32(radix_digits(&mut chars, 16, 8))),
+            Some(c @ '0'..='7') => {
+                let mut v = c.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            v = v * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                out.extend(char::from_u32(v));
+            }
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// One or more adjacent string literals, concatenated
+fn string_literal(input: &str) -> IResult<&str, String> {
+    map(
+        pair(quoted, many0(preceded(many0(br), quoted))),
+        |(first, rest)| {
+            let mut s = unescape(first);
+            for r in rest {
+                s.push_str(&unescape(r));
+            }
+            s
+        },
     )(input)
 }
 
-fn key_val(input: &str) -> IResult<&str, (&str, &str)> {
+/// Integer or float literal, with an optional sign
+fn number(input: &str) -> IResult<&str, String> {
+    let b = input.as_bytes();
+    let mut end = usize::from(matches!(b.first(), Some(b'-') | Some(b'+')));
+    if !matches!(b.get(end), Some(c) if c.is_ascii_digit() || *c == b'.') {
+        return Err(nom::Err::Error(Failure::expected(
+            input,
+            "a number".to_string(),
+        )));
+    }
+    let hex = input[end..].starts_with("0x") || input[end..].starts_with("0X");
+    while let Some(&c) = b.get(end) {
+        if c.is_ascii_alphanumeric()
+            || c == b'.'
+            || (c == b'-' || c == b'+') && !hex && matches!(b[end - 1], b'e' | b'E')
+        {
+            end += 1;
+        } else {
+            break;
+        }
+    }
+    Ok((&input[end..], input[..end].to_string()))
+}
+
+/// Option name, `(custom.option).field` or `java_package`
+fn option_name(input: &str) -> IResult<&str, String> {
+    map(
+        recognize(pair(
+            alt((
+                recognize(delimited(tag("("), qualifiable_name, tag(")"))),
+                word_ref,
+            )),
+            many0(pair(tag("."), word_ref)),
+        )),
+        str::to_owned,
+    )(input)
+}
+
+fn aggregate_field(input: &str) -> IResult<&str, (String, OptionValue)> {
+    pair(
+        alt((
+            map(
+                recognize(delimited(tag("["), qualifiable_name, tag("]"))),
+                str::to_owned,
+            ),
+            word,
+        )),
+        preceded(
+            many0(br),
+            alt((
+                preceded(pair(tag(":"), many0(br)), option_value),
+                // the colon is optional before messages
+                map(aggregate, OptionValue::Aggregate),
+            )),
+        ),
+    )(input)
+}
+
+fn aggregate(input: &str) -> IResult<&str, Vec<(String, OptionValue)>> {
     delimited(
-        pair(tag("["), many0(br)),
+        pair(alt((tag("{"), tag("<"))), many0(br)),
+        many0(terminated(
+            aggregate_field,
+            tuple((many0(br), opt(alt((tag(","), tag(";")))), many0(br))),
+        )),
+        alt((tag("}"), tag(">"))),
+    )(input)
+}
+
+pub(crate) fn option_value(input: &str) -> IResult<&str, OptionValue> {
+    alt((
+        map(aggregate, OptionValue::Aggregate),
+        map(
+            delimited(
+                pair(tag("["), many0(br)),
+                separated_list0(tuple((many0(br), tag(","), many0(br))), option_value),
+                pair(many0(br), tag("]")),
+            ),
+            OptionValue::List,
+        ),
+        map(string_literal, OptionValue::String),
+        map(number, OptionValue::Number),
+        map(
+            recognize(pair(opt(tag("-")), qualifiable_name)),
+            |s: &str| OptionValue::Ident(s.to_owned()),
+        ),
+    ))(input)
+}
+
+fn proto_option(input: &str) -> IResult<&str, ProtoOption> {
+    map(
         separated_pair(
-            word_ref,
-            delimited(many0(br), tag("="), many0(br)),
-            map(take_until("]"), |v: &str| v.trim()),
+            option_name,
+            tuple((many0(br), tag("="), many0(br))),
+            option_value,
+        ),
+        |(name, value)| ProtoOption { name, value },
+    )(input)
+}
+
+/// `option name = value;` statement
+fn option_statement(input: &str) -> IResult<&str, ProtoOption> {
+    context(
+        "option",
+        delimited(
+            pair(tag("option"), many0(br)),
+            proto_option,
+            pair(many0(br), tag(";")),
+        ),
+    )(input)
+}
+
+/// Field or enum value options `[name = value, ...]`
+fn field_options(input: &str) -> IResult<&str, Vec<ProtoOption>> {
+    context(
+        "field options",
+        delimited(
+            pair(tag("["), many0(br)),
+            separated_list1(tuple((many0(br), tag(","), many0(br))), proto_option),
+            pair(many0(br), tag("]")),
         ),
-        tag("]"),
     )(input)
 }
 
@@ -189,154 +750,229 @@ fn frequency(input: &str) -> IResult<&str, Frequency> {
 }
 
 fn field_type(input: &str) -> IResult<&str, FieldType> {
-    alt((
-        value(FieldType::Int32, tag("int32")),
-        value(FieldType::Int64, tag("int64")),
-        value(FieldType::Uint32, tag("uint32")),
-        value(FieldType::Uint64, tag("uint64")),
-        value(FieldType::Sint32, tag("sint32")),
-        value(FieldType::Sint64, tag("sint64")),
-        value(FieldType::Fixed32, tag("fixed32")),
-        value(FieldType::Sfixed32, tag("sfixed32")),
-        value(FieldType::Fixed64, tag("fixed64")),
-        value(FieldType::Sfixed64, tag("sfixed64")),
-        value(FieldType::Bool, tag("bool")),
-        value(FieldType::StringCow, tag("string")),
-        value(FieldType::BytesCow, tag("bytes")),
-        value(FieldType::Float, tag("float")),
-        value(FieldType::Double, tag("double")),
-        map(map_field, |(k, v)| FieldType::Map(Box::new(k), Box::new(v))),
-        map(qualifiable_name, FieldType::MessageOrEnum),
-    ))(input)
+    expecting(
+        "a type",
+        alt((
+            value(FieldType::Int32, tag("int32")),
+            value(FieldType::Int64, tag("int64")),
+            value(FieldType::Uint32, tag("uint32")),
+            value(FieldType::Uint64, tag("uint64")),
+            value(FieldType::Sint32, tag("sint32")),
+            value(FieldType::Sint64, tag("sint64")),
+            value(FieldType::Fixed32, tag("fixed32")),
+            value(FieldType::Sfixed32, tag("sfixed32")),
+            value(FieldType::Fixed64, tag("fixed64")),
+            value(FieldType::Sfixed64, tag("sfixed64")),
+            value(FieldType::Bool, tag("bool")),
+            value(FieldType::StringCow, tag("string")),
+            value(FieldType::BytesCow, tag("bytes")),
+            value(FieldType::Float, tag("float")),
+            value(FieldType::Double, tag("double")),
+            map(map_field, |(k, v)| FieldType::Map(Box::new(k), Box::new(v))),
+            map(qualifiable_name, FieldType::MessageOrEnum),
+        )),
+    )(input)
 }
 
 fn map_field(input: &str) -> IResult<&str, (FieldType, FieldType)> {
-    delimited(
-        tuple((tag("map"), many0(br), tag("<"), many0(br))),
-        separated_pair(
-            field_type,
-            delimited(many0(br), tag(","), many0(br)),
-            field_type,
+    context(
+        "map",
+        delimited(
+            tuple((tag("map"), many0(br), tag("<"), many0(br))),
+            separated_pair(
+                field_type,
+                delimited(many0(br), tag(","), many0(br)),
+                field_type,
+            ),
+            pair(many0(br), tag(">")),
         ),
-        pair(many0(br), tag(">")),
     )(input)
 }
 
 fn message_field(input: &str) -> IResult<&str, Field> {
-    map(
-        tuple((
-            attribute_comments,
-            opt(terminated(frequency, many1(br))),
-            terminated(field_type, many1(br)),
-            separated_pair(
-                word,
-                delimited(many0(br), tag("="), many0(br)),
-                alt((integer, hex_integer)),
-            ),
-            delimited(many0(br), many0(key_val), pair(many0(br), tag(";"))),
-        )),
-        |(attributes, freq, typ, (name, number), key_vals)| Field {
-            name,
-            frequency: freq.unwrap_or(Frequency::Optional),
-            number,
-            default: key_vals.iter().find_map(|&(k, v)| {
-                if k == "default" {
-                    Some(v.to_string())
-                } else {
-                    None
-                }
-            }),
-            packed: key_vals.iter().find_map(|&(k, v)| {
-                if k == "packed" {
-                    Some(v.parse().expect("Cannot parse Packed value"))
-                } else {
-                    None
-                }
-            }),
-            boxed: false,
-            typ,
-            deprecated: key_vals
-                .iter()
-                .find_map(|&(k, v)| {
-                    if k == "deprecated" {
-                        Some(v.parse().expect("Cannot parse Deprecated value"))
-                    } else {
-                        None
-                    }
-                })
-                .unwrap_or(false),
-            attributes,
-        },
+    context(
+        "field",
+        map(
+            tuple((
+                leading_comments,
+                attribute_comments,
+                opt(terminated(frequency, many1(br))),
+                terminated(field_type, many1(br)),
+                separated_pair(
+                    word,
+                    delimited(many0(br), tag("="), many0(br)),
+                    alt((integer, hex_integer)),
+                ),
+                delimited(
+                    many0(br),
+                    map(maybe(field_options), Option::unwrap_or_default),
+                    pair(many0(br), tag(";")),
+                ),
+                trailing_comment,
+            )),
+            |(comments, attributes, freq, typ, (name, number), options, trailing)| Field {
+                name,
+                // reset in `file_descriptor` for proto2 files
+                proto3_optional: freq == Some(Frequency::Optional),
+                frequency: freq.unwrap_or(Frequency::Optional),
+                number,
+                default: get_option(&options, "default").and_then(|v| match v {
+                    OptionValue::String(s) => Some(format!("{:?}", s)),
+                    OptionValue::Ident(s) | OptionValue::Number(s) => Some(s.clone()),
+                    _ => None,
+                }),
+                packed: get_option(&options, "packed")
+                    .map(|v| v.as_bool().expect("Cannot parse Packed value")),
+                boxed: false,
+                custom_type: None,
+                skip_debug: false,
+                capacity: None,
+                max_len: None,
+                typ,
+                deprecated: get_option(&options, "deprecated")
+                    .map(|v| v.as_bool().expect("Cannot parse Deprecated value"))
+                    .unwrap_or(false),
+                attributes,
+                options,
+                comments: Comments { trailing, ..comments },
+            },
+        ),
     )(input)
 }
 
 fn one_of(input: &str) -> IResult<&str, OneOf> {
-    map(
-        tuple((
-            attribute_comments,
-            one_of_attribute_comments,
-            preceded(pair(tag("oneof"), many1(br)), word),
-            delimited(
-                pair(many0(br), tag("{")),
-                many1(delimited(many0(br), message_field, many0(br))),
-                tag("}"),
-            ),
-        )),
-        |(field_attributes, container_attributes, name, fields)| OneOf {
-            name,
-            fields,
-            package: "".to_string(),
-            module: "".to_string(),
-            imported: false,
-            field_attributes,
-            container_attributes,
-        },
+    context(
+        "oneof",
+        map(
+            tuple((
+                leading_comments,
+                attribute_comments,
+                one_of_attribute_comments,
+                preceded(pair(tag("oneof"), many1(br)), word),
+                preceded(
+                    pair(many0(br), tag("{")),
+                    block(alt((
+                        map(message_field, OneOfEvent::Field),
+                        map(option_statement, OneOfEvent::Option),
+                        value(OneOfEvent::Ignore, br),
+                    ))),
+                ),
+                trailing_comment,
+            )),
+            |(comments, field_attributes, container_attributes, name, events, trailing)| {
+                let mut one_of = OneOf {
+                    name,
+                    field_attributes,
+                    container_attributes,
+                    comments: Comments { trailing, ..comments },
+                    ..Default::default()
+                };
+                for e in events {
+                    match e {
+                        OneOfEvent::Field(f) => one_of.fields.push(f),
+                        OneOfEvent::Option(o) => one_of.options.push(o),
+                        OneOfEvent::Ignore => (),
+                    }
+                }
+                one_of
+            },
+        ),
+    )(input)
+}
+
+/// The argument or return type of an rpc, `[stream] Type`
+fn rpc_type(input: &str) -> IResult<&str, (bool, String)> {
+    delimited(
+        pair(tag("("), many0(br)),
+        pair(
+            map(opt(pair(tag("stream"), many1(br))), |s| s.is_some()),
+            expecting("a type", qualifiable_name),
+        ),
+        pair(many0(br), tag(")")),
     )(input)
 }
 
 fn rpc_function_declaration(input: &str) -> IResult<&str, RpcFunctionDeclaration> {
-    map(
-        tuple((
-            delimited(pair(tag("rpc"), many1(br)), word, many0(br)),
-            delimited(pair(tag("("), many0(br)), word, pair(many0(br), tag(")"))),
-            delimited(
-                tuple((many1(br), tag("returns"), many0(br), tag("("), many0(br))),
-                word,
-                pair(many0(br), tag(")")),
-            ),
-            preceded(
-                many0(br),
-                alt((
-                    value(
-                        (),
-                        delimited(
-                            pair(tag("{"), many0(br)),
-                            many0(alt((option_ignore, value((), tag(";"))))),
-                            pair(many0(br), tag("}")),
+    context(
+        "rpc",
+        map(
+            tuple((
+                leading_comments,
+                delimited(pair(tag("rpc"), many1(br)), word, many0(br)),
+                rpc_type,
+                preceded(tuple((many0(br), tag("returns"), many0(br))), rpc_type),
+                preceded(
+                    many0(br),
+                    alt((
+                        preceded(
+                            tag("{"),
+                            block(alt((
+                                map(option_statement, Some),
+                                value(None, tag(";")),
+                                value(None, br),
+                            ))),
                         ),
-                    ),
-                    value((), tag(";")),
-                )),
-            ),
-        )),
-        |(name, arg, ret, _)| RpcFunctionDeclaration { name, arg, ret },
+                        value(Vec::new(), tag(";")),
+                    )),
+                ),
+                trailing_comment,
+            )),
+            |(
+                comments,
+                name,
+                (client_streaming, arg),
+                (server_streaming, ret),
+                options,
+                trailing,
+            )| {
+                RpcFunctionDeclaration {
+                    name,
+                    arg,
+                    ret,
+                    client_streaming,
+                    server_streaming,
+                    options: options.into_iter().flatten().collect(),
+                    comments: Comments { trailing, ..comments },
+                    ..Default::default()
+                }
+            },
+        ),
     )(input)
 }
 
 fn rpc_service(input: &str) -> IResult<&str, RpcService> {
-    map(
-        pair(
-            delimited(pair(tag("service"), many1(br)), word, many0(br)),
-            delimited(
-                tag("{"),
-                many0(delimited(many0(br), rpc_function_declaration, many0(br))),
-                tag("}"),
-            ),
+    context(
+        "service",
+        map(
+            tuple((
+                leading_comments,
+                delimited(pair(tag("service"), many1(br)), word, many0(br)),
+                preceded(
+                    tag("{"),
+                    block(alt((
+                        map(rpc_function_declaration, ServiceEvent::Function),
+                        map(option_statement, ServiceEvent::Option),
+                        value(ServiceEvent::Ignore, br),
+                    ))),
+                ),
+                trailing_comment,
+            )),
+            |(comments, service_name, events, trailing)| {
+                let mut service = RpcService {
+                    service_name,
+                    comments: Comments { trailing, ..comments },
+                    ..Default::default()
+                };
+                for event in events {
+                    match event {
+                        ServiceEvent::Function(f) => service.functions.push(f),
+                        ServiceEvent::Option(o) => service.options.push(o),
+                        ServiceEvent::Ignore => (),
+                    }
+                }
+                service
+            },
         ),
-        |(service_name, functions)| RpcService {
-            service_name,
-            functions,
-        },
     )(input)
 }
 
@@ -348,125 +984,152 @@ fn message_event(input: &str) -> IResult<&str, MessageEvent> {
         map(message, MessageEvent::Message),
         map(enumerator, MessageEvent::Enumerator),
         map(one_of, MessageEvent::OneOf),
-        value(MessageEvent::Ignore, extensions),
+        map(extensions, MessageEvent::Extensions),
+        map(extend, MessageEvent::Extend),
+        map(option_statement, MessageEvent::Option),
         value(MessageEvent::Ignore, br),
     ))(input)
 }
 
 fn message(input: &str) -> IResult<&str, Message> {
-    map(
-        terminated(
+    context(
+        "message",
+        map(
             tuple((
+                leading_comments,
                 attribute_comments,
                 delimited(pair(tag("message"), many1(br)), word, many0(br)),
-                delimited(
-                    tag("{"),
-                    many0(message_event),
-                    tag("}"),
-                ),
+                preceded(tag("{"), block(message_event)),
+                opt(pair(many0(br), tag(";"))),
+                trailing_comment,
             )),
-            opt(pair(many0(br), tag(";"))),
-        ),
-        |(attributes, name, events)| {
-            let mut msg = Message {
-                name,
-                attributes,
-                ..Default::default()
-            };
-            for e in events {
-                match e {
-                    MessageEvent::Field(f) => msg.fields.push(f),
-                    MessageEvent::ReservedNums(r) => msg.reserved_nums = Some(r),
-                    MessageEvent::ReservedNames(r) => msg.reserved_names = Some(r),
-                    MessageEvent::Message(m) => msg.messages.push(m),
-                    MessageEvent::Enumerator(e) => msg.enums.push(e),
-                    MessageEvent::OneOf(o) => msg.oneofs.push(o),
-                    MessageEvent::Ignore => (),
+            |(comments, attributes, name, events, _, trailing)| {
+                let mut msg = Message {
+                    name,
+                    attributes,
+                    comments: Comments { trailing, ..comments },
+                    ..Default::default()
+                };
+                for e in events {
+                    match e {
+                        MessageEvent::Field(f) => msg.fields.push(f),
+                        MessageEvent::ReservedNums(r) => {
+                            msg.reserved_nums.get_or_insert_with(Vec::new).extend(r)
+                        }
+                        MessageEvent::ReservedNames(r) => {
+                            msg.reserved_names.get_or_insert_with(Vec::new).extend(r)
+                        }
+                        MessageEvent::Message(m) => msg.messages.push(m),
+                        MessageEvent::Enumerator(e) => msg.enums.push(e),
+                        MessageEvent::OneOf(o) => msg.oneofs.push(o),
+                        MessageEvent::Extensions(r) => msg.extension_ranges.extend(r),
+                        MessageEvent::Extend(e) => msg.extends.push(e),
+                        MessageEvent::Option(o) => msg.options.push(o),
+                        MessageEvent::Ignore => (),
+                    }
                 }
-            }
-            msg
-        },
+                msg
+            },
+        ),
     )(input)
 }
 
 
 // TODO: add proper deprecation later. We ignore deprecated enum fields for now
 fn enum_field(input: &str) -> IResult<&str, EnumField> {
-    map(
-        terminated(
-            separated_pair(
-                pair(attribute_comments, word),
-                tuple((many0(br), tag("="), many0(br))),
-                alt((hex_integer, integer)),
-            ),
-            pair(
-                many0(alt((
-                    br,
-                    // TODO: add proper deprecation later. We ignore deprecated enum
-                    // fields for now
-                    value(
-                        (),
-                        tuple((
-                            tag("["),
-                            many0(multispace1),
-                            tag("deprecated"),
-                            many0(multispace1),
-                            tag("="),
-                            many0(multispace1),
-                            word,
-                            many0(multispace1),
-                            tag("]"),
-                        )),
-                    ),
-                ))),
-                tag(";"),
-            )
+    context(
+        "enum value",
+        map(
+            tuple((
+                leading_comments,
+                separated_pair(
+                    pair(attribute_comments, word),
+                    tuple((many0(br), tag("="), many0(br))),
+                    alt((hex_integer, integer)),
+                ),
+                delimited(
+                    many0(br),
+                    map(maybe(field_options), Option::unwrap_or_default),
+                    pair(many0(br), tag(";")),
+                ),
+                trailing_comment,
+            )),
+            |(comments, ((attributes, name), tag), options, trailing)| EnumField {
+                name,
+                tag,
+                attributes,
+                options,
+                comments: Comments { trailing, ..comments },
+            },
         ),
-        |((attributes, name), tag)| EnumField {
-            name,
-            tag,
-            attributes,
-        }
     )(input)
 }
 
 fn enumerator(input: &str) -> IResult<&str, Enumerator> {
-    map(
-        tuple((
-            attribute_comments,
-            delimited(pair(tag("enum"), many1(br)), word, many0(br)),
-            delimited(
-                pair(tag("{"), many0(br)),
-                separated_list0(br, enum_field),
-                pair(many0(br), tag("}")),
-            ),
-            opt(pair(many0(br), tag(";"))),
-        )),
-        |(attributes, name, fields, _)| Enumerator {
-            name,
-            fields,
-            attributes,
-            ..Default::default()
-        },
+    context(
+        "enum",
+        map(
+            tuple((
+                leading_comments,
+                attribute_comments,
+                delimited(pair(tag("enum"), many1(br)), word, many0(br)),
+                preceded(
+                    tag("{"),
+                    block(alt((
+                        map(option_statement, EnumEvent::Option),
+                        map(enum_field, EnumEvent::Field),
+                        value(EnumEvent::Ignore, br),
+                    ))),
+                ),
+                opt(pair(many0(br), tag(";"))),
+                trailing_comment,
+            )),
+            |(comments, attributes, name, events, _, trailing)| {
+                let mut e = Enumerator {
+                    name,
+                    attributes,
+                    comments: Comments { trailing, ..comments },
+                    ..Default::default()
+                };
+                for event in events {
+                    match event {
+                        EnumEvent::Field(f) => e.fields.push(f),
+                        EnumEvent::Option(o) => e.options.push(o),
+                        EnumEvent::Ignore => (),
+                    }
+                }
+                e
+            },
+        ),
     )(input)
 }
 
-fn option_ignore(input: &str) -> IResult<&str, ()> {
-    value((), delimited(tag("option"), take_until(";"), tag(";")))(input)
+/// `optional` only gives presence to proto3 fields, proto2 ones always have it
+fn clear_proto3_optional(messages: &mut [Message]) {
+    for m in messages {
+        for f in &mut m.fields {
+            f.proto3_optional = false;
+        }
+        clear_proto3_optional(&mut m.messages);
+    }
 }
 
-pub fn file_descriptor(input: &str) -> IResult<&str, FileDescriptor, nom::error::Error<String>> {
+fn file_descriptor(input: &str) -> IResult<&str, FileDescriptor> {
     map(
-        many0(alt((
-            map(syntax, Event::Syntax),
-            map(import, Event::Import),
-            map(package, Event::Package),
-            map(message, Event::Message),
-            map(enumerator, Event::Enum),
-            map(rpc_service, Event::RpcService),
-            value(Event::Ignore, option_ignore),
-            value(Event::Ignore, br),
-        ))),
+        items_till(
+            alt((
+                map(syntax, Event::Syntax),
+                map(import, Event::Import),
+                map(package, Event::Package),
+                map(message, Event::Message),
+                map(enumerator, Event::Enum),
+                map(extend, Event::Extend),
+                map(rpc_service, Event::RpcService),
+                map(option_statement, Event::Option),
+                value(Event::Ignore, br),
+            )),
+            eof,
+        ),
         |events| {
             let mut desc = FileDescriptor::default();
             for event in events {
@@ -476,13 +1139,30 @@ pub fn file_descriptor(input: &str) -> IResult<&str, FileDescriptor, nom::error:
                     Event::Package(p) => desc.package = p,
                     Event::Message(m) => desc.messages.push(m),
                     Event::Enum(e) => desc.enums.push(e),
+                    Event::Extend(e) => desc.extends.push(e),
                     Event::RpcService(r) => desc.rpc_services.push(r),
+                    Event::Option(o) => desc.options.push(o),
                     Event::Ignore => (),
                 }
             }
+            if desc.syntax == Syntax::Proto2 {
+                clear_proto3_optional(&mut desc.messages);
+            }
             desc
         },
-    )(input).map_err(|e: nom::Err<nom::error::Error<&str>>| e.to_owned())
+    )(input)
+}
+
+/// Parses the content of the .proto file `path`
+pub(crate) fn parse_file(path: &Path, input: &str) -> Result<FileDescriptor, Error> {
+    match file_descriptor(input) {
+        Ok((_, desc)) => Ok(desc),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+            Err(Error::Parse(Box::new(e.into_parse_error(path, input))))
+        }
+        // only complete parsers are used
+        Err(nom::Err::Incomplete(_)) => unreachable!(),
+    }
 }
 
 #[cfg(test)]
@@ -491,7 +1171,7 @@ mod test {
 
     use std::path::Path;
 
-    fn assert_complete<T>(parse: nom::IResult<&str, T>) -> T {
+    fn assert_complete<T>(parse: IResult<&str, T>) -> T {
         let (rem, obj) = parse.expect("valid parse");
         assert_eq!(rem, "", "expected no trailing data");
         obj
@@ -595,8 +1275,11 @@ mod test {
     fn test_ignore() {
         let msg = r#"option optimize_for = SPEED;"#;
 
-        match option_ignore(msg) {
-            ::nom::IResult::Ok(_) => (),
+        match option_statement(msg) {
+            ::nom::IResult::Ok((_, o)) => {
+                assert_eq!("optimize_for", o.name);
+                assert_eq!(OptionValue::Ident("SPEED".to_string()), o.value);
+            }
             e => panic!("Expecting done {:?}", e),
         }
         assert_desc(msg);
@@ -799,7 +1482,7 @@ mod test {
     #[test]
     fn test_reserved() {
         let msg = r#"message Sample {
-       reserved 4, 15, 17 to 20, 30;
+       reserved 4, 15, 17 to 20, 30, 1000 to max;
        reserved "foo", "bar";
        uint64 age =1;
        bytes name =2;
@@ -808,7 +1491,16 @@ mod test {
         let mess = message(msg);
         dbg!(&mess);
         if let ::nom::IResult::Ok((_, mess)) = mess {
-            assert_eq!(Some(vec![4, 15, 17, 18, 19, 20, 30]), mess.reserved_nums);
+            assert_eq!(
+                Some(vec![
+                    (4, 4),
+                    (15, 15),
+                    (17, 20),
+                    (30, 30),
+                    (1000, MAX_FIELD_NUMBER)
+                ]),
+                mess.reserved_nums
+            );
             assert_eq!(
                 Some(vec!["foo".to_string(), "bar".to_string()]),
                 mess.reserved_names
@@ -820,6 +1512,77 @@ mod test {
         assert_desc(msg);
     }
 
+    #[test]
+    fn test_sanity_checks() {
+        let invalid = [
+            (
+                "message A { int32 a = 1; int32 b = 1; }",
+                "Fields a and b share the number 1",
+            ),
+            (
+                "message A { int32 a = 1; oneof o { int32 b = 1; } }",
+                "Fields a and b share the number 1",
+            ),
+            (
+                "message A { int32 a = 1; int32 a = 2; }",
+                "Field a is defined twice, with the numbers 1 and 2",
+            ),
+            (
+                "message A { int32 a = 19500; }",
+                "Field a number 19500 is reserved for the protocol buffers implementation",
+            ),
+            (
+                "message A { int32 a = 536870912; }",
+                "Field a number 536870912 is not between 1 and 536870911",
+            ),
+            (
+                "message A { int32 a = 0; }",
+                "Field a number 0 is not between 1 and 536870911",
+            ),
+            (
+                "message A { reserved 10 to max; int32 a = 1000000; }",
+                "conflict with reserved fields",
+            ),
+            (
+                "message A { reserved 10 to 5; }",
+                "Invalid reserved range 10 to 5",
+            ),
+            (
+                "message A { extensions 10 to 20; int32 a = 15; }",
+                "Field a number 15 is in an extension range",
+            ),
+            (
+                "enum E { A = 0; B = 0; }",
+                "Variants A and B share the value 0",
+            ),
+            (
+                "enum E { option allow_alias = true; A = 0; A = 1; }",
+                "Variant A is defined twice, with the values 0 and 1",
+            ),
+        ];
+        for (msg, expected) in &invalid {
+            match assert_desc(msg).sanity_checks() {
+                Err(Error::InvalidMessage(e)) | Err(Error::InvalidEnum(e)) => {
+                    assert!(e.contains(expected), "{}", e)
+                }
+                r => panic!("{} should be invalid, got {:?}", msg, r),
+            }
+        }
+
+        assert_desc(
+            "message A {
+                reserved 2, 4 to 6, 1000 to max;
+                reserved 3;
+                int32 a = 1;
+                int32 b = 7;
+                int32 c = 999;
+            }
+            enum E { option allow_alias = true; A = 0; B = 0; }",
+        )
+        .sanity_checks()
+        .unwrap();
+    }
+
     #[test]
     fn enum_comments() {
         let msg = r#"enum Turn {
@@ -868,6 +1631,7 @@ mod test {
     fn test_rpc_service() {
         let msg = r#"
             service RpcService {
+                option deprecated = true;
                 rpc function0(InStruct0) returns (OutStruct0);
                 rpc function1(InStruct1) returns (OutStruct1);
                 rpc function2  (  InStruct2  ) returns (  OutStruct2  ) {  }
@@ -882,6 +1646,7 @@ mod test {
                 let func1 = service.functions.get(1).expect("Function 1 not returned!");
                 let func2 = service.functions.get(2).expect("Function 2 not returned!");
                 assert_eq!("RpcService", service.service_name);
+                assert_eq!("deprecated", service.options[0].name);
                 assert_eq!("function0", func0.name);
                 assert_eq!("InStruct0", func0.arg);
                 assert_eq!("OutStruct0", func0.ret);
@@ -909,6 +1674,30 @@ mod test {
             }
             other => panic!("Could not parse RPC Function Declaration: {:?}", other),
         }
+
+        let msg = r#"rpc Get(stream foo.v1.Request) returns (stream .foo.v1.Reply) {
+            option deprecated = true;
+            option (my.opt) = { x: 1 };
+        }"#;
+        let declaration = assert_complete(rpc_function_declaration(msg));
+        assert_eq!("foo.v1.Request", declaration.arg);
+        assert_eq!(".foo.v1.Reply", declaration.ret);
+        assert!(declaration.client_streaming);
+        assert!(declaration.server_streaming);
+        assert_eq!(
+            vec!["deprecated", "(my.opt)"],
+            declaration
+                .options
+                .iter()
+                .map(|o| &*o.name)
+                .collect::<Vec<_>>()
+        );
+
+        let declaration = assert_complete(rpc_function_declaration(
+            "rpc Get(streaming) returns (Reply);",
+        ));
+        assert_eq!("streaming", declaration.arg);
+        assert!(!declaration.client_streaming);
     }
 
     #[test]
@@ -1266,4 +2055,217 @@ mod test {
             e.unwrap().1.fields.iter().map(|f| &f.name).collect::<Vec<_>>().as_slice()
         );
     }
+
+    #[test]
+    fn test_proto3_optional() {
+        let msg = r#"syntax = "proto3";
+            message A {
+                optional int32 a = 1;
+                int32 b = 2;
+            }"#;
+        let desc = assert_desc(msg);
+        let fields = &desc.messages[0].fields;
+        assert!(fields[0].proto3_optional);
+        assert!(!fields[1].proto3_optional);
+
+        let msg = r#"syntax = "proto2";
+            message A {
+                optional int32 a = 1;
+            }"#;
+        let desc = assert_desc(msg);
+        assert!(!desc.messages[0].fields[0].proto3_optional);
+    }
+
+    #[test]
+    fn test_extensions() {
+        let msg = r#"message A {
+                optional int32 a = 1;
+                extensions 100 to 199, 300, 1000 to max;
+                extend B {
+                    optional string nested = 100;
+                }
+            }
+            extend .pkg.A {
+                optional int32 b = 100;
+                repeated string c = 101;
+            }"#;
+        let desc = assert_desc(msg);
+        let m = &desc.messages[0];
+        assert_eq!(
+            vec![(100, 199), (300, 300), (1000, MAX_FIELD_NUMBER)],
+            m.extension_ranges
+        );
+        assert_eq!("B", m.extends[0].extendee);
+        assert_eq!("nested", m.extends[0].fields[0].name);
+        assert_eq!(1, desc.extends.len());
+        let e = &desc.extends[0];
+        assert_eq!(".pkg.A", e.extendee);
+        assert_eq!(
+            vec!["b", "c"],
+            e.fields.iter().map(|f| &f.name).collect::<Vec<_>>()
+        );
+        assert_eq!(Frequency::Repeated, e.fields[1].frequency);
+    }
+
+    #[test]
+    fn test_options() {
+        let msg = r#"option java_package = "com.example" ".foo";
+            option (my.file_opt) = { name: "x" inner { a: 1, b: [2, -3] } [ext.field]: ENUM_VAL };
+            message A {
+                option (my.msg_opt).sub = true;
+                optional string a = 1 [json_name = "aa", default = "a\"b\n", deprecated = true];
+                optional float b = 2 [default = -inf, (my.field_opt) = { x: 1.5e-3 }];
+                oneof c {
+                    option (my.oneof_opt) = 'one';
+                    int32 d = 3;
+                }
+            }
+            enum E {
+                option allow_alias = true;
+                X = 0 [(my.value_opt) = 0x10];
+                Y = 1;
+            }"#;
+        let desc = assert_desc(msg);
+        assert_eq!(
+            Some("com.example.foo"),
+            get_option(&desc.options, "java_package").and_then(OptionValue::as_str)
+        );
+        let agg = get_option(&desc.options, "(my.file_opt)").unwrap();
+        assert_eq!(Some("x"), agg.get("name").and_then(OptionValue::as_str));
+        assert_eq!(
+            Some(&OptionValue::List(vec![
+                OptionValue::Number("2".to_string()),
+                OptionValue::Number("-3".to_string())
+            ])),
+            agg.get("inner").and_then(|i| i.get("b"))
+        );
+        assert_eq!(
+            Some(&OptionValue::Ident("ENUM_VAL".to_string())),
+            agg.get("[ext.field]")
+        );
+        assert_eq!(
+            r#"{ name: "x" inner: { a: 1 b: [2, -3] } [ext.field]: ENUM_VAL }"#,
+            agg.to_string()
+        );
+
+        let m = &desc.messages[0];
+        assert_eq!("(my.msg_opt).sub", m.options[0].name);
+        assert_eq!(Some(true), m.options[0].value.as_bool());
+        let a = &m.fields[0];
+        assert_eq!(3, a.options.len());
+        assert_eq!(Some("\"a\\\"b\\n\"".to_string()), a.default);
+        assert!(a.deprecated);
+        let b = &m.fields[1];
+        assert_eq!(Some("-inf".to_string()), b.default);
+        assert_eq!(
+            Some(&OptionValue::Number("1.5e-3".to_string())),
+            get_option(&b.options, "(my.field_opt)").and_then(|o| o.get("x"))
+        );
+        let c = &m.oneofs[0];
+        assert_eq!(1, c.fields.len());
+        assert_eq!(Some("one"), c.options[0].value.as_str());
+
+        let e = &desc.enums[0];
+        assert_eq!(
+            Some(true),
+            get_option(&e.options, "allow_alias").and_then(OptionValue::as_bool)
+        );
+        assert_eq!(2, e.fields.len());
+        assert_eq!(
+            Some(&OptionValue::Number("0x10".to_string())),
+            get_option(&e.fields[0].options, "(my.value_opt)")
+        );
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        let msg = r#"syntax = "proto3";
+            // Detached from everything
+
+            // A message
+            //   with an indented line
+            message A {
+                // Leading comment of a
+                int32 a = 1; // Trailing comment of a
+                int32 b = 2;
+                /* Block comment
+                 * of c */
+                int32 c = 3;
+                // Leading comment of d
+
+                int32 d = 4;
+                oneof e {
+                    // Leading comment of f
+                    int32 f = 5; // Trailing comment of f
+                }
+            }
+
+            /** An enum */
+            enum E {
+                X = 0; // Trailing comment of X
+            }
+
+            // A service
+            service S {
+                // A function
+                rpc F(A) returns (A);
+            }
+        "#;
+        let desc = assert_desc(msg);
+
+        let m = &desc.messages[0];
+        assert_eq!(vec![" A message", "   with an indented line"], m.comments.leading);
+        assert!(m.comments.trailing.is_empty());
+        assert_eq!(vec![" Leading comment of a"], m.fields[0].comments.leading);
+        assert_eq!(vec![" Trailing comment of a"], m.fields[0].comments.trailing);
+        assert!(m.fields[1].comments.is_empty());
+        assert_eq!(vec![" Block comment", " of c"], m.fields[2].comments.leading);
+        assert_eq!(vec![vec![" Leading comment of d"]], m.fields[3].comments.detached);
+        assert!(m.fields[3].comments.leading.is_empty());
+        let f = &m.oneofs[0].fields[0];
+        assert_eq!(vec![" Leading comment of f"], f.comments.leading);
+        assert_eq!(vec![" Trailing comment of f"], f.comments.trailing);
+
+        let e = &desc.enums[0];
+        assert_eq!(vec![" An enum"], e.comments.leading);
+        assert_eq!(vec![" Trailing comment of X"], e.fields[0].comments.trailing);
+
+        let s = &desc.rpc_services[0];
+        assert_eq!(vec![" A service"], s.comments.leading);
+        assert_eq!(vec![" A function"], s.functions[0].comments.leading);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse_error = |msg: &str| match parse_file(Path::new("a.proto"), msg) {
+            Err(Error::Parse(e)) => e,
+            _ => panic!("expected a parse error"),
+        };
+
+        let msg = "syntax = \"proto3\";\n\nmessage A {\n    int32 a 1;\n}\n";
+        let e = parse_error(msg);
+        assert_eq!((4, 13), (e.line, e.column));
+        assert_eq!(Some("field"), e.construct);
+        assert_eq!(vec!["`=`"], e.expected);
+        assert_eq!("`1`", e.found);
+        assert_eq!(
+            "expected `=`, found `1` in field\n --> a.proto:4:13\n  |\n4 |     int32 a 1;\n  |             ^",
+            e.to_string()
+        );
+
+        let e = parse_error("message A {\n    string a = 1 [default = \"x];\n}\n");
+        assert_eq!((2, 33), (e.line, e.column));
+        assert_eq!(vec!["closing `\"`"], e.expected);
+
+        let e = parse_error("mesage A {}\n");
+        assert_eq!((1, 1), (e.line, e.column));
+        assert_eq!(None, e.construct);
+        assert!(e.expected.contains(&"`message`".to_string()));
+        assert_eq!("`mesage`", e.found);
+
+        let e = parse_error("enum E {\n    A = 0;\n");
+        assert_eq!((3, 1), (e.line, e.column));
+        assert_eq!(Some("enum"), e.construct);
+        assert_eq!("end of file", e.found);
+    }
 }
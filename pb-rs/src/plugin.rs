@@ -0,0 +1,219 @@
+//! protoc plugin, used by the `protoc-gen-quickrs` binary
+//!
+//! protoc writes a `CodeGeneratorRequest`, with the files to generate and all the files they
+//! import, to the standard input of the plugin and reads the generated files from the
+//! `CodeGeneratorResponse` written to its standard output.
+//!
+//! The `--quickrs_opt` parameter is a comma separated list of [ConfigBuilder] options:
+//!
+//! - `single_mod`, `error_cycle`, `no_headers`, `dont_use_cow`, `owned`, `nostd`, `hashbrown`,
+//!   `strict`, `heapless`, `gen_info`, `gen_builders`, `gen_accessors`, `add_deprecated_fields` and
+//!   `generate_services`, like the pb-rs flags of the same name
+//! - `custom_repr=<repr>`
+//! - `custom_struct_derive=<derives>` or `custom_struct_derive=<message>=<derives>`, with the
+//!   derives separated by `+`, e.g. `custom_struct_derive=Eq+Hash`
+//! - `custom_field_type=<field>=<type>`, with the fully qualified name of the field, e.g.
+//!   `custom_field_type=foo.Block.hash=[u8;32]`
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use quick_protobuf::{BytesReader, MessageRead};
+
+use crate::descriptor::google::protobuf::compiler::mod_CodeGeneratorResponse::{Feature, File};
+use crate::descriptor::google::protobuf::compiler::{CodeGeneratorRequest, CodeGeneratorResponse};
+use crate::descriptor::google::protobuf::FileDescriptorSet;
+use crate::descriptor_set::encode;
+use crate::errors::{Error, Result};
+use crate::types::FileDescriptor;
+use crate::ConfigBuilder;
+
+/// Decodes a `CodeGeneratorRequest` and returns the encoded `CodeGeneratorResponse`
+///
+/// Only decoding errors are returned, code generation errors are reported to protoc in the
+/// `error` of the response.
+pub fn run(request: &[u8]) -> Result<Vec<u8>> {
+    let mut reader = BytesReader::from_bytes(request);
+    let request = CodeGeneratorRequest::from_reader(&mut reader, request)?;
+    encode(&generate(&request))
+}
+
+/// Generates the code of the files of the request
+pub fn generate(request: &CodeGeneratorRequest) -> CodeGeneratorResponse<'static> {
+    let mut response = CodeGeneratorResponse {
+        supported_features: Some(Feature::FEATURE_PROTO3_OPTIONAL as u64),
+        ..Default::default()
+    };
+    match generate_files(request) {
+        Ok(file) => response.file = file,
+        Err(e) => response.error = Some(Cow::Owned(e.to_string())),
+    }
+    response
+}
+
+/// The generator writes files, it works in a scratch directory removed afterwards
+fn generate_files(request: &CodeGeneratorRequest) -> Result<Vec<File<'static>>> {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "protoc-gen-quickrs-{}-{}",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::SeqCst)
+    ));
+    let files = generate_in(request, &dir);
+    let _ = std::fs::remove_dir_all(&dir);
+    files
+}
+
+fn generate_in(request: &CodeGeneratorRequest, dir: &Path) -> Result<Vec<File<'static>>> {
+    let out_dir = dir.join("out");
+    std::fs::create_dir_all(&out_dir)?;
+
+    // `ConfigBuilder` reads its input from files
+    let set = FileDescriptorSet {
+        file: request.proto_file.clone(),
+    };
+    let set_file = dir.join("request.pb");
+    std::fs::write(&set_file, encode(&set)?)?;
+
+    let builder = ConfigBuilder::new(&[set_file], None, Some(&out_dir), &[])?;
+    let parameter = request.parameter.as_deref().unwrap_or_default();
+    let configs = config_builder(builder, parameter)?
+        .descriptor_set(true)
+        .build();
+    let mut matched = HashSet::new();
+    for config in &configs {
+        FileDescriptor::write_set(
+            &set,
+            config,
+            |name| request.file_to_generate.iter().any(|f| f == name),
+            &mut matched,
+        )?;
+    }
+    FileDescriptor::check_custom_field_types(&configs, &matched)?;
+
+    let mut paths = Vec::new();
+    list_files(&out_dir, &mut paths)?;
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(&path)?;
+            let name = path.strip_prefix(&out_dir).unwrap_or(&path);
+            let name = name.to_string_lossy().replace('\\', "/");
+            Ok(File {
+                name: Some(Cow::Owned(name)),
+                content: Some(Cow::Owned(content)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Applies the plugin parameter to `builder`
+fn config_builder(mut builder: ConfigBuilder, parameter: &str) -> Result<ConfigBuilder> {
+    let mut default_custom_struct_derive = String::new();
+    let mut custom_struct_derive = HashMap::new();
+    let mut custom_field_types = HashMap::new();
+    for param in parameter
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        let (key, value) = match param.find('=') {
+            Some(i) => (&param[..i], Some(&param[i + 1..])),
+            None => (param, None),
+        };
+        builder = match (key, value) {
+            ("single_mod", None) => builder.single_module(true),
+            ("error_cycle", None) => builder.error_cycle(true),
+            ("no_headers", None) => builder.headers(false),
+            ("dont_use_cow", None) => builder.dont_use_cow(true),
+            ("owned", None) => builder.owned(true),
+            ("nostd", None) => builder.nostd(true),
+            ("hashbrown", None) => builder.hashbrown(true),
+            ("strict", None) => builder.strict(true),
+            ("heapless", None) => builder.heapless(true),
+            ("gen_info", None) => builder.gen_info(true),
+            ("gen_builders", None) => builder.gen_builders(true),
+            ("gen_accessors", None) => builder.gen_accessors(true),
+            ("add_deprecated_fields", None) => builder.add_deprecated_fields(true),
+            ("generate_services", None) => builder.generate_services(true),
+            ("custom_repr", Some(repr)) => builder.custom_repr(Some(repr.to_string())),
+            ("custom_struct_derive", Some(derive)) => {
+                let derives = |d: &str| d.split('+').collect::<Vec<_>>().join(", ") + ", ";
+                match derive.find('=') {
+                    Some(i) => {
+                        custom_struct_derive
+                            .insert(derive[..i].to_string(), derives(&derive[i + 1..]));
+                    }
+                    None => default_custom_struct_derive = derives(derive),
+                }
+                builder
+            }
+            ("custom_field_type", Some(t)) => match t.find('=') {
+                Some(i) => {
+                    custom_field_types.insert(t[..i].to_string(), t[i + 1..].to_string());
+                    builder
+                }
+                None => return Err(Error::PluginParameter(param.to_string())),
+            },
+            _ => return Err(Error::PluginParameter(param.to_string())),
+        };
+    }
+    Ok(builder
+        .default_custom_struct_derive(default_custom_struct_derive)
+        .custom_struct_derive(custom_struct_derive)
+        .custom_field_types(custom_field_types))
+}
+
+/// The files in `dir` and its subdirectories
+fn list_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_config_builder() {
+        let builder = ConfigBuilder::new(&["src/lib.rs"], None, None, &[]).unwrap();
+        let config = config_builder(
+            builder,
+            "owned, nostd,heapless,custom_struct_derive=Eq+Hash,custom_struct_derive=M=PartialOrd,\
+             custom_field_type=p.M.hash=[u8;32]",
+        )
+        .unwrap()
+        .build()
+        .remove(0);
+        assert!(config.owned);
+        assert!(config.nostd);
+        assert!(config.heapless);
+        assert!(!config.dont_use_cow);
+        assert_eq!("Eq, Hash, ", config.default_custom_struct_derive);
+        assert_eq!(
+            Some("PartialOrd, "),
+            config.custom_struct_derive.get("M").map(|d| &**d)
+        );
+        assert_eq!(
+            Some("[u8;32]"),
+            config.custom_field_types.get("p.M.hash").map(|t| &**t)
+        );
+
+        let builder = ConfigBuilder::new(&["src/lib.rs"], None, None, &[]).unwrap();
+        match config_builder(builder, "owned=true") {
+            Err(Error::PluginParameter(p)) => assert_eq!("owned=true", p),
+            _ => panic!("owned=true should be rejected"),
+        }
+    }
+}
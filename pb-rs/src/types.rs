@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
@@ -6,9 +6,11 @@ use std::path::{Path, PathBuf};
 
 use log::{debug, warn};
 
+use crate::descriptor::google::protobuf::FileDescriptorSet;
+use crate::descriptor_set;
 use crate::errors::{Error, Result};
 use crate::keywords::sanitize_keyword;
-use crate::parser::file_descriptor;
+use crate::parser::parse_file;
 
 fn sizeof_varint(v: u32) -> usize {
     match v {
@@ -270,7 +272,7 @@ impl FieldType {
             | FieldType::Sfixed32
             | FieldType::String_
             | FieldType::Bytes_
-            | FieldType::Float => packed, // Cow<[M]>
+            | FieldType::Float => packed && !config.heapless, // Cow<[M]>
             FieldType::Map(ref key, ref value) => {
                 key.has_lifetime(desc, config, false, ignore) || value.has_lifetime(desc, config, false, ignore)
             }
@@ -415,17 +417,275 @@ impl FieldType {
     }
 }
 
+/// The value of an option, as written in the .proto file
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionValue {
+    /// Identifier, including `true`, `false`, enum values and `inf`/`nan`, maybe with a sign
+    Ident(String),
+    /// Integer or float literal
+    Number(String),
+    /// Unescaped string literal
+    String(String),
+    /// Aggregate (text format message) value `{ a: 1 b { c: "x" } }`
+    Aggregate(Vec<(String, OptionValue)>),
+    /// List value `[1, 2]`, only found in aggregates
+    List(Vec<OptionValue>),
+}
+
+impl OptionValue {
+    /// The string value, if `self` is a string literal
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            OptionValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The boolean value, if `self` is `true` or `false`
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            OptionValue::Ident(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets the value of `key` in an aggregate (the last one if repeated)
+    pub fn get(&self, key: &str) -> Option<&OptionValue> {
+        match self {
+            OptionValue::Aggregate(fields) => {
+                fields.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for OptionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OptionValue::Ident(s) | OptionValue::Number(s) => f.write_str(s),
+            OptionValue::String(s) => {
+                f.write_str("\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => f.write_str("\\\"")?,
+                        '\\' => f.write_str("\\\\")?,
+                        '\n' => f.write_str("\\n")?,
+                        '\r' => f.write_str("\\r")?,
+                        '\t' => f.write_str("\\t")?,
+                        c if (c as u32) < 0x20 || c as u32 == 0x7F => {
+                            write!(f, "\\{:03o}", c as u32)?
+                        }
+                        c => write!(f, "{}", c)?,
+                    }
+                }
+                f.write_str("\"")
+            }
+            OptionValue::Aggregate(fields) => {
+                f.write_str("{")?;
+                for (k, v) in fields {
+                    write!(f, " {}: {}", k, v)?;
+                }
+                f.write_str(" }")
+            }
+            OptionValue::List(values) => {
+                f.write_str("[")?;
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// An option (`option name = value;` or a field `[name = value]`)
+///
+/// Custom option names keep their parentheses, e.g. `(my.option).field`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtoOption {
+    pub name: String,
+    pub value: OptionValue,
+}
+
+/// Gets the value of the option `name` (the last one if repeated)
+pub fn get_option<'a>(options: &'a [ProtoOption], name: &str) -> Option<&'a OptionValue> {
+    options
+        .iter()
+        .rev()
+        .find(|o| o.name == name)
+        .map(|o| &o.value)
+}
+
+/// Comments attached to an element of a .proto file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Comments {
+    /// Lines of the comments before the element, separated from it by a blank line, by paragraph
+    pub detached: Vec<Vec<String>>,
+    /// Lines of the comments right before the element
+    pub leading: Vec<String>,
+    /// Lines of the comment after the element, on the same line
+    pub trailing: Vec<String>,
+}
+
+impl Comments {
+    pub fn is_empty(&self) -> bool {
+        self.detached.is_empty() && self.leading.is_empty() && self.trailing.is_empty()
+    }
+
+    /// Writes the comments as `///` doc comments, each line prefixed by `indent`
+    ///
+    /// The common indentation is removed and code blocks, fenced or indented by 4 spaces, are
+    /// written as `text` fenced blocks so that they are not run as doc tests.
+    pub fn write_doc<W: Write + ?Sized>(&self, w: &mut W, indent: &str) -> Result<()> {
+        let mut lines = self.leading.iter().map(|l| l.trim_end()).collect::<Vec<_>>();
+        if !self.leading.is_empty() && !self.trailing.is_empty() {
+            lines.push("");
+        }
+        lines.extend(self.trailing.iter().map(|l| l.trim_end()));
+        let margin = lines
+            .iter()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.len() - l.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let mut fenced = false;
+        let mut indented = false;
+        // blank lines of an indented block, written once we know whether the block goes on
+        let mut blanks = 0;
+        let mut previous_blank = true;
+        for line in lines {
+            let line = line.get(margin..).unwrap_or("");
+            if indented {
+                if line.is_empty() {
+                    blanks += 1;
+                    continue;
+                }
+                if let Some(code) = line.strip_prefix("    ") {
+                    for _ in 0..blanks {
+                        writeln!(w, "{}///", indent)?;
+                    }
+                    blanks = 0;
+                    writeln!(w, "{}/// {}", indent, code)?;
+                    continue;
+                }
+                writeln!(w, "{}/// ```", indent)?;
+                indented = false;
+                for _ in 0..blanks {
+                    writeln!(w, "{}///", indent)?;
+                }
+                blanks = 0;
+            }
+            if line.starts_with("```") {
+                if !fenced {
+                    writeln!(w, "{}/// ```text", indent)?;
+                } else {
+                    writeln!(w, "{}/// ```", indent)?;
+                }
+                fenced = !fenced;
+            } else if line.is_empty() {
+                writeln!(w, "{}///", indent)?;
+            } else if !fenced && previous_blank && line.starts_with("    ") {
+                writeln!(w, "{}/// ```text", indent)?;
+                writeln!(w, "{}/// {}", indent, &line[4..])?;
+                indented = true;
+            } else {
+                writeln!(w, "{}/// {}", indent, line)?;
+            }
+            previous_blank = line.is_empty();
+        }
+        if indented {
+            writeln!(w, "{}/// ```", indent)?;
+            for _ in 0..blanks {
+                writeln!(w, "{}///", indent)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Gets the name of an option declared in `rust_options.proto`, `attr` for `(rust.attr)`
+fn rust_option_name(name: &str) -> Option<&str> {
+    name.strip_prefix('(')?
+        .strip_suffix(')')?
+        .trim_start_matches('.')
+        .strip_prefix("rust.")
+}
+
+fn rust_option_str(option: &ProtoOption) -> Result<&str> {
+    option.value.as_str().ok_or_else(|| {
+        Error::InvalidOption(format!(
+            "{} = {}, expecting a string",
+            option.name, option.value
+        ))
+    })
+}
+
+fn rust_option_bool(option: &ProtoOption) -> Result<bool> {
+    option.value.as_bool().ok_or_else(|| {
+        Error::InvalidOption(format!(
+            "{} = {}, expecting a boolean",
+            option.name, option.value
+        ))
+    })
+}
+
+fn rust_option_usize(option: &ProtoOption) -> Result<usize> {
+    match option.value {
+        OptionValue::Number(ref n) => n.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        Error::InvalidOption(format!(
+            "{} = {}, expecting a positive integer",
+            option.name, option.value
+        ))
+    })
+}
+
+/// Formats an attribute option, the `#[...]` being optional
+fn rust_option_attribute(option: &ProtoOption) -> Result<String> {
+    let attr = rust_option_str(option)?;
+    if attr.starts_with("#[") {
+        Ok(attr.to_string())
+    } else {
+        Ok(format!("#[{}]", attr))
+    }
+}
+
+fn unknown_rust_option(option: &ProtoOption, item: &str) -> Error {
+    Error::InvalidOption(format!(
+        "{} is not a valid option for {}",
+        option.name, item
+    ))
+}
+
 #[derive(Debug, Clone)]
 pub struct Field {
     pub name: String,
     pub frequency: Frequency,
+    /// `optional` label in a proto3 file, the field has presence and is an `Option`
+    pub proto3_optional: bool,
     pub typ: FieldType,
     pub number: i32,
     pub default: Option<String>,
     pub packed: Option<bool>,
     pub boxed: bool,
+    /// Rust type replacing the generated one, `(rust.type)` option
+    pub custom_type: Option<String>,
+    /// Field left out of the `Debug` implementation, `(rust.skip_debug)` option
+    pub skip_debug: bool,
+    /// Maximum number of items of a repeated field or map, `(rust.capacity)` option
+    pub capacity: Option<usize>,
+    /// Maximum length of strings and bytes, `(rust.max_len)` option
+    pub max_len: Option<usize>,
     pub deprecated: bool,
     pub attributes: Vec<String>,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl Field {
@@ -433,6 +693,78 @@ impl Field {
         self.packed.unwrap_or(false)
     }
 
+    /// Whether the field is generated as an `Option`
+    fn is_option(&self, desc: &FileDescriptor, config: &Config) -> bool {
+        self.frequency == Frequency::Optional
+            && (desc.syntax == Syntax::Proto2 && self.field_default(config).is_none()
+                || self.proto3_optional
+                || self.typ.message().is_some())
+    }
+
+    fn has_lifetime(&self, desc: &FileDescriptor, config: &Config, ignore: &mut Vec<MessageIndex>) -> bool {
+        match self.custom_type {
+            Some(ref t) => t.contains("'a"),
+            None => self.typ.has_lifetime(desc, config, self.packed(), ignore),
+        }
+    }
+
+    fn apply_rust_options(&mut self) -> Result<()> {
+        for o in &self.options {
+            match rust_option_name(&o.name) {
+                Some("attr") => self.attributes.push(rust_option_attribute(o)?),
+                Some("type") => self.custom_type = Some(rust_option_str(o)?.to_string()),
+                Some("box") => self.boxed = rust_option_bool(o)?,
+                Some("skip_debug") => self.skip_debug = rust_option_bool(o)?,
+                Some("capacity") => self.capacity = Some(rust_option_usize(o)?),
+                Some("max_len") => self.max_len = Some(rust_option_usize(o)?),
+                Some(_) => return Err(unknown_rust_option(o, &format!("field {}", self.name))),
+                None => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts `s`, a reference to a value of the `custom_type`, into a reference to the
+    /// value to encode
+    fn proto_ref(&self, s: &str, desc: &FileDescriptor, config: &Config) -> Result<String> {
+        if self.custom_type.is_none() {
+            return Ok(s.to_string());
+        }
+        Ok(match self.typ {
+            FieldType::StringCow | FieldType::String_ => format!("&AsRef::<str>::as_ref({})", s),
+            FieldType::BytesCow | FieldType::Bytes_ => format!("&AsRef::<[u8]>::as_ref({})", s),
+            FieldType::Message(_) => format!(
+                "AsRef::<{}>::as_ref({})",
+                self.typ.rust_type(desc, config)?.replace("<'a>", "<'_>"),
+                s
+            ),
+            _ => format!(
+                "&Into::<{}>::into(Clone::clone({}))",
+                self.typ.rust_type(desc, config)?,
+                s
+            ),
+        })
+    }
+
+    /// Condition for a value of the `custom_type` to be skipped when encoding a proto3 field
+    ///
+    /// Strings and bytes are compared to the default value of their type, which is not always
+    /// empty (e.g. `[u8; 4]`).
+    fn custom_is_default(&self, desc: &FileDescriptor, config: &Config) -> Result<String> {
+        let v = self.proto_ref(&format!("&self.{}", self.name), desc, config)?;
+        Ok(match (&self.typ, &self.custom_type) {
+            (
+                FieldType::StringCow | FieldType::String_ | FieldType::BytesCow | FieldType::Bytes_,
+                Some(typ),
+            ) => format!("self.{} == <{} as Default>::default()", self.name, typ),
+            _ => format!(
+                "*{} == {}",
+                v,
+                self.typ.regular_default(desc).unwrap_or("None")
+            ),
+        })
+    }
+
     fn sanitize_default(&mut self, desc: &FileDescriptor, config: &Config) -> Result<()> {
         if let Some(ref mut d) = self.default {
             *d = match &*self.typ.rust_type(desc, config)? {
@@ -464,8 +796,23 @@ impl Field {
         Ok(())
     }
 
-    fn has_regular_default(&self, desc: &FileDescriptor) -> bool {
-        self.default.is_none() || self.default.as_deref() == self.typ.regular_default(desc)
+    /// The declared default the field is initialized with
+    ///
+    /// With `Config::gen_accessors`, optional fields are an `Option` instead and their accessor
+    /// returns the declared default.
+    fn field_default(&self, config: &Config) -> Option<&String> {
+        if config.gen_accessors && self.frequency == Frequency::Optional {
+            None
+        } else {
+            self.default.as_ref()
+        }
+    }
+
+    fn has_regular_default(&self, desc: &FileDescriptor, config: &Config) -> bool {
+        match self.field_default(config) {
+            None => true,
+            Some(d) => Some(d.as_str()) == self.typ.regular_default(desc),
+        }
     }
 
     fn tag(&self) -> u32 {
@@ -485,79 +832,420 @@ impl Field {
                 return Ok(());
             }
         }
+        self.comments.write_doc(w, "    ")?;
         for attr in &self.attributes {
             writeln!(w, "{}", attr)?;
         }
         write!(w, "    pub {}: ", self.name)?;
-        let rust_type = self.typ.rust_type(desc, config)?;
+        let rust_type = match self.custom_type {
+            Some(ref t) => t.clone(),
+            None if config.heapless => self.heapless_type(desc, config)?,
+            None => self.typ.rust_type(desc, config)?,
+        };
         match self.frequency {
             _ if self.boxed => writeln!(w, "Option<Box<{}>>,", rust_type)?,
-            Frequency::Optional
-                if desc.syntax == Syntax::Proto2 && self.default.is_none()
-                    || self.typ.message().is_some() =>
-            {
+            Frequency::Optional if self.is_option(desc, config) => {
                 writeln!(w, "Option<{}>,", rust_type)?
             }
-            Frequency::Repeated
-                if self.packed() && self.typ.is_fixed_size() && !config.dont_use_cow =>
-            {
+            Frequency::Repeated if self.is_packed_cow(config) => {
                 writeln!(w, "Cow<'a, [{}]>,", rust_type)?;
             }
+            Frequency::Repeated if config.heapless => writeln!(w, "{},", rust_type)?,
             Frequency::Repeated => writeln!(w, "Vec<{}>,", rust_type)?,
             Frequency::Required | Frequency::Optional => writeln!(w, "{},", rust_type)?,
         }
         Ok(())
     }
 
-    fn write_match_tag<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+    /// Whether the field is generated as a `Cow<'a, [T]>` of packed fixed size values
+    fn is_packed_cow(&self, config: &Config) -> bool {
+        self.frequency == Frequency::Repeated
+            && self.packed()
+            && self.typ.is_fixed_size()
+            && !config.dont_use_cow
+            && !config.heapless
+            && self.custom_type.is_none()
+    }
+
+    /// The fixed capacity type of the field with `Config::heapless`, a repeated field being a
+    /// `heapless::Vec` of `(rust.capacity)` items
+    fn heapless_type(&self, desc: &FileDescriptor, config: &Config) -> Result<String> {
+        Ok(match self.typ {
+            FieldType::Map(ref key, ref value) => {
+                let capacity = self.heapless_capacity()?;
+                if capacity < 2 || !capacity.is_power_of_two() {
+                    return Err(Error::Heapless(format!(
+                        "the (rust.capacity) of map {} must be a power of 2, greater than 1",
+                        self.name
+                    )));
+                }
+                format!(
+                    "heapless::FnvIndexMap<{}, {}, {}>",
+                    self.heapless_value_type(key, desc, config)?,
+                    self.heapless_value_type(value, desc, config)?,
+                    capacity
+                )
+            }
+            _ if self.frequency == Frequency::Repeated => format!(
+                "heapless::Vec<{}, {}>",
+                self.heapless_value_type(&self.typ, desc, config)?,
+                self.heapless_capacity()?
+            ),
+            _ => self.heapless_value_type(&self.typ, desc, config)?,
+        })
+    }
+
+    /// The type of a single value of the field (or of the key or value of a map), strings and
+    /// bytes being bounded by the `(rust.max_len)` of the field
+    fn heapless_value_type(&self, typ: &FieldType, desc: &FileDescriptor, config: &Config) -> Result<String> {
+        let max_len = || {
+            self.max_len.ok_or_else(|| {
+                Error::Heapless(format!("field {} needs a (rust.max_len) option", self.name))
+            })
+        };
+        Ok(match *typ {
+            FieldType::String_ => format!("heapless::String<{}>", max_len()?),
+            FieldType::Bytes_ => format!("heapless::Vec<u8, {}>", max_len()?),
+            _ => typ.rust_type(desc, config)?,
+        })
+    }
+
+    fn heapless_capacity(&self) -> Result<usize> {
+        self.capacity.ok_or_else(|| {
+            Error::Heapless(format!("field {} needs a (rust.capacity) option", self.name))
+        })
+    }
+
+    /// Rejects the fields which cannot be generated with `Config::heapless`
+    fn heapless_checks(&self, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let unsupported = |what: &str| {
+            Err(Error::Heapless(format!("field {} {}", self.name, what)))
+        };
+        if self.boxed {
+            return unsupported("is boxed, recursive messages need an allocator");
+        }
+        if self.custom_type.is_some() && (self.frequency == Frequency::Repeated || self.typ.is_map()) {
+            return unsupported("is a repeated field or a map with a custom type");
+        }
+        if self.default.is_some() && self.typ.has_bytes_and_string() {
+            return unsupported("is a string or bytes with a default value");
+        }
+        if self.custom_type.is_none() {
+            self.heapless_type(desc, config)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the setters of the field in the builder of its message
+    ///
+    /// Strings and bytes are taken as `impl Into<..>`, repeated fields get a `push_` setter and
+    /// maps an `insert_` one.
+    fn write_builder_setters<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let (param, value) = match self.custom_type {
+            Some(ref t) => (t.clone(), "v".to_string()),
+            None => builder_param(&self.typ, "v", desc, config)?,
+        };
+        let name = &self.name;
+        let setter = |w: &mut W, fn_name: &str, params: &str, set: &str| -> Result<()> {
+            if self.deprecated {
+                writeln!(w, "    #[deprecated]")?;
+            }
+            writeln!(w, "    pub fn {}(mut self, {}) -> Self {{", fn_name, params)?;
+            writeln!(w, "        {};", set)?;
+            if self.frequency == Frequency::Required && !self.typ.is_map() {
+                writeln!(w, "        self.has_{} = true;", name)?;
+            }
+            writeln!(w, "        self")?;
+            writeln!(w, "    }}")?;
+            writeln!(w)?;
+            Ok(())
+        };
+        let params = format!("v: {}", param);
+        match self.frequency {
+            _ if self.boxed => setter(
+                w,
+                name,
+                &params,
+                &format!("self.msg.{} = Some(Box::new({}))", name, value),
+            ),
+            Frequency::Optional if self.is_option(desc, config) => setter(
+                w,
+                name,
+                &params,
+                &format!("self.msg.{} = Some({})", name, value),
+            ),
+            _ if self.custom_type.is_none() && self.typ.is_map() => {
+                let (key, val) = match self.typ {
+                    FieldType::Map(ref key, ref val) => (key, val),
+                    _ => unreachable!(),
+                };
+                let (key_param, key_value) = builder_param(key, "k", desc, config)?;
+                let (val_param, val_value) = builder_param(val, "v", desc, config)?;
+                setter(
+                    w,
+                    name,
+                    &format!("v: {}", self.typ.rust_type(desc, config)?),
+                    &format!("self.msg.{} = v", name),
+                )?;
+                setter(
+                    w,
+                    &format!("insert_{}", name),
+                    &format!("k: {}, v: {}", key_param, val_param),
+                    &format!("self.msg.{}.insert({}, {})", name, key_value, val_value),
+                )
+            }
+            Frequency::Required | Frequency::Optional => setter(
+                w,
+                name,
+                &params,
+                &format!("self.msg.{} = {}", name, value),
+            ),
+            Frequency::Repeated => {
+                let element = match self.custom_type {
+                    Some(ref t) => t.clone(),
+                    None => self.typ.rust_type(desc, config)?,
+                };
+                let (values, set, push) = if self.is_packed_cow(config) {
+                    (format!("impl Into<Cow<'a, [{}]>>", element), "v.into()", "to_mut().push")
+                } else {
+                    (format!("Vec<{}>", element), "v", "push")
+                };
+                setter(
+                    w,
+                    name,
+                    &format!("v: {}", values),
+                    &format!("self.msg.{} = {}", name, set),
+                )?;
+                setter(
+                    w,
+                    &format!("push_{}", name),
+                    &params,
+                    &format!("self.msg.{}.{}({})", name, push, value),
+                )
+            }
+        }
+    }
+
+    /// Writes the accessors of a field with a presence
+    ///
+    /// `x()` returns the declared default when the field is not set (it is not written for
+    /// messages and custom types), along with `has_x()`, `clear_x()`, `set_x()` and `mut_x()`.
+    fn write_accessors<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        if self.frequency != Frequency::Optional || !self.boxed && !self.is_option(desc, config) {
+            return Ok(());
+        }
+        let name = &self.name;
+        let (rust_type, (param, value)) = match self.custom_type {
+            Some(ref t) => (t.clone(), (t.clone(), "v".to_string())),
+            None => (
+                self.typ.rust_type(desc, config)?,
+                builder_param(&self.typ, "v", desc, config)?,
+            ),
+        };
+        let accessor = |w: &mut W, sig: String, body: String| {
+            write_accessor(w, self.deprecated, &sig, &body)
+        };
+        let getter = match self.typ {
+            _ if self.custom_type.is_some() || self.typ.message().is_some() => None,
+            FieldType::StringCow | FieldType::String_ => Some(("&str", ".as_deref()")),
+            FieldType::BytesCow | FieldType::Bytes_ => Some(("&[u8]", ".as_deref()")),
+            _ => Some((&*rust_type, "")),
+        };
+        if let Some((ret, get)) = getter {
+            let default = match self.default {
+                Some(ref d) => format!("unwrap_or({})", default_literal(d)),
+                None => "unwrap_or_default()".to_string(),
+            };
+            accessor(
+                w,
+                format!("{}(&self) -> {}", name, ret),
+                format!("self.{}{}.{}", name, get, default),
+            )?;
+        }
+        accessor(w, format!("has_{}(&self) -> bool", name), format!("self.{}.is_some()", name))?;
+        accessor(w, format!("clear_{}(&mut self)", name), format!("self.{} = None;", name))?;
+        let (value, deref) = if self.boxed {
+            (format!("Box::new({})", value), "&mut **")
+        } else {
+            (value, "")
+        };
+        accessor(
+            w,
+            format!("set_{}(&mut self, v: {})", name, param),
+            format!("self.{} = Some({});", name, value),
+        )?;
+        accessor(
+            w,
+            format!("mut_{}(&mut self) -> &mut {}", name, rust_type),
+            format!("{}self.{}.get_or_insert_with(Default::default)", deref, name),
+        )
+    }
+
+    /// Writes the match arm reading this field
+    ///
+    /// `seen` is the index of the field in the `seen` array used to detect duplicate singular
+    /// fields in strict mode, `None` for repeated fields or without `Config::strict`.
+    fn write_match_tag<W: Write>(
+        &self,
+        w: &mut W,
+        desc: &FileDescriptor,
+        config: &Config,
+        seen: Option<usize>,
+    ) -> Result<()> {
         if self.deprecated && !config.add_deprecated_fields {
             return Ok(());
         }
 
         // special case for FieldType::Map: destructure tuple before inserting in HashMap
         if let FieldType::Map(ref key, ref value) = self.typ {
+            let read = |typ: &FieldType| match heapless_read_fn(typ, self.number, config) {
+                Some(read) => Ok(read),
+                None => typ.read_fn(desc).map(|(_, read)| read),
+            };
             writeln!(w, "                Ok({}) => {{", self.tag())?;
             writeln!(
                 w,
                 "                    let (key, value) = \
-                 r.read_map(bytes, |r, bytes| Ok({}), |r, bytes| Ok({}))?;",
-                key.read_fn(desc)?.1,
-                value.read_fn(desc)?.1
-            )?;
-            writeln!(
-                w,
-                "                    msg.{}.insert(key, value);",
-                self.name
+                 r.read_map_entry(bytes, {}, |r, bytes| Ok({}), {}, |r, bytes| Ok({}))?;",
+                tag(1, key, false),
+                read(key)?,
+                tag(2, value, false),
+                read(value)?
             )?;
+            if config.heapless {
+                writeln!(
+                    w,
+                    "                    self.{}.insert(key, value){}?;",
+                    self.name,
+                    capacity_error(self.number)
+                )?;
+            } else {
+                writeln!(
+                    w,
+                    "                    self.{}.insert(key, value);",
+                    self.name
+                )?;
+            }
             writeln!(w, "                }}")?;
             return Ok(());
         }
 
-        let (val, val_cow) = self.typ.read_fn(desc)?;
         let name = &self.name;
-        write!(w, "                Ok({}) => ", self.tag())?;
-        match self.frequency {
-            _ if self.boxed => writeln!(w, "msg.{} = Some(Box::new({})),", name, val)?,
-            Frequency::Optional
-                if desc.syntax == Syntax::Proto2 && self.default.is_none()
-                    || self.typ.message().is_some() =>
+        if self.custom_type.is_some() {
+            let (val, _) = self.typ.read_fn(desc)?;
+            let val = match self.typ {
+                FieldType::StringCow
+                | FieldType::BytesCow
+                | FieldType::String_
+                | FieldType::Bytes_ => format!("{}?", val),
+                _ => val,
+            };
+            let val = format!(
+                "core::convert::TryFrom::try_from({})\
+                 .map_err(|_| quick_protobuf::Error::Conversion({}))?",
+                val, self.number
+            );
+            let read = match self.frequency {
+                Frequency::Repeated if self.packed() => format!(
+                    "r.read_packed_into(bytes, &mut self.{}, |r, bytes| Ok({}))?",
+                    name, val
+                ),
+                Frequency::Repeated => format!("self.{}.push({})", name, val),
+                _ if self.is_option(desc, config) => format!("self.{} = Some({})", name, val),
+                _ => format!("self.{} = {}", name, val),
+            };
+            return write_match_arm(w, self.tag(), seen, &read);
+        }
+
+        let (val, mut val_cow) = self.typ.read_fn(desc)?;
+        if let Some(read) = heapless_read_fn(&self.typ, self.number, config) {
+            val_cow = read;
+        }
+        let read = match self.frequency {
+            // nested messages are merged into the existing ones
+            Frequency::Optional | Frequency::Required
+                if self.boxed && self.typ.message().is_some() =>
             {
-                writeln!(w, "msg.{} = Some({}),", name, val_cow)?
+                format!(
+                    "r.merge_message(bytes, &mut **self.{}.get_or_insert_with(Default::default))?",
+                    name
+                )
             }
-            Frequency::Required | Frequency::Optional => {
-                writeln!(w, "msg.{} = {},", name, val_cow)?
+            _ if self.boxed => format!("self.{} = Some(Box::new({}))", name, val),
+            Frequency::Optional if self.typ.message().is_some() => format!(
+                "r.merge_message(bytes, self.{}.get_or_insert_with(Default::default))?",
+                name
+            ),
+            Frequency::Required if self.typ.message().is_some() => {
+                format!("r.merge_message(bytes, &mut self.{})?", name)
             }
-            Frequency::Repeated if self.packed() && self.typ.is_fixed_size() => {
-                writeln!(w, "msg.{} = r.read_packed_fixed(bytes)?.into(),", name)?;
+            Frequency::Optional if self.is_option(desc, config) => {
+                format!("self.{} = Some({})", name, val_cow)
             }
-            Frequency::Repeated if self.packed() => {
-                writeln!(
-                    w,
-                    "msg.{} = r.read_packed(bytes, |r, bytes| Ok({}))?,",
-                    name, val_cow
-                )?;
+            // reuse the capacity of owned strings and bytes
+            Frequency::Required | Frequency::Optional
+                if self.typ == FieldType::String_ && !config.heapless =>
+            {
+                format!("self.{0}.clear(); self.{0}.push_str({1}?);", name, val)
+            }
+            Frequency::Required | Frequency::Optional
+                if self.typ == FieldType::Bytes_ && !config.heapless =>
+            {
+                format!("self.{0}.clear(); self.{0}.extend_from_slice({1}?);", name, val)
+            }
+            Frequency::Required | Frequency::Optional => format!("self.{} = {}", name, val_cow),
+            // fixed size values cannot be borrowed, nor copied into an allocated `Vec`
+            Frequency::Repeated if self.packed() && config.heapless => format!(
+                "r.read_packed_with(bytes, |r, bytes| Ok(self.{}.push({}){}?))?",
+                name,
+                val_cow,
+                capacity_error(self.number)
+            ),
+            Frequency::Repeated if config.heapless => format!(
+                "self.{}.push({}){}?",
+                name,
+                val_cow,
+                capacity_error(self.number)
+            ),
+            Frequency::Repeated if self.packed() && self.typ.is_fixed_size() => {
+                if config.dont_use_cow {
+                    format!(
+                        "self.{}.extend_from_slice(&r.read_packed_fixed(bytes)?)",
+                        name
+                    )
+                } else {
+                    // borrows the first chunk, copies it to append the next ones
+                    format!(
+                        "if self.{0}.is_empty() {{ self.{0} = r.read_packed_fixed(bytes)? }} \
+                         else {{ self.{0}.to_mut().extend_from_slice(&r.read_packed_fixed(bytes)?) }}",
+                        name
+                    )
+                }
             }
-            Frequency::Repeated => writeln!(w, "msg.{}.push({}),", name, val_cow)?,
+            Frequency::Repeated if self.packed() => format!(
+                "r.read_packed_into(bytes, &mut self.{}, |r, bytes| Ok({}))?",
+                name, val_cow
+            ),
+            Frequency::Repeated => format!("self.{}.push({})", name, val_cow),
+        };
+        write_match_arm(w, self.tag(), seen, &read)
+    }
+
+    fn write_clear<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let owned_vec = match self.frequency {
+            _ if self.boxed => false,
+            _ if self.custom_type.is_some() => self.frequency == Frequency::Repeated,
+            Frequency::Repeated => !self.is_packed_cow(config),
+            Frequency::Optional if self.is_option(desc, config) => false,
+            _ => matches!(self.typ, FieldType::String_ | FieldType::Bytes_ | FieldType::Map(..)),
+        };
+        if !self.has_regular_default(desc, config) {
+            writeln!(w, "        self.{} = {};", self.name, self.default.as_ref().unwrap())?;
+        } else if owned_vec {
+            // keep the capacity
+            writeln!(w, "        self.{}.clear();", self.name)?;
+        } else {
+            writeln!(w, "        self.{} = Default::default();", self.name)?;
         }
         Ok(())
     }
@@ -569,18 +1257,21 @@ impl Field {
 
         write!(w, "        + ")?;
         let tag_size = sizeof_varint(self.tag());
+        let v = |s: &str| self.proto_ref(s, desc, config);
         match self.frequency {
             Frequency::Optional
-                if desc.syntax == Syntax::Proto2 || self.typ.message().is_some() =>
+                if desc.syntax == Syntax::Proto2
+                    || self.proto3_optional
+                    || self.typ.message().is_some() =>
             {
                 // TODO this might be incorrect behavior for proto2
-                match self.default.as_ref() {
+                match self.field_default(config) {
                     None => {
                         write!(w, "self.{}.as_ref().map_or(0, ", self.name)?;
                         if self.typ.is_fixed_size() {
                             writeln!(w, "|_| {} + {})", tag_size, self.typ.get_size(""))?;
                         } else {
-                            writeln!(w, "|m| {} + {})", tag_size, self.typ.get_size("m"))?;
+                            writeln!(w, "|m| {} + {})", tag_size, self.typ.get_size(&v("m")?))?;
                         }
                     }
                     Some(d) => {
@@ -604,8 +1295,15 @@ impl Field {
                     self.typ.get_size("")
                 )?;
             }
+            Frequency::Optional if self.custom_type.is_some() => writeln!(
+                w,
+                "if {} {{ 0 }} else {{ {} + {} }}",
+                self.custom_is_default(desc, config)?,
+                tag_size,
+                self.typ.get_size(&v(&format!("&self.{}", self.name))?)
+            )?,
             Frequency::Optional => match self.typ {
-                FieldType::Bytes_ => writeln!(
+                FieldType::String_ | FieldType::Bytes_ => writeln!(
                     w,
                     "if self.{}.is_empty() {{ 0 }} else {{ {} + {} }}",
                     self.name,
@@ -628,7 +1326,7 @@ impl Field {
                 w,
                 "{} + {}",
                 tag_size,
-                self.typ.get_size(&format!("&self.{}", self.name))
+                self.typ.get_size(&v(&format!("&self.{}", self.name))?)
             )?,
             Frequency::Repeated => {
                 if self.packed() {
@@ -644,7 +1342,7 @@ impl Field {
                             w,
                             "sizeof_len(self.{}.iter().map(|s| {}).sum::<usize>()) }}",
                             self.name,
-                            self.typ.get_size("s")
+                            self.typ.get_size(&v("s")?)
                         )?,
                     }
                 } else {
@@ -656,7 +1354,7 @@ impl Field {
                             "self.{}.iter().map(|s| {} + {}).sum::<usize>()",
                             self.name,
                             tag_size,
-                            self.typ.get_size("s")
+                            self.typ.get_size(&v("s")?)
                         )?,
                     }
                 }
@@ -670,11 +1368,14 @@ impl Field {
             return Ok(());
         }
 
+        let v = |s: &str| self.proto_ref(s, desc, config);
         match self.frequency {
             Frequency::Optional
-                if desc.syntax == Syntax::Proto2 || self.typ.message().is_some() =>
+                if desc.syntax == Syntax::Proto2
+                    || self.proto3_optional
+                    || self.typ.message().is_some() =>
             {
-                match self.default.as_ref() {
+                match self.field_default(config) {
                     None => {
                         writeln!(
                             w,
@@ -682,7 +1383,7 @@ impl Field {
                              self.{} {{ w.write_with_tag({}, |w| w.{})?; }}",
                             self.name,
                             self.tag(),
-                            self.typ.get_write("s", self.boxed)
+                            self.typ.get_write(&v("s")?, self.boxed)
                         )?;
                     }
                     Some(d) => {
@@ -698,8 +1399,16 @@ impl Field {
                     }
                 }
             }
+            Frequency::Optional if self.custom_type.is_some() => writeln!(
+                w,
+                "        if !({}) {{ w.write_with_tag({}, |w| w.{})?; }}",
+                self.custom_is_default(desc, config)?,
+                self.tag(),
+                self.typ
+                    .get_write(&v(&format!("&self.{}", self.name))?, false)
+            )?,
             Frequency::Optional => match self.typ {
-                FieldType::Bytes_ => {
+                FieldType::String_ | FieldType::Bytes_ => {
                     writeln!(
                         w,
                         "        if !self.{}.is_empty() {{ w.write_with_tag({}, |w| w.{})?; }}",
@@ -739,22 +1448,26 @@ impl Field {
                     "        w.write_with_tag({}, |w| w.{})?;",
                     self.tag(),
                     self.typ
-                        .get_write(&format!("&self.{}", self.name), self.boxed)
+                        .get_write(&v(&format!("&self.{}", self.name))?, self.boxed)
                 )?;
             }
-            Frequency::Repeated if self.packed() && self.typ.is_fixed_size() => writeln!(
-                w,
-                "        w.write_packed_fixed_with_tag({}, &self.{})?;",
-                self.tag(),
-                self.name
-            )?,
+            Frequency::Repeated
+                if self.packed() && self.typ.is_fixed_size() && self.custom_type.is_none() =>
+            {
+                writeln!(
+                    w,
+                    "        w.write_packed_fixed_with_tag({}, &self.{})?;",
+                    self.tag(),
+                    self.name
+                )?
+            }
             Frequency::Repeated if self.packed() => writeln!(
                 w,
                 "        w.write_packed_with_tag({}, &self.{}, |w, m| w.{}, &|m| {})?;",
                 self.tag(),
                 self.name,
-                self.typ.get_write("m", self.boxed),
-                self.typ.get_size("m")
+                self.typ.get_write(&v("m")?, self.boxed),
+                self.typ.get_size(&v("m")?)
             )?,
             Frequency::Repeated => {
                 writeln!(
@@ -762,7 +1475,7 @@ impl Field {
                     "        for s in &self.{} {{ w.write_with_tag({}, |w| w.{})?; }}",
                     self.name,
                     self.tag(),
-                    self.typ.get_write("s", self.boxed)
+                    self.typ.get_write(&v("s")?, self.boxed)
                 )?;
             }
         }
@@ -780,12 +1493,18 @@ fn get_modules(module: &str, imported: bool, desc: &FileDescriptor) -> String {
         .collect()
 }
 
+/// Largest field number
+pub const MAX_FIELD_NUMBER: i32 = (1 << 29) - 1;
+
+/// Field numbers reserved for the protocol buffers implementation
+const IMPLEMENTATION_RESERVED_NUMBERS: (i32, i32) = (19_000, 19_999);
+
 #[derive(Debug, Clone, Default)]
 pub struct Message {
     pub name: String,
     pub fields: Vec<Field>,
     pub oneofs: Vec<OneOf>,
-    pub reserved_nums: Option<Vec<i32>>,
+    pub reserved_nums: Option<Vec<(i32, i32)>>, // inclusive
     pub reserved_names: Option<Vec<String>>,
     pub imported: bool,
     pub package: String,        // package from imports + nested items
@@ -796,6 +1515,10 @@ pub struct Message {
     pub import: PathBuf,
     pub index: MessageIndex,
     pub attributes: Vec<String>,
+    pub extension_ranges: Vec<(i32, i32)>, // inclusive
+    pub extends: Vec<Extend>,              // nested `extend` blocks
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl Message {
@@ -823,6 +1546,15 @@ impl Message {
             f.typ = new_type;
         }
 
+        for f in self
+            .extends
+            .iter_mut()
+            .flat_map(|e| e.fields.iter_mut())
+            .filter(|f| f.typ == *from)
+        {
+            f.typ = to.clone();
+        }
+
         for message in &mut self.messages {
             message.convert_field_types(from, to);
         }
@@ -834,8 +1566,7 @@ impl Message {
         }
         ignore.push(self.index.clone());
         let res = self.all_fields().any(|f| {
-            f.typ.has_lifetime(desc, config, f.packed(), ignore)
-                && (!f.deprecated || config.add_deprecated_fields)
+            f.has_lifetime(desc, config, ignore) && (!f.deprecated || config.add_deprecated_fields)
         });
         ignore.pop();
         res
@@ -858,9 +1589,62 @@ impl Message {
         get_modules(&self.module, self.imported, desc)
     }
 
+    fn apply_rust_options(&mut self) -> Result<()> {
+        for o in &self.options {
+            match rust_option_name(&o.name) {
+                Some("message_attr") => self.attributes.push(rust_option_attribute(o)?),
+                Some(_) => return Err(unknown_rust_option(o, &format!("message {}", self.name))),
+                None => (),
+            }
+        }
+        for f in &mut self.fields {
+            f.apply_rust_options()?;
+        }
+        for o in &mut self.oneofs {
+            o.apply_rust_options()?;
+        }
+        for m in &mut self.messages {
+            m.apply_rust_options()?;
+        }
+        for e in &mut self.enums {
+            e.apply_rust_options()?;
+        }
+        Ok(())
+    }
+
+    /// Sets the `custom_type` of the fields in `types`, keyed by fully qualified field name, and
+    /// adds the keys found to `matched`
+    fn apply_custom_field_types(
+        &mut self,
+        types: &HashMap<String, String>,
+        matched: &mut HashSet<String>,
+    ) {
+        let message = [&*self.package, &*self.name]
+            .iter()
+            .filter(|p| !p.is_empty())
+            .map(|p| format!("{}.", p))
+            .collect::<String>();
+        for f in self.all_fields_mut() {
+            let name = format!("{}{}", message, f.name);
+            if let Some(t) = types.get(&name) {
+                f.custom_type = Some(t.clone());
+                matched.insert(name);
+            }
+        }
+        for m in &mut self.messages {
+            m.apply_custom_field_types(types, matched);
+        }
+    }
+
+    /// Whether `Debug` is implemented manually to leave out `(rust.skip_debug)` fields
+    fn has_skip_debug(&self) -> bool {
+        self.fields.iter().any(|f| f.skip_debug)
+    }
+
     fn is_unit(&self) -> bool {
         self.fields.is_empty()
             && self.oneofs.is_empty()
+            && self.extension_ranges.is_empty()
             && self.messages.iter().all(|m| m.is_unit())
     }
 
@@ -870,6 +1654,11 @@ impl Message {
         desc: &FileDescriptor,
         config: &Config,
     ) -> Result<()> {
+        if config.heapless {
+            // fields are `heapless` collections, nothing to import from `alloc`
+            return Ok(());
+        }
+
         if config.nostd {
             writeln!(w, "use alloc::vec::Vec;")?;
         }
@@ -925,11 +1714,15 @@ impl Message {
     }
 
     fn write<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
-        println!("Writing message {}{}", self.get_modules(desc), self.name);
+        debug!("Writing message {}{}", self.get_modules(desc), self.name);
         writeln!(w)?;
 
         self.write_definition(w, desc, config)?;
         writeln!(w)?;
+        if self.has_skip_debug() {
+            self.write_impl_debug(w, desc, config)?;
+            writeln!(w)?;
+        }
         self.write_impl_message_read(w, desc, config)?;
         writeln!(w)?;
         self.write_impl_message_write(w, desc, config)?;
@@ -939,6 +1732,21 @@ impl Message {
             writeln!(w)?;
         }
 
+        if !self.extension_ranges.is_empty() {
+            writeln!(w)?;
+            self.write_impl_extendable_message(w, desc, config)?;
+        }
+
+        if config.gen_accessors {
+            writeln!(w)?;
+            self.write_accessors(w, desc, config)?;
+        }
+
+        if config.gen_builders {
+            writeln!(w)?;
+            self.write_builder(w, desc, config)?;
+        }
+
         if desc.owned {
             writeln!(w)?;
 
@@ -949,14 +1757,19 @@ impl Message {
             }
         }
 
-        if !(self.messages.is_empty() && self.enums.is_empty() && self.oneofs.is_empty()) {
+        if !(self.messages.is_empty()
+            && self.enums.is_empty()
+            && self.oneofs.is_empty()
+            && self.extends.is_empty())
+        {
             writeln!(w)?;
+            writeln!(w, "/// Nested messages, enums and oneofs of `{}`", self.name)?;
             writeln!(w, "pub mod mod_{} {{", self.name)?;
             writeln!(w)?;
 
             Self::write_common_uses(w, &self.messages, desc, config)?;
 
-            if !self.messages.is_empty() || !self.oneofs.is_empty() {
+            if !self.messages.is_empty() || !self.oneofs.is_empty() || !self.extends.is_empty() {
                 writeln!(w, "use super::*;")?;
             }
             for m in &self.messages {
@@ -968,6 +1781,9 @@ impl Message {
             for o in &self.oneofs {
                 o.write(w, desc, config)?;
             }
+            for e in &self.extends {
+                e.write(w, desc, config)?;
+            }
 
             writeln!(w)?;
             writeln!(w, "}}")?;
@@ -988,8 +1804,13 @@ impl Message {
             .map(Into::into)
             .unwrap_or(&config.default_custom_struct_derive);
 
+        self.comments.write_doc(w, "")?;
         writeln!(w, "#[allow(clippy::derive_partial_eq_without_eq)]")?;
-        writeln!(w, "#[derive({}Debug, Default, PartialEq, Clone)]", derive)?;
+        if self.has_skip_debug() {
+            writeln!(w, "#[derive({}Default, PartialEq, Clone)]", derive)?;
+        } else {
+            writeln!(w, "#[derive({}Debug, Default, PartialEq, Clone)]", derive)?;
+        }
         for attr in &self.attributes {
             writeln!(w, "{}", attr)?;
         }
@@ -1018,6 +1839,63 @@ impl Message {
         for o in &self.oneofs {
             o.write_message_definition(w, desc, config)?;
         }
+        if !self.extension_ranges.is_empty() {
+            writeln!(w, "    pub extensions: quick_protobuf::extensions::Extensions,")?;
+        }
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    fn write_impl_debug<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        if self.has_lifetime(desc, config, &mut Vec::new()) {
+            writeln!(w, "impl<'a> core::fmt::Debug for {}<'a> {{", self.name)?;
+        } else {
+            writeln!(w, "impl core::fmt::Debug for {} {{", self.name)?;
+        }
+        writeln!(
+            w,
+            "    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+        )?;
+        writeln!(w, "        f.debug_struct({:?})", self.name)?;
+        for f in self.fields.iter().filter(|f| !f.deprecated || config.add_deprecated_fields) {
+            if !f.skip_debug {
+                writeln!(w, "            .field({:?}, &self.{})", f.name, f.name)?;
+            }
+        }
+        for o in &self.oneofs {
+            writeln!(w, "            .field({:?}, &self.{})", o.name, o.name)?;
+        }
+        if !self.extension_ranges.is_empty() {
+            writeln!(w, "            .field(\"extensions\", &self.extensions)")?;
+        }
+        writeln!(w, "            .finish_non_exhaustive()")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    fn write_impl_extendable_message<W: Write>(
+        &self,
+        w: &mut W,
+        desc: &FileDescriptor,
+        config: &Config,
+    ) -> Result<()> {
+        let mut ignore = Vec::new();
+        if config.dont_use_cow {
+            ignore.push(self.index.clone());
+        }
+        if self.has_lifetime(desc, config, &mut ignore) {
+            writeln!(w, "impl<'a> quick_protobuf::extensions::ExtendableMessage for {}<'a> {{", self.name)?;
+        } else {
+            writeln!(w, "impl quick_protobuf::extensions::ExtendableMessage for {} {{", self.name)?;
+        }
+        writeln!(w, "    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {{")?;
+        writeln!(w, "        &self.extensions")?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+        writeln!(w, "    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {{")?;
+        writeln!(w, "        &mut self.extensions")?;
+        writeln!(w, "    }}")?;
         writeln!(w, "}}")?;
         Ok(())
     }
@@ -1061,6 +1939,14 @@ impl Message {
             writeln!(w, "        r.read_to_end();")?;
             writeln!(w, "        Ok(Self::default())")?;
             writeln!(w, "    }}")?;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {{"
+            )?;
+            writeln!(w, "        r.read_to_end();")?;
+            writeln!(w, "        Ok(())")?;
+            writeln!(w, "    }}")?;
             writeln!(w, "}}")?;
             return Ok(());
         }
@@ -1083,33 +1969,72 @@ impl Message {
             )?;
         }
 
-        let unregular_defaults = self
+        self.write_default_msg(w, desc, config, "let mut msg")?;
+        writeln!(w, "        msg.merge_from_reader(r, bytes)?;")?;
+        writeln!(w, "        Ok(msg)")?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+
+        writeln!(w, "    fn clear(&mut self) {{")?;
+        for f in self
             .fields
             .iter()
-            .filter(|f| !f.has_regular_default(desc))
-            .collect::<Vec<_>>();
-        if unregular_defaults.is_empty() {
-            writeln!(w, "        let mut msg = Self::default();")?;
-        } else {
-            writeln!(w, "        let mut msg = {} {{", self.name)?;
-            for f in unregular_defaults {
-                writeln!(
-                    w,
-                    "            {}: {},",
-                    f.name,
-                    f.default.as_ref().unwrap()
-                )?;
+            .filter(|f| !f.deprecated || config.add_deprecated_fields)
+        {
+            f.write_clear(w, desc, config)?;
+        }
+        for o in &self.oneofs {
+            writeln!(w, "        self.{} = Default::default();", o.name)?;
+        }
+        if !self.extension_ranges.is_empty() {
+            writeln!(w, "        self.extensions.clear();")?;
+        }
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {{"
+        )?;
+        // singular fields and oneofs which may appear only once in strict mode
+        let mut singular = 0;
+        let mut seen = Vec::with_capacity(self.fields.len());
+        for f in &self.fields {
+            if !config.strict || f.frequency == Frequency::Repeated || f.typ.is_map() {
+                seen.push(None);
+            } else {
+                seen.push(Some(singular));
+                singular += 1;
             }
-            writeln!(w, "            ..Self::default()")?;
-            writeln!(w, "        }};")?;
+        }
+        let oneof_seen = singular;
+        if config.strict {
+            singular += self.oneofs.len();
+        }
+        if singular > 0 {
+            writeln!(w, "        let mut seen = [false; {}];", singular)?;
         }
         writeln!(w, "        while !r.is_eof() {{")?;
         writeln!(w, "            match r.next_tag(bytes) {{")?;
-        for f in &self.fields {
-            f.write_match_tag(w, desc, config)?;
+        for (f, seen) in self.fields.iter().zip(seen) {
+            f.write_match_tag(w, desc, config, seen)?;
         }
-        for o in &self.oneofs {
-            o.write_match_tag(w, desc, config)?;
+        for (i, o) in self.oneofs.iter().enumerate() {
+            let seen = if config.strict { Some(oneof_seen + i) } else { None };
+            o.write_match_tag(w, desc, config, seen)?;
+        }
+        if !self.extension_ranges.is_empty() {
+            let ranges = self
+                .extension_ranges
+                .iter()
+                .map(|&(from, to)| format!("{}..={}", from, to))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            writeln!(
+                w,
+                "                Ok(t) if matches!(t >> 3, {}) => self.extensions.read_field(r, bytes, t)?,",
+                ranges
+            )?;
         }
         writeln!(
             w,
@@ -1118,7 +2043,7 @@ impl Message {
         writeln!(w, "                Err(e) => return Err(e),")?;
         writeln!(w, "            }}")?;
         writeln!(w, "        }}")?;
-        writeln!(w, "        Ok(msg)")?;
+        writeln!(w, "        Ok(())")?;
         writeln!(w, "    }}")?;
         writeln!(w, "}}")?;
 
@@ -1128,6 +2053,37 @@ impl Message {
         Ok(())
     }
 
+    /// Writes `{binding} = ...;`, a message with the declared defaults of its fields
+    fn write_default_msg<W: Write>(
+        &self,
+        w: &mut W,
+        desc: &FileDescriptor,
+        config: &Config,
+        binding: &str,
+    ) -> Result<()> {
+        let unregular_defaults = self
+            .fields
+            .iter()
+            .filter(|f| !f.has_regular_default(desc, config))
+            .collect::<Vec<_>>();
+        if unregular_defaults.is_empty() {
+            writeln!(w, "        {} = Self::default();", binding)?;
+        } else {
+            writeln!(w, "        {} = {} {{", binding, self.name)?;
+            for f in unregular_defaults {
+                writeln!(
+                    w,
+                    "            {}: {},",
+                    f.name,
+                    f.default.as_ref().unwrap()
+                )?;
+            }
+            writeln!(w, "            ..Self::default()")?;
+            writeln!(w, "        }};")?;
+        }
+        Ok(())
+    }
+
     fn write_impl_message_write<W: Write>(
         &self,
         w: &mut W,
@@ -1146,13 +2102,112 @@ impl Message {
         if self.has_lifetime(desc, config, &mut ignore) {
             writeln!(w, "impl<'a> MessageWrite for {}<'a> {{", self.name)?;
         } else {
-            writeln!(w, "impl MessageWrite for {} {{", self.name)?;
+            writeln!(w, "impl MessageWrite for {} {{", self.name)?;
+        }
+        self.write_get_size(w, desc, config)?;
+        writeln!(w)?;
+        self.write_write_message(w, desc, config)?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    /// Writes the accessors of the fields and oneofs, if any
+    fn write_accessors<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let mut accessors = Vec::new();
+        for f in self.fields.iter().filter(|f| !f.deprecated || config.add_deprecated_fields) {
+            f.write_accessors(&mut accessors, desc, config)?;
+        }
+        for o in &self.oneofs {
+            o.write_accessors(&mut accessors, desc, config)?;
+        }
+        if accessors.is_empty() {
+            return Ok(());
+        }
+        // no empty line before the closing brace
+        accessors.pop();
+
+        let lifetime = self.impl_lifetime(desc, config);
+        writeln!(w, "impl{} {}{} {{", lifetime, self.name, lifetime)?;
+        w.write_all(&accessors)?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    /// Writes `{Message}::builder()` and the `{Message}Builder` struct
+    ///
+    /// The builder starts from a message with the declared defaults, `build` fails if a
+    /// required field has not been set.
+    fn write_builder<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let lifetime = self.impl_lifetime(desc, config);
+        let ty = format!("{}{}", self.name, lifetime);
+        let fields = self
+            .fields
+            .iter()
+            .filter(|f| !f.deprecated || config.add_deprecated_fields)
+            .collect::<Vec<_>>();
+        let required = fields
+            .iter()
+            .filter(|f| f.frequency == Frequency::Required && !f.typ.is_map())
+            .collect::<Vec<_>>();
+
+        writeln!(w, "impl{} {} {{", lifetime, ty)?;
+        writeln!(w, "    /// A builder of `{}`", self.name)?;
+        writeln!(w, "    pub fn builder() -> {}Builder{} {{", self.name, lifetime)?;
+        self.write_default_msg(w, desc, config, "let msg")?;
+        write!(w, "        {}Builder {{ msg", self.name)?;
+        for f in &required {
+            write!(w, ", has_{}: false", f.name)?;
+        }
+        writeln!(w, " }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+
+        writeln!(w, "/// Builder of [`{}`], see `{}::builder`", self.name, self.name)?;
+        writeln!(w, "#[derive(Debug, Clone)]")?;
+        writeln!(w, "pub struct {}Builder{} {{", self.name, lifetime)?;
+        writeln!(w, "    msg: {},", ty)?;
+        for f in &required {
+            writeln!(w, "    has_{}: bool,", f.name)?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+
+        writeln!(w, "impl{} {}Builder{} {{", lifetime, self.name, lifetime)?;
+        for f in &fields {
+            f.write_builder_setters(w, desc, config)?;
+        }
+        for o in &self.oneofs {
+            o.write_builder_setters(w, desc, config)?;
+        }
+        writeln!(w, "    /// Builds the `{}`, fails if a required field is not set", self.name)?;
+        writeln!(w, "    pub fn build(self) -> Result<{}> {{", ty)?;
+        for f in &required {
+            writeln!(w, "        if !self.has_{} {{", f.name)?;
+            writeln!(
+                w,
+                "            return Err(quick_protobuf::Error::MissingField(\"{}.{}\"));",
+                self.name, f.name
+            )?;
+            writeln!(w, "        }}")?;
+        }
+        writeln!(w, "        Ok(self.msg)")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    /// `<'a>` if the generated struct has a lifetime, else an empty string
+    fn impl_lifetime(&self, desc: &FileDescriptor, config: &Config) -> &'static str {
+        let mut ignore = Vec::new();
+        if config.dont_use_cow {
+            ignore.push(self.index.clone());
+        }
+        if self.has_lifetime(desc, config, &mut ignore) {
+            "<'a>"
+        } else {
+            ""
         }
-        self.write_get_size(w, desc, config)?;
-        writeln!(w)?;
-        self.write_write_message(w, desc, config)?;
-        writeln!(w, "}}")?;
-        Ok(())
     }
 
     fn write_impl_owned<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
@@ -1317,6 +2372,9 @@ impl Message {
         for o in self.oneofs.iter() {
             o.write_get_size(w, desc, config)?;
         }
+        if !self.extension_ranges.is_empty() {
+            writeln!(w, "        + self.extensions.get_size()")?;
+        }
         writeln!(w, "    }}")?;
         Ok(())
     }
@@ -1358,22 +2416,87 @@ impl Message {
         for o in &self.oneofs {
             o.write_write(w, desc, config)?;
         }
+        if !self.extension_ranges.is_empty() {
+            writeln!(w, "        self.extensions.write(w)?;")?;
+        }
         writeln!(w, "        Ok(())")?;
         writeln!(w, "    }}")?;
         Ok(())
     }
 
     fn sanity_checks(&self, desc: &FileDescriptor) -> Result<()> {
-        for f in self.all_fields() {
+        for f in self.oneofs.iter().flat_map(|o| o.fields.iter()) {
+            if f.custom_type.is_some() || f.skip_debug {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Field {} of a oneof cannot have a custom type or skip Debug",
+                    self.name, f.name
+                )));
+            }
+        }
+        for &(from, to) in self.reserved_nums.iter().flatten() {
+            if from < 1 || from > to || to > MAX_FIELD_NUMBER {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Invalid reserved range {} to {}, field numbers go from 1 to {}",
+                    self.name, from, to, MAX_FIELD_NUMBER
+                )));
+            }
+        }
+        let in_ranges = |ranges: &[(i32, i32)], number: i32| {
+            ranges
+                .iter()
+                .any(|&(from, to)| from <= number && number <= to)
+        };
+        for (i, f) in self.all_fields().enumerate() {
+            // check numbers
+            if let Some(other) = self.all_fields().take(i).find(|o| o.number == f.number) {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Fields {} and {} share the number {}",
+                    self.name, other.name, f.name, f.number
+                )));
+            }
+            if let Some(other) = self.all_fields().take(i).find(|o| o.name == f.name) {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Field {} is defined twice, with the numbers {} and {}",
+                    self.name, f.name, other.number, f.number
+                )));
+            }
+            if f.number < 1 || f.number > MAX_FIELD_NUMBER {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Field {} number {} is not between 1 and {}",
+                    self.name, f.name, f.number, MAX_FIELD_NUMBER
+                )));
+            }
+            if in_ranges(&[IMPLEMENTATION_RESERVED_NUMBERS], f.number) {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Field {} number {} is reserved for the protocol buffers implementation \
+                     ({} to {})",
+                    self.name,
+                    f.name,
+                    f.number,
+                    IMPLEMENTATION_RESERVED_NUMBERS.0,
+                    IMPLEMENTATION_RESERVED_NUMBERS.1
+                )));
+            }
+            if in_ranges(&self.extension_ranges, f.number) {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in message {}\n\
+                     Field {} number {} is in an extension range",
+                    self.name, f.name, f.number
+                )));
+            }
+
             // check reserved
             if self
                 .reserved_names
                 .as_ref()
                 .map_or(false, |names| names.contains(&f.name))
-                || self
-                    .reserved_nums
-                    .as_ref()
-                    .map_or(false, |nums| nums.contains(&f.number))
+                || in_ranges(self.reserved_nums.as_deref().unwrap_or(&[]), f.number)
             {
                 return Err(Error::InvalidMessage(format!(
                     "Error in message {}\n\
@@ -1382,6 +2505,26 @@ impl Message {
                 )));
             }
 
+            // check `(rust.type)` overrides
+            if f.custom_type.is_some() {
+                let unsupported = if f.typ.is_map() {
+                    Some("maps")
+                } else if f.default.is_some() {
+                    Some("fields with a default value")
+                } else if f.boxed {
+                    Some("boxed fields")
+                } else {
+                    None
+                };
+                if let Some(unsupported) = unsupported {
+                    return Err(Error::InvalidMessage(format!(
+                        "Error in message {}\n\
+                         Field {} cannot have a custom type, not supported for {}",
+                        self.name, f.name, unsupported
+                    )));
+                }
+            }
+
             // check default enums
             if let Some(var) = f.default.as_ref() {
                 if let FieldType::Enum(ref e) = f.typ {
@@ -1397,6 +2540,26 @@ impl Message {
         Ok(())
     }
 
+    /// Rejects the messages which cannot be generated with `Config::heapless`
+    fn heapless_checks(&self, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        if !self.extension_ranges.is_empty() || !self.extends.is_empty() {
+            return Err(Error::Heapless(format!(
+                "message {} has extensions, which need an allocator",
+                self.name
+            )));
+        }
+        for f in self.all_fields() {
+            f.heapless_checks(desc, config).map_err(|e| match e {
+                Error::Heapless(e) => Error::Heapless(format!("{} in message {}", e, self.name)),
+                e => e,
+            })?;
+        }
+        for m in &self.messages {
+            m.heapless_checks(desc, config)?;
+        }
+        Ok(())
+    }
+
     fn set_package(&mut self, package: &str, module: &str) {
         // The complication here is that the _package_ (as declared in the proto file) does
         // not directly map to the _module_. For example, the package 'a.A' where A is a
@@ -1480,6 +2643,9 @@ impl Message {
         for o in &mut self.oneofs {
             o.sanitize_names();
         }
+        for e in &mut self.extends {
+            e.sanitize_names();
+        }
     }
 
     /// Return an iterator producing references to all the `Field`s of `self`,
@@ -1499,22 +2665,380 @@ impl Message {
     }
 }
 
+/// An `extend` block
+#[derive(Debug, Clone, Default)]
+pub struct Extend {
+    pub extendee: String,
+    pub fields: Vec<Field>,
+    pub index: MessageIndex, // extendee, once resolved
+}
+
+impl Extend {
+    fn sanitize_names(&mut self) {
+        for f in self.fields.iter_mut() {
+            sanitize_keyword(&mut f.name);
+        }
+    }
+
+    fn sanity_checks(&self, desc: &FileDescriptor) -> Result<()> {
+        let m = self.index.get_message(desc);
+        for f in &self.fields {
+            if !m
+                .extension_ranges
+                .iter()
+                .any(|&(from, to)| from <= f.number && f.number <= to)
+            {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in extend {}\n\
+                     Field {} number {} is not in an extension range of {}",
+                    self.extendee, f.name, f.number, m.name
+                )));
+            }
+            if f.typ.is_map() {
+                return Err(Error::InvalidMessage(format!(
+                    "Error in extend {}\n\
+                     Field {} cannot be a map",
+                    self.extendee, f.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes an `Extension` descriptor per field, with its `ExtensionType` for messages and enums
+    fn write<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        debug!("Writing extend {}", self.extendee);
+        let m = self.index.get_message(desc);
+        let lifetime = if m.has_lifetime(desc, config, &mut Vec::new()) {
+            "<'static>"
+        } else {
+            ""
+        };
+        let extendee = format!("{}{}{}", m.get_modules(desc), m.name, lifetime);
+        for f in &self.fields {
+            if f.deprecated && !config.add_deprecated_fields {
+                continue;
+            }
+            let typ = match f.typ {
+                FieldType::Int32 => "quick_protobuf::extensions::Int32".to_string(),
+                FieldType::Int64 => "quick_protobuf::extensions::Int64".to_string(),
+                FieldType::Uint32 => "quick_protobuf::extensions::Uint32".to_string(),
+                FieldType::Uint64 => "quick_protobuf::extensions::Uint64".to_string(),
+                FieldType::Sint32 => "quick_protobuf::extensions::Sint32".to_string(),
+                FieldType::Sint64 => "quick_protobuf::extensions::Sint64".to_string(),
+                FieldType::Bool => "quick_protobuf::extensions::Bool".to_string(),
+                FieldType::Fixed32 => "quick_protobuf::extensions::Fixed32".to_string(),
+                FieldType::Fixed64 => "quick_protobuf::extensions::Fixed64".to_string(),
+                FieldType::Sfixed32 => "quick_protobuf::extensions::Sfixed32".to_string(),
+                FieldType::Sfixed64 => "quick_protobuf::extensions::Sfixed64".to_string(),
+                FieldType::Float => "quick_protobuf::extensions::Float".to_string(),
+                FieldType::Double => "quick_protobuf::extensions::Double".to_string(),
+                FieldType::StringCow | FieldType::String_ => {
+                    "quick_protobuf::extensions::Str".to_string()
+                }
+                FieldType::BytesCow | FieldType::Bytes_ => {
+                    "quick_protobuf::extensions::Bytes".to_string()
+                }
+                FieldType::Message(_) | FieldType::Enum(_) => {
+                    let typ = format!("{}Extension", camel_case(&f.name));
+                    Self::write_extension_type(w, &typ, f, desc, config)?;
+                    typ
+                }
+                FieldType::Map(_, _) | FieldType::MessageOrEnum(_) => {
+                    unreachable!("Map extensions are rejected in sanity checks")
+                }
+            };
+            writeln!(w)?;
+            f.comments.write_doc(w, "")?;
+            if f.deprecated {
+                writeln!(w, "#[deprecated]")?;
+            }
+            writeln!(
+                w,
+                "pub const {}: quick_protobuf::extensions::Extension<{}, {}> = \
+                 quick_protobuf::extensions::Extension::new({});",
+                f.name, extendee, typ, f.number
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_extension_type<W: Write>(
+        w: &mut W,
+        name: &str,
+        f: &Field,
+        desc: &FileDescriptor,
+        config: &Config,
+    ) -> Result<()> {
+        writeln!(w)?;
+        writeln!(w, "#[derive(Debug, Clone, Copy)]")?;
+        writeln!(w, "pub struct {};", name)?;
+        writeln!(w)?;
+        writeln!(w, "impl quick_protobuf::extensions::ExtensionType for {} {{", name)?;
+        writeln!(w, "    type Value<'a> = {};", f.typ.rust_type(desc, config)?)?;
+        writeln!(w)?;
+        writeln!(w, "    const WIRE_TYPE: u32 = {};", f.typ.wire_type_num_non_packed())?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self::Value<'a>> {{"
+        )?;
+        writeln!(w, "        Ok({})", f.typ.read_fn(desc)?.0)?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+        writeln!(w, "    fn get_size(v: &Self::Value<'_>) -> usize {{")?;
+        writeln!(w, "        {}", f.typ.get_size("v"))?;
+        writeln!(w, "    }}")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &Self::Value<'_>) -> Result<()> {{"
+        )?;
+        writeln!(w, "        w.{}", f.typ.get_write("v", false))?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct RpcFunctionDeclaration {
     pub name: String,
+    /// Argument message, as written in the .proto file, e.g. `.foo.v1.Request`
     pub arg: String,
+    /// Return message, as written in the .proto file
     pub ret: String,
+    pub arg_index: MessageIndex,
+    pub ret_index: MessageIndex,
+    /// Rust path of the argument message, e.g. `foo::v1::Request`, set before calling the
+    /// rpc generator
+    pub arg_type: String,
+    /// Rust path of the return message
+    pub ret_type: String,
+    /// The generated argument message has a lifetime, it is `arg_type<'a>`
+    pub arg_lifetime: bool,
+    /// The generated return message has a lifetime, it is `ret_type<'a>`
+    pub ret_lifetime: bool,
+    pub client_streaming: bool,
+    pub server_streaming: bool,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct RpcService {
     pub service_name: String,
+    pub package: String,
     pub functions: Vec<RpcFunctionDeclaration>,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl RpcService {
-    fn write_definition<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
-        (config.custom_rpc_generator)(self, w)
+    fn write_definition<W: Write>(
+        &self,
+        w: &mut W,
+        desc: &FileDescriptor,
+        config: &Config,
+    ) -> Result<()> {
+        // names are only final once the file is about to be written
+        let mut service = self.clone();
+        for f in &mut service.functions {
+            let arg = f.arg_index.get_message(desc);
+            f.arg_type = format!("{}{}", arg.get_modules(desc), arg.name);
+            f.arg_lifetime = arg.has_lifetime(desc, config, &mut Vec::new());
+            let ret = f.ret_index.get_message(desc);
+            f.ret_type = format!("{}{}", ret.get_modules(desc), ret.name);
+            f.ret_lifetime = ret.has_lifetime(desc, config, &mut Vec::new());
+        }
+        (config.custom_rpc_generator)(&service, w)
+    }
+}
+
+impl RpcService {
+    /// Writes a trait, a server and a client stub for this service, the rpc generator of
+    /// `--generate_services`
+    ///
+    /// The server and the client use the `quick_protobuf::rpc` traits, see its documentation.
+    pub fn write_service<W: Write + ?Sized>(&self, w: &mut W) -> Result<()> {
+        self.write_trait(w)?;
+        writeln!(w)?;
+        self.write_server(w)?;
+        writeln!(w)?;
+        self.write_client(w)
+    }
+
+    /// The full name of the method, e.g. `/helloworld.Greeter/SayHello`
+    fn method_path(&self, f: &RpcFunctionDeclaration) -> String {
+        if self.package.is_empty() {
+            format!("/{}/{}", self.service_name, f.name)
+        } else {
+            format!("/{}.{}/{}", self.package, self.service_name, f.name)
+        }
+    }
+
+    fn write_trait<W: Write + ?Sized>(&self, w: &mut W) -> Result<()> {
+        self.comments.write_doc(w, "")?;
+        writeln!(w, "pub trait {} {{", self.service_name)?;
+        for f in &self.functions {
+            let lifetime = |has_lifetime| if has_lifetime { "<'a>" } else { "" };
+            let arg = format!("{}{}", f.arg_type, lifetime(f.arg_lifetime));
+            let ret = format!("{}{}", f.ret_type, lifetime(f.ret_lifetime));
+            let arg = if f.client_streaming {
+                format!("requests: Vec<{}>", arg)
+            } else {
+                format!("request: {}", arg)
+            };
+            f.comments.write_doc(w, "    ")?;
+            writeln!(
+                w,
+                "    fn {}{}(&self, {}) -> quick_protobuf::Result<{}>;",
+                f.method_name(),
+                lifetime(f.arg_lifetime || f.ret_lifetime),
+                arg,
+                stream(f.server_streaming, &ret),
+            )?;
+        }
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    fn write_server<W: Write + ?Sized>(&self, w: &mut W) -> Result<()> {
+        let name = &self.service_name;
+        writeln!(
+            w,
+            "/// Server of the `{}` service, handling the encoded requests with `S`",
+            name
+        )?;
+        writeln!(w, "pub struct {}Server<S: {}>(pub S);", name, name)?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "impl<S: {}> quick_protobuf::rpc::Service for {}Server<S> {{",
+            name, name
+        )?;
+        writeln!(
+            w,
+            "    fn call(&self, method: &str, request: &[u8]) -> quick_protobuf::Result<Vec<u8>> {{"
+        )?;
+        writeln!(w, "        match method {{")?;
+        for f in &self.functions {
+            writeln!(
+                w,
+                "            {:?} => quick_protobuf::rpc::{}(&self.0.{}(quick_protobuf::rpc::{}(request)?)?),",
+                self.method_path(f),
+                if f.server_streaming { "encode_stream" } else { "encode" },
+                f.method_name(),
+                if f.client_streaming { "decode_stream" } else { "decode" },
+            )?;
+        }
+        writeln!(
+            w,
+            "            _ => Err(quick_protobuf::Error::UnknownMethod(method.into())),"
+        )?;
+        writeln!(w, "        }}")?;
+        writeln!(w, "    }}")?;
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    fn write_client<W: Write + ?Sized>(&self, w: &mut W) -> Result<()> {
+        let name = &self.service_name;
+        writeln!(
+            w,
+            "/// Client of the `{}` service, sending the encoded requests through `T`",
+            name
+        )?;
+        writeln!(w, "///")?;
+        writeln!(
+            w,
+            "/// Responses borrow the buffer of the client, until the next call."
+        )?;
+        writeln!(
+            w,
+            "pub struct {}Client<T: quick_protobuf::rpc::Transport> {{",
+            name
+        )?;
+        writeln!(w, "    pub transport: T,")?;
+        writeln!(w, "    response: Vec<u8>,")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "impl<T: quick_protobuf::rpc::Transport> {}Client<T> {{",
+            name
+        )?;
+        writeln!(w, "    pub fn new(transport: T) -> Self {{")?;
+        writeln!(
+            w,
+            "        {}Client {{ transport, response: Vec::new() }}",
+            name
+        )?;
+        writeln!(w, "    }}")?;
+        for f in &self.functions {
+            let lifetime = |has_lifetime| if has_lifetime { "<'_>" } else { "" };
+            let arg = format!("{}{}", f.arg_type, lifetime(f.arg_lifetime));
+            let ret = format!("{}{}", f.ret_type, lifetime(f.ret_lifetime));
+            let arg = if f.client_streaming {
+                format!("requests: &[{}]", arg)
+            } else {
+                format!("request: &{}", arg)
+            };
+            writeln!(w)?;
+            f.comments.write_doc(w, "    ")?;
+            writeln!(
+                w,
+                "    pub fn {}(&mut self, {}) -> quick_protobuf::Result<{}> {{",
+                f.method_name(),
+                arg,
+                stream(f.server_streaming, &ret),
+            )?;
+            writeln!(
+                w,
+                "        let request = quick_protobuf::rpc::{}(request{})?;",
+                if f.client_streaming {
+                    "encode_stream"
+                } else {
+                    "encode"
+                },
+                if f.client_streaming { "s" } else { "" },
+            )?;
+            writeln!(
+                w,
+                "        self.response = self.transport.call({:?}, &request)?;",
+                self.method_path(f)
+            )?;
+            writeln!(
+                w,
+                "        quick_protobuf::rpc::{}(&self.response)",
+                if f.server_streaming {
+                    "decode_stream"
+                } else {
+                    "decode"
+                },
+            )?;
+            writeln!(w, "    }}")?;
+        }
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+}
+
+/// The type of the messages of a streaming rpc, or of its single message
+fn stream(is_stream: bool, typ: &str) -> String {
+    if is_stream {
+        format!("Vec<{}>", typ)
+    } else {
+        typ.to_string()
+    }
+}
+
+impl RpcFunctionDeclaration {
+    /// Name of the Rust methods of the rpc, in snake case
+    fn method_name(&self) -> String {
+        let mut name = snake_case(&self.name);
+        sanitize_keyword(&mut name);
+        name
     }
 }
 
@@ -1533,6 +3057,8 @@ pub struct Enumerator {
     pub import: PathBuf,
     pub index: EnumIndex,
     pub attributes: Vec<String>,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl Enumerator {
@@ -1566,12 +3092,68 @@ impl Enumerator {
         }
     }
 
+    fn apply_rust_options(&mut self) -> Result<()> {
+        for o in &self.options {
+            match rust_option_name(&o.name) {
+                Some("enum_attr") => self.attributes.push(rust_option_attribute(o)?),
+                Some(_) => return Err(unknown_rust_option(o, &format!("enum {}", self.name))),
+                None => (),
+            }
+        }
+        for f in &mut self.fields {
+            for o in &f.options {
+                match rust_option_name(&o.name) {
+                    Some("variant_attr") => f.attributes.push(rust_option_attribute(o)?),
+                    Some(_) => {
+                        return Err(unknown_rust_option(o, &format!("enum value {}", f.name)))
+                    }
+                    None => (),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sanity_checks(&self) -> Result<()> {
+        let allow_alias =
+            get_option(&self.options, "allow_alias").and_then(OptionValue::as_bool) == Some(true);
+        for (i, f) in self.fields.iter().enumerate() {
+            if let Some(other) = self.fields[..i].iter().find(|o| o.name == f.name) {
+                return Err(Error::InvalidEnum(format!(
+                    "Error in enum {}\n\
+                     Variant {} is defined twice, with the values {} and {}",
+                    self.name, f.name, other.tag, f.tag
+                )));
+            }
+            if allow_alias {
+                continue;
+            }
+            if let Some(alias) = self.fields[..i].iter().find(|o| o.tag == f.tag) {
+                return Err(Error::InvalidEnum(format!(
+                    "Error in enum {}\n\
+                     Variants {} and {} share the value {}, \
+                     set `option allow_alias = true;` to define aliases",
+                    self.name, alias.name, f.name, f.tag
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The variant `f` is an alias of, if an earlier variant has the same value
+    fn alias_of(&self, f: &EnumField) -> Option<&EnumField> {
+        self.fields
+            .iter()
+            .find(|o| o.tag == f.tag)
+            .filter(|o| o.name != f.name)
+    }
+
     fn get_modules(&self, desc: &FileDescriptor) -> String {
         get_modules(&self.module, self.imported, desc)
     }
 
     fn write<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
-        println!("Writing enum {}", self.name);
+        debug!("Writing enum {}", self.name);
         writeln!(w)?;
         self.write_definition(w, config)?;
         writeln!(w)?;
@@ -1593,12 +3175,36 @@ impl Enumerator {
             .map(Into::into)
             .unwrap_or(&config.default_custom_struct_derive);
 
+        self.comments.write_doc(w, "")?;
         writeln!(w, "#[derive({}Debug, PartialEq, Eq, Clone, Copy)]", derive)?;
+        for attr in &self.attributes {
+            writeln!(w, "{}", attr)?;
+        }
         writeln!(w, "pub enum {} {{", self.name)?;
-        for enum_field in &self.fields {
+        for enum_field in self.fields.iter().filter(|f| self.alias_of(f).is_none()) {
             enum_field.write_definition(w, config)?;
         }
         writeln!(w, "}}")?;
+
+        // aliases cannot be variants, they would have the same discriminant
+        let aliases = self
+            .fields
+            .iter()
+            .filter_map(|f| self.alias_of(f).map(|o| (f, o)))
+            .collect::<Vec<_>>();
+        if !aliases.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "impl {} {{", self.name)?;
+            for (alias, f) in aliases {
+                alias.comments.write_doc(w, "    ")?;
+                writeln!(
+                    w,
+                    "    pub const {}: {} = {}::{};",
+                    alias.name, self.name, self.name, f.name
+                )?;
+            }
+            writeln!(w, "}}")?;
+        }
         Ok(())
     }
 
@@ -1616,7 +3222,7 @@ impl Enumerator {
         writeln!(w, "impl From<i32> for {} {{", self.name)?;
         writeln!(w, "    fn from(i: i32) -> Self {{")?;
         writeln!(w, "        match i {{")?;
-        for enum_field in &self.fields {
+        for enum_field in self.fields.iter().filter(|f| self.alias_of(f).is_none()) {
             writeln!(w, "            {} => {}::{},", enum_field.tag, self.name, enum_field.name)?;
         }
         writeln!(w, "            _ => Self::default(),")?;
@@ -1646,10 +3252,13 @@ pub struct EnumField {
     pub name: String,
     pub tag: i32,
     pub attributes: Vec<String>,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl EnumField {
     fn write_definition<W: Write>(&self, w: &mut W, _config: &Config) -> Result<()> {
+        self.comments.write_doc(w, "    ")?;
         for attr in &self.attributes {
             writeln!(w, "    {}", attr)?;
         }
@@ -1667,13 +3276,14 @@ pub struct OneOf {
     pub imported: bool,
     pub field_attributes: Vec<String>,
     pub container_attributes: Vec<String>,
+    pub options: Vec<ProtoOption>,
+    pub comments: Comments,
 }
 
 impl OneOf {
     fn has_lifetime(&self, desc: &FileDescriptor, config: &Config) -> bool {
         self.fields.iter().any(|f| {
-            f.typ
-                .has_lifetime(desc, config, f.packed(), &mut Vec::new())
+            f.has_lifetime(desc, config, &mut Vec::new())
                 && (!f.deprecated || config.add_deprecated_fields)
         })
     }
@@ -1683,6 +3293,21 @@ impl OneOf {
         self.module = module.to_string();
     }
 
+    fn apply_rust_options(&mut self) -> Result<()> {
+        for o in &self.options {
+            match rust_option_name(&o.name) {
+                Some("oneof_attr") => self.container_attributes.push(rust_option_attribute(o)?),
+                Some("oneof_field_attr") => self.field_attributes.push(rust_option_attribute(o)?),
+                Some(_) => return Err(unknown_rust_option(o, &format!("oneof {}", self.name))),
+                None => (),
+            }
+        }
+        for f in &mut self.fields {
+            f.apply_rust_options()?;
+        }
+        Ok(())
+    }
+
     fn sanitize_names(&mut self) {
         sanitize_keyword(&mut self.name);
         sanitize_keyword(&mut self.package);
@@ -1710,6 +3335,7 @@ impl OneOf {
             .map(Into::into)
             .unwrap_or(&config.default_custom_struct_derive);
 
+        self.comments.write_doc(w, "")?;
         writeln!(w, "#[derive({}Debug, PartialEq, Clone)]", derive)?;
         for attr in &self.container_attributes {
             writeln!(w, "{}", attr)?;
@@ -1728,16 +3354,22 @@ impl OneOf {
                 }
             }
 
+            f.comments.write_doc(w, "    ")?;
             for attr in &f.attributes {
                 writeln!(w, "    {}", attr)?;
             }
-            let rust_type = f.typ.rust_type(desc, config)?;
+            let rust_type = if config.heapless {
+                f.heapless_value_type(&f.typ, desc, config)?
+            } else {
+                f.typ.rust_type(desc, config)?
+            };
             if f.boxed {
                 writeln!(w, "    {}(Box<{}>),", f.name, rust_type)?;
             } else {
                 writeln!(w, "    {}({}),", f.name, rust_type)?;
             }
         }
+        writeln!(w, "    /// None of the fields is set")?;
         writeln!(w, "    None,")?;
         writeln!(w, "}}")?;
 
@@ -1786,7 +3418,86 @@ impl OneOf {
         Ok(())
     }
 
+    /// Writes `has_v()`, `v()` and `set_v()` for each variant `v` and `clear_{oneof}()`
+    fn write_accessors<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        let oneof = format!("{}OneOf{}", self.get_modules(desc), self.name);
+        for f in self.fields.iter().filter(|f| !f.deprecated || config.add_deprecated_fields) {
+            let variant = format!("{}::{}", oneof, f.name);
+            let rust_type = f.typ.rust_type(desc, config)?;
+            let (ret, get) = match f.typ {
+                FieldType::StringCow | FieldType::String_ => ("Option<&str>".to_string(), "(ref v) => Some(&**v)"),
+                FieldType::BytesCow | FieldType::Bytes_ => ("Option<&[u8]>".to_string(), "(ref v) => Some(&**v)"),
+                FieldType::Message(_) if f.boxed => (format!("Option<&{}>", rust_type), "(ref v) => Some(&**v)"),
+                FieldType::Message(_) => (format!("Option<&{}>", rust_type), "(ref v) => Some(v)"),
+                _ => (format!("Option<{}>", rust_type), "(v) => Some(v)"),
+            };
+            let (param, value) = builder_param(&f.typ, "v", desc, config)?;
+            let value = if f.boxed {
+                format!("Box::new({})", value)
+            } else {
+                value
+            };
+            write_accessor(
+                w,
+                f.deprecated,
+                &format!("{}(&self) -> {}", f.name, ret),
+                &format!(
+                    "match self.{} {{\n            {}{},\n            _ => None,\n        }}",
+                    self.name, variant, get
+                ),
+            )?;
+            write_accessor(
+                w,
+                f.deprecated,
+                &format!("has_{}(&self) -> bool", f.name),
+                &format!("matches!(self.{}, {}(_))", self.name, variant),
+            )?;
+            write_accessor(
+                w,
+                f.deprecated,
+                &format!("set_{}(&mut self, v: {})", f.name, param),
+                &format!("self.{} = {}({});", self.name, variant, value),
+            )?;
+        }
+        write_accessor(
+            w,
+            false,
+            &format!("clear_{}(&mut self)", self.name),
+            &format!("self.{} = {}::None;", self.name, oneof),
+        )
+    }
+
+    /// Writes a setter of each variant in the builder of the message
+    fn write_builder_setters<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        for f in self.fields.iter().filter(|f| !f.deprecated || config.add_deprecated_fields) {
+            let (param, value) = builder_param(&f.typ, "v", desc, config)?;
+            let value = if f.boxed {
+                format!("Box::new({})", value)
+            } else {
+                value
+            };
+            if f.deprecated {
+                writeln!(w, "    #[deprecated]")?;
+            }
+            writeln!(w, "    pub fn {}(mut self, v: {}) -> Self {{", f.name, param)?;
+            writeln!(
+                w,
+                "        self.msg.{} = {}OneOf{}::{}({});",
+                self.name,
+                self.get_modules(desc),
+                self.name,
+                f.name,
+                value
+            )?;
+            writeln!(w, "        self")?;
+            writeln!(w, "    }}")?;
+            writeln!(w)?;
+        }
+        Ok(())
+    }
+
     fn write_message_definition<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+        self.comments.write_doc(w, "    ")?;
         for attr in &self.field_attributes {
             writeln!(w, "    {}", attr)?;
         }
@@ -1810,32 +3521,38 @@ impl OneOf {
         Ok(())
     }
 
-    fn write_match_tag<W: Write>(&self, w: &mut W, desc: &FileDescriptor, config: &Config) -> Result<()> {
+    fn write_match_tag<W: Write>(
+        &self,
+        w: &mut W,
+        desc: &FileDescriptor,
+        config: &Config,
+        seen: Option<usize>,
+    ) -> Result<()> {
         for f in self.fields.iter().filter(|f| !f.deprecated || config.add_deprecated_fields) {
-            let (val, val_cow) = f.typ.read_fn(desc)?;
-            if f.boxed {
-                writeln!(
-                    w,
-                    "                Ok({}) => msg.{} = {}OneOf{}::{}(Box::new({})),",
-                    f.tag(),
+            let (val, mut val_cow) = f.typ.read_fn(desc)?;
+            if let Some(read) = heapless_read_fn(&f.typ, f.number, config) {
+                val_cow = read;
+            }
+            let read = if f.boxed {
+                format!(
+                    "self.{} = {}OneOf{}::{}(Box::new({}))",
                     self.name,
                     self.get_modules(desc),
                     self.name,
                     f.name,
                     val
-                )?;
+                )
             } else {
-                writeln!(
-                    w,
-                    "                Ok({}) => msg.{} = {}OneOf{}::{}({}),",
-                    f.tag(),
+                format!(
+                    "self.{} = {}OneOf{}::{}({})",
                     self.name,
                     self.get_modules(desc),
                     self.name,
                     f.name,
                     val_cow
-                )?;
-            }
+                )
+            };
+            write_match_arm(w, f.tag(), seen, &read)?;
         }
         Ok(())
     }
@@ -1912,13 +3629,26 @@ pub struct Config {
     pub custom_repr: Option<String>,
     pub default_custom_struct_derive: String,
     pub custom_struct_derive: HashMap<String, String>,
+    /// Rust types replacing the types of fields, by fully qualified field name, like
+    /// `(rust.type)` options
+    pub custom_field_types: HashMap<String, String>,
     pub custom_rpc_generator: RpcGeneratorFunction,
     pub custom_includes: Vec<String>,
     pub owned: bool,
     pub nostd: bool,
     pub hashbrown: bool,
+    /// Detect duplicate singular fields and oneofs when decoding with a strict `BytesReader`
+    pub strict: bool,
+    /// Use fixed capacity `heapless` collections, so the generated code doesn't need `alloc`
+    pub heapless: bool,
     pub gen_info: bool,
+    /// Generate a `{Message}Builder` for each message
+    pub gen_builders: bool,
+    /// Generate accessors honouring the presence and declared defaults of the fields
+    pub gen_accessors: bool,
     pub add_deprecated_fields: bool,
+    /// `in_file` is an encoded `FileDescriptorSet`, code is generated for all its files
+    pub descriptor_set: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -1928,21 +3658,90 @@ pub struct FileDescriptor {
     pub syntax: Syntax,
     pub messages: Vec<Message>,
     pub enums: Vec<Enumerator>,
+    pub extends: Vec<Extend>,
     pub module: String,
     pub rpc_services: Vec<RpcService>,
+    pub options: Vec<ProtoOption>,
     pub owned: bool,
 }
 
 impl FileDescriptor {
     pub fn run(configs: &[Config]) -> Result<()> {
+        let mut matched = HashSet::new();
         for config in configs {
-            Self::write_proto(config)?
+            Self::write_proto_matching(config, &mut matched)?
         }
-        Ok(())
+        Self::check_custom_field_types(configs, &matched)
     }
 
     pub fn write_proto(config: &Config) -> Result<()> {
-        let mut desc = FileDescriptor::read_proto(&config.in_file, &config.import_search_path)?;
+        let mut matched = HashSet::new();
+        Self::write_proto_matching(config, &mut matched)?;
+        Self::check_custom_field_types(std::slice::from_ref(config), &matched)
+    }
+
+    /// Same as `write_proto`, adding the `custom_field_types` keys found to `matched`
+    fn write_proto_matching(config: &Config, matched: &mut HashSet<String>) -> Result<()> {
+        if config.descriptor_set {
+            let bytes = std::fs::read(&config.in_file)?;
+            let set = descriptor_set::decode(&bytes)?;
+            return Self::write_set(&set, config, |_| true, matched);
+        }
+        let desc = FileDescriptor::read_proto(&config.in_file, &config.import_search_path)?;
+        let name = config.in_file.file_name().and_then(|e| e.to_str()).unwrap();
+        Self::write_desc(desc, name, &config.out_file, config, matched)
+    }
+
+    /// Fails if some `custom_field_types` keys of `configs` are not in `matched`, i.e. do not
+    /// name any field of the generated files
+    pub(crate) fn check_custom_field_types(
+        configs: &[Config],
+        matched: &HashSet<String>,
+    ) -> Result<()> {
+        let mut unknown = configs
+            .iter()
+            .flat_map(|c| c.custom_field_types.keys())
+            .filter(|k| !matched.contains(*k))
+            .cloned()
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            return Ok(());
+        }
+        unknown.sort();
+        unknown.dedup();
+        Err(Error::UnknownCustomFieldTypes(unknown))
+    }
+
+    /// Generates the code of the files of `set`, read from `config.in_file`, selected by
+    /// `generate`
+    pub(crate) fn write_set(
+        set: &FileDescriptorSet,
+        config: &Config,
+        generate: impl Fn(&str) -> bool,
+        matched: &mut HashSet<String>,
+    ) -> Result<()> {
+        for (name, desc) in descriptor_set::file_descriptors(set, &config.in_file)? {
+            // `rust_options.proto` only declares the options applied to the other files
+            let local =
+                desc.messages.iter().any(|m| !m.imported) || desc.enums.iter().any(|e| !e.imported);
+            if name == "rust_options.proto" || !local || !generate(&name) {
+                continue;
+            }
+            let file_stem = get_file_stem(Path::new(&name))?;
+            let out_file = config.out_file.with_file_name(format!("{}.rs", file_stem));
+            Self::write_desc(desc, &name, &out_file, config, matched)?;
+        }
+        Ok(())
+    }
+
+    /// Generates the code of `desc`, read from the file `name`
+    fn write_desc(
+        mut desc: FileDescriptor,
+        name: &str,
+        out_file: &Path,
+        config: &Config,
+        matched: &mut HashSet<String>,
+    ) -> Result<()> {
         desc.owned = config.owned;
 
         if desc.messages.is_empty() && desc.enums.is_empty() {
@@ -1952,11 +3751,17 @@ impl FileDescriptor {
 
         desc.resolve_types()?;
         desc.break_cycles(config.error_cycle)?;
+        for m in &mut desc.messages {
+            m.apply_custom_field_types(&config.custom_field_types, matched);
+        }
         desc.sanity_checks()?;
-        if config.dont_use_cow {
+        if config.dont_use_cow || config.heapless {
             desc.convert_field_types(&FieldType::StringCow, &FieldType::String_);
             desc.convert_field_types(&FieldType::BytesCow, &FieldType::Bytes_);
         }
+        if config.heapless {
+            desc.heapless_checks(config)?;
+        }
         desc.set_defaults(config)?;
         desc.sanitize_names();
 
@@ -1967,7 +3772,7 @@ impl FileDescriptor {
         let (prefix, file_package) = split_package(&desc.package);
 
         let mut file_stem = if file_package.is_empty() {
-            get_file_stem(&config.out_file)?
+            get_file_stem(out_file)?
         } else {
             file_package.to_string()
         };
@@ -1975,7 +3780,7 @@ impl FileDescriptor {
         if !file_package.is_empty() {
             sanitize_keyword(&mut file_stem);
         }
-        let mut out_file = config.out_file.with_file_name(format!("{}.rs", file_stem));
+        let mut out_file = out_file.with_file_name(format!("{}.rs", file_stem));
 
         if !prefix.is_empty() {
             use std::fs::create_dir_all;
@@ -2014,27 +3819,30 @@ impl FileDescriptor {
             return Ok(());
         }
 
-        let name = config.in_file.file_name().and_then(|e| e.to_str()).unwrap();
         let mut w = BufWriter::new(File::create(&out_file)?);
         desc.write(&mut w, name, config)?;
         update_mod_file(&out_file)
     }
 
     pub fn convert_field_types(&mut self, from: &FieldType, to: &FieldType) {
-        // Messages and enums are the only structures with types
+        // Messages and extends are the only structures with types
         for m in &mut self.messages {
             m.convert_field_types(from, to);
         }
+        for f in self
+            .extends
+            .iter_mut()
+            .flat_map(|e| e.fields.iter_mut())
+            .filter(|f| f.typ == *from)
+        {
+            f.typ = to.clone();
+        }
     }
 
     /// Opens a proto file, reads it and returns raw parsed data
     pub fn read_proto(in_file: &Path, import_search_path: &[PathBuf]) -> Result<FileDescriptor> {
         let file = std::fs::read_to_string(in_file)?;
-        let (rem, mut desc) = file_descriptor(&file).map_err(Error::Nom)?;
-        let rem = rem.trim();
-        if !rem.is_empty() {
-            return Err(Error::TrailingGarbage(rem.chars().take(50).collect()));
-        }
+        let mut desc = parse_file(in_file, &file)?;
         for m in &mut desc.messages {
             if m.path.as_os_str().is_empty() {
                 m.path = in_file.to_path_buf();
@@ -2053,84 +3861,129 @@ impl FileDescriptor {
             desc.package.clone()
         };
 
+        desc.apply_rust_options()?;
         desc.fetch_imports(in_file, import_search_path)?;
         Ok(desc)
     }
 
-    fn sanity_checks(&self) -> Result<()> {
+    pub(crate) fn sanity_checks(&self) -> Result<()> {
+        fn rec_nested_checks(m: &Message, desc: &FileDescriptor) -> Result<()> {
+            for e in &m.extends {
+                e.sanity_checks(desc)?;
+            }
+            for e in &m.enums {
+                e.sanity_checks()?;
+            }
+            for m in &m.messages {
+                m.sanity_checks(desc)?;
+                rec_nested_checks(m, desc)?;
+            }
+            Ok(())
+        }
+
         for m in &self.messages {
             m.sanity_checks(self)?;
+            rec_nested_checks(m, self)?;
+        }
+        for e in &self.enums {
+            e.sanity_checks()?;
+        }
+        for e in &self.extends {
+            e.sanity_checks(self)?;
+        }
+        Ok(())
+    }
+
+    /// Rejects the options and messages which cannot be generated with `Config::heapless`
+    fn heapless_checks(&self, config: &Config) -> Result<()> {
+        if config.gen_builders || config.gen_accessors || config.owned {
+            return Err(Error::Heapless(
+                "builders, accessors and owned messages are not supported".to_string(),
+            ));
+        }
+        if !self.extends.is_empty() {
+            return Err(Error::Heapless(
+                "extensions need an allocator".to_string(),
+            ));
+        }
+        for m in self.messages.iter().filter(|m| !m.imported) {
+            m.heapless_checks(self, config)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the options of `rust_options.proto`
+    pub(crate) fn apply_rust_options(&mut self) -> Result<()> {
+        for m in &mut self.messages {
+            m.apply_rust_options()?;
+        }
+        for e in &mut self.enums {
+            e.apply_rust_options()?;
         }
         Ok(())
     }
 
     /// Get messages and enums from imports
     fn fetch_imports(&mut self, in_file: &Path, import_search_path: &[PathBuf]) -> Result<()> {
+        self.set_packages();
+
+        for import in self.import_paths.clone() {
+            // only declares the options applied by `apply_rust_options`
+            if import == Path::new("rust_options.proto") {
+                continue;
+            }
+
+            let proto_file = find_import(in_file, &import, import_search_path)?;
+            let f = FileDescriptor::read_proto(&proto_file, import_search_path)?;
+            self.add_import(f, &proto_file, &import);
+        }
+        Ok(())
+    }
+
+    /// Sets the package and module of the messages, enums and services of this file
+    pub(crate) fn set_packages(&mut self) {
         for m in &mut self.messages {
             m.set_package(&self.package, &self.module);
         }
         for m in &mut self.enums {
             m.set_package(&self.package, &self.module);
         }
+        for s in &mut self.rpc_services {
+            s.package = self.package.clone();
+        }
+    }
 
-        for import in &self.import_paths {
-            // this is the same logic as the C preprocessor;
-            // if the include path item is absolute, then append the filename,
-            // otherwise it is always relative to the file.
-            let mut matching_file = None;
-            for path in import_search_path {
-                let candidate = if path.is_absolute() {
-                    path.join(import)
-                } else {
-                    in_file
-                        .parent()
-                        .map_or_else(|| path.join(import), |p| p.join(path).join(import))
-                };
-                if candidate.exists() {
-                    matching_file = Some(candidate);
-                    break;
-                }
+    /// Adds the messages and enums of `f`, read from `proto_file` and imported as `import`
+    pub(crate) fn add_import(&mut self, mut f: FileDescriptor, proto_file: &Path, import: &Path) {
+        // if the proto has a packge then the names will be prefixed
+        let package = f.package.clone();
+        let module = f.module.clone();
+        self.messages.extend(f.messages.drain(..).map(|mut m| {
+            if m.package.is_empty() {
+                m.set_package(&package, &module);
             }
-            if matching_file.is_none() {
-                return Err(Error::InvalidImport(format!(
-                    "file {} not found on import path",
-                    import.display()
-                )));
+            if m.path.as_os_str().is_empty() {
+                m.path = proto_file.to_path_buf();
             }
-            let proto_file = matching_file.unwrap();
-            let mut f = FileDescriptor::read_proto(&proto_file, import_search_path)?;
-
-            // if the proto has a packge then the names will be prefixed
-            let package = f.package.clone();
-            let module = f.module.clone();
-            self.messages.extend(f.messages.drain(..).map(|mut m| {
-                if m.package.is_empty() {
-                    m.set_package(&package, &module);
-                }
-                if m.path.as_os_str().is_empty() {
-                    m.path = proto_file.clone();
-                }
-                if m.import.as_os_str().is_empty() {
-                    m.import = import.clone();
-                }
-                m.set_imported();
-                m
-            }));
-            self.enums.extend(f.enums.drain(..).map(|mut e| {
-                if e.package.is_empty() {
-                    e.set_package(&package, &module);
-                }
-                if e.path.as_os_str().is_empty() {
-                    e.path = proto_file.clone();
-                }
-                if e.import.as_os_str().is_empty() {
-                    e.import = import.clone();
-                }
-                e.imported = true;
-                e
-            }));
-        }
-        Ok(())
+            if m.import.as_os_str().is_empty() {
+                m.import = import.to_path_buf();
+            }
+            m.set_imported();
+            m
+        }));
+        self.enums.extend(f.enums.drain(..).map(|mut e| {
+            if e.package.is_empty() {
+                e.set_package(&package, &module);
+            }
+            if e.path.as_os_str().is_empty() {
+                e.path = proto_file.to_path_buf();
+            }
+            if e.import.as_os_str().is_empty() {
+                e.import = import.to_path_buf();
+            }
+            e.imported = true;
+            e
+        }));
     }
 
     fn set_defaults(&mut self, config: &Config) -> Result<()> {
@@ -2164,6 +4017,9 @@ impl FileDescriptor {
         for e in &mut self.enums {
             e.sanitize_names();
         }
+        for e in &mut self.extends {
+            e.sanitize_names();
+        }
     }
 
     /// Breaks cycles by adding boxes when necessary
@@ -2303,57 +4159,68 @@ impl FileDescriptor {
         (full_msgs, full_enums)
     }
 
-    fn resolve_types(&mut self) -> Result<()> {
+    pub(crate) fn resolve_types(&mut self) -> Result<()> {
         let (full_msgs, full_enums) = self.get_full_names();
 
-        fn rec_resolve_types(
-            m: &mut Message,
+        fn resolve_type(
+            typ: &mut FieldType,
+            scope: &str,
             full_msgs: &HashMap<String, MessageIndex>,
             full_enums: &HashMap<String, EnumIndex>,
         ) -> Result<()> {
-            // Interestingly, we can't call all_fields_mut to iterate over the
-            // fields here: writing out the field traversal as below lets Rust
-            // split m's mutable borrow, permitting the loop body to use fields
-            // of `m` other than `fields` and `oneofs`.
-            'types: for typ in m
-                .fields
-                .iter_mut()
-                .chain(m.oneofs.iter_mut().flat_map(|o| o.fields.iter_mut()))
-                .map(|f| &mut f.typ)
-                .flat_map(|typ| match *typ {
-                    FieldType::Map(ref mut key, ref mut value) => {
-                        vec![&mut **key, &mut **value].into_iter()
-                    }
-                    _ => vec![typ].into_iter(),
-                })
-            {
-                if let FieldType::MessageOrEnum(name) = typ.clone() {
-                    let test_names: Vec<String> = if name.starts_with('.') {
-                        vec![name.clone().split_off(1)]
-                    } else if m.package.is_empty() {
-                        vec![format!("{}.{}", m.name, name), name.clone()]
-                    } else {
-                        let mut v = vec![
-                            format!("{}.{}.{}", m.package, m.name, name),
-                            format!("{}.{}", m.package, name),
-                        ];
-                        for (index, _) in m.package.match_indices('.').rev() {
-                            v.push(format!("{}.{}", &m.package[..index], name));
-                        }
-                        v.push(name.clone());
-                        v
-                    };
-                    for name in &test_names {
-                        if let Some(msg) = full_msgs.get(name) {
+            match typ {
+                FieldType::Map(ref mut key, ref mut value) => {
+                    resolve_type(key, scope, full_msgs, full_enums)?;
+                    resolve_type(value, scope, full_msgs, full_enums)
+                }
+                FieldType::MessageOrEnum(name) => {
+                    for test_name in &candidate_names(name, scope) {
+                        if let Some(msg) = full_msgs.get(test_name) {
                             *typ = FieldType::Message(msg.clone());
-                            continue 'types;
-                        } else if let Some(e) = full_enums.get(name) {
+                            return Ok(());
+                        } else if let Some(e) = full_enums.get(test_name) {
                             *typ = FieldType::Enum(e.clone());
-                            continue 'types;
+                            return Ok(());
                         }
                     }
-                    return Err(Error::MessageOrEnumNotFound(name));
+                    Err(Error::MessageOrEnumNotFound(name.clone()))
                 }
+                _ => Ok(()),
+            }
+        }
+
+        fn resolve_extend(
+            e: &mut Extend,
+            scope: &str,
+            full_msgs: &HashMap<String, MessageIndex>,
+            full_enums: &HashMap<String, EnumIndex>,
+        ) -> Result<()> {
+            e.index = candidate_names(&e.extendee, scope)
+                .iter()
+                .find_map(|name| full_msgs.get(name))
+                .cloned()
+                .ok_or_else(|| Error::MessageOrEnumNotFound(e.extendee.clone()))?;
+            for f in &mut e.fields {
+                resolve_type(&mut f.typ, scope, full_msgs, full_enums)?;
+            }
+            Ok(())
+        }
+
+        fn rec_resolve_types(
+            m: &mut Message,
+            full_msgs: &HashMap<String, MessageIndex>,
+            full_enums: &HashMap<String, EnumIndex>,
+        ) -> Result<()> {
+            let scope = if m.package.is_empty() {
+                m.name.clone()
+            } else {
+                format!("{}.{}", m.package, m.name)
+            };
+            for f in m.all_fields_mut() {
+                resolve_type(&mut f.typ, &scope, full_msgs, full_enums)?;
+            }
+            for e in m.extends.iter_mut() {
+                resolve_extend(e, &scope, full_msgs, full_enums)?;
             }
             for m in m.messages.iter_mut() {
                 rec_resolve_types(m, full_msgs, full_enums)?;
@@ -2364,11 +4231,26 @@ impl FileDescriptor {
         for m in self.messages.iter_mut() {
             rec_resolve_types(m, &full_msgs, &full_enums)?;
         }
+        for e in self.extends.iter_mut() {
+            resolve_extend(e, &self.package, &full_msgs, &full_enums)?;
+        }
+        let package = &self.package;
+        let resolve_message = |name: &str| {
+            candidate_names(name, package)
+                .iter()
+                .find_map(|name| full_msgs.get(name))
+                .cloned()
+                .ok_or_else(|| Error::MessageOrEnumNotFound(name.to_string()))
+        };
+        for f in self.rpc_services.iter_mut().flat_map(|s| s.functions.iter_mut()) {
+            f.arg_index = resolve_message(&f.arg)?;
+            f.ret_index = resolve_message(&f.ret)?;
+        }
         Ok(())
     }
 
     fn write<W: Write>(&self, w: &mut W, filename: &str, config: &Config) -> Result<()> {
-        println!(
+        debug!(
             "Found {} messages, and {} enums",
             self.messages.len(),
             self.enums.len()
@@ -2381,6 +4263,7 @@ impl FileDescriptor {
         self.write_imports(w)?;
         self.write_enums(w, config)?;
         self.write_messages(w, config)?;
+        self.write_extends(w, config)?;
         self.write_rpc_services(w, config)?;
         self.write_package_end(w)?;
         Ok(())
@@ -2415,7 +4298,7 @@ impl FileDescriptor {
     }
 
     fn write_uses<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
-        if self.messages.iter().all(|m| m.is_unit()) {
+        if self.messages.iter().all(|m| m.is_unit()) && self.extends.is_empty() {
             writeln!(
                 w,
                 "use quick_protobuf::{{BytesReader, Result, MessageInfo, MessageRead, MessageWrite}};"
@@ -2468,7 +4351,7 @@ impl FileDescriptor {
 
     fn write_enums<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
         for m in self.enums.iter().filter(|e| !e.imported) {
-            println!("Writing enum {}", m.name);
+            debug!("Writing enum {}", m.name);
             writeln!(w)?;
             m.write_definition(w, config)?;
             writeln!(w)?;
@@ -2483,9 +4366,9 @@ impl FileDescriptor {
 
     fn write_rpc_services<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
         for m in self.rpc_services.iter() {
-            println!("Writing Rpc {}", m.service_name);
+            debug!("Writing Rpc {}", m.service_name);
             writeln!(w)?;
-            m.write_definition(w, config)?;
+            m.write_definition(w, self, config)?;
         }
         Ok(())
     }
@@ -2496,6 +4379,91 @@ impl FileDescriptor {
         }
         Ok(())
     }
+
+    fn write_extends<W: Write>(&self, w: &mut W, config: &Config) -> Result<()> {
+        for e in &self.extends {
+            e.write(w, self, config)?;
+        }
+        Ok(())
+    }
+}
+
+/// The type of a builder setter parameter for a value of `typ`, and the expression converting
+/// the parameter `param` into the value
+fn builder_param(
+    typ: &FieldType,
+    param: &str,
+    desc: &FileDescriptor,
+    config: &Config,
+) -> Result<(String, String)> {
+    let rust_type = typ.rust_type(desc, config)?;
+    Ok(match typ {
+        FieldType::StringCow | FieldType::BytesCow | FieldType::String_ | FieldType::Bytes_ => {
+            (format!("impl Into<{}>", rust_type), format!("{}.into()", param))
+        }
+        _ => (rust_type, param.to_string()),
+    })
+}
+
+/// The `&str` or `&[u8]` literal of a declared default, as sanitized by `Field::sanitize_default`
+fn default_literal(default: &str) -> &str {
+    default
+        .strip_prefix("Cow::Borrowed(")
+        .or_else(|| default.strip_prefix("String::from("))
+        .and_then(|d| d.strip_suffix(')'))
+        .or_else(|| default.strip_suffix(".to_vec()"))
+        .unwrap_or(default)
+}
+
+/// Reads a string or bytes into a `heapless` collection with `Config::heapless`
+fn heapless_read_fn(typ: &FieldType, number: i32, config: &Config) -> Option<String> {
+    let (collection, push) = match *typ {
+        FieldType::String_ if config.heapless => ("String", "push_str"),
+        FieldType::Bytes_ if config.heapless => ("Vec", "extend_from_slice"),
+        _ => return None,
+    };
+    Some(format!(
+        "{{ let mut s = heapless::{}::new(); s.{}(r.read_{}(bytes)?){}?; s }}",
+        collection,
+        push,
+        typ.proto_type(),
+        capacity_error(number)
+    ))
+}
+
+/// Converts the error of a full `heapless` collection into an `Error::Capacity`
+fn capacity_error(number: i32) -> String {
+    format!(".map_err(|_| quick_protobuf::Error::Capacity({}))", number)
+}
+
+/// Writes a `pub fn {sig} { body }` accessor, followed by an empty line
+fn write_accessor<W: Write>(w: &mut W, deprecated: bool, sig: &str, body: &str) -> Result<()> {
+    if deprecated {
+        writeln!(w, "    #[deprecated]")?;
+    }
+    writeln!(w, "    pub fn {} {{", sig)?;
+    writeln!(w, "        {}", body)?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Writes a `Ok(tag) => read` match arm, checking for duplicates of singular fields
+fn write_match_arm<W: Write>(w: &mut W, tag: u32, seen: Option<usize>, read: &str) -> Result<()> {
+    let read = read.trim_end_matches(';');
+    match seen {
+        Some(i) => writeln!(
+            w,
+            "                Ok({}) => {{ r.check_singular(&mut seen[{}], {})?; {}; }}",
+            tag, i, tag, read
+        )?,
+        // reads made of several statements, e.g. reusing the capacity of owned strings
+        None if read.contains(';') => {
+            writeln!(w, "                Ok({}) => {{ {}; }}", tag, read)?
+        }
+        None => writeln!(w, "                Ok({}) => {},", tag, read)?,
+    }
+    Ok(())
 }
 
 /// Calculates the tag value
@@ -2503,6 +4471,78 @@ fn tag(number: u32, typ: &FieldType, packed: bool) -> u32 {
     number << 3 | typ.wire_type_num(packed)
 }
 
+/// `CamelCase` to `snake_case`
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if c.is_uppercase() {
+            if previous_lower {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+            previous_lower = false;
+        } else {
+            snake.push(c);
+            previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        }
+    }
+    snake
+}
+
+/// `snake_case` to `CamelCase`
+pub(crate) fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let mut chars = p.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Names a type referenced from `scope` (`package.Message`) may resolve to, innermost first
+pub(crate) fn candidate_names(name: &str, scope: &str) -> Vec<String> {
+    if let Some(name) = name.strip_prefix('.') {
+        return vec![name.to_string()];
+    }
+    let mut v = Vec::new();
+    if !scope.is_empty() {
+        v.push(format!("{}.{}", scope, name));
+        for (index, _) in scope.match_indices('.').rev() {
+            v.push(format!("{}.{}", &scope[..index], name));
+        }
+    }
+    v.push(name.to_string());
+    v
+}
+
+/// Finds the file imported as `import` by `in_file`
+pub(crate) fn find_import(in_file: &Path, import: &Path, import_search_path: &[PathBuf]) -> Result<PathBuf> {
+    // this is the same logic as the C preprocessor;
+    // if the include path item is absolute, then append the filename,
+    // otherwise it is always relative to the file.
+    for path in import_search_path {
+        let candidate = if path.is_absolute() {
+            path.join(import)
+        } else {
+            in_file
+                .parent()
+                .map_or_else(|| path.join(import), |p| p.join(path).join(import))
+        };
+        if candidate.exists() {
+            return Ok(candidate);
+        }
+    }
+    Err(Error::InvalidImport(format!(
+        "file {} not found on import path",
+        import.display()
+    )))
+}
+
 /// "" is ("",""), "a" is ("","a"), "a.b" is ("a"."b"), and so forth.
 fn split_package(package: &str) -> (&str, &str) {
     if package.is_empty() {
@@ -2566,7 +4606,7 @@ fn update_mod_file(path: &Path) -> Result<()> {
 }
 
 /// get the proper sanitized file stem from an input file path
-fn get_file_stem(path: &Path) -> Result<String> {
+pub(crate) fn get_file_stem(path: &Path) -> Result<String> {
     let mut file_stem = path
         .file_stem()
         .and_then(|f| f.to_str())
@@ -2578,3 +4618,69 @@ fn get_file_stem(path: &Path) -> Result<String> {
     sanitize_keyword(&mut file_stem);
     Ok(file_stem)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn doc(leading: &[&str]) -> String {
+        let comments = Comments {
+            leading: leading.iter().map(|l| l.to_string()).collect(),
+            ..Default::default()
+        };
+        let mut w = Vec::new();
+        comments.write_doc(&mut w, "").unwrap();
+        String::from_utf8(w).unwrap()
+    }
+
+    #[test]
+    fn test_write_doc_fenced_code() {
+        let code = doc(&[" Example", " ```", " let x = 1;", " ```"]);
+        assert_eq!("/// Example\n/// ```text\n/// let x = 1;\n/// ```\n", code);
+    }
+
+    #[test]
+    fn test_write_doc_fenced_code_with_language() {
+        let code = doc(&[
+            " ```rust",
+            " let x = not rust;",
+            " ```",
+            " ```text",
+            " ok",
+            " ```",
+        ]);
+        assert_eq!(
+            "/// ```text\n/// let x = not rust;\n/// ```\n/// ```text\n/// ok\n/// ```\n",
+            code
+        );
+    }
+
+    #[test]
+    fn test_write_doc_indented_code() {
+        let code = doc(&[
+            " Example:",
+            "",
+            "     let x = not rust;",
+            "",
+            "     x += 1;",
+            "",
+            " Done",
+        ]);
+        assert_eq!(
+            "/// Example:\n///\n/// ```text\n/// let x = not rust;\n///\n/// x += 1;\n/// ```\n\
+             ///\n/// Done\n",
+            code
+        );
+
+        // continuation lines of a paragraph are not code
+        let code = doc(&[" - item", "     continued"]);
+        assert_eq!("/// - item\n///     continued\n", code);
+
+        // a block ending the comment is closed
+        let code = doc(&[" Example:", "", "     let x = 1;"]);
+        assert_eq!(
+            "/// Example:\n///\n/// ```text\n/// let x = 1;\n/// ```\n",
+            code
+        );
+    }
+}
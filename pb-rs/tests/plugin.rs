@@ -0,0 +1,83 @@
+//! protoc-gen-quickrs tests
+//!
+//! `plugin/request.pb` is the `CodeGeneratorRequest` for
+//! `protoc -I plugin --quickrs_out=. --quickrs_opt=dont_use_cow,gen_info shapes.proto`, without
+//! `source_code_info`. It can be captured again with a plugin saving its input, e.g. a
+//! `protoc-gen-quickrs` script running `cat > request.pb`.
+
+use pb_rs::descriptor::google::protobuf::compiler::{CodeGeneratorRequest, CodeGeneratorResponse};
+use pb_rs::{plugin, types::FileDescriptor, ConfigBuilder};
+use quick_protobuf::{BytesReader, MessageRead};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn request() -> Vec<u8> {
+    std::fs::read("tests/plugin/request.pb").unwrap()
+}
+
+fn decode_response(bytes: &[u8]) -> CodeGeneratorResponse<'_> {
+    CodeGeneratorResponse::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap()
+}
+
+#[test]
+fn test_generate() {
+    let bytes = request();
+    let request =
+        CodeGeneratorRequest::from_reader(&mut BytesReader::from_bytes(&bytes), &bytes).unwrap();
+    let response = plugin::generate(&request);
+    assert_eq!(None, response.error);
+    assert_eq!(Some(1), response.supported_features);
+
+    // `common.proto` is only imported
+    let names = response
+        .file
+        .iter()
+        .map(|f| f.name.as_deref().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["mod.rs", "shapes.rs"], names);
+
+    // same code as generated from the .proto file
+    let out_dir = std::env::temp_dir().join("pb_rs_test_plugin");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let config = ConfigBuilder::new(
+        &["tests/plugin/shapes.proto"],
+        None,
+        Some(&out_dir.to_str().unwrap()),
+        &["."],
+    )
+    .unwrap()
+    .dont_use_cow(true)
+    .gen_info(true);
+    FileDescriptor::run(&config.build()).unwrap();
+    let expected = std::fs::read_to_string(out_dir.join("shapes.rs")).unwrap();
+    assert_eq!(Some(&*expected), response.file[1].content.as_deref());
+}
+
+#[test]
+fn test_invalid_parameter() {
+    let bytes = request();
+    let mut request =
+        CodeGeneratorRequest::from_reader(&mut BytesReader::from_bytes(&bytes), &bytes).unwrap();
+    request.parameter = Some("dont_use_cow,ownd".into());
+    let response = plugin::generate(&request);
+    assert_eq!(
+        Some("Unknown plugin parameter 'ownd'"),
+        response.error.as_deref()
+    );
+    assert!(response.file.is_empty());
+}
+
+#[test]
+fn test_binary() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_protoc-gen-quickrs"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(&request()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(plugin::run(&request()).unwrap(), output.stdout);
+    let response = decode_response(&output.stdout);
+    assert_eq!(2, response.file.len());
+}
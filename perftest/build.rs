@@ -18,14 +18,17 @@ fn generate_rpc_test<W: Write + ?Sized>(
         }
     */
 
-    writeln!(w, "\npub trait {SERVICE} {{", SERVICE = rpc.service_name)?;
+    writeln!(w)?;
+    rpc.comments.write_doc(w, "")?;
+    writeln!(w, "pub trait {SERVICE} {{", SERVICE = rpc.service_name)?;
     for func in rpc.functions.iter() {
+        func.comments.write_doc(w, "   ")?;
         writeln!(
             w,
             "   fn {FUNC}(&self, arg: &{ARG}) -> std::result::Result<{RET}, quick_protobuf::Error>;",
             FUNC = func.name,
-            ARG = func.arg,
-            RET = func.ret
+            ARG = func.arg_type,
+            RET = func.ret_type
         )?;
     }
     writeln!(w, "}}\n")?;
@@ -57,14 +60,20 @@ fn main() {
         dont_use_cow: false,
         default_custom_struct_derive: String::new(),
         custom_struct_derive: HashMap::new(),
+        custom_field_types: HashMap::new(),
         custom_repr: None,
         custom_rpc_generator: Box::new(|rpc, writer| generate_rpc_test(rpc, writer)),
         custom_includes: Vec::new(),
         owned: false,
         hashbrown: false,
         nostd: false,
+        strict: false,
+        heapless: false,
         gen_info: false,
+        gen_builders: false,
+        gen_accessors: false,
         add_deprecated_fields: false,
+        descriptor_set: false,
     };
     FileDescriptor::write_proto(&config).unwrap();
 
@@ -113,7 +113,7 @@ impl TestRunner {
 
     fn quick_run_test<M>(&self, data: &[M]) -> [u128; 4]
     where
-        M: for<'a> MessageRead<'a> + MessageWrite + Clone + PartialEq + ::std::fmt::Debug,
+        M: for<'a> MessageRead<'a> + MessageWrite + Clone + Default + PartialEq + ::std::fmt::Debug,
     {
         let mut b = [0; 4];
 
@@ -163,12 +163,28 @@ impl TestRunner {
             None,
         );
 
+        b[3] = measure(
+            random_data.len() as u128,
+            || {
+                let mut reader = BytesReader::from_bytes(&buf);
+                let mut msg = M::default();
+                let mut count = 0;
+                while !reader.is_eof() {
+                    msg.clear();
+                    reader.merge_message(&buf, &mut msg).unwrap();
+                    count += 1;
+                }
+                count
+            },
+            None,
+        );
+
         b
     }
 
     fn quick_test<M>(&mut self, data: &[M]) -> [u128; 4]
     where
-        M: for<'a> MessageRead<'a> + MessageWrite + Clone + PartialEq + ::std::fmt::Debug,
+        M: for<'a> MessageRead<'a> + MessageWrite + Clone + Default + PartialEq + ::std::fmt::Debug,
     {
         let b = self.quick_run_test(data);
         self.any_matched = true;
@@ -224,6 +240,22 @@ impl TestRunner {
             None,
         );
 
+        b[3] = measure(
+            random_data.len() as u128,
+            || {
+                let mut reader = BytesReader::from_bytes(&buf);
+                let mut msg = perftest_data_quick::TestStrings::default();
+                let mut count = 0;
+                while !reader.is_eof() {
+                    msg.clear();
+                    reader.merge_message(&buf, &mut msg).unwrap();
+                    count += 1;
+                }
+                count
+            },
+            None,
+        );
+
         b
     }
 
@@ -276,6 +308,22 @@ impl TestRunner {
             None,
         );
 
+        b[3] = measure(
+            random_data.len() as u128,
+            || {
+                let mut reader = BytesReader::from_bytes(&buf);
+                let mut msg = perftest_data_quick::TestBytes::default();
+                let mut count = 0;
+                while !reader.is_eof() {
+                    msg.clear();
+                    reader.merge_message(&buf, &mut msg).unwrap();
+                    count += 1;
+                }
+                count
+            },
+            None,
+        );
+
         b
     }
 
@@ -381,8 +429,13 @@ fn print_results(name: &str, a: &[u128], b: &[u128], c: &[u128], print_header: b
     }
     let i = 3;
     println!(
-        "{:>15} {:>15} {:>15} {:>15} {:>15}",
-        labels[i], a[i], "", "NA", "NA"
+        "{:>15} {:>15} {:>15} {:>15} {:>15.1} {:>15}",
+        labels[i],
+        a[i],
+        b[i],
+        "NA",
+        100. - b[i] as f32 / a[i] as f32 * 100.,
+        "NA"
     );
 }
 
@@ -57,14 +57,23 @@ pub struct BarMessage {
 impl<'a> MessageRead<'a> for BarMessage {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.b_required_int32 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(8) => msg.b_required_int32 = r.read_int32(bytes)?,
+                Ok(8) => self.b_required_int32 = r.read_int32(bytes)?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -119,45 +128,80 @@ impl<'a> MessageRead<'a> for FooMessage<'a> {
             f_bool: true,
             ..Self::default()
         };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.f_int32 = Default::default();
+        self.f_int64 = Default::default();
+        self.f_uint32 = Default::default();
+        self.f_uint64 = Default::default();
+        self.f_sint32 = Default::default();
+        self.f_sint64 = 4i64;
+        self.f_bool = true;
+        self.f_FooEnum = Default::default();
+        self.f_fixed64 = Default::default();
+        self.f_sfixed64 = Default::default();
+        self.f_fixed32 = Default::default();
+        self.f_sfixed32 = Default::default();
+        self.f_double = Default::default();
+        self.f_float = Default::default();
+        self.f_bytes = Default::default();
+        self.f_string = Default::default();
+        self.f_self_message = Default::default();
+        self.f_bar_message = Default::default();
+        self.f_repeated_int32.clear();
+        self.f_repeated_packed_int32.clear();
+        self.f_repeated_packed_float = Default::default();
+        self.f_imported = Default::default();
+        self.f_baz = Default::default();
+        self.f_nested = Default::default();
+        self.f_nested_enum = Default::default();
+        self.f_map.clear();
+        self.test_oneof = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(8) => msg.f_int32 = Some(r.read_int32(bytes)?),
-                Ok(16) => msg.f_int64 = Some(r.read_int64(bytes)?),
-                Ok(24) => msg.f_uint32 = Some(r.read_uint32(bytes)?),
-                Ok(32) => msg.f_uint64 = Some(r.read_uint64(bytes)?),
-                Ok(40) => msg.f_sint32 = Some(r.read_sint32(bytes)?),
-                Ok(48) => msg.f_sint64 = r.read_sint64(bytes)?,
-                Ok(56) => msg.f_bool = r.read_bool(bytes)?,
-                Ok(64) => msg.f_FooEnum = Some(r.read_enum(bytes)?),
-                Ok(73) => msg.f_fixed64 = Some(r.read_fixed64(bytes)?),
-                Ok(81) => msg.f_sfixed64 = Some(r.read_sfixed64(bytes)?),
-                Ok(93) => msg.f_fixed32 = r.read_fixed32(bytes)?,
-                Ok(101) => msg.f_sfixed32 = Some(r.read_sfixed32(bytes)?),
-                Ok(105) => msg.f_double = Some(r.read_double(bytes)?),
-                Ok(117) => msg.f_float = Some(r.read_float(bytes)?),
-                Ok(122) => msg.f_bytes = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
-                Ok(130) => msg.f_string = Some(r.read_string(bytes).map(Cow::Borrowed)?),
-                Ok(138) => msg.f_self_message = Some(Box::new(r.read_message::<FooMessage>(bytes)?)),
-                Ok(146) => msg.f_bar_message = Some(r.read_message::<BarMessage>(bytes)?),
-                Ok(152) => msg.f_repeated_int32.push(r.read_int32(bytes)?),
-                Ok(162) => msg.f_repeated_packed_int32 = r.read_packed(bytes, |r, bytes| Ok(r.read_int32(bytes)?))?,
-                Ok(170) => msg.f_repeated_packed_float = r.read_packed_fixed(bytes)?.into(),
-                Ok(178) => msg.f_imported = Some(r.read_message::<a::b::ImportedMessage>(bytes)?),
-                Ok(186) => msg.f_baz = Some(r.read_message::<BazMessage>(bytes)?),
-                Ok(194) => msg.f_nested = Some(r.read_message::<mod_BazMessage::Nested>(bytes)?),
-                Ok(200) => msg.f_nested_enum = Some(r.read_enum(bytes)?),
+                Ok(8) => self.f_int32 = Some(r.read_int32(bytes)?),
+                Ok(16) => self.f_int64 = Some(r.read_int64(bytes)?),
+                Ok(24) => self.f_uint32 = Some(r.read_uint32(bytes)?),
+                Ok(32) => self.f_uint64 = Some(r.read_uint64(bytes)?),
+                Ok(40) => self.f_sint32 = Some(r.read_sint32(bytes)?),
+                Ok(48) => self.f_sint64 = r.read_sint64(bytes)?,
+                Ok(56) => self.f_bool = r.read_bool(bytes)?,
+                Ok(64) => self.f_FooEnum = Some(r.read_enum(bytes)?),
+                Ok(73) => self.f_fixed64 = Some(r.read_fixed64(bytes)?),
+                Ok(81) => self.f_sfixed64 = Some(r.read_sfixed64(bytes)?),
+                Ok(93) => self.f_fixed32 = r.read_fixed32(bytes)?,
+                Ok(101) => self.f_sfixed32 = Some(r.read_sfixed32(bytes)?),
+                Ok(105) => self.f_double = Some(r.read_double(bytes)?),
+                Ok(117) => self.f_float = Some(r.read_float(bytes)?),
+                Ok(122) => self.f_bytes = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(130) => self.f_string = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(138) => r.merge_message(bytes, &mut **self.f_self_message.get_or_insert_with(Default::default))?,
+                Ok(146) => r.merge_message(bytes, self.f_bar_message.get_or_insert_with(Default::default))?,
+                Ok(152) => self.f_repeated_int32.push(r.read_int32(bytes)?),
+                Ok(162) => r.read_packed_into(bytes, &mut self.f_repeated_packed_int32, |r, bytes| Ok(r.read_int32(bytes)?))?,
+                Ok(170) => if self.f_repeated_packed_float.is_empty() { self.f_repeated_packed_float = r.read_packed_fixed(bytes)? } else { self.f_repeated_packed_float.to_mut().extend_from_slice(&r.read_packed_fixed(bytes)?) },
+                Ok(178) => r.merge_message(bytes, self.f_imported.get_or_insert_with(Default::default))?,
+                Ok(186) => r.merge_message(bytes, self.f_baz.get_or_insert_with(Default::default))?,
+                Ok(194) => r.merge_message(bytes, self.f_nested.get_or_insert_with(Default::default))?,
+                Ok(200) => self.f_nested_enum = Some(r.read_enum(bytes)?),
                 Ok(210) => {
-                    let (key, value) = r.read_map(bytes, |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?), |r, bytes| Ok(r.read_int32(bytes)?))?;
-                    msg.f_map.insert(key, value);
+                    let (key, value) = r.read_map_entry(bytes, 10, |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?), 16, |r, bytes| Ok(r.read_int32(bytes)?))?;
+                    self.f_map.insert(key, value);
                 }
-                Ok(216) => msg.test_oneof = mod_FooMessage::OneOftest_oneof::f1(r.read_int32(bytes)?),
-                Ok(224) => msg.test_oneof = mod_FooMessage::OneOftest_oneof::f2(r.read_bool(bytes)?),
-                Ok(234) => msg.test_oneof = mod_FooMessage::OneOftest_oneof::f3(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(216) => self.test_oneof = mod_FooMessage::OneOftest_oneof::f1(r.read_int32(bytes)?),
+                Ok(224) => self.test_oneof = mod_FooMessage::OneOftest_oneof::f2(r.read_bool(bytes)?),
+                Ok(234) => self.test_oneof = mod_FooMessage::OneOftest_oneof::f3(r.read_string(bytes).map(Cow::Borrowed)?),
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -232,6 +276,7 @@ impl<'a> MessageWrite for FooMessage<'a> {
     }
 }
 
+/// Nested messages, enums and oneofs of `FooMessage`
 pub mod mod_FooMessage {
 
 use super::*;
@@ -241,6 +286,7 @@ pub enum OneOftest_oneof<'a> {
     f1(i32),
     f2(bool),
     f3(Cow<'a, str>),
+    /// None of the fields is set
     None,
 }
 
@@ -261,14 +307,23 @@ pub struct BazMessage {
 impl<'a> MessageRead<'a> for BazMessage {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.nested = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(10) => msg.nested = Some(r.read_message::<mod_BazMessage::Nested>(bytes)?),
+                Ok(10) => r.merge_message(bytes, self.nested.get_or_insert_with(Default::default))?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -284,6 +339,7 @@ impl MessageWrite for BazMessage {
     }
 }
 
+/// Nested messages, enums and oneofs of `BazMessage`
 pub mod mod_BazMessage {
 
 use super::*;
@@ -297,14 +353,23 @@ pub struct Nested {
 impl<'a> MessageRead<'a> for Nested {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.f_nested = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(10) => msg.f_nested = r.read_message::<mod_BazMessage::mod_Nested::NestedMessage>(bytes)?,
+                Ok(10) => r.merge_message(bytes, &mut self.f_nested)?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -320,6 +385,7 @@ impl MessageWrite for Nested {
     }
 }
 
+/// Nested messages, enums and oneofs of `Nested`
 pub mod mod_Nested {
 
 use super::*;
@@ -333,14 +399,23 @@ pub struct NestedMessage {
 impl<'a> MessageRead<'a> for NestedMessage {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.f_nested = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(8) => msg.f_nested = r.read_int32(bytes)?,
+                Ok(8) => self.f_nested = r.read_int32(bytes)?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
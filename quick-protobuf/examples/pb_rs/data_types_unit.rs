@@ -50,6 +50,11 @@ impl<'a> MessageRead<'a> for unit_message {
         r.read_to_end();
         Ok(Self::default())
     }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
 }
 
 impl MessageWrite for unit_message { }
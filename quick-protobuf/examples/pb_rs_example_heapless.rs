@@ -0,0 +1,38 @@
+#![no_std]
+
+// only links the runtime to run `main`, neither quick-protobuf (built without its `alloc`
+// feature) nor the generated code can allocate
+extern crate std as ext_std;
+
+extern crate quick_protobuf;
+
+mod pb_rs_heapless;
+
+use crate::pb_rs_heapless::protos::no_alloc::{
+    mod_NoAllocMessage::OneOfstatus, NoAllocMessage, Reading, Unit,
+};
+use core::convert::TryInto;
+use quick_protobuf::{deserialize_from_slice, serialize_into_slice};
+
+fn main() {
+    let mut message = NoAllocMessage::default();
+    message.device.push_str("sensor").unwrap();
+    message.key.extend_from_slice(&[1, 2, 3]).unwrap();
+    message.samples.extend_from_slice(&[10, 20]).unwrap();
+    message
+        .readings
+        .push(Reading {
+            sensor: 1,
+            value: -5,
+            unit: Unit::Fahrenheit,
+        })
+        .unwrap();
+    message.counters.insert("a".try_into().unwrap(), 3).unwrap();
+    message.status = OneOfstatus::code(7);
+
+    let mut buf = [0u8; 1024];
+    serialize_into_slice(&message, &mut buf).unwrap();
+
+    let read_message: NoAllocMessage = deserialize_from_slice(&buf).unwrap();
+    assert_eq!(message, read_message);
+}
@@ -57,15 +57,25 @@ pub struct EmbeddedMessage {
 impl<'a> MessageRead<'a> for EmbeddedMessage {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.val = Default::default();
+        self.e = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(8) => msg.val = r.read_int32(bytes)?,
-                Ok(16) => msg.e = r.read_enum(bytes)?,
+                Ok(8) => self.val = r.read_int32(bytes)?,
+                Ok(16) => self.e = r.read_enum(bytes)?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -95,17 +105,29 @@ pub struct NoStdMessage<'a> {
 impl<'a> MessageRead<'a> for NoStdMessage<'a> {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.num = Default::default();
+        self.nums = Default::default();
+        self.message = Default::default();
+        self.messages.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(13) => msg.num = r.read_fixed32(bytes)?,
-                Ok(18) => msg.nums = r.read_packed_fixed(bytes)?.into(),
-                Ok(26) => msg.message = Some(r.read_message::<protos::no_std::EmbeddedMessage>(bytes)?),
-                Ok(34) => msg.messages.push(r.read_message::<protos::no_std::EmbeddedMessage>(bytes)?),
+                Ok(13) => self.num = r.read_fixed32(bytes)?,
+                Ok(18) => if self.nums.is_empty() { self.nums = r.read_packed_fixed(bytes)? } else { self.nums.to_mut().extend_from_slice(&r.read_packed_fixed(bytes)?) },
+                Ok(26) => r.merge_message(bytes, self.message.get_or_insert_with(Default::default))?,
+                Ok(34) => self.messages.push(r.read_message::<protos::no_std::EmbeddedMessage>(bytes)?),
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
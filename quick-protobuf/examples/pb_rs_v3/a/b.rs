@@ -22,14 +22,23 @@ pub struct ImportedMessage {
 impl<'a> MessageRead<'a> for ImportedMessage {
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
         let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.i = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
         while !r.is_eof() {
             match r.next_tag(bytes) {
-                Ok(8) => msg.i = r.read_bool(bytes)?,
+                Ok(8) => self.i = r.read_bool(bytes)?,
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
         }
-        Ok(msg)
+        Ok(())
     }
 }
 
@@ -0,0 +1,76 @@
+// Automatically generated rust module for 'data_types_import.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use core::convert::{TryFrom, TryInto};
+use quick_protobuf::Owned;
+use quick_protobuf::sizeofs::*;
+use super::super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ImportedMessage {
+    pub i: bool,
+}
+
+impl<'a> MessageRead<'a> for ImportedMessage {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.i = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.i = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for ImportedMessage {
+    fn get_size(&self) -> usize {
+        0
+        + if self.i == false { 0 } else { 1 + sizeof_varint(*(&self.i) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.i != false { w.write_with_tag(8, |w| w.write_bool(*&self.i))?; }
+        Ok(())
+    }
+}
+
+
+            impl TryFrom<&[u8]> for ImportedMessage {
+                type Error=quick_protobuf::Error;
+
+                fn try_from(buf: &[u8]) -> Result<Self> {
+                    let mut reader = BytesReader::from_bytes(&buf);
+                    Ok(ImportedMessage::from_reader(&mut reader, &buf)?)
+                }
+            }
+
+            impl TryFrom<Vec<u8>> for ImportedMessage {
+                type Error=quick_protobuf::Error;
+
+                fn try_from(buf: Vec<u8>) -> Result<Self> {
+                    ImportedMessage::try_from(buf.as_slice())
+                }
+            }
+            
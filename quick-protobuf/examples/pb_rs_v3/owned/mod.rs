@@ -0,0 +1,4 @@
+// Automatically generated mod.rs
+pub mod data_types;
+pub mod a;
+pub mod data_types_unit;
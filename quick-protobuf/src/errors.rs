@@ -1,5 +1,8 @@
 //! A module to handle all errors
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
 /// An error enum
 #[derive(Debug)]
 pub enum Error {
@@ -18,7 +21,7 @@ pub enum Error {
     /// Varint decoding error
     Varint,
     /// Error while parsing protocol buffer message
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     Message(String),
     /// Unexpected map tag
     Map(u8),
@@ -26,6 +29,34 @@ pub enum Error {
     UnexpectedEndOfBuffer,
     /// The supplied output buffer is not large enough to serialize the message
     OutputBufferTooSmall,
+    /// Invalid tag: field number 0, unexpected end group or unexpected map entry wire type
+    InvalidTag(u32),
+    /// Messages or groups are nested too deeply
+    RecursionLimitExceeded,
+    /// Valid but non canonical encoding, rejected in strict mode
+    NonCanonical(&'static str),
+    /// Bytes left after the end of a message, rejected in strict mode
+    TrailingBytes(usize),
+    /// Singular field (or oneof) found more than once, rejected in strict mode
+    DuplicateField(u32),
+    /// Field value rejected by the conversion into the custom Rust type of the field
+    Conversion(u32),
+    /// The service has no such rpc method
+    #[cfg(feature = "alloc")]
+    UnknownMethod(String),
+    /// Error returned by the server of an rpc
+    #[cfg(feature = "alloc")]
+    Rpc(String),
+    /// Message longer than the maximum length of a frame
+    MessageTooLarge(usize),
+    /// Invalid compression flag of a frame, must be 0 or 1
+    InvalidFrameFlag(u8),
+    /// Error while compressing or decompressing a message
+    Compression(&'static str),
+    /// Required field not set when building a message, `Message.field`
+    MissingField(&'static str),
+    /// Field value exceeding the capacity of its fixed capacity collection
+    Capacity(u32),
 }
 
 /// A wrapper for `Result<T, Error>`
@@ -79,11 +110,36 @@ impl core::fmt::Display for Error {
                 write!(f, "Unknown wire type '{}', must be less than 6", e)
             }
             Error::Varint => write!(f, "Cannot decode varint"),
-            #[cfg(feature = "std")]
+            #[cfg(feature = "alloc")]
             Error::Message(msg) => write!(f, "Error while parsing message: {}", msg),
-            Error::Map(tag) => write!(f, "Unexpected map tag: '{}', expecting 1 or 2", tag),
+            Error::Map(tag) => write!(
+                f,
+                "Unexpected map tag: '{}', expecting a key (field 1) or value (field 2) of the map's types",
+                tag
+            ),
             Error::UnexpectedEndOfBuffer => write!(f, "Unexpected end of buffer"),
             Error::OutputBufferTooSmall => write!(f, "Output buffer too small"),
+            Error::InvalidTag(tag) => write!(f, "Invalid tag '{}'", tag),
+            Error::RecursionLimitExceeded => write!(f, "Recursion limit exceeded"),
+            Error::NonCanonical(e) => write!(f, "Non canonical encoding: {}", e),
+            Error::TrailingBytes(n) => write!(f, "{} trailing bytes after message", n),
+            Error::DuplicateField(n) => write!(f, "Duplicate singular field '{}'", n),
+            Error::Conversion(n) => write!(f, "Cannot convert the value of field '{}'", n),
+            #[cfg(feature = "alloc")]
+            Error::UnknownMethod(m) => write!(f, "Unknown rpc method '{}'", m),
+            #[cfg(feature = "alloc")]
+            Error::Rpc(e) => write!(f, "Rpc error: {}", e),
+            Error::MessageTooLarge(n) => write!(f, "Message of {} bytes is too large", n),
+            Error::InvalidFrameFlag(flag) => {
+                write!(
+                    f,
+                    "Invalid frame compression flag '{}', must be 0 or 1",
+                    flag
+                )
+            }
+            Error::Compression(e) => write!(f, "Compression error: {}", e),
+            Error::MissingField(field) => write!(f, "Required field '{}' is not set", field),
+            Error::Capacity(n) => write!(f, "The value of field '{}' exceeds its capacity", n),
         }
     }
 }
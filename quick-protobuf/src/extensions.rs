@@ -0,0 +1,440 @@
+//! A module to handle proto2 extensions
+//!
+//! Fields of a message whose numbers are within its `extensions` ranges are not known when the
+//! message is generated. They are kept as raw bytes in an `Extensions` field and decoded on
+//! demand with a typed `Extension` descriptor, generated by pb-rs for each field of an `extend`
+//! block.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use quick_protobuf::extensions::{self, Extension, Extensions};
+//!
+//! struct Foo;
+//!
+//! // extend Foo { optional int32 bar = 100; repeated string baz = 101; }
+//! const BAR: Extension<Foo, extensions::Int32> = Extension::new(100);
+//! const BAZ: Extension<Foo, extensions::Str> = Extension::new(101);
+//!
+//! let mut ext = Extensions::default();
+//! ext.set(&BAR, &150).unwrap();
+//! ext.add(&BAZ, &"a").unwrap();
+//! ext.add(&BAZ, &"b").unwrap();
+//!
+//! assert_eq!(ext.get(&BAR).unwrap(), Some(150));
+//! assert_eq!(ext.get_repeated(&BAZ).unwrap(), ["a", "b"]);
+//! ```
+//!
+//! An extension cannot be used on another message:
+//!
+//! ```rust,compile_fail
+//! use quick_protobuf::extensions::{self, ExtendableMessage, Extension, Extensions};
+//!
+//! struct Foo;
+//! struct Qux(Extensions);
+//!
+//! impl ExtendableMessage for Qux {
+//!     fn extensions(&self) -> &Extensions {
+//!         &self.0
+//!     }
+//!
+//!     fn extensions_mut(&mut self) -> &mut Extensions {
+//!         &mut self.0
+//!     }
+//! }
+//!
+//! const BAR: Extension<Foo, extensions::Int32> = Extension::new(100);
+//!
+//! Qux(Extensions::default()).get_extension(&BAR);
+//! ```
+
+use core::marker::PhantomData;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::errors::Result;
+use crate::reader::BytesReader;
+use crate::sizeofs::*;
+use crate::writer::{BytesWriter, Writer, WriterBackend};
+
+/// A type which can be used as the value of an extension field
+///
+/// It describes how a single value is encoded. It is implemented for all scalar types in this
+/// module, pb-rs generates an implementation for each message and enum extension field.
+pub trait ExtensionType {
+    /// The Rust type of a value, possibly borrowing from the message
+    type Value<'a>;
+
+    /// The wire type of a (non packed) value
+    const WIRE_TYPE: u32;
+
+    /// Reads a value, after its tag
+    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self::Value<'a>>;
+
+    /// Computes the size of a value once serialized, without its tag
+    fn get_size(v: &Self::Value<'_>) -> usize;
+
+    /// Writes a value, without its tag
+    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &Self::Value<'_>) -> Result<()>;
+}
+
+macro_rules! extension_type {
+    ($(#[$doc:meta])* $name:ident, $t:ty, $wire:expr, $read:ident, $write:ident, $size:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl ExtensionType for $name {
+            type Value<'a> = $t;
+
+            const WIRE_TYPE: u32 = $wire;
+
+            fn read(r: &mut BytesReader, bytes: &[u8]) -> Result<$t> {
+                r.$read(bytes)
+            }
+
+            fn get_size(v: &$t) -> usize {
+                $size(*v)
+            }
+
+            fn write<W: WriterBackend>(w: &mut Writer<W>, v: &$t) -> Result<()> {
+                w.$write(*v)
+            }
+        }
+    };
+}
+
+extension_type!(
+    /// `int32` extension
+    Int32, i32, 0, read_int32, write_int32, sizeof_int32
+);
+extension_type!(
+    /// `int64` extension
+    Int64, i64, 0, read_int64, write_int64, sizeof_int64
+);
+extension_type!(
+    /// `uint32` extension
+    Uint32, u32, 0, read_uint32, write_uint32, sizeof_uint32
+);
+extension_type!(
+    /// `uint64` extension
+    Uint64, u64, 0, read_uint64, write_uint64, sizeof_uint64
+);
+extension_type!(
+    /// `sint32` extension
+    Sint32, i32, 0, read_sint32, write_sint32, sizeof_sint32
+);
+extension_type!(
+    /// `sint64` extension
+    Sint64, i64, 0, read_sint64, write_sint64, sizeof_sint64
+);
+extension_type!(
+    /// `bool` extension
+    Bool, bool, 0, read_bool, write_bool, sizeof_bool
+);
+extension_type!(
+    /// `fixed64` extension
+    Fixed64, u64, 1, read_fixed64, write_fixed64, |_| 8
+);
+extension_type!(
+    /// `sfixed64` extension
+    Sfixed64, i64, 1, read_sfixed64, write_sfixed64, |_| 8
+);
+extension_type!(
+    /// `double` extension
+    Double, f64, 1, read_double, write_double, |_| 8
+);
+extension_type!(
+    /// `fixed32` extension
+    Fixed32, u32, 5, read_fixed32, write_fixed32, |_| 4
+);
+extension_type!(
+    /// `sfixed32` extension
+    Sfixed32, i32, 5, read_sfixed32, write_sfixed32, |_| 4
+);
+extension_type!(
+    /// `float` extension
+    Float, f32, 5, read_float, write_float, |_| 4
+);
+
+/// `string` extension
+#[derive(Debug, Clone, Copy)]
+pub struct Str;
+
+impl ExtensionType for Str {
+    type Value<'a> = &'a str;
+
+    const WIRE_TYPE: u32 = 2;
+
+    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<&'a str> {
+        r.read_string(bytes)
+    }
+
+    fn get_size(v: &&str) -> usize {
+        sizeof_len(v.len())
+    }
+
+    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &&str) -> Result<()> {
+        w.write_string(v)
+    }
+}
+
+/// `bytes` extension
+#[derive(Debug, Clone, Copy)]
+pub struct Bytes;
+
+impl ExtensionType for Bytes {
+    type Value<'a> = &'a [u8];
+
+    const WIRE_TYPE: u32 = 2;
+
+    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<&'a [u8]> {
+        r.read_bytes(bytes)
+    }
+
+    fn get_size(v: &&[u8]) -> usize {
+        sizeof_len(v.len())
+    }
+
+    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &&[u8]) -> Result<()> {
+        w.write_bytes(v)
+    }
+}
+
+/// A typed extension field descriptor of the message `M`
+///
+/// The `ExtendableMessage` methods only accept the extensions of their message. Messages with a
+/// lifetime are extended by their `'static` version, e.g. `Extension<Foo<'static>, T>`.
+#[derive(Debug)]
+pub struct Extension<M, T> {
+    number: u32,
+    _type: PhantomData<fn() -> (M, T)>,
+}
+
+impl<M, T> Extension<M, T> {
+    /// Creates a new extension descriptor for field `number`
+    pub const fn new(number: u32) -> Self {
+        Extension {
+            number,
+            _type: PhantomData,
+        }
+    }
+
+    /// The field number of the extension
+    pub const fn number(&self) -> u32 {
+        self.number
+    }
+}
+
+impl<M, T> Clone for Extension<M, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, T> Copy for Extension<M, T> {}
+
+/// The extension fields of a message
+///
+/// Each field is kept as it was encoded (tags included) and decoded with its `Extension`. Unlike
+/// `ExtendableMessage`, it does not check the extendee of the extensions.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Extensions {
+    fields: BTreeMap<u32, Vec<u8>>,
+}
+
+impl Extensions {
+    /// Checks if there is no extension field
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Removes all extension fields
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Checks if the extension field is set
+    pub fn has<M, T>(&self, ext: &Extension<M, T>) -> bool {
+        self.fields.contains_key(&ext.number)
+    }
+
+    /// Removes the extension field
+    pub fn remove<M, T>(&mut self, ext: &Extension<M, T>) {
+        self.fields.remove(&ext.number);
+    }
+
+    /// Reads the extension field value
+    ///
+    /// If the field has been encoded several times, the last value wins.
+    pub fn get<'a, M, T: ExtensionType>(
+        &'a self,
+        ext: &Extension<M, T>,
+    ) -> Result<Option<T::Value<'a>>> {
+        Ok(self.get_repeated(ext)?.pop())
+    }
+
+    /// Reads all the values of a repeated extension field, accepting both packed and non packed
+    /// encodings
+    pub fn get_repeated<'a, M, T: ExtensionType>(
+        &'a self,
+        ext: &Extension<M, T>,
+    ) -> Result<Vec<T::Value<'a>>> {
+        let mut values = Vec::new();
+        let bytes = match self.fields.get(&ext.number) {
+            Some(bytes) => bytes,
+            None => return Ok(values),
+        };
+        let mut r = BytesReader::from_bytes(bytes);
+        while !r.is_eof() {
+            let tag = r.next_tag(bytes)?;
+            match tag & 0x7 {
+                t if t == T::WIRE_TYPE => values.push(T::read(&mut r, bytes)?),
+                2 => r.read_packed_into(bytes, &mut values, |r, bytes| T::read(r, bytes))?,
+                _ => r.read_unknown(bytes, tag)?,
+            }
+        }
+        Ok(values)
+    }
+
+    /// Sets the extension field value, replacing any previous one
+    ///
+    /// The field is left untouched if the value cannot be written.
+    pub fn set<M, T: ExtensionType>(&mut self, ext: &Extension<M, T>, v: &T::Value<'_>) -> Result<()> {
+        let bytes = Self::encode(ext, v)?;
+        self.fields.insert(ext.number, bytes);
+        Ok(())
+    }
+
+    /// Appends a value to a repeated extension field
+    ///
+    /// Values are always written non packed. The field is left untouched if the value cannot be
+    /// written.
+    pub fn add<M, T: ExtensionType>(&mut self, ext: &Extension<M, T>, v: &T::Value<'_>) -> Result<()> {
+        let bytes = Self::encode(ext, v)?;
+        self.fields.entry(ext.number).or_default().extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    /// Encodes a value, tag included
+    fn encode<M, T: ExtensionType>(ext: &Extension<M, T>, v: &T::Value<'_>) -> Result<Vec<u8>> {
+        let tag = ext.number << 3 | T::WIRE_TYPE;
+        let mut bytes = vec![0; sizeof_varint(tag as u64) + T::get_size(v)];
+        let mut w = Writer::new(BytesWriter::new(&mut bytes));
+        w.write_tag(tag)?;
+        T::write(&mut w, v)?;
+        Ok(bytes)
+    }
+
+    /// Reads an extension field of a message, after its tag
+    ///
+    /// Called by generated messages for tags in their extension ranges.
+    pub fn read_field(&mut self, r: &mut BytesReader, bytes: &[u8], tag: u32) -> Result<()> {
+        let value = r.read_unknown_bytes(bytes, tag)?;
+        let field = self.fields.entry(tag >> 3).or_default();
+        let start = field.len();
+        field.resize(start + sizeof_varint(tag as u64), 0);
+        Writer::new(BytesWriter::new(&mut field[start..])).write_tag(tag)?;
+        field.extend_from_slice(value);
+        Ok(())
+    }
+
+    /// Computes the size of all extension fields once serialized, tags included
+    pub fn get_size(&self) -> usize {
+        self.fields.values().map(Vec::len).sum()
+    }
+
+    /// Writes all extension fields
+    pub fn write<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for bytes in self.fields.values() {
+            w.backend_mut().pb_write_all(bytes)?;
+        }
+        Ok(())
+    }
+}
+
+/// A message with extension ranges
+///
+/// pb-rs implements it for messages declaring `extensions`.
+pub trait ExtendableMessage: Sized {
+    /// The extension fields of the message
+    fn extensions(&self) -> &Extensions;
+
+    /// The extension fields of the message
+    fn extensions_mut(&mut self) -> &mut Extensions;
+
+    /// Checks if the extension field is set
+    fn has_extension<T>(&self, ext: &Extension<Self, T>) -> bool {
+        self.extensions().has(ext)
+    }
+
+    /// Reads the extension field value, see `Extensions::get`
+    fn get_extension<'a, T: ExtensionType>(
+        &'a self,
+        ext: &Extension<Self, T>,
+    ) -> Result<Option<T::Value<'a>>> {
+        self.extensions().get(ext)
+    }
+
+    /// Reads all the values of a repeated extension field, see `Extensions::get_repeated`
+    fn get_repeated_extension<'a, T: ExtensionType>(
+        &'a self,
+        ext: &Extension<Self, T>,
+    ) -> Result<Vec<T::Value<'a>>> {
+        self.extensions().get_repeated(ext)
+    }
+
+    /// Sets the extension field value, replacing any previous one
+    fn set_extension<T: ExtensionType>(
+        &mut self,
+        ext: &Extension<Self, T>,
+        v: &T::Value<'_>,
+    ) -> Result<()> {
+        self.extensions_mut().set(ext, v)
+    }
+
+    /// Appends a value to a repeated extension field
+    fn add_extension<T: ExtensionType>(
+        &mut self,
+        ext: &Extension<Self, T>,
+        v: &T::Value<'_>,
+    ) -> Result<()> {
+        self.extensions_mut().add(ext, v)
+    }
+
+    /// Removes the extension field
+    fn clear_extension<T>(&mut self, ext: &Extension<Self, T>) {
+        self.extensions_mut().remove(ext)
+    }
+}
+
+#[test]
+fn test_extensions_packed() {
+    // field 100, packed int32 [1, 150]
+    let bytes = [0xA2, 0x06, 0x03, 0x01, 0x96, 0x01];
+    let mut ext = Extensions::default();
+    let mut r = BytesReader::from_bytes(&bytes);
+    let tag = r.next_tag(&bytes).unwrap();
+    ext.read_field(&mut r, &bytes, tag).unwrap();
+    assert!(r.is_eof());
+    assert_eq!(ext.get_size(), bytes.len());
+
+    const EXT: Extension<(), Int32> = Extension::new(100);
+    assert_eq!(ext.get_repeated(&EXT).unwrap(), [1, 150]);
+    assert_eq!(ext.get(&EXT).unwrap(), Some(150));
+
+    ext.set(&EXT, &2).unwrap();
+    assert_eq!(ext.get_repeated(&EXT).unwrap(), [2]);
+    assert_eq!(ext.get_size(), 3);
+
+    ext.clear();
+    assert!(!ext.has(&EXT));
+    assert_eq!(ext.get(&EXT).unwrap(), None);
+}
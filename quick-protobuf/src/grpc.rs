@@ -0,0 +1,327 @@
+//! A module to encode and decode messages framed like gRPC
+//!
+//! gRPC sends each message in a frame: a 1-byte flag, set if the message is compressed, the
+//! 4-byte big-endian length of the (compressed) message, then the message itself.
+//!
+//! [`Codec`] encodes and decodes frames in byte buffers, [`FrameWriter`] and [`FrameReader`]
+//! stream them over `std::io::Write` and `std::io::Read`. Messages are not compressed unless
+//! the codec is given a [`Compression`], for instance gzip with flate2:
+//!
+//! ```rust,ignore
+//! use quick_protobuf::grpc::{Codec, Compression};
+//! use std::io::{Read, Write};
+//!
+//! struct Gzip;
+//!
+//! impl Compression for Gzip {
+//!     fn name(&self) -> &str {
+//!         "gzip"
+//!     }
+//!
+//!     fn compress(&self, data: &[u8], out: &mut Vec<u8>) -> quick_protobuf::Result<()> {
+//!         let mut encoder = flate2::write::GzEncoder::new(out, flate2::Compression::default());
+//!         encoder.write_all(data)?;
+//!         encoder.finish()?;
+//!         Ok(())
+//!     }
+//!
+//!     fn decompress(
+//!         &self,
+//!         data: &[u8],
+//!         out: &mut Vec<u8>,
+//!         max_len: usize,
+//!     ) -> quick_protobuf::Result<()> {
+//!         let decoder = flate2::read::GzDecoder::new(data);
+//!         decoder.take(max_len as u64 + 1).read_to_end(out)?;
+//!         if out.len() > max_len {
+//!             return Err(quick_protobuf::Error::MessageTooLarge(out.len()));
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let codec = Codec::with_compression(Gzip);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::errors::{Error, Result};
+use crate::message::{MessageRead, MessageWrite};
+use crate::reader::BytesReader;
+use crate::writer::{BytesWriter, Writer};
+
+/// Length of the header of a frame, the compression flag and the length of the message
+pub const HEADER_LEN: usize = 5;
+
+/// Default maximum length of the decoded messages, 4 MiB like gRPC
+pub const DEFAULT_MAX_MESSAGE_LEN: usize = 4 * 1024 * 1024;
+
+/// A compression of the messages, the `grpc-encoding` of a gRPC call
+pub trait Compression {
+    /// Name of the compression, e.g. `gzip`
+    fn name(&self) -> &str;
+
+    /// Appends the compressed `data` to `out`
+    fn compress(&self, data: &[u8], out: &mut Vec<u8>) -> Result<()>;
+
+    /// Appends the decompressed `data` to `out`
+    ///
+    /// Fails with `Error::MessageTooLarge` as soon as more than `max_len` bytes are decompressed,
+    /// so that a small frame cannot expand into an arbitrarily large message.
+    fn decompress(&self, data: &[u8], out: &mut Vec<u8>, max_len: usize) -> Result<()>;
+}
+
+/// No compression, the `identity` encoding
+///
+/// Compressed frames cannot be decoded.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Identity;
+
+impl Compression for Identity {
+    fn name(&self) -> &str {
+        "identity"
+    }
+
+    fn compress(&self, data: &[u8], out: &mut Vec<u8>) -> Result<()> {
+        out.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn decompress(&self, _data: &[u8], _out: &mut Vec<u8>, _max_len: usize) -> Result<()> {
+        Err(Error::Compression("compressed frame without compression"))
+    }
+}
+
+/// Encodes messages into frames and decodes frames into messages
+#[derive(Debug, Clone)]
+pub struct Codec<C = Identity> {
+    compression: C,
+    compress: bool,
+    max_message_len: usize,
+    buf: Vec<u8>,
+}
+
+impl Codec<Identity> {
+    /// A codec without compression
+    pub fn new() -> Self {
+        Codec {
+            compression: Identity,
+            compress: false,
+            max_message_len: DEFAULT_MAX_MESSAGE_LEN,
+            buf: Vec::new(),
+        }
+    }
+}
+
+impl Default for Codec<Identity> {
+    fn default() -> Self {
+        Codec::new()
+    }
+}
+
+impl<C: Compression> Codec<C> {
+    /// A codec compressing the messages it encodes and decompressing the compressed frames it
+    /// decodes with `compression`
+    ///
+    /// The frames are not compressed with the `identity` compression, which is no compression
+    /// in gRPC.
+    pub fn with_compression(compression: C) -> Self {
+        Codec {
+            compress: compression.name() != "identity",
+            compression,
+            max_message_len: DEFAULT_MAX_MESSAGE_LEN,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum length of the decoded messages, compressed or not
+    pub fn max_message_len(mut self, len: usize) -> Self {
+        self.max_message_len = len;
+        self
+    }
+
+    /// The compression of the messages
+    pub fn compression(&self) -> &C {
+        &self.compression
+    }
+
+    /// Appends the frame of `message` to `out`
+    pub fn encode<M: MessageWrite>(&mut self, message: &M, out: &mut Vec<u8>) -> Result<()> {
+        let start = out.len();
+        out.extend_from_slice(&[0; HEADER_LEN]);
+        if self.compress {
+            self.buf.clear();
+            self.buf.resize(message.get_size(), 0);
+            message.write_message(&mut Writer::new(BytesWriter::new(&mut self.buf)))?;
+            self.compression.compress(&self.buf, out)?;
+        } else {
+            out.resize(start + HEADER_LEN + message.get_size(), 0);
+            let bytes = &mut out[start + HEADER_LEN..];
+            message.write_message(&mut Writer::new(BytesWriter::new(bytes)))?;
+        }
+        let len = out.len() - start - HEADER_LEN;
+        if len > u32::MAX as usize {
+            out.truncate(start);
+            return Err(Error::MessageTooLarge(len));
+        }
+        out[start] = self.compress as u8;
+        out[start + 1..start + HEADER_LEN].copy_from_slice(&(len as u32).to_be_bytes());
+        Ok(())
+    }
+
+    /// Decodes the first frame of `bytes`
+    ///
+    /// Returns the message and the length of its frame, or `None` if `bytes` does not contain
+    /// a whole frame yet. The message may borrow the codec, which keeps decompressed messages.
+    pub fn decode<'a, M: MessageRead<'a>>(
+        &'a mut self,
+        bytes: &'a [u8],
+    ) -> Result<Option<(M, usize)>> {
+        if bytes.len() < HEADER_LEN {
+            return Ok(None);
+        }
+        let len = self.frame_len(&bytes[..HEADER_LEN])?;
+        match bytes.get(HEADER_LEN..HEADER_LEN + len) {
+            Some(message) => {
+                let message = self.decode_message(bytes[0], message)?;
+                Ok(Some((message, HEADER_LEN + len)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Checks the header of a frame and returns the length of its message
+    fn frame_len(&self, header: &[u8]) -> Result<usize> {
+        if header[0] > 1 {
+            return Err(Error::InvalidFrameFlag(header[0]));
+        }
+        let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+        if len > self.max_message_len {
+            return Err(Error::MessageTooLarge(len));
+        }
+        Ok(len)
+    }
+
+    fn decode_message<'a, M: MessageRead<'a>>(
+        &'a mut self,
+        flag: u8,
+        message: &'a [u8],
+    ) -> Result<M> {
+        let message = if flag == 1 {
+            self.buf.clear();
+            self.compression
+                .decompress(message, &mut self.buf, self.max_message_len)?;
+            if self.buf.len() > self.max_message_len {
+                return Err(Error::MessageTooLarge(self.buf.len()));
+            }
+            &self.buf
+        } else {
+            message
+        };
+        M::from_reader(&mut BytesReader::from_bytes(message), message)
+    }
+}
+
+/// Writes framed messages to a `std::io::Write`
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct FrameWriter<W, C = Identity> {
+    inner: W,
+    codec: Codec<C>,
+    buf: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> FrameWriter<W> {
+    /// A writer without compression
+    pub fn new(inner: W) -> Self {
+        FrameWriter::with_codec(inner, Codec::new())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write, C: Compression> FrameWriter<W, C> {
+    /// A writer encoding the messages with `codec`
+    pub fn with_codec(inner: W, codec: Codec<C>) -> Self {
+        FrameWriter {
+            inner,
+            codec,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Writes the frame of `message`
+    pub fn write_message<M: MessageWrite>(&mut self, message: &M) -> Result<()> {
+        self.buf.clear();
+        self.codec.encode(message, &mut self.buf)?;
+        self.inner.write_all(&self.buf)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer
+    pub fn flush(&mut self) -> Result<()> {
+        self.inner.flush()?;
+        Ok(())
+    }
+
+    /// Gets the underlying writer
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads framed messages from a `std::io::Read`
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct FrameReader<R, C = Identity> {
+    inner: R,
+    codec: Codec<C>,
+    frame: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> FrameReader<R> {
+    /// A reader without compression
+    pub fn new(inner: R) -> Self {
+        FrameReader::with_codec(inner, Codec::new())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read, C: Compression> FrameReader<R, C> {
+    /// A reader decoding the messages with `codec`
+    pub fn with_codec(inner: R, codec: Codec<C>) -> Self {
+        FrameReader {
+            inner,
+            codec,
+            frame: Vec::new(),
+        }
+    }
+
+    /// Reads the next message, or `None` at the end of the stream
+    ///
+    /// The message may borrow the reader, until the next message is read.
+    pub fn read_message<'a, M: MessageRead<'a>>(&'a mut self) -> Result<Option<M>> {
+        let mut header = [0; HEADER_LEN];
+        let mut read = 0;
+        while read < HEADER_LEN {
+            match self.inner.read(&mut header[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(Error::UnexpectedEndOfBuffer),
+                Ok(n) => read += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let len = self.codec.frame_len(&header)?;
+        self.frame.resize(len, 0);
+        self.inner.read_exact(&mut self.frame)?;
+        self.codec.decode_message(header[0], &self.frame).map(Some)
+    }
+
+    /// Gets the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
@@ -6,21 +6,34 @@
 #![allow(dead_code)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 
 pub mod errors;
+#[cfg(feature = "alloc")]
+pub mod extensions;
+#[cfg(feature = "alloc")]
+pub mod grpc;
 pub mod message;
+pub mod packed;
+#[cfg(feature = "alloc")]
+pub mod raw;
 pub mod reader;
+#[cfg(feature = "alloc")]
+pub mod rpc;
 pub mod sizeofs;
 pub mod writer;
 
 pub use crate::errors::{Error, Result};
-pub use crate::message::{MessageInfo, MessageRead, MessageWrite, Owned};
-pub use crate::reader::{deserialize_from_slice, BytesReader};
+#[cfg(feature = "alloc")]
+pub use crate::extensions::{ExtendableMessage, Extension, Extensions};
+pub use crate::message::{DynMessageInfo, DynMessageWrite, MessageInfo, MessageRead, MessageWrite};
+pub use crate::reader::{deserialize_from_slice, deserialize_from_slice_strict, BytesReader};
 pub use crate::writer::{serialize_into_slice, BytesWriter, Writer, WriterBackend};
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
+pub use crate::message::Owned;
+#[cfg(feature = "alloc")]
 pub use crate::reader::Reader;
 #[cfg(feature = "std")]
 pub use crate::writer::serialize_into_vec;
@@ -8,7 +8,9 @@ use std::fs::File;
 use std::io::BufWriter;
 #[cfg(feature = "std")]
 use std::path::Path;
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
 use crate::errors::Result;
@@ -36,6 +38,82 @@ pub trait MessageWrite: Sized {
     }
 }
 
+/// An object safe version of `MessageWrite`
+///
+/// It is implemented for every `MessageWrite` and allows working with `dyn DynMessageWrite`,
+/// for instance to keep a `Vec<Box<dyn DynMessageWrite>>` of heterogeneous messages.
+/// Boxed messages implement `MessageWrite` again so they can be used with `Writer::write_message`
+/// or `serialize_into_vec`.
+///
+/// # Examples
+///
+/// ```rust
+/// use quick_protobuf::{serialize_into_vec, DynMessageWrite, MessageWrite, Result, Writer, WriterBackend};
+///
+/// struct Foo(u32);
+/// impl MessageWrite for Foo {
+///     fn get_size(&self) -> usize { 1 + quick_protobuf::sizeofs::sizeof_varint(self.0 as u64) }
+///     fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+///         w.write_with_tag(8, |w| w.write_uint32(self.0))
+///     }
+/// }
+///
+/// let messages: Vec<Box<dyn DynMessageWrite>> = vec![Box::new(Foo(150)), Box::new(Foo(1))];
+/// let bytes = serialize_into_vec(&messages[0]).unwrap();
+/// assert_eq!(bytes, [3, 0x08, 0x96, 0x01]);
+/// ```
+pub trait DynMessageWrite {
+    /// Writes `self` into a `WriterBackend`
+    fn dyn_write_message(&self, w: &mut dyn WriterBackend) -> Result<()>;
+
+    /// Computes necessary binary size of self once serialized in protobuf
+    fn dyn_get_size(&self) -> usize;
+}
+
+impl<M: MessageWrite> DynMessageWrite for M {
+    fn dyn_write_message(&self, w: &mut dyn WriterBackend) -> Result<()> {
+        self.write_message(&mut Writer::new(w))
+    }
+
+    fn dyn_get_size(&self) -> usize {
+        self.get_size()
+    }
+}
+
+/// An object safe version of `MessageWrite` + `MessageInfo`
+///
+/// It is implemented for every message generated with `MessageInfo` (`pb-rs --gen-info`).
+pub trait DynMessageInfo: DynMessageWrite {
+    /// Full message path, in form of Module.Message
+    fn path(&self) -> &'static str;
+}
+
+impl<M: MessageWrite + MessageInfo> DynMessageInfo for M {
+    fn path(&self) -> &'static str {
+        M::PATH
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_message_write_for_dyn {
+    ($t:ident) => {
+        impl<'a> MessageWrite for Box<dyn $t + 'a> {
+            fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+                (**self).dyn_write_message(w.backend_mut())
+            }
+
+            fn get_size(&self) -> usize {
+                (**self).dyn_get_size()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_message_write_for_dyn!(DynMessageWrite);
+#[cfg(feature = "alloc")]
+impl_message_write_for_dyn!(DynMessageInfo);
+
 /// A trait to handle deserialization from protocol buffers.
 pub trait MessageRead<'a>: Sized {
     /// Constructs an instance of `Self` by reading from the given bytes
@@ -44,6 +122,28 @@ pub trait MessageRead<'a>: Sized {
     /// It does NOT read message length first. If you want to read a variable
     /// length message, use `BytesReader::read_message` directly
     fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self>;
+
+    /// Resets `self` to its default value
+    ///
+    /// Generated messages keep the capacity of their repeated fields, maps and owned strings
+    /// so that `self` can be reused with `merge_from_reader` without reallocating.
+    fn clear(&mut self)
+    where
+        Self: Default,
+    {
+        *self = Self::default();
+    }
+
+    /// Reads the given bytes into `self`
+    ///
+    /// Singular fields are overwritten, nested messages are merged and repeated fields and maps
+    /// are extended. The default implementation simply replaces `self` with a new message.
+    ///
+    /// Like `from_reader`, it does NOT read message length first.
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        *self = Self::from_reader(r, bytes)?;
+        Ok(())
+    }
 }
 
 /// A trait to provide basic information about a given message
@@ -53,6 +153,7 @@ pub trait MessageInfo {
 }
 
 /// A trait for Owned messages
+#[cfg(feature = "alloc")]
 pub trait Owned: Sized + 'static {
     /// The proto message type
     type Inner<'a>: 'a;
@@ -0,0 +1,74 @@
+//! A module to handle packed repeated fields of fixed size types
+//!
+//! Protobuf encodes fixed size types in little endian, without any alignment guarantee. When the
+//! host is little endian and the data happens to be aligned, packed fields can be read without
+//! copying them, else they are decoded item by item.
+
+use byteorder::{ByteOrder, LittleEndian as LE};
+
+use crate::errors::Result;
+use crate::writer::WriterBackend;
+
+/// A fixed size type which can be read from or written to packed fields as a whole
+///
+/// # Safety
+///
+/// Implementors must be plain old data, without padding and valid for any bit pattern, so that
+/// a slice of properly aligned little endian bytes can be reinterpreted as a slice of `Self`.
+pub unsafe trait PackedFixed: Copy {
+    /// Reads `Self` from exactly `size_of::<Self>()` little endian bytes
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Writes `self` in little endian
+    fn write_le<W: WriterBackend + ?Sized>(self, w: &mut W) -> Result<()>;
+}
+
+macro_rules! impl_packed_fixed {
+    ($t:ty, $read:ident, $write:ident) => {
+        unsafe impl PackedFixed for $t {
+            #[cfg_attr(std, inline(always))]
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                LE::$read(bytes)
+            }
+
+            #[cfg_attr(std, inline(always))]
+            fn write_le<W: WriterBackend + ?Sized>(self, w: &mut W) -> Result<()> {
+                w.$write(self)
+            }
+        }
+    };
+}
+
+impl_packed_fixed!(u32, read_u32, pb_write_u32);
+impl_packed_fixed!(i32, read_i32, pb_write_i32);
+impl_packed_fixed!(f32, read_f32, pb_write_f32);
+impl_packed_fixed!(u64, read_u64, pb_write_u64);
+impl_packed_fixed!(i64, read_i64, pb_write_i64);
+impl_packed_fixed!(f64, read_f64, pb_write_f64);
+
+/// Reinterprets `bytes` as a slice of `M` if the host is little endian and `bytes` is aligned
+///
+/// `bytes.len()` must be a multiple of `size_of::<M>()`.
+pub(crate) fn cast_slice<M: PackedFixed>(bytes: &[u8]) -> Option<&[M]> {
+    if cfg!(target_endian = "little")
+        && bytes.as_ptr() as usize & (::core::mem::align_of::<M>() - 1) == 0
+    {
+        let len = bytes.len() / ::core::mem::size_of::<M>();
+        // SAFETY: the pointer is aligned, M is valid for any bit pattern (`PackedFixed`) and
+        // its in memory representation is the little endian one
+        Some(unsafe { ::core::slice::from_raw_parts(bytes.as_ptr() as *const M, len) })
+    } else {
+        None
+    }
+}
+
+/// Views a slice of `M` as its little endian bytes if the host is little endian
+pub(crate) fn as_bytes<M: PackedFixed>(v: &[M]) -> Option<&[u8]> {
+    if cfg!(target_endian = "little") {
+        let len = ::core::mem::size_of_val(v);
+        // SAFETY: M has no padding (`PackedFixed`) and u8 has no alignment requirement
+        Some(unsafe { ::core::slice::from_raw_parts(v.as_ptr() as *const u8, len) })
+    } else {
+        None
+    }
+}
@@ -0,0 +1,286 @@
+//! A module to decode protobuf data without its `.proto` definition
+//!
+//! This is the equivalent of `protoc --decode_raw`: the data is decoded into a tree of field
+//! numbers and values. As the schema is unknown, length delimited fields are heuristically
+//! interpreted as nested messages, UTF-8 strings, packed varints or, as a last resort, raw bytes.
+//!
+//! The `Display` implementation pretty-prints the tree in a `protoc`-like format.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use quick_protobuf::raw::{decode_raw, RawValue};
+//!
+//! // field 1: varint 150, field 2: "testing"
+//! let bytes = [0x08, 0x96, 0x01, 0x12, 0x07, b't', b'e', b's', b't', b'i', b'n', b'g'];
+//! let msg = decode_raw(&bytes).expect("Cannot decode bytes");
+//!
+//! assert_eq!(msg.fields[0].number, 1);
+//! assert_eq!(msg.fields[0].value, RawValue::Varint(150));
+//! assert_eq!(msg.fields[1].value, RawValue::String("testing"));
+//! assert_eq!(msg.to_string(), "1: 150\n2: \"testing\"\n");
+//! ```
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::errors::{Error, Result};
+use crate::reader::BytesReader;
+
+const WIRE_TYPE_VARINT: u8 = 0;
+const WIRE_TYPE_FIXED64: u8 = 1;
+const WIRE_TYPE_LENGTH_DELIMITED: u8 = 2;
+const WIRE_TYPE_START_GROUP: u8 = 3;
+const WIRE_TYPE_END_GROUP: u8 = 4;
+const WIRE_TYPE_FIXED32: u8 = 5;
+
+/// Maximum nesting of messages and groups
+///
+/// Deeper length delimited fields are not tried as nested messages, deeper groups are an error.
+const MAX_DEPTH: usize = 64;
+
+/// A message decoded without its schema
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RawMessage<'a> {
+    /// All the fields, in the order they appear on the wire
+    pub fields: Vec<RawField<'a>>,
+}
+
+/// A field of a `RawMessage`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawField<'a> {
+    /// The field number
+    pub number: u32,
+    /// The decoded value
+    pub value: RawValue<'a>,
+}
+
+/// A value of a `RawField`
+///
+/// Variants are named after the wire type except for length delimited fields, which are
+/// split into `Message`, `String`, `Packed` and `Bytes` depending on their content.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawValue<'a> {
+    /// A varint (wire type 0)
+    Varint(u64),
+    /// A 64-bit value (wire type 1)
+    Fixed64(u64),
+    /// A 32-bit value (wire type 5)
+    Fixed32(u32),
+    /// A length delimited field which is a valid non empty message
+    Message(RawMessage<'a>),
+    /// A length delimited field which is a valid UTF-8 string
+    String(&'a str),
+    /// A length delimited field which is a sequence of varints
+    Packed(Vec<u64>),
+    /// Any other length delimited field
+    Bytes(&'a [u8]),
+    /// A group (deprecated wire types 3 and 4)
+    Group(RawMessage<'a>),
+}
+
+/// Decodes `bytes` as a message without knowing its definition
+///
+/// Fails only if `bytes` is not valid protobuf at the wire level (invalid tags or wire types,
+/// truncated data, unbalanced groups).
+pub fn decode_raw(bytes: &[u8]) -> Result<RawMessage<'_>> {
+    let mut r = BytesReader::from_bytes(bytes);
+    read_fields(&mut r, bytes, None, 0)
+}
+
+/// Reads fields until eof or, for groups, until the matching end group tag
+fn read_fields<'a>(
+    r: &mut BytesReader,
+    bytes: &'a [u8],
+    group: Option<u32>,
+    depth: usize,
+) -> Result<RawMessage<'a>> {
+    let mut fields = Vec::new();
+    while !r.is_eof() {
+        let tag = r.next_tag(bytes)?;
+        let number = tag >> 3;
+        if number == 0 {
+            return Err(Error::InvalidTag(tag));
+        }
+        let value = match (tag & 0x7) as u8 {
+            WIRE_TYPE_VARINT => RawValue::Varint(r.read_varint64(bytes)?),
+            WIRE_TYPE_FIXED64 => RawValue::Fixed64(r.read_fixed64(bytes)?),
+            WIRE_TYPE_FIXED32 => RawValue::Fixed32(r.read_fixed32(bytes)?),
+            WIRE_TYPE_LENGTH_DELIMITED => length_delimited(r.read_bytes(bytes)?, depth),
+            WIRE_TYPE_START_GROUP => {
+                if depth >= MAX_DEPTH {
+                    return Err(Error::RecursionLimitExceeded);
+                }
+                RawValue::Group(read_fields(r, bytes, Some(number), depth + 1)?)
+            }
+            WIRE_TYPE_END_GROUP if group == Some(number) => return Ok(RawMessage { fields }),
+            WIRE_TYPE_END_GROUP => return Err(Error::InvalidTag(tag)),
+            t => return Err(Error::UnknownWireType(t)),
+        };
+        fields.push(RawField { number, value });
+    }
+    match group {
+        Some(_) => Err(Error::UnexpectedEndOfBuffer),
+        None => Ok(RawMessage { fields }),
+    }
+}
+
+/// Guesses what a length delimited chunk of data is
+///
+/// Printable text is kept as a string even if it happens to be a valid message, which is
+/// common for short ascii strings.
+fn length_delimited(data: &[u8], depth: usize) -> RawValue<'_> {
+    let text = ::core::str::from_utf8(data).ok();
+    if let Some(s) = text {
+        if s.chars().all(|c| !c.is_control() || c == '\n' || c == '\r' || c == '\t') {
+            return RawValue::String(s);
+        }
+    }
+    if depth < MAX_DEPTH {
+        let mut r = BytesReader::from_bytes(data);
+        if let Ok(msg) = read_fields(&mut r, data, None, depth + 1) {
+            if !msg.fields.is_empty() {
+                return RawValue::Message(msg);
+            }
+        }
+    }
+    if let Some(s) = text {
+        return RawValue::String(s);
+    }
+    let mut r = BytesReader::from_bytes(data);
+    let mut packed = Vec::new();
+    while !r.is_eof() {
+        match r.read_varint64(data) {
+            Ok(v) => packed.push(v),
+            Err(_) => return RawValue::Bytes(data),
+        }
+    }
+    RawValue::Packed(packed)
+}
+
+impl<'a> RawMessage<'a> {
+    fn fmt_indented(&self, f: &mut fmt::Formatter, indent: usize) -> fmt::Result {
+        for field in &self.fields {
+            write!(f, "{:indent$}{}", "", field.number, indent = indent)?;
+            match field.value {
+                RawValue::Varint(v) => writeln!(f, ": {}", v)?,
+                RawValue::Fixed64(v) => writeln!(f, ": 0x{:016x}", v)?,
+                RawValue::Fixed32(v) => writeln!(f, ": 0x{:08x}", v)?,
+                RawValue::String(s) => writeln!(f, ": {:?}", s)?,
+                RawValue::Bytes(b) => {
+                    write!(f, ": \"")?;
+                    for &c in b {
+                        match c {
+                            b'"' => write!(f, "\\\"")?,
+                            b'\\' => write!(f, "\\\\")?,
+                            0x20..=0x7e => write!(f, "{}", c as char)?,
+                            _ => write!(f, "\\x{:02x}", c)?,
+                        }
+                    }
+                    writeln!(f, "\"")?;
+                }
+                RawValue::Packed(ref v) => {
+                    write!(f, ": [")?;
+                    for (i, v) in v.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", v)?;
+                    }
+                    writeln!(f, "]")?;
+                }
+                RawValue::Message(ref m) | RawValue::Group(ref m) => {
+                    writeln!(f, " {{")?;
+                    m.fmt_indented(f, indent + 2)?;
+                    writeln!(f, "{:indent$}}}", "", indent = indent)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for RawMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[test]
+fn test_decode_raw_nested() {
+    // 1: 150, 3 { 1: 1, 2: "ab" }, 4: [1, 300]
+    let bytes = [
+        0x08, 0x96, 0x01, // varint
+        0x1a, 0x06, 0x08, 0x01, 0x12, 0x02, b'a', b'b', // nested message
+        0x22, 0x03, 0x01, 0xac, 0x02, // packed varints
+    ];
+    let msg = decode_raw(&bytes).unwrap();
+    assert_eq!(3, msg.fields.len());
+    assert_eq!(RawValue::Varint(150), msg.fields[0].value);
+    match msg.fields[1].value {
+        RawValue::Message(ref m) => {
+            assert_eq!(RawValue::Varint(1), m.fields[0].value);
+            assert_eq!(RawValue::String("ab"), m.fields[1].value);
+        }
+        ref v => panic!("expecting a message, got {:?}", v),
+    }
+    assert_eq!(RawValue::Packed(vec![1, 300]), msg.fields[2].value);
+    assert_eq!(
+        "1: 150\n3 {\n  1: 1\n  2: \"ab\"\n}\n4: [1, 300]\n",
+        msg.to_string()
+    );
+}
+
+#[test]
+fn test_decode_raw_fixed_and_bytes() {
+    let bytes = [
+        0x09, 1, 0, 0, 0, 0, 0, 0, 0, // fixed64
+        0x15, 2, 0, 0, 0, // fixed32
+        0x1a, 0x02, 0xff, 0xff, // neither message, string nor packed varints
+        0x22, 0x00, // empty
+    ];
+    let msg = decode_raw(&bytes).unwrap();
+    assert_eq!(RawValue::Fixed64(1), msg.fields[0].value);
+    assert_eq!(RawValue::Fixed32(2), msg.fields[1].value);
+    assert_eq!(RawValue::Bytes(&[0xff, 0xff]), msg.fields[2].value);
+    assert_eq!(RawValue::String(""), msg.fields[3].value);
+    assert_eq!(
+        "1: 0x0000000000000001\n2: 0x00000002\n3: \"\\xff\\xff\"\n4: \"\"\n",
+        msg.to_string()
+    );
+}
+
+#[test]
+fn test_decode_raw_groups() {
+    // 1 { 2: 5 } as a group
+    let bytes = [0x0b, 0x10, 0x05, 0x0c];
+    let msg = decode_raw(&bytes).unwrap();
+    assert_eq!(
+        RawValue::Group(RawMessage {
+            fields: vec![RawField {
+                number: 2,
+                value: RawValue::Varint(5)
+            }]
+        }),
+        msg.fields[0].value
+    );
+
+    // unbalanced groups
+    assert!(matches!(
+        decode_raw(&[0x0b, 0x10, 0x05]),
+        Err(Error::UnexpectedEndOfBuffer)
+    ));
+    assert!(matches!(decode_raw(&[0x0c]), Err(Error::InvalidTag(12))));
+}
+
+#[test]
+fn test_decode_raw_invalid() {
+    assert!(matches!(decode_raw(&[0x00]), Err(Error::InvalidTag(0))));
+    assert!(matches!(decode_raw(&[0x0e]), Err(Error::UnknownWireType(6))));
+    assert!(matches!(
+        decode_raw(&[0x0a, 0x05, 0x01]),
+        Err(Error::UnexpectedEndOfBuffer)
+    ));
+}
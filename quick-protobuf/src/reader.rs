@@ -16,13 +16,17 @@ use std::path::Path;
 
 use core::convert::TryFrom;
 
-#[cfg(not(feature = "std"))]
-extern crate alloc;
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::borrow::Cow;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 use crate::errors::{Error, Result};
 use crate::message::MessageRead;
+#[cfg(feature = "alloc")]
+use crate::packed::{self, PackedFixed};
 
 use byteorder::ByteOrder;
 use byteorder::LittleEndian as LE;
@@ -77,10 +81,22 @@ const WIRE_TYPE_FIXED32: u8 = 5;
 ///     println!("Found {} foos and {} bars", foobar.foos.len(), foobar.bars.len());
 /// }
 /// ```
+///
+/// # Strict mode
+///
+/// By default the reader is lenient like Google's implementation. In strict mode, only canonical
+/// encodings are accepted, which is useful when the bytes are hashed or signed. Are rejected:
+/// - non minimal varints and out of range 32-bit values
+/// - bools other than 0 or 1
+/// - nested messages and packed fields not exactly matching their length
+/// - singular fields (or oneofs) found more than once, in messages generated with `pb-rs --strict`
+///
+/// See `deserialize_from_slice_strict` for trailing bytes after the message.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BytesReader {
     start: usize,
     end: usize,
+    strict: bool,
 }
 
 impl BytesReader {
@@ -89,9 +105,40 @@ impl BytesReader {
         BytesReader {
             start: 0,
             end: bytes.len(),
+            strict: false,
         }
     }
 
+    /// Creates a new strict reader from chunks of data
+    pub fn from_bytes_strict(bytes: &[u8]) -> BytesReader {
+        BytesReader {
+            start: 0,
+            end: bytes.len(),
+            strict: true,
+        }
+    }
+
+    /// Enables or disables strict mode
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Checks if the reader is in strict mode
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Marks a singular field as read, fails if it had already been read in strict mode
+    ///
+    /// Used by generated code, `tag` is the tag of the field.
+    #[cfg_attr(std, inline(always))]
+    pub fn check_singular(&self, seen: &mut bool, tag: u32) -> Result<()> {
+        if self.strict && ::core::mem::replace(seen, true) {
+            return Err(Error::DuplicateField(tag >> 3));
+        }
+        Ok(())
+    }
+
     /// Reads next tag, `None` if all bytes have been read
     #[cfg_attr(std, inline(always))]
     pub fn next_tag(&mut self, bytes: &[u8]) -> Result<u32> {
@@ -109,6 +156,10 @@ impl BytesReader {
     /// Reads the next varint encoded u64
     #[cfg_attr(std, inline(always))]
     pub fn read_varint32(&mut self, bytes: &[u8]) -> Result<u32> {
+        if self.strict {
+            let v = self.read_varint_strict(bytes)?;
+            return u32::try_from(v).map_err(|_| Error::NonCanonical("32-bit value out of range"));
+        }
         let mut b = self.read_u8(bytes)?; // byte0
         if b & 0x80 == 0 {
             return Ok(b as u32);
@@ -197,6 +248,9 @@ impl BytesReader {
     /// Reads the next varint encoded u64
     #[cfg_attr(std, inline(always))]
     pub fn read_varint64(&mut self, bytes: &[u8]) -> Result<u64> {
+        if self.strict {
+            return self.read_varint_strict(bytes);
+        }
         // part0
         let mut b = self.read_u8(bytes)?;
         if b & 0x80 == 0 {
@@ -279,9 +333,33 @@ impl BytesReader {
         Err(Error::Varint)
     }
 
+    /// Reads the next varint, rejecting non minimal encodings and overflows
+    fn read_varint_strict(&mut self, bytes: &[u8]) -> Result<u64> {
+        let mut r = 0u64;
+        for i in 0..10 {
+            let b = self.read_u8(bytes)?;
+            if i == 9 && b > 1 {
+                return Err(Error::NonCanonical("varint overflows 64 bits"));
+            }
+            r |= ((b & 0x7f) as u64) << (7 * i);
+            if b & 0x80 == 0 {
+                if b == 0 && i > 0 {
+                    return Err(Error::NonCanonical("non minimal varint"));
+                }
+                return Ok(r);
+            }
+        }
+        Err(Error::Varint)
+    }
+
     /// Reads int32 (varint)
     #[cfg_attr(std, inline)]
     pub fn read_int32(&mut self, bytes: &[u8]) -> Result<i32> {
+        if self.strict {
+            // negative values are sign extended to 64 bits
+            let v = self.read_varint_strict(bytes)? as i64;
+            return i32::try_from(v).map_err(|_| Error::NonCanonical("32-bit value out of range"));
+        }
         self.read_varint32(bytes).map(|i| i as i32)
     }
 
@@ -370,7 +448,12 @@ impl BytesReader {
     /// Reads bool (varint, check if == 0)
     #[cfg_attr(std, inline)]
     pub fn read_bool(&mut self, bytes: &[u8]) -> Result<bool> {
-        self.read_varint32(bytes).map(|i| i != 0)
+        match self.read_varint32(bytes)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ if self.strict => Err(Error::NonCanonical("bool must be 0 or 1")),
+            _ => Ok(true),
+        }
     }
 
     /// Reads enum, encoded as i32
@@ -398,6 +481,10 @@ impl BytesReader {
         let cur_end = self.end;
         self.end = self.start + len;
         let v = read(self, bytes)?;
+        if self.strict && self.start > self.end {
+            // the last item overflowed the length
+            return Err(Error::UnexpectedEndOfBuffer);
+        }
         self.start = self.end;
         self.end = cur_end;
         Ok(v)
@@ -426,39 +513,84 @@ impl BytesReader {
     ///
     /// Note: packed field are stored as a variable length chunk of data, while regular repeated
     /// fields behaves like an iterator, yielding their tag everytime
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(std, inline)]
+    pub fn read_packed<'a, M, F>(&mut self, bytes: &'a [u8], read: F) -> Result<Vec<M>>
+    where
+        F: FnMut(&mut BytesReader, &'a [u8]) -> Result<M>,
+    {
+        let mut v = Vec::new();
+        self.read_packed_into(bytes, &mut v, read)?;
+        Ok(v)
+    }
+
+    /// Reads packed repeated field (Vec<M>) and appends its items to `v`
+    ///
+    /// Same as `read_packed` but reuses the capacity of an existing `Vec`
+    #[cfg(feature = "alloc")]
     #[cfg_attr(std, inline)]
-    pub fn read_packed<'a, M, F>(&mut self, bytes: &'a [u8], mut read: F) -> Result<Vec<M>>
+    pub fn read_packed_into<'a, M, F>(
+        &mut self,
+        bytes: &'a [u8],
+        v: &mut Vec<M>,
+        mut read: F,
+    ) -> Result<()>
     where
         F: FnMut(&mut BytesReader, &'a [u8]) -> Result<M>,
+    {
+        self.read_packed_with(bytes, |r, b| {
+            v.push(read(r, b)?);
+            Ok(())
+        })
+    }
+
+    /// Reads packed repeated field, calling `read` for each of its items
+    ///
+    /// Same as `read_packed_into` without a `Vec`, e.g. to push the items into a fixed capacity
+    /// collection
+    #[cfg_attr(std, inline)]
+    pub fn read_packed_with<'a, F>(&mut self, bytes: &'a [u8], mut read: F) -> Result<()>
+    where
+        F: FnMut(&mut BytesReader, &'a [u8]) -> Result<()>,
     {
         self.read_len_varint(bytes, |r, b| {
-            let mut v = Vec::new();
             while !r.is_eof() {
-                v.push(read(r, b)?);
+                read(r, b)?;
             }
-            Ok(v)
+            Ok(())
         })
     }
 
-    /// Reads packed repeated field where M can directly be transmutted from raw bytes
+    /// Reads packed repeated field of fixed size items (fixed32, fixed64, float, double etc.)
+    ///
+    /// The data is borrowed when the host is little endian and `bytes` is suitably aligned for
+    /// `M`, else it is decoded into an owned `Vec`.
     ///
     /// Note: packed field are stored as a variable length chunk of data, while regular repeated
     /// fields behaves like an iterator, yielding their tag everytime
+    #[cfg(feature = "alloc")]
     #[cfg_attr(std, inline)]
-    pub fn read_packed_fixed<'a, M>(&mut self, bytes: &'a [u8]) -> Result<&'a [M]> {
+    pub fn read_packed_fixed<'a, M: PackedFixed>(
+        &mut self,
+        bytes: &'a [u8],
+    ) -> Result<Cow<'a, [M]>> {
         let len = self.read_varint32(bytes)? as usize;
         if self.len() < len {
             return Err(Error::UnexpectedEndOfBuffer);
         }
-        let n = len / ::core::mem::size_of::<M>();
-        let slice = unsafe {
-            ::core::slice::from_raw_parts(
-                bytes.get_unchecked(self.start) as *const u8 as *const M,
-                n,
-            )
-        };
+        let size = ::core::mem::size_of::<M>();
+        let trailing = len % size;
+        if self.strict && trailing > 0 {
+            return Err(Error::TrailingBytes(trailing));
+        }
+        let data = bytes
+            .get(self.start..self.start + len - trailing)
+            .ok_or(Error::UnexpectedEndOfBuffer)?;
         self.start += len;
-        Ok(slice)
+        Ok(match packed::cast_slice(data) {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(data.chunks_exact(size).map(M::from_le_slice).collect()),
+        })
     }
 
     /// Reads a nested message
@@ -472,6 +604,18 @@ impl BytesReader {
         self.read_len_varint(bytes, M::from_reader)
     }
 
+    /// Reads a nested message into an existing one
+    ///
+    /// First reads a varint and interprets it as the length of the message, then calls
+    /// `MessageRead::merge_from_reader`
+    #[cfg_attr(std, inline)]
+    pub fn merge_message<'a, M>(&mut self, bytes: &'a [u8], msg: &mut M) -> Result<()>
+    where
+        M: MessageRead<'a>,
+    {
+        self.read_len_varint(bytes, |r, b| msg.merge_from_reader(r, b))
+    }
+
     /// Reads a nested message
     ///
     /// Reads just the message and does not try to read it's size first.
@@ -485,11 +629,55 @@ impl BytesReader {
     }
 
     /// Reads a map item: (key, value)
+    ///
+    /// Reads field 1 as the key and field 2 as the value, whatever their wire type. A missing key
+    /// or value gets its default value and unknown fields are skipped.
+    #[deprecated(note = "use `read_map_entry`, which also checks the wire types of the key and value")]
     #[cfg_attr(std, inline)]
     pub fn read_map<'a, K, V, F, G>(
         &mut self,
         bytes: &'a [u8],
+        read_key: F,
+        read_val: G,
+    ) -> Result<(K, V)>
+    where
+        F: FnMut(&mut BytesReader, &'a [u8]) -> Result<K>,
+        G: FnMut(&mut BytesReader, &'a [u8]) -> Result<V>,
+        K: ::core::fmt::Debug + Default,
+        V: ::core::fmt::Debug + Default,
+    {
+        self.read_map_fields(bytes, |t| t >> 3 == 1, read_key, |t| t >> 3 == 2, read_val)
+    }
+
+    /// Reads a map item: (key, value)
+    ///
+    /// `tag_key` and `tag_val` are the expected tags of the key (field 1) and value (field 2),
+    /// like in `Writer::write_map`. A missing key or value gets its default value, unknown fields
+    /// are skipped and a key or value with an unexpected wire type is an `Error::Map`.
+    #[cfg_attr(std, inline)]
+    pub fn read_map_entry<'a, K, V, F, G>(
+        &mut self,
+        bytes: &'a [u8],
+        tag_key: u32,
+        read_key: F,
+        tag_val: u32,
+        read_val: G,
+    ) -> Result<(K, V)>
+    where
+        F: FnMut(&mut BytesReader, &'a [u8]) -> Result<K>,
+        G: FnMut(&mut BytesReader, &'a [u8]) -> Result<V>,
+        K: ::core::fmt::Debug + Default,
+        V: ::core::fmt::Debug + Default,
+    {
+        self.read_map_fields(bytes, |t| t == tag_key, read_key, |t| t == tag_val, read_val)
+    }
+
+    fn read_map_fields<'a, K, V, F, G>(
+        &mut self,
+        bytes: &'a [u8],
+        is_key: impl Fn(u32) -> bool,
         mut read_key: F,
+        is_val: impl Fn(u32) -> bool,
         mut read_val: G,
     ) -> Result<(K, V)>
     where
@@ -502,11 +690,15 @@ impl BytesReader {
             let mut k = K::default();
             let mut v = V::default();
             while !r.is_eof() {
-                let t = r.read_u8(bytes)?;
-                match t >> 3 {
-                    1 => k = read_key(r, bytes)?,
-                    2 => v = read_val(r, bytes)?,
-                    t => return Err(Error::Map(t)),
+                let t = r.next_tag(bytes)?;
+                if is_key(t) {
+                    k = read_key(r, bytes)?;
+                } else if is_val(t) {
+                    v = read_val(r, bytes)?;
+                } else if t >> 3 <= 2 {
+                    return Err(Error::Map(t as u8));
+                } else {
+                    r.read_unknown(bytes, t)?;
                 }
             }
             Ok((k, v))
@@ -548,6 +740,13 @@ impl BytesReader {
         }
     }
 
+    /// Reads unknown data like `read_unknown` and returns its raw bytes, length included
+    pub(crate) fn read_unknown_bytes<'a>(&mut self, bytes: &'a [u8], tag_value: u32) -> Result<&'a [u8]> {
+        let start = self.start;
+        self.read_unknown(bytes, tag_value)?;
+        Ok(&bytes[start..self.start])
+    }
+
     /// Gets the remaining length of bytes not read yet
     #[cfg_attr(std, inline(always))]
     pub fn len(&self) -> usize {
@@ -614,11 +813,13 @@ impl BytesReader {
 ///     println!("Found {} foos and {} bars!", foobar.foos.len(), foobar.bars.len());
 /// }
 /// ```
+#[cfg(feature = "alloc")]
 pub struct Reader {
     buffer: Vec<u8>,
     inner: BytesReader,
 }
 
+#[cfg(feature = "alloc")]
 impl Reader {
     /// Creates a new `Reader`
     #[cfg(feature = "std")]
@@ -645,6 +846,7 @@ impl Reader {
         let reader = BytesReader {
             start: 0,
             end: bytes.len(),
+            strict: false,
         };
         Reader {
             buffer: bytes,
@@ -678,6 +880,18 @@ pub fn deserialize_from_slice<'a, M: MessageRead<'a>>(bytes: &'a [u8]) -> Result
     reader.read_message::<M>(bytes)
 }
 
+/// Deserialize a `MessageRead from a `&[u8]` in strict mode
+///
+/// Only canonical encodings are accepted, without any byte after the message
+pub fn deserialize_from_slice_strict<'a, M: MessageRead<'a>>(bytes: &'a [u8]) -> Result<M> {
+    let mut reader = BytesReader::from_bytes_strict(bytes);
+    let msg = reader.read_message::<M>(bytes)?;
+    if !reader.is_eof() {
+        return Err(Error::TrailingBytes(reader.len()));
+    }
+    Ok(msg)
+}
+
 #[test]
 fn test_varint() {
     let data = [0x96, 0x01];
@@ -716,3 +930,90 @@ fn read_size_overflowing_unknown() {
 
     assert!(matches!(e, Error::Varint), "{:?}", e);
 }
+
+#[test]
+fn test_strict_varint() {
+    // 150 padded with a zero continuation byte: valid but not minimal
+    let data = [0x96, 0x81, 0x00];
+    let mut r = BytesReader::from_bytes(&data[..]);
+    assert_eq!(150, r.read_varint32(&data[..]).unwrap());
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(matches!(
+        r.read_varint64(&data[..]),
+        Err(Error::NonCanonical(_))
+    ));
+
+    // 2^32 does not fit in a uint32
+    let data = [0x80, 0x80, 0x80, 0x80, 0x10];
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(matches!(
+        r.read_uint32(&data[..]),
+        Err(Error::NonCanonical(_))
+    ));
+
+    // -1 as int32 is sign extended but not as uint32
+    let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert_eq!(-1, r.read_int32(&data[..]).unwrap());
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(r.read_uint32(&data[..]).is_err());
+    let data = [0xff, 0xff, 0xff, 0xff, 0x0f];
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(r.read_int32(&data[..]).is_err());
+
+    // 64-bit overflow
+    let data = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(r.read_uint64(&data[..]).is_err());
+}
+
+#[test]
+fn test_strict_bool() {
+    let data = [0x02];
+    let mut r = BytesReader::from_bytes(&data[..]);
+    assert!(r.read_bool(&data[..]).unwrap());
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(matches!(
+        r.read_bool(&data[..]),
+        Err(Error::NonCanonical(_))
+    ));
+}
+
+#[test]
+fn test_strict_length() {
+    // packed fixed32 with a length which is not a multiple of 4
+    let data = [0x05, 1, 0, 0, 0, 2];
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(matches!(
+        r.read_packed_fixed::<u32>(&data[..]),
+        Err(Error::TrailingBytes(1))
+    ));
+
+    // message overflowing its length
+    struct Varint(u32);
+    impl<'a> MessageRead<'a> for Varint {
+        fn from_reader(r: &mut BytesReader, bytes: &[u8]) -> Result<Self> {
+            r.read_varint32(bytes).map(Varint)
+        }
+    }
+    let data = [0x01, 0x96, 0x01];
+    let mut r = BytesReader::from_bytes(&data[..]);
+    assert_eq!(150, r.read_message::<Varint>(&data[..]).unwrap().0);
+    let mut r = BytesReader::from_bytes_strict(&data[..]);
+    assert!(r.read_message::<Varint>(&data[..]).is_err());
+
+    // trailing bytes after the message
+    struct Empty;
+    impl<'a> MessageRead<'a> for Empty {
+        fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+            r.read_to_end();
+            Ok(Empty)
+        }
+    }
+    let data = [0x00, 0x00];
+    assert!(deserialize_from_slice::<Empty>(&data[..]).is_ok());
+    assert!(matches!(
+        deserialize_from_slice_strict::<Empty>(&data[..]),
+        Err(Error::TrailingBytes(1))
+    ));
+}
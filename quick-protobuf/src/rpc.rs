@@ -0,0 +1,110 @@
+//! A module to call the services generated by pb-rs, independently of the transport
+//!
+//! With `--generate_services`, pb-rs generates for each `service Greeter` of a .proto file:
+//!
+//! - a `Greeter` trait, with a method per rpc, to implement on the server side
+//! - a `GreeterServer<S: Greeter>` [`Service`], decoding the requests, calling `S` and encoding
+//!   the responses
+//! - a `GreeterClient<T: Transport>` stub, encoding the requests and decoding the responses
+//!   sent through `T`
+//!
+//! The request or response of a unary rpc is encoded as a single message. The messages of a
+//! streaming request or response are buffered, each one prefixed by its length.
+//!
+//! [`Loopback`] connects a client directly to a server, e.g. for tests.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use quick_protobuf::rpc::{self, Loopback, Service, Transport};
+//! use quick_protobuf::{Error, Result};
+//!
+//! // echoes the encoded requests, like a generated server of `rpc Echo(M) returns (M)`
+//! struct Echo;
+//!
+//! impl Service for Echo {
+//!     fn call(&self, method: &str, request: &[u8]) -> Result<Vec<u8>> {
+//!         match method {
+//!             "/echo.Echo/Echo" => Ok(request.to_vec()),
+//!             _ => Err(Error::UnknownMethod(method.to_string())),
+//!         }
+//!     }
+//! }
+//!
+//! let mut transport = Loopback(Echo);
+//! assert_eq!(vec![8, 1], transport.call("/echo.Echo/Echo", &[8, 1]).unwrap());
+//! assert!(transport.call("/echo.Echo/Other", &[]).is_err());
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::errors::Result;
+use crate::message::{MessageRead, MessageWrite};
+use crate::reader::BytesReader;
+use crate::writer::{BytesWriter, Writer};
+
+/// A way to send encoded requests to a server, e.g. over HTTP/2
+pub trait Transport {
+    /// Sends the encoded `request` of `method` and returns the encoded response
+    ///
+    /// `method` is the full name of the rpc, like the path of a gRPC call, e.g.
+    /// `/helloworld.Greeter/SayHello`.
+    fn call(&mut self, method: &str, request: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// The server side of a service, handling encoded requests
+pub trait Service {
+    /// Handles the encoded `request` of `method` and returns the encoded response
+    ///
+    /// Fails with `Error::UnknownMethod` if the service has no such method.
+    fn call(&self, method: &str, request: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// A `Transport` calling a `Service` in process, without any IO
+#[derive(Debug, Default, Clone)]
+pub struct Loopback<S>(pub S);
+
+impl<S: Service> Transport for Loopback<S> {
+    fn call(&mut self, method: &str, request: &[u8]) -> Result<Vec<u8>> {
+        self.0.call(method, request)
+    }
+}
+
+/// Encodes the request or response of a unary rpc
+pub fn encode<M: MessageWrite>(message: &M) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; message.get_size()];
+    message.write_message(&mut Writer::new(BytesWriter::new(&mut bytes)))?;
+    Ok(bytes)
+}
+
+/// Decodes the request or response of a unary rpc
+pub fn decode<'a, M: MessageRead<'a>>(bytes: &'a [u8]) -> Result<M> {
+    M::from_reader(&mut BytesReader::from_bytes(bytes), bytes)
+}
+
+/// Encodes the messages of a streaming request or response
+pub fn encode_stream<M: MessageWrite>(messages: &[M]) -> Result<Vec<u8>> {
+    let len = messages
+        .iter()
+        .map(|m| crate::sizeofs::sizeof_len(m.get_size()))
+        .sum();
+    let mut bytes = vec![0; len];
+    let mut writer = Writer::new(BytesWriter::new(&mut bytes));
+    for m in messages {
+        writer.write_message(m)?;
+    }
+    Ok(bytes)
+}
+
+/// Decodes the messages of a streaming request or response
+pub fn decode_stream<'a, M: MessageRead<'a>>(bytes: &'a [u8]) -> Result<Vec<M>> {
+    let mut reader = BytesReader::from_bytes(bytes);
+    let mut messages = Vec::new();
+    while !reader.is_eof() {
+        messages.push(reader.read_message(bytes)?);
+    }
+    Ok(messages)
+}
@@ -2,6 +2,7 @@
 
 use crate::errors::{Error, Result};
 use crate::message::MessageWrite;
+use crate::packed::{self, PackedFixed};
 
 use byteorder::{ByteOrder, LittleEndian as LE};
 
@@ -61,6 +62,11 @@ impl<W: WriterBackend> Writer<W> {
         Writer { inner: w }
     }
 
+    /// Gets a mutable reference to the underlying `WriterBackend`
+    pub(crate) fn backend_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
     /// Writes a byte which is NOT internally coded as a `varint`
     pub fn write_u8(&mut self, byte: u8) -> Result<()> {
         self.inner.pb_write_u8(byte)
@@ -195,16 +201,21 @@ impl<W: WriterBackend> Writer<W> {
         Ok(())
     }
 
-    /// Writes packed repeated field when we know the size of items
+    /// Writes packed repeated field of fixed size items (fixed32, fixed64, float, double etc.)
     ///
-    /// `item_size` is internally used to compute the total length
-    /// As the length is fixed (and the same as rust internal representation, we can directly dump
-    /// all data at once
+    /// The items are written in little endian, all at once when the host is little endian
     #[cfg_attr(std, inline)]
-    pub fn write_packed_fixed<M>(&mut self, v: &[M]) -> Result<()> {
-        let len = v.len() * ::core::mem::size_of::<M>();
-        let bytes = unsafe { ::core::slice::from_raw_parts(v.as_ptr() as *const u8, len) };
-        self.write_bytes(bytes)
+    pub fn write_packed_fixed<M: PackedFixed>(&mut self, v: &[M]) -> Result<()> {
+        self.write_varint(::core::mem::size_of_val(v) as u64)?;
+        match packed::as_bytes(v) {
+            Some(bytes) => self.inner.pb_write_all(bytes),
+            None => {
+                for m in v {
+                    m.write_le(&mut self.inner)?;
+                }
+                Ok(())
+            }
+        }
     }
 
     /// Writes a message which implements `MessageWrite`
@@ -255,34 +266,28 @@ impl<W: WriterBackend> Writer<W> {
     /// Writes tag then repeated field
     ///
     /// If array is empty, then do nothing (do not even write the tag)
-    pub fn write_packed_fixed_with_tag<M>(&mut self, tag: u32, v: &[M]) -> Result<()> {
+    pub fn write_packed_fixed_with_tag<M: PackedFixed>(&mut self, tag: u32, v: &[M]) -> Result<()> {
         if v.is_empty() {
             return Ok(());
         }
 
         self.write_tag(tag)?;
-        let len = ::core::mem::size_of::<M>() * v.len();
-        let bytes = unsafe { ::core::slice::from_raw_parts(v.as_ptr() as *const u8, len) };
-        self.write_bytes(bytes)
+        self.write_packed_fixed(v)
     }
 
     /// Writes tag then repeated field with fixed length item size
     ///
+    /// `item_size` must be `size_of::<M>()`, it is only kept for backward compatibility.
+    ///
     /// If array is empty, then do nothing (do not even write the tag)
-    pub fn write_packed_fixed_size_with_tag<M>(
+    pub fn write_packed_fixed_size_with_tag<M: PackedFixed>(
         &mut self,
         tag: u32,
         v: &[M],
         item_size: usize,
     ) -> Result<()> {
-        if v.is_empty() {
-            return Ok(());
-        }
-        self.write_tag(tag)?;
-        let len = v.len() * item_size;
-        let bytes =
-            unsafe { ::core::slice::from_raw_parts(v as *const [M] as *const M as *const u8, len) };
-        self.write_bytes(bytes)
+        debug_assert_eq!(item_size, ::core::mem::size_of::<M>());
+        self.write_packed_fixed_with_tag(tag, v)
     }
 
     /// Write entire map
@@ -462,6 +467,48 @@ impl<'a> WriterBackend for BytesWriter<'a> {
     }
 }
 
+impl WriterBackend for &mut dyn WriterBackend {
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_u8(&mut self, x: u8) -> Result<()> {
+        (**self).pb_write_u8(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_u32(&mut self, x: u32) -> Result<()> {
+        (**self).pb_write_u32(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_i32(&mut self, x: i32) -> Result<()> {
+        (**self).pb_write_i32(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_f32(&mut self, x: f32) -> Result<()> {
+        (**self).pb_write_f32(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_u64(&mut self, x: u64) -> Result<()> {
+        (**self).pb_write_u64(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_i64(&mut self, x: i64) -> Result<()> {
+        (**self).pb_write_i64(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_f64(&mut self, x: f64) -> Result<()> {
+        (**self).pb_write_f64(x)
+    }
+
+    #[cfg_attr(std, inline(always))]
+    fn pb_write_all(&mut self, buf: &[u8]) -> Result<()> {
+        (**self).pb_write_all(buf)
+    }
+}
+
 #[cfg(feature = "std")]
 impl<W: std::io::Write> WriterBackend for W {
     #[inline(always)]
@@ -0,0 +1,12024 @@
+// Automatically generated rust module for 'mesos.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::super::*;
+
+/// Status is used to indicate the state of the scheduler and executor
+/// driver after function calls.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Status {
+    DRIVER_NOT_STARTED = 1,
+    DRIVER_RUNNING = 2,
+    DRIVER_ABORTED = 3,
+    DRIVER_STOPPED = 4,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::DRIVER_NOT_STARTED
+    }
+}
+
+impl From<i32> for Status {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Status::DRIVER_NOT_STARTED,
+            2 => Status::DRIVER_RUNNING,
+            3 => Status::DRIVER_ABORTED,
+            4 => Status::DRIVER_STOPPED,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "DRIVER_NOT_STARTED" => Status::DRIVER_NOT_STARTED,
+            "DRIVER_RUNNING" => Status::DRIVER_RUNNING,
+            "DRIVER_ABORTED" => Status::DRIVER_ABORTED,
+            "DRIVER_STOPPED" => Status::DRIVER_STOPPED,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Describes possible task states. IMPORTANT: Mesos assumes tasks that
+/// enter terminal states (see below) imply the task is no longer
+/// running and thus clean up any thing associated with the task
+/// (ultimately offering any resources being consumed by that task to
+/// another task).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TaskState {
+    /// Initial state. Framework status updates should not use.
+    TASK_STAGING = 6,
+    /// The task is being launched by the executor.
+    TASK_STARTING = 0,
+    TASK_RUNNING = 1,
+    /// NOTE: This should only be sent when the framework has
+    /// the TASK_KILLING_STATE capability.
+    ///
+    /// The task is being killed by the executor.
+    TASK_KILLING = 8,
+    /// The task finished successfully on its own without external interference.
+    ///
+    /// TERMINAL.
+    TASK_FINISHED = 2,
+    /// TERMINAL: The task failed to finish successfully.
+    TASK_FAILED = 3,
+    /// TERMINAL: The task was killed by the executor.
+    TASK_KILLED = 4,
+    /// TERMINAL: The task description contains an error.
+    TASK_ERROR = 7,
+    /// In Mesos 1.3, this will only be sent when the framework does NOT
+    /// opt-in to the PARTITION_AWARE capability.
+    ///
+    /// NOTE: This state is not always terminal. For example, tasks might
+    /// transition from TASK_LOST to TASK_RUNNING or other states when a
+    /// partitioned agent reregisters.
+    ///
+    /// The task failed but can be rescheduled.
+    TASK_LOST = 5,
+    /// The task failed to launch because of a transient error. The
+    /// task's executor never started running. Unlike TASK_ERROR, the
+    /// task description is valid -- attempting to launch the task again
+    /// may be successful.
+    ///
+    /// TERMINAL.
+    TASK_DROPPED = 9,
+    /// The task was running on an agent that has lost contact with the
+    /// master, typically due to a network failure or partition. The task
+    /// may or may not still be running.
+    TASK_UNREACHABLE = 10,
+    /// The task is no longer running. This can occur if the agent has
+    /// been terminated along with all of its tasks (e.g., the host that
+    /// was running the agent was rebooted). It might also occur if the
+    /// task was terminated due to an agent or containerizer error, or if
+    /// the task was preempted by the QoS controller in an
+    /// oversubscription scenario.
+    ///
+    /// TERMINAL.
+    TASK_GONE = 11,
+    /// The task was running on an agent that the master cannot contact;
+    /// the operator has asserted that the agent has been shutdown, but
+    /// this has not been directly confirmed by the master. If the
+    /// operator is correct, the task is not running and this is a
+    /// terminal state; if the operator is mistaken, the task may still
+    /// be running and might return to RUNNING in the future.
+    TASK_GONE_BY_OPERATOR = 12,
+    /// The master has no knowledge of the task. This is typically
+    /// because either (a) the master never had knowledge of the task, or
+    /// (b) the master forgot about the task because it garbage collected
+    /// its metadata about the task. The task may or may not still be
+    /// running.
+    TASK_UNKNOWN = 13,
+}
+
+impl Default for TaskState {
+    fn default() -> Self {
+        TaskState::TASK_STAGING
+    }
+}
+
+impl From<i32> for TaskState {
+    fn from(i: i32) -> Self {
+        match i {
+            6 => TaskState::TASK_STAGING,
+            0 => TaskState::TASK_STARTING,
+            1 => TaskState::TASK_RUNNING,
+            8 => TaskState::TASK_KILLING,
+            2 => TaskState::TASK_FINISHED,
+            3 => TaskState::TASK_FAILED,
+            4 => TaskState::TASK_KILLED,
+            7 => TaskState::TASK_ERROR,
+            5 => TaskState::TASK_LOST,
+            9 => TaskState::TASK_DROPPED,
+            10 => TaskState::TASK_UNREACHABLE,
+            11 => TaskState::TASK_GONE,
+            12 => TaskState::TASK_GONE_BY_OPERATOR,
+            13 => TaskState::TASK_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskState {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "TASK_STAGING" => TaskState::TASK_STAGING,
+            "TASK_STARTING" => TaskState::TASK_STARTING,
+            "TASK_RUNNING" => TaskState::TASK_RUNNING,
+            "TASK_KILLING" => TaskState::TASK_KILLING,
+            "TASK_FINISHED" => TaskState::TASK_FINISHED,
+            "TASK_FAILED" => TaskState::TASK_FAILED,
+            "TASK_KILLED" => TaskState::TASK_KILLED,
+            "TASK_ERROR" => TaskState::TASK_ERROR,
+            "TASK_LOST" => TaskState::TASK_LOST,
+            "TASK_DROPPED" => TaskState::TASK_DROPPED,
+            "TASK_UNREACHABLE" => TaskState::TASK_UNREACHABLE,
+            "TASK_GONE" => TaskState::TASK_GONE,
+            "TASK_GONE_BY_OPERATOR" => TaskState::TASK_GONE_BY_OPERATOR,
+            "TASK_UNKNOWN" => TaskState::TASK_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Describes possible operation states.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OperationState {
+    /// Default value if the enum is not set. See MESOS-4997.
+    OPERATION_UNSUPPORTED = 0,
+    /// Initial state.
+    OPERATION_PENDING = 1,
+    /// TERMINAL: The operation was successfully applied.
+    OPERATION_FINISHED = 2,
+    /// TERMINAL: The operation failed to apply.
+    OPERATION_FAILED = 3,
+    /// TERMINAL: The operation description contains an error.
+    OPERATION_ERROR = 4,
+    /// TERMINAL: The operation was dropped due to a transient error.
+    OPERATION_DROPPED = 5,
+    /// The operation affects an agent that has lost contact with the master,
+    /// typically due to a network failure or partition. The operation may or may
+    /// not still be pending.
+    OPERATION_UNREACHABLE = 6,
+    /// The operation affected an agent that the master cannot contact;
+    /// the operator has asserted that the agent has been shutdown, but this has
+    /// not been directly confirmed by the master.
+    ///
+    /// If the operator is correct, the operation is not pending and this is a
+    /// terminal state; if the operator is mistaken, the operation may still be
+    /// pending and might return to a different state in the future.
+    OPERATION_GONE_BY_OPERATOR = 7,
+    /// The operation affects an agent that the master recovered from its
+    /// state, but that agent has not yet re-registered.
+    ///
+    /// The operation can transition to `OPERATION_UNREACHABLE` if the
+    /// corresponding agent is marked as unreachable, and will transition to
+    /// another status if the agent re-registers.
+    OPERATION_RECOVERING = 8,
+    /// The master has no knowledge of the operation. This is typically
+    /// because either (a) the master never had knowledge of the operation, or
+    /// (b) the master forgot about the operation because it garbage collected
+    /// its metadata about the operation. The operation may or may not still be
+    /// pending.
+    OPERATION_UNKNOWN = 9,
+}
+
+impl Default for OperationState {
+    fn default() -> Self {
+        OperationState::OPERATION_UNSUPPORTED
+    }
+}
+
+impl From<i32> for OperationState {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => OperationState::OPERATION_UNSUPPORTED,
+            1 => OperationState::OPERATION_PENDING,
+            2 => OperationState::OPERATION_FINISHED,
+            3 => OperationState::OPERATION_FAILED,
+            4 => OperationState::OPERATION_ERROR,
+            5 => OperationState::OPERATION_DROPPED,
+            6 => OperationState::OPERATION_UNREACHABLE,
+            7 => OperationState::OPERATION_GONE_BY_OPERATOR,
+            8 => OperationState::OPERATION_RECOVERING,
+            9 => OperationState::OPERATION_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationState {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "OPERATION_UNSUPPORTED" => OperationState::OPERATION_UNSUPPORTED,
+            "OPERATION_PENDING" => OperationState::OPERATION_PENDING,
+            "OPERATION_FINISHED" => OperationState::OPERATION_FINISHED,
+            "OPERATION_FAILED" => OperationState::OPERATION_FAILED,
+            "OPERATION_ERROR" => OperationState::OPERATION_ERROR,
+            "OPERATION_DROPPED" => OperationState::OPERATION_DROPPED,
+            "OPERATION_UNREACHABLE" => OperationState::OPERATION_UNREACHABLE,
+            "OPERATION_GONE_BY_OPERATOR" => OperationState::OPERATION_GONE_BY_OPERATOR,
+            "OPERATION_RECOVERING" => OperationState::OPERATION_RECOVERING,
+            "OPERATION_UNKNOWN" => OperationState::OPERATION_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// A unique ID assigned to a framework. A framework can reuse this ID
+/// in order to do failover (see MesosSchedulerDriver).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FrameworkID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for FrameworkID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FrameworkID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// A unique ID assigned to an offer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OfferID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for OfferID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for OfferID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// A unique ID assigned to an agent. Currently, an agent gets a new ID
+/// whenever it (re)registers with Mesos. Framework writers shouldn't
+/// assume any binding between an agent ID and and a hostname.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct AgentID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for AgentID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for AgentID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// A framework-generated ID to distinguish a task. The ID must remain
+/// unique while the task is active. A framework can reuse an ID _only_
+/// if the previous task with the same ID has reached a terminal state
+/// (e.g., TASK_FINISHED, TASK_KILLED, etc.). However, reusing task IDs
+/// is strongly discouraged (MESOS-2198).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for TaskID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TaskID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// A framework-generated ID to distinguish an executor. Only one
+/// executor with the same ID can be active on the same agent at a
+/// time. However, reusing executor IDs is discouraged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ExecutorID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for ExecutorID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ExecutorID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// ID used to uniquely identify a container. If the `parent` is not
+/// specified, the ID is a UUID generated by the agent to uniquely
+/// identify the container of an executor run. If the `parent` field is
+/// specified, it represents a nested container.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ContainerID<'a> {
+    pub value: Cow<'a, str>,
+    pub parent: Option<Box<mesos::v1::ContainerID<'a>>>,
+}
+
+impl<'a> MessageRead<'a> for ContainerID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+        self.parent = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, &mut **self.parent.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ContainerID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+        + self.parent.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        if let Some(ref s) = self.parent { w.write_with_tag(18, |w| w.write_message(&**s))?; }
+        Ok(())
+    }
+}
+
+/// A unique ID assigned to a resource provider. Currently, a resource
+/// provider gets a new ID whenever it (re)registers with Mesos.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ResourceProviderID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for ResourceProviderID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ResourceProviderID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// A framework-generated ID to distinguish an operation. The ID
+/// must be unique within the framework.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OperationID<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for OperationID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for OperationID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// Represents time since the epoch, in nanoseconds.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TimeInfo {
+    pub nanoseconds: i64,
+}
+
+impl<'a> MessageRead<'a> for TimeInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.nanoseconds = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.nanoseconds = r.read_int64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TimeInfo {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.nanoseconds) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_int64(*&self.nanoseconds))?;
+        Ok(())
+    }
+}
+
+/// Represents duration in nanoseconds.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DurationInfo {
+    pub nanoseconds: i64,
+}
+
+impl<'a> MessageRead<'a> for DurationInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.nanoseconds = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.nanoseconds = r.read_int64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for DurationInfo {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.nanoseconds) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_int64(*&self.nanoseconds))?;
+        Ok(())
+    }
+}
+
+/// A network address.
+///
+/// TODO(bmahler): Use this more widely.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Address<'a> {
+    /// May contain a hostname, IP address, or both.
+    pub hostname: Option<Cow<'a, str>>,
+    pub ip: Option<Cow<'a, str>>,
+    pub port: i32,
+}
+
+impl<'a> MessageRead<'a> for Address<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.hostname = Default::default();
+        self.ip = Default::default();
+        self.port = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.hostname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.ip = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(24) => self.port = r.read_int32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Address<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.hostname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.ip.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + 1 + sizeof_varint(*(&self.port) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.hostname { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.ip { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        w.write_with_tag(24, |w| w.write_int32(*&self.port))?;
+        Ok(())
+    }
+}
+
+/// Represents a URL.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct URL<'a> {
+    pub scheme: Cow<'a, str>,
+    pub address: mesos::v1::Address<'a>,
+    pub path: Option<Cow<'a, str>>,
+    pub query: Vec<mesos::v1::Parameter<'a>>,
+    pub fragment: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for URL<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.scheme = Default::default();
+        self.address = Default::default();
+        self.path = Default::default();
+        self.query.clear();
+        self.fragment = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.scheme = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, &mut self.address)?,
+                Ok(26) => self.path = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.query.push(r.read_message::<mesos::v1::Parameter>(bytes)?),
+                Ok(42) => self.fragment = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for URL<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.scheme).len())
+        + 1 + sizeof_len((&self.address).get_size())
+        + self.path.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.query.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.fragment.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.scheme))?;
+        w.write_with_tag(18, |w| w.write_message(&self.address))?;
+        if let Some(ref s) = self.path { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.query { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.fragment { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Represents an interval, from a given start time over a given duration.
+/// This interval pertains to an unavailability event, such as maintenance,
+/// and is not a generic interval.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Unavailability {
+    pub start: mesos::v1::TimeInfo,
+    /// When added to `start`, this represents the end of the interval.
+    /// If unspecified, the duration is assumed to be infinite.
+    pub duration: Option<mesos::v1::DurationInfo>,
+}
+
+impl<'a> MessageRead<'a> for Unavailability {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.start = Default::default();
+        self.duration = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.start)?,
+                Ok(18) => r.merge_message(bytes, self.duration.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Unavailability {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.start).get_size())
+        + self.duration.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.start))?;
+        if let Some(ref s) = self.duration { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Represents a single machine, which may hold one or more agents.
+///
+/// NOTE: In order to match an agent to a machine, both the `hostname` and
+/// `ip` must match the values advertised by the agent to the master.
+/// Hostname is not case-sensitive.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MachineID<'a> {
+    pub hostname: Option<Cow<'a, str>>,
+    pub ip: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for MachineID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.hostname = Default::default();
+        self.ip = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.hostname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.ip = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MachineID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.hostname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.ip.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.hostname { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.ip { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Holds information about a single machine, its `mode`, and any other
+/// relevant information which may affect the behavior of the machine.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MachineInfo<'a> {
+    pub id: mesos::v1::MachineID<'a>,
+    pub mode: Option<mesos::v1::mod_MachineInfo::Mode>,
+    /// Signifies that the machine may be unavailable during the given interval.
+    /// See comments in `Unavailability` and for the `unavailability` fields
+    /// in `Offer` and `InverseOffer` for more information.
+    pub unavailability: Option<mesos::v1::Unavailability>,
+}
+
+impl<'a> MessageRead<'a> for MachineInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.mode = Default::default();
+        self.unavailability = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.id)?,
+                Ok(16) => self.mode = Some(r.read_enum(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.unavailability.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MachineInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).get_size())
+        + self.mode.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.unavailability.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.id))?;
+        if let Some(ref s) = self.mode { w.write_with_tag(16, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.unavailability { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `MachineInfo`
+pub mod mod_MachineInfo {
+
+
+/// Describes the several states that a machine can be in.  A `Mode`
+/// applies to a machine and to all associated agents on the machine.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    /// In this mode, a machine is behaving normally;
+    /// offering resources, executing tasks, etc.
+    UP = 1,
+    /// In this mode, all agents on the machine are expected to cooperate with
+    /// frameworks to drain resources.  In general, draining is done ahead of
+    /// a pending `unavailability`.  The resources should be drained so as to
+    /// maximize utilization prior to the maintenance but without knowingly
+    /// violating the frameworks' requirements.
+    DRAINING = 2,
+    /// In this mode, a machine is not running any tasks and will not offer
+    /// any of its resources.  Agents on the machine will not be allowed to
+    /// register with the master.
+    DOWN = 3,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::UP
+    }
+}
+
+impl From<i32> for Mode {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Mode::UP,
+            2 => Mode::DRAINING,
+            3 => Mode::DOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Mode {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UP" => Mode::UP,
+            "DRAINING" => Mode::DRAINING,
+            "DOWN" => Mode::DOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a framework.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FrameworkInfo<'a> {
+    /// Used to determine the Unix user that an executor or task should be
+    /// launched as.
+    ///
+    /// When using the MesosSchedulerDriver, if the field is set to an
+    /// empty string, it will automagically set it to the current user.
+    ///
+    /// When using the HTTP Scheduler API, the user has to be set
+    /// explicitly.
+    pub user: Cow<'a, str>,
+    /// Name of the framework that shows up in the Mesos Web UI.
+    pub name: Cow<'a, str>,
+    /// Note that 'id' is only available after a framework has
+    /// registered, however, it is included here in order to facilitate
+    /// scheduler failover (i.e., if it is set then the
+    /// MesosSchedulerDriver expects the scheduler is performing
+    /// failover).
+    pub id: Option<mesos::v1::FrameworkID<'a>>,
+    /// The amount of time (in seconds) that the master will wait for the
+    /// scheduler to failover before it tears down the framework by
+    /// killing all its tasks/executors. This should be non-zero if a
+    /// framework expects to reconnect after a failure and not lose its
+    /// tasks/executors.
+    ///
+    /// NOTE: To avoid accidental destruction of tasks, production
+    /// frameworks typically set this to a large value (e.g., 1 week).
+    pub failover_timeout: f64,
+    /// If set, agents running tasks started by this framework will write
+    /// the framework pid, executor pids and status updates to disk. If
+    /// the agent exits (e.g., due to a crash or as part of upgrading
+    /// Mesos), this checkpointed data allows the restarted agent to
+    /// reconnect to executors that were started by the old instance of
+    /// the agent. Enabling checkpointing improves fault tolerance, at
+    /// the cost of a (usually small) increase in disk I/O.
+    pub checkpoint: bool,
+    pub roles: Vec<Cow<'a, str>>,
+    /// Used to indicate the current host from which the scheduler is
+    /// registered in the Mesos Web UI. If set to an empty string Mesos
+    /// will automagically set it to the current hostname if one is
+    /// available.
+    pub hostname: Option<Cow<'a, str>>,
+    /// This field should match the credential's principal the framework
+    /// uses for authentication. This field is used for framework API
+    /// rate limiting and dynamic reservations. It should be set even
+    /// if authentication is not enabled if these features are desired.
+    pub principal: Option<Cow<'a, str>>,
+    /// This field allows a framework to advertise its web UI, so that
+    /// the Mesos web UI can link to it. It is expected to be a full URL,
+    /// for example http://my-scheduler.example.com:8080/.
+    pub webui_url: Option<Cow<'a, str>>,
+    /// This field allows a framework to advertise its set of
+    /// capabilities (e.g., ability to receive offers for revocable
+    /// resources).
+    pub capabilities: Vec<mesos::v1::mod_FrameworkInfo::Capability>,
+    /// Labels are free-form key value pairs supplied by the framework
+    /// scheduler (e.g., to describe additional functionality offered by
+    /// the framework). These labels are not interpreted by Mesos itself.
+    /// Labels should not contain duplicate key-value pairs.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for FrameworkInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = FrameworkInfo {
+            failover_timeout: 0.0f64,
+            role: Cow::Borrowed("*"),
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.user = Default::default();
+        self.name = Default::default();
+        self.id = Default::default();
+        self.failover_timeout = 0.0f64;
+        self.checkpoint = Default::default();
+        self.roles.clear();
+        self.hostname = Default::default();
+        self.principal = Default::default();
+        self.webui_url = Default::default();
+        self.capabilities.clear();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.user = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => r.merge_message(bytes, self.id.get_or_insert_with(Default::default))?,
+                Ok(33) => self.failover_timeout = r.read_double(bytes)?,
+                Ok(40) => self.checkpoint = r.read_bool(bytes)?,
+                Ok(98) => self.roles.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => self.hostname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(66) => self.principal = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(74) => self.webui_url = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(82) => self.capabilities.push(r.read_message::<mesos::v1::mod_FrameworkInfo::Capability>(bytes)?),
+                Ok(90) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FrameworkInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.user).len())
+        + 1 + sizeof_len((&self.name).len())
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.failover_timeout == 0.0f64 { 0 } else { 1 + 8 }
+        + if self.checkpoint == false { 0 } else { 1 + sizeof_varint(*(&self.checkpoint) as u64) }
+        + self.roles.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.hostname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.principal.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.webui_url.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.capabilities.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.user))?;
+        w.write_with_tag(18, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.id { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if self.failover_timeout != 0.0f64 { w.write_with_tag(33, |w| w.write_double(*&self.failover_timeout))?; }
+        if self.checkpoint != false { w.write_with_tag(40, |w| w.write_bool(*&self.checkpoint))?; }
+        for s in &self.roles { w.write_with_tag(98, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.hostname { w.write_with_tag(58, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.principal { w.write_with_tag(66, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.webui_url { w.write_with_tag(74, |w| w.write_string(&**s))?; }
+        for s in &self.capabilities { w.write_with_tag(82, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(90, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `FrameworkInfo`
+pub mod mod_FrameworkInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Capability {
+    /// Enum fields should be optional, see: MESOS-4997.
+    pub type_pb: Option<mesos::v1::mod_FrameworkInfo::mod_Capability::Type>,
+}
+
+impl<'a> MessageRead<'a> for Capability {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Capability {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Capability`
+pub mod mod_Capability {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    /// This must be the first enum value in this list, to
+    /// ensure that if 'type' is not set, the default value
+    /// is UNKNOWN. This enables enum values to be added
+    /// in a backwards-compatible way. See: MESOS-4997.
+    UNKNOWN = 0,
+    /// Receive offers with revocable resources. See 'Resource'
+    /// message for details.
+    REVOCABLE_RESOURCES = 1,
+    /// Receive the TASK_KILLING TaskState when a task is being
+    /// killed by an executor. The executor will examine this
+    /// capability to determine whether it can send TASK_KILLING.
+    TASK_KILLING_STATE = 2,
+    /// Indicates whether the framework is aware of GPU resources.
+    /// Frameworks that are aware of GPU resources are expected to
+    /// avoid placing non-GPU workloads on GPU agents, in order
+    /// to avoid occupying a GPU agent and preventing GPU workloads
+    /// from running! Currently, if a framework is unaware of GPU
+    /// resources, it will not be offered *any* of the resources on
+    /// an agent with GPUs. This restriction is in place because we
+    /// do not have a revocation mechanism that ensures GPU workloads
+    /// can evict GPU agent occupants if necessary.
+    ///
+    /// TODO(bmahler): As we add revocation we can relax the
+    /// restriction here. See MESOS-5634 for more information.
+    GPU_RESOURCES = 3,
+    /// Receive offers with resources that are shared.
+    SHARED_RESOURCES = 4,
+    /// Indicates that (1) the framework is prepared to handle the
+    /// following TaskStates: TASK_UNREACHABLE, TASK_DROPPED,
+    /// TASK_GONE, TASK_GONE_BY_OPERATOR, and TASK_UNKNOWN, and (2)
+    /// the framework will assume responsibility for managing
+    /// partitioned tasks that reregister with the master.
+    ///
+    /// Frameworks that enable this capability can define how they
+    /// would like to handle partitioned tasks. Frameworks will
+    /// receive TASK_UNREACHABLE for tasks on agents that are
+    /// partitioned from the master.
+    ///
+    /// Without this capability, frameworks will receive TASK_LOST
+    /// for tasks on partitioned agents.
+    /// NOTE: Prior to Mesos 1.5, such tasks will be killed by Mesos
+    /// when the agent reregisters (unless the master has failed over).
+    /// However due to the lack of benefit in maintaining different
+    /// behaviors depending on whether the master has failed over
+    /// (see MESOS-7215), as of 1.5, Mesos will not kill these
+    /// tasks in either case.
+    PARTITION_AWARE = 5,
+    /// This expresses the ability for the framework to be
+    /// "multi-tenant" via using the newly introduced `roles`
+    /// field, and examining `Offer.allocation_info` to determine
+    /// which role the offers are being made to. We also
+    /// expect that "single-tenant" schedulers eventually
+    /// provide this and move away from the deprecated
+    /// `role` field.
+    MULTI_ROLE = 6,
+    /// This capability has two effects for a framework.
+    ///
+    /// (1) The framework is offered resources in a new format.
+    ///
+    /// ```text
+    /// The offered resources have the `Resource.reservations` field set
+    /// rather than `Resource.role` and `Resource.reservation`. In short,
+    /// an empty `reservations` field denotes unreserved resources, and
+    /// each `ReservationInfo` in the `reservations` field denotes a
+    /// reservation that refines the previous one.
+    ///
+    /// See the 'Resource Format' section for more details.
+    /// ```
+    ///
+    /// (2) The framework can create refined reservations.
+    ///
+    /// ```text
+    /// A framework can refine an existing reservation via the
+    /// `Resource.reservations` field. For example, a reservation for role
+    /// `eng` can be refined to `eng/front_end`.
+    ///
+    /// See `ReservationInfo.reservations` for more details.
+    /// ```
+    ///
+    /// NOTE: Without this capability, a framework is not offered resources
+    /// that have refined reservations. A resource is said to have refined
+    /// reservations if it uses the `Resource.reservations` field, and
+    /// `Resource.reservations_size() > 1`.
+    ///
+    /// EXPERIMENTAL.
+    RESERVATION_REFINEMENT = 7,
+    /// Indicates that the framework is prepared to receive offers
+    /// for agents whose region is different from the master's
+    /// region. Network links between hosts in different regions
+    /// typically have higher latency and lower bandwidth than
+    /// network links within a region, so frameworks should be
+    /// careful to only place suitable workloads in remote regions.
+    /// Frameworks that are not region-aware will never receive
+    /// offers for remote agents; region-aware frameworks are assumed
+    /// to implement their own logic to decide which workloads (if
+    /// any) are suitable for placement on remote agents.
+    REGION_AWARE = 8,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::REVOCABLE_RESOURCES,
+            2 => Type::TASK_KILLING_STATE,
+            3 => Type::GPU_RESOURCES,
+            4 => Type::SHARED_RESOURCES,
+            5 => Type::PARTITION_AWARE,
+            6 => Type::MULTI_ROLE,
+            7 => Type::RESERVATION_REFINEMENT,
+            8 => Type::REGION_AWARE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "REVOCABLE_RESOURCES" => Type::REVOCABLE_RESOURCES,
+            "TASK_KILLING_STATE" => Type::TASK_KILLING_STATE,
+            "GPU_RESOURCES" => Type::GPU_RESOURCES,
+            "SHARED_RESOURCES" => Type::SHARED_RESOURCES,
+            "PARTITION_AWARE" => Type::PARTITION_AWARE,
+            "MULTI_ROLE" => Type::MULTI_ROLE,
+            "RESERVATION_REFINEMENT" => Type::RESERVATION_REFINEMENT,
+            "REGION_AWARE" => Type::REGION_AWARE,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// Describes a general non-interpreting non-killing check for a task or
+/// executor (or any arbitrary process/command). A type is picked by
+/// specifying one of the optional fields. Specifying more than one type
+/// is an error.
+///
+/// NOTE: This API is subject to change and the related feature is experimental.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CheckInfo<'a> {
+    /// The type of the check.
+    pub type_pb: Option<mesos::v1::mod_CheckInfo::Type>,
+    /// Command check.
+    pub command: Option<mesos::v1::mod_CheckInfo::Command<'a>>,
+    /// HTTP check.
+    pub http: Option<mesos::v1::mod_CheckInfo::Http<'a>>,
+    /// TCP check.
+    pub tcp: Option<mesos::v1::mod_CheckInfo::Tcp>,
+    /// Amount of time to wait to start checking the task after it
+    /// transitions to `TASK_RUNNING` or `TASK_STARTING` if the latter
+    /// is used by the executor.
+    pub delay_seconds: f64,
+    /// Interval between check attempts, i.e., amount of time to wait after
+    /// the previous check finished or timed out to start the next check.
+    pub interval_seconds: f64,
+    /// Amount of time to wait for the check to complete. Zero means infinite
+    /// timeout.
+    ///
+    /// After this timeout, the check attempt is aborted and no result is
+    /// reported. Note that this may be considered a state change and hence
+    /// may trigger a check status change delivery to the corresponding
+    /// scheduler. See `CheckStatusInfo` for more details.
+    pub timeout_seconds: f64,
+}
+
+impl<'a> MessageRead<'a> for CheckInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = CheckInfo {
+            delay_seconds: 15.0f64,
+            interval_seconds: 10.0f64,
+            timeout_seconds: 20.0f64,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.command = Default::default();
+        self.http = Default::default();
+        self.tcp = Default::default();
+        self.delay_seconds = 15.0f64;
+        self.interval_seconds = 10.0f64;
+        self.timeout_seconds = 20.0f64;
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.http.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.tcp.get_or_insert_with(Default::default))?,
+                Ok(33) => self.delay_seconds = r.read_double(bytes)?,
+                Ok(41) => self.interval_seconds = r.read_double(bytes)?,
+                Ok(49) => self.timeout_seconds = r.read_double(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CheckInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.http.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tcp.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.delay_seconds == 15.0f64 { 0 } else { 1 + 8 }
+        + if self.interval_seconds == 10.0f64 { 0 } else { 1 + 8 }
+        + if self.timeout_seconds == 20.0f64 { 0 } else { 1 + 8 }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.http { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.tcp { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if self.delay_seconds != 15.0f64 { w.write_with_tag(33, |w| w.write_double(*&self.delay_seconds))?; }
+        if self.interval_seconds != 10.0f64 { w.write_with_tag(41, |w| w.write_double(*&self.interval_seconds))?; }
+        if self.timeout_seconds != 20.0f64 { w.write_with_tag(49, |w| w.write_double(*&self.timeout_seconds))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CheckInfo`
+pub mod mod_CheckInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Describes a command check. If applicable, enters mount and/or network
+/// namespaces of the task.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Command<'a> {
+    pub command: mesos::v1::CommandInfo<'a>,
+}
+
+impl<'a> MessageRead<'a> for Command<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.command = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.command)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Command<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.command).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.command))?;
+        Ok(())
+    }
+}
+
+/// Describes an HTTP check. Sends a GET request to
+/// http://<host>:port/path. Note that <host> is not configurable and is
+/// resolved automatically to 127.0.0.1.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Http<'a> {
+    /// Port to send the HTTP request.
+    pub port: u32,
+    /// HTTP request path.
+    pub path: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Http<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.port = Default::default();
+        self.path = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.port = r.read_uint32(bytes)?,
+                Ok(18) => self.path = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Http<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.port) as u64)
+        + self.path.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.port))?;
+        if let Some(ref s) = self.path { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a TCP check, i.e. based on establishing a TCP connection to
+/// the specified port. Note that <host> is not configurable and is resolved
+/// automatically to 127.0.0.1.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Tcp {
+    pub port: u32,
+}
+
+impl<'a> MessageRead<'a> for Tcp {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.port = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.port = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Tcp {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.port) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.port))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    COMMAND = 1,
+    HTTP = 2,
+    TCP = 3,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::COMMAND,
+            2 => Type::HTTP,
+            3 => Type::TCP,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "COMMAND" => Type::COMMAND,
+            "HTTP" => Type::HTTP,
+            "TCP" => Type::TCP,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a health check for a task or executor (or any arbitrary
+/// process/command). A type is picked by specifying one of the
+/// optional fields. Specifying more than one type is an error.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HealthCheck<'a> {
+    /// Amount of time to wait to start health checking the task after it
+    /// transitions to `TASK_RUNNING` or `TASK_STATING` if the latter is
+    /// used by the executor.
+    pub delay_seconds: f64,
+    /// Interval between health checks, i.e., amount of time to wait after
+    /// the previous health check finished or timed out to start the next
+    /// health check.
+    pub interval_seconds: f64,
+    /// Amount of time to wait for the health check to complete. After this
+    /// timeout, the health check is aborted and treated as a failure. Zero
+    /// means infinite timeout.
+    pub timeout_seconds: f64,
+    /// Number of consecutive failures until the task is killed by the executor.
+    pub consecutive_failures: u32,
+    /// Amount of time after the task is launched during which health check
+    /// failures are ignored. Once a check succeeds for the first time,
+    /// the grace period does not apply anymore. Note that it includes
+    /// `delay_seconds`, i.e., setting `grace_period_seconds` < `delay_seconds`
+    /// has no effect.
+    pub grace_period_seconds: f64,
+    /// The type of health check.
+    pub type_pb: Option<mesos::v1::mod_HealthCheck::Type>,
+    /// Command health check.
+    pub command: Option<mesos::v1::CommandInfo<'a>>,
+    /// HTTP health check.
+    pub http: Option<mesos::v1::mod_HealthCheck::HTTPCheckInfo<'a>>,
+    /// TCP health check.
+    pub tcp: Option<mesos::v1::mod_HealthCheck::TCPCheckInfo>,
+}
+
+impl<'a> MessageRead<'a> for HealthCheck<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = HealthCheck {
+            delay_seconds: 15.0f64,
+            interval_seconds: 10.0f64,
+            timeout_seconds: 20.0f64,
+            consecutive_failures: 3u32,
+            grace_period_seconds: 10.0f64,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.delay_seconds = 15.0f64;
+        self.interval_seconds = 10.0f64;
+        self.timeout_seconds = 20.0f64;
+        self.consecutive_failures = 3u32;
+        self.grace_period_seconds = 10.0f64;
+        self.type_pb = Default::default();
+        self.command = Default::default();
+        self.http = Default::default();
+        self.tcp = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(17) => self.delay_seconds = r.read_double(bytes)?,
+                Ok(25) => self.interval_seconds = r.read_double(bytes)?,
+                Ok(33) => self.timeout_seconds = r.read_double(bytes)?,
+                Ok(40) => self.consecutive_failures = r.read_uint32(bytes)?,
+                Ok(49) => self.grace_period_seconds = r.read_double(bytes)?,
+                Ok(64) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(58) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(10) => r.merge_message(bytes, self.http.get_or_insert_with(Default::default))?,
+                Ok(74) => r.merge_message(bytes, self.tcp.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for HealthCheck<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.delay_seconds == 15.0f64 { 0 } else { 1 + 8 }
+        + if self.interval_seconds == 10.0f64 { 0 } else { 1 + 8 }
+        + if self.timeout_seconds == 20.0f64 { 0 } else { 1 + 8 }
+        + if self.consecutive_failures == 3u32 { 0 } else { 1 + sizeof_varint(*(&self.consecutive_failures) as u64) }
+        + if self.grace_period_seconds == 10.0f64 { 0 } else { 1 + 8 }
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.http.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tcp.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.delay_seconds != 15.0f64 { w.write_with_tag(17, |w| w.write_double(*&self.delay_seconds))?; }
+        if self.interval_seconds != 10.0f64 { w.write_with_tag(25, |w| w.write_double(*&self.interval_seconds))?; }
+        if self.timeout_seconds != 20.0f64 { w.write_with_tag(33, |w| w.write_double(*&self.timeout_seconds))?; }
+        if self.consecutive_failures != 3u32 { w.write_with_tag(40, |w| w.write_uint32(*&self.consecutive_failures))?; }
+        if self.grace_period_seconds != 10.0f64 { w.write_with_tag(49, |w| w.write_double(*&self.grace_period_seconds))?; }
+        if let Some(ref s) = self.type_pb { w.write_with_tag(64, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.http { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.tcp { w.write_with_tag(74, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `HealthCheck`
+pub mod mod_HealthCheck {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Describes an HTTP health check. Sends a GET request to
+/// scheme://<host>:port/path. Note that <host> is not configurable and is
+/// resolved automatically, in most cases to 127.0.0.1. Default executors
+/// treat return codes between 200 and 399 as success; custom executors
+/// may employ a different strategy, e.g. leveraging the `statuses` field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HTTPCheckInfo<'a> {
+    pub protocol: mesos::v1::mod_NetworkInfo::Protocol,
+    /// Currently "http" and "https" are supported.
+    pub scheme: Option<Cow<'a, str>>,
+    /// Port to send the HTTP request.
+    pub port: u32,
+    /// HTTP request path.
+    pub path: Option<Cow<'a, str>>,
+    /// NOTE: It is up to the custom executor to interpret and act on this
+    /// field. Setting this field has no effect on the default executors.
+    ///
+    /// TODO(haosdent): Deprecate this field when we add better support for
+    /// success and possibly failure statuses, e.g. ranges of success and
+    /// failure statuses.
+    pub statuses: Vec<u32>,
+}
+
+impl<'a> MessageRead<'a> for HTTPCheckInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.protocol = Default::default();
+        self.scheme = Default::default();
+        self.port = Default::default();
+        self.path = Default::default();
+        self.statuses.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(40) => self.protocol = r.read_enum(bytes)?,
+                Ok(26) => self.scheme = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(8) => self.port = r.read_uint32(bytes)?,
+                Ok(18) => self.path = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(32) => self.statuses.push(r.read_uint32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for HTTPCheckInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.protocol == mesos::v1::mod_NetworkInfo::Protocol::IPv4 { 0 } else { 1 + sizeof_varint(*(&self.protocol) as u64) }
+        + self.scheme.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + 1 + sizeof_varint(*(&self.port) as u64)
+        + self.path.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.statuses.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.protocol != mesos::v1::mod_NetworkInfo::Protocol::IPv4 { w.write_with_tag(40, |w| w.write_enum(*&self.protocol as i32))?; }
+        if let Some(ref s) = self.scheme { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        w.write_with_tag(8, |w| w.write_uint32(*&self.port))?;
+        if let Some(ref s) = self.path { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        for s in &self.statuses { w.write_with_tag(32, |w| w.write_uint32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a TCP health check, i.e. based on establishing
+/// a TCP connection to the specified port.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TCPCheckInfo {
+    pub protocol: mesos::v1::mod_NetworkInfo::Protocol,
+    /// Port expected to be open.
+    pub port: u32,
+}
+
+impl<'a> MessageRead<'a> for TCPCheckInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.protocol = Default::default();
+        self.port = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(16) => self.protocol = r.read_enum(bytes)?,
+                Ok(8) => self.port = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TCPCheckInfo {
+    fn get_size(&self) -> usize {
+        0
+        + if self.protocol == mesos::v1::mod_NetworkInfo::Protocol::IPv4 { 0 } else { 1 + sizeof_varint(*(&self.protocol) as u64) }
+        + 1 + sizeof_varint(*(&self.port) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.protocol != mesos::v1::mod_NetworkInfo::Protocol::IPv4 { w.write_with_tag(16, |w| w.write_enum(*&self.protocol as i32))?; }
+        w.write_with_tag(8, |w| w.write_uint32(*&self.port))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    COMMAND = 1,
+    HTTP = 2,
+    TCP = 3,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::COMMAND,
+            2 => Type::HTTP,
+            3 => Type::TCP,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "COMMAND" => Type::COMMAND,
+            "HTTP" => Type::HTTP,
+            "TCP" => Type::TCP,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a kill policy for a task. Currently does not express
+/// different policies (e.g. hitting HTTP endpoints), only controls
+/// how long to wait between graceful and forcible task kill:
+///
+/// ```text
+/// graceful kill --------------> forcible kill
+///                grace_period
+/// ```
+///
+/// Kill policies are best-effort, because machine failures / forcible
+/// terminations may occur.
+///
+/// NOTE: For executor-less command-based tasks, the kill is performed
+/// via sending a signal to the task process: SIGTERM for the graceful
+/// kill and SIGKILL for the forcible kill. For the docker executor-less
+/// tasks the grace period is passed to 'docker stop --time'.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct KillPolicy {
+    /// The grace period specifies how long to wait before forcibly
+    /// killing the task. It is recommended to attempt to gracefully
+    /// kill the task (and send TASK_KILLING) to indicate that the
+    /// graceful kill is in progress. Once the grace period elapses,
+    /// if the task has not terminated, a forcible kill should occur.
+    /// The task should not assume that it will always be allotted
+    /// the full grace period. For example, the executor may be
+    /// shutdown more quickly by the agent, or failures / forcible
+    /// terminations may occur.
+    pub grace_period: Option<mesos::v1::DurationInfo>,
+}
+
+impl<'a> MessageRead<'a> for KillPolicy {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.grace_period = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.grace_period.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for KillPolicy {
+    fn get_size(&self) -> usize {
+        0
+        + self.grace_period.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.grace_period { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a command, executed via: '/bin/sh -c value'. Any URIs specified
+/// are fetched before executing the command.  If the executable field for an
+/// uri is set, executable file permission is set on the downloaded file.
+/// Otherwise, if the downloaded file has a recognized archive extension
+/// (currently [compressed] tar and zip) it is extracted into the executor's
+/// working directory. This extraction can be disabled by setting `extract` to
+/// false. In addition, any environment variables are set before executing
+/// the command (so they can be used to "parameterize" your command).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CommandInfo<'a> {
+    pub uris: Vec<mesos::v1::mod_CommandInfo::URI<'a>>,
+    pub environment: Option<mesos::v1::Environment<'a>>,
+    /// There are two ways to specify the command:
+    /// 1) If 'shell == true', the command will be launched via shell
+    /// 	(i.e., /bin/sh -c 'value'). The 'value' specified will be
+    /// 	treated as the shell command. The 'arguments' will be ignored.
+    /// 2) If 'shell == false', the command will be launched by passing
+    /// 	arguments to an executable. The 'value' specified will be
+    /// 	treated as the filename of the executable. The 'arguments'
+    /// 	will be treated as the arguments to the executable. This is
+    /// 	similar to how POSIX exec families launch processes (i.e.,
+    /// 	execlp(value, arguments(0), arguments(1), ...)).
+    /// NOTE: The field 'value' is changed from 'required' to 'optional'
+    /// in 0.20.0. It will only cause issues if a new framework is
+    /// connecting to an old master.
+    pub shell: bool,
+    pub value: Option<Cow<'a, str>>,
+    pub arguments: Vec<Cow<'a, str>>,
+    /// Enables executor and tasks to run as a specific user. If the user
+    /// field is present both in FrameworkInfo and here, the CommandInfo
+    /// user value takes precedence.
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for CommandInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = CommandInfo {
+            shell: true,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.uris.clear();
+        self.environment = Default::default();
+        self.shell = true;
+        self.value = Default::default();
+        self.arguments.clear();
+        self.user = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.uris.push(r.read_message::<mesos::v1::mod_CommandInfo::URI>(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.environment.get_or_insert_with(Default::default))?,
+                Ok(48) => self.shell = r.read_bool(bytes)?,
+                Ok(26) => self.value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => self.arguments.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => self.user = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CommandInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.uris.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.environment.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.shell == true { 0 } else { 1 + sizeof_varint(*(&self.shell) as u64) }
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.arguments.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.user.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.uris { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.environment { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if self.shell != true { w.write_with_tag(48, |w| w.write_bool(*&self.shell))?; }
+        if let Some(ref s) = self.value { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.arguments { w.write_with_tag(58, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.user { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CommandInfo`
+pub mod mod_CommandInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct URI<'a> {
+    pub value: Cow<'a, str>,
+    pub executable: Option<bool>,
+    /// In case the fetched file is recognized as an archive, extract
+    /// its contents into the sandbox. Note that a cached archive is
+    /// not copied from the cache to the sandbox in case extraction
+    /// originates from an archive in the cache.
+    pub extract: bool,
+    /// If this field is "true", the fetcher cache will be used. If not,
+    /// fetching bypasses the cache and downloads directly into the
+    /// sandbox directory, no matter whether a suitable cache file is
+    /// available or not. The former directs the fetcher to download to
+    /// the file cache, then copy from there to the sandbox. Subsequent
+    /// fetch attempts with the same URI will omit downloading and copy
+    /// from the cache as long as the file is resident there. Cache files
+    /// may get evicted at any time, which then leads to renewed
+    /// downloading. See also "docs/fetcher.md" and
+    /// "docs/fetcher-cache-internals.md".
+    pub cache: Option<bool>,
+    /// The fetcher's default behavior is to use the URI string's basename to
+    /// name the local copy. If this field is provided, the local copy will be
+    /// named with its value instead. If there is a directory component (which
+    /// must be a relative path), the local copy will be stored in that
+    /// subdirectory inside the sandbox.
+    pub output_file: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for URI<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = URI {
+            extract: true,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+        self.executable = Default::default();
+        self.extract = true;
+        self.cache = Default::default();
+        self.output_file = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.executable = Some(r.read_bool(bytes)?),
+                Ok(24) => self.extract = r.read_bool(bytes)?,
+                Ok(32) => self.cache = Some(r.read_bool(bytes)?),
+                Ok(42) => self.output_file = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for URI<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+        + self.executable.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + if self.extract == true { 0 } else { 1 + sizeof_varint(*(&self.extract) as u64) }
+        + self.cache.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.output_file.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        if let Some(ref s) = self.executable { w.write_with_tag(16, |w| w.write_bool(*s))?; }
+        if self.extract != true { w.write_with_tag(24, |w| w.write_bool(*&self.extract))?; }
+        if let Some(ref s) = self.cache { w.write_with_tag(32, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.output_file { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+}
+
+/// Describes information about an executor.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ExecutorInfo<'a> {
+    /// For backwards compatibility, if this field is not set when using `LAUNCH`
+    /// operation, Mesos will infer the type by checking if `command` is set
+    /// (`CUSTOM`) or unset (`DEFAULT`). `type` must be set when using
+    /// `LAUNCH_GROUP` operation.
+    ///
+    /// TODO(vinod): Add support for explicitly setting `type` to `DEFAULT` in
+    /// `LAUNCH` operation.
+    pub type_pb: Option<mesos::v1::mod_ExecutorInfo::Type>,
+    pub executor_id: mesos::v1::ExecutorID<'a>,
+    /// TODO(benh): Make this required.
+    pub framework_id: Option<mesos::v1::FrameworkID<'a>>,
+    pub command: Option<mesos::v1::CommandInfo<'a>>,
+    /// Executor provided with a container will launch the container
+    /// with the executor's CommandInfo and we expect the container to
+    /// act as a Mesos executor.
+    pub container: Option<mesos::v1::ContainerInfo<'a>>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub name: Option<Cow<'a, str>>,
+    /// This field can be used to pass arbitrary bytes to an executor.
+    pub data: Option<Cow<'a, [u8]>>,
+    /// Service discovery information for the executor. It is not
+    /// interpreted or acted upon by Mesos. It is up to a service
+    /// discovery system to use this information as needed and to handle
+    /// executors without service discovery information.
+    pub discovery: Option<mesos::v1::DiscoveryInfo<'a>>,
+    /// When shutting down an executor the agent will wait in a
+    /// best-effort manner for the grace period specified here
+    /// before forcibly destroying the container. The executor
+    /// must not assume that it will always be allotted the full
+    /// grace period, as the agent may decide to allot a shorter
+    /// period and failures / forcible terminations may occur.
+    pub shutdown_grace_period: Option<mesos::v1::DurationInfo>,
+    /// Labels are free-form key value pairs which are exposed through
+    /// master and agent endpoints. Labels will not be interpreted or
+    /// acted upon by Mesos itself. As opposed to the data field, labels
+    /// will be kept in memory on master and agent processes. Therefore,
+    /// labels should be used to tag executors with lightweight metadata.
+    /// Labels should not contain duplicate key-value pairs.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ExecutorInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.executor_id = Default::default();
+        self.framework_id = Default::default();
+        self.command = Default::default();
+        self.container = Default::default();
+        self.resources.clear();
+        self.name = Default::default();
+        self.data = Default::default();
+        self.discovery = Default::default();
+        self.shutdown_grace_period = Default::default();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(120) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(10) => r.merge_message(bytes, &mut self.executor_id)?,
+                Ok(66) => r.merge_message(bytes, self.framework_id.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(90) => r.merge_message(bytes, self.container.get_or_insert_with(Default::default))?,
+                Ok(42) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(74) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.data = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(98) => r.merge_message(bytes, self.discovery.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.shutdown_grace_period.get_or_insert_with(Default::default))?,
+                Ok(114) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ExecutorInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + 1 + sizeof_len((&self.executor_id).get_size())
+        + self.framework_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.container.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.discovery.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.shutdown_grace_period.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(120, |w| w.write_enum(*s as i32))?; }
+        w.write_with_tag(10, |w| w.write_message(&self.executor_id))?;
+        if let Some(ref s) = self.framework_id { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.container { w.write_with_tag(90, |w| w.write_message(s))?; }
+        for s in &self.resources { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.name { w.write_with_tag(74, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.data { w.write_with_tag(34, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.discovery { w.write_with_tag(98, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.shutdown_grace_period { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(114, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ExecutorInfo`
+pub mod mod_ExecutorInfo {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    /// Mesos provides a simple built-in default executor that frameworks can
+    /// leverage to run shell commands and containers.
+    ///
+    /// NOTES:
+    ///
+    /// 1) `command` must not be set when using a default executor.
+    ///
+    /// 2) Default executor only accepts a *single* `LAUNCH` or `LAUNCH_GROUP`
+    ///    operation.
+    ///
+    /// 3) If `container` is set, `container.type` must be `MESOS`
+    ///    and `container.mesos.image` must not be set.
+    DEFAULT = 1,
+    /// For frameworks that need custom functionality to run tasks, a `CUSTOM`
+    /// executor can be used. Note that `command` must be set when using a
+    /// `CUSTOM` executor.
+    CUSTOM = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::DEFAULT,
+            2 => Type::CUSTOM,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "DEFAULT" => Type::DEFAULT,
+            "CUSTOM" => Type::CUSTOM,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a domain. A domain is a collection of hosts that have
+/// similar characteristics. Mesos currently only supports "fault
+/// domains", which identify groups of hosts with similar failure
+/// characteristics.
+///
+/// Frameworks can generally assume that network links between hosts in
+/// the same fault domain have lower latency, higher bandwidth, and better
+/// availability than network links between hosts in different domains.
+/// Schedulers may prefer to place network-intensive workloads in the
+/// same domain, as this may improve performance. Conversely, a single
+/// failure that affects a host in a domain may be more likely to
+/// affect other hosts in the same domain; hence, schedulers may prefer
+/// to place workloads that require high availability in multiple
+/// domains. (For example, all the hosts in a single rack might lose
+/// power or network connectivity simultaneously.)
+///
+/// There are two kinds of fault domains: regions and zones. Regions
+/// offer the highest degree of fault isolation, but network latency
+/// between regions is typically high (typically >50 ms). Zones offer a
+/// modest degree of fault isolation along with reasonably low network
+/// latency (typically <10 ms).
+///
+/// The mapping from fault domains to physical infrastructure is up to
+/// the operator to configure. In cloud environments, regions and zones
+/// can be mapped to the "region" and "availability zone" concepts
+/// exposed by most cloud providers, respectively. In on-premise
+/// deployments, regions and zones can be mapped to data centers and
+/// racks, respectively.
+///
+/// Both masters and agents can be configured with domains. Frameworks
+/// can compare the domains of two hosts to determine if the hosts are
+/// in the same zone, in different zones in the same region, or in
+/// different regions. Note that all masters in a given Mesos cluster
+/// must be in the same region.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DomainInfo<'a> {
+    pub fault_domain: Option<mesos::v1::mod_DomainInfo::FaultDomain<'a>>,
+}
+
+impl<'a> MessageRead<'a> for DomainInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.fault_domain = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.fault_domain.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DomainInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.fault_domain.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.fault_domain { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DomainInfo`
+pub mod mod_DomainInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FaultDomain<'a> {
+    pub region: mesos::v1::mod_DomainInfo::mod_FaultDomain::RegionInfo<'a>,
+    pub zone: mesos::v1::mod_DomainInfo::mod_FaultDomain::ZoneInfo<'a>,
+}
+
+impl<'a> MessageRead<'a> for FaultDomain<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.region = Default::default();
+        self.zone = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.region)?,
+                Ok(18) => r.merge_message(bytes, &mut self.zone)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FaultDomain<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.region).get_size())
+        + 1 + sizeof_len((&self.zone).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.region))?;
+        w.write_with_tag(18, |w| w.write_message(&self.zone))?;
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `FaultDomain`
+pub mod mod_FaultDomain {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RegionInfo<'a> {
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for RegionInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for RegionInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ZoneInfo<'a> {
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for ZoneInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ZoneInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        Ok(())
+    }
+}
+
+}
+
+}
+
+/// Describes a master. This will probably have more fields in the
+/// future which might be used, for example, to link a framework webui
+/// to a master webui.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MasterInfo<'a> {
+    pub id: Cow<'a, str>,
+    /// The IP address (only IPv4) as a packed 4-bytes integer,
+    /// stored in network order.  Deprecated, use `address.ip` instead.
+    pub ip: u32,
+    /// The TCP port the Master is listening on for incoming
+    /// HTTP requests; deprecated, use `address.port` instead.
+    pub port: u32,
+    /// In the default implementation, this will contain information
+    /// about both the IP address, port and Master name; it should really
+    /// not be relied upon by external tooling/frameworks and be
+    /// considered an "internal" implementation field.
+    pub pid: Option<Cow<'a, str>>,
+    /// The server's hostname, if available; it may be unreliable
+    /// in environments where the DNS configuration does not resolve
+    /// internal hostnames (eg, some public cloud providers).
+    /// Deprecated, use `address.hostname` instead.
+    pub hostname: Option<Cow<'a, str>>,
+    /// The running Master version, as a string; taken from the
+    /// generated "master/version.hpp".
+    pub version: Option<Cow<'a, str>>,
+    /// The full IP address (supports both IPv4 and IPv6 formats)
+    /// and supersedes the use of `ip`, `port` and `hostname`.
+    /// Since Mesos 0.24.
+    pub address: Option<mesos::v1::Address<'a>>,
+    /// The domain that this master belongs to. All masters in a Mesos
+    /// cluster should belong to the same region.
+    pub domain: Option<mesos::v1::DomainInfo<'a>>,
+    pub capabilities: Vec<mesos::v1::mod_MasterInfo::Capability>,
+}
+
+impl<'a> MessageRead<'a> for MasterInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = MasterInfo {
+            port: 5050u32,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.ip = Default::default();
+        self.port = 5050u32;
+        self.pid = Default::default();
+        self.hostname = Default::default();
+        self.version = Default::default();
+        self.address = Default::default();
+        self.domain = Default::default();
+        self.capabilities.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.id = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.ip = r.read_uint32(bytes)?,
+                Ok(24) => self.port = r.read_uint32(bytes)?,
+                Ok(34) => self.pid = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => self.hostname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => self.version = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => r.merge_message(bytes, self.address.get_or_insert_with(Default::default))?,
+                Ok(66) => r.merge_message(bytes, self.domain.get_or_insert_with(Default::default))?,
+                Ok(74) => self.capabilities.push(r.read_message::<mesos::v1::mod_MasterInfo::Capability>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MasterInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).len())
+        + 1 + sizeof_varint(*(&self.ip) as u64)
+        + 1 + sizeof_varint(*(&self.port) as u64)
+        + self.pid.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.hostname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.version.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.address.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.domain.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.capabilities.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.id))?;
+        w.write_with_tag(16, |w| w.write_uint32(*&self.ip))?;
+        w.write_with_tag(24, |w| w.write_uint32(*&self.port))?;
+        if let Some(ref s) = self.pid { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.hostname { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.version { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.address { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.domain { w.write_with_tag(66, |w| w.write_message(s))?; }
+        for s in &self.capabilities { w.write_with_tag(74, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `MasterInfo`
+pub mod mod_MasterInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Capability {
+    pub type_pb: Option<mesos::v1::mod_MasterInfo::mod_Capability::Type>,
+}
+
+impl<'a> MessageRead<'a> for Capability {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Capability {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Capability`
+pub mod mod_Capability {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    /// The master can handle slaves whose state
+    /// changes after reregistering.
+    AGENT_UPDATE = 1,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::AGENT_UPDATE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "AGENT_UPDATE" => Type::AGENT_UPDATE,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// Describes an agent. Note that the 'id' field is only available
+/// after an agent is registered with the master, and is made available
+/// here to facilitate re-registration.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct AgentInfo<'a> {
+    pub hostname: Cow<'a, str>,
+    pub port: i32,
+    /// The configured resources at the agent. This does not include any
+    /// dynamic reservations or persistent volumes that may currently
+    /// exist at the agent.
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub attributes: Vec<mesos::v1::Attribute<'a>>,
+    pub id: Option<mesos::v1::AgentID<'a>>,
+    /// The domain that this agent belongs to. If the agent's region
+    /// differs from the master's region, it will not appear in resource
+    /// offers to frameworks that have not enabled the REGION_AWARE
+    /// capability.
+    pub domain: Option<mesos::v1::DomainInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for AgentInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = AgentInfo {
+            port: 5051i32,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.hostname = Default::default();
+        self.port = 5051i32;
+        self.resources.clear();
+        self.attributes.clear();
+        self.id = Default::default();
+        self.domain = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.hostname = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(64) => self.port = r.read_int32(bytes)?,
+                Ok(26) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(42) => self.attributes.push(r.read_message::<mesos::v1::Attribute>(bytes)?),
+                Ok(50) => r.merge_message(bytes, self.id.get_or_insert_with(Default::default))?,
+                Ok(82) => r.merge_message(bytes, self.domain.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for AgentInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.hostname).len())
+        + if self.port == 5051i32 { 0 } else { 1 + sizeof_varint(*(&self.port) as u64) }
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.attributes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.domain.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.hostname))?;
+        if self.port != 5051i32 { w.write_with_tag(64, |w| w.write_int32(*&self.port))?; }
+        for s in &self.resources { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.attributes { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.id { w.write_with_tag(50, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.domain { w.write_with_tag(82, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `AgentInfo`
+pub mod mod_AgentInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Capability {
+    /// Enum fields should be optional, see: MESOS-4997.
+    pub type_pb: Option<mesos::v1::mod_AgentInfo::mod_Capability::Type>,
+}
+
+impl<'a> MessageRead<'a> for Capability {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Capability {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Capability`
+pub mod mod_Capability {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    /// This must be the first enum value in this list, to
+    /// ensure that if 'type' is not set, the default value
+    /// is UNKNOWN. This enables enum values to be added
+    /// in a backwards-compatible way. See: MESOS-4997.
+    UNKNOWN = 0,
+    /// This expresses the ability for the agent to be able
+    /// to launch tasks of a 'multi-role' framework.
+    MULTI_ROLE = 1,
+    /// This expresses the ability for the agent to be able to launch
+    /// tasks, reserve resources, and create volumes using resources
+    /// allocated to a 'hierarchical-role'.
+    /// NOTE: This capability is required specifically for creating
+    /// volumes because a hierchical role includes '/' (slashes) in them.
+    /// Agents with this capability know to transform the '/' (slashes)
+    /// into ' ' (spaces).
+    HIERARCHICAL_ROLE = 2,
+    /// This capability has three effects for an agent.
+    ///
+    /// (1) The format of the checkpointed resources, and
+    ///     the resources reported to master.
+    ///
+    /// ```text
+    /// These resources are reported in the "pre-reservation-refinement"
+    /// format if none of the resources have refined reservations. If any
+    /// of the resources have refined reservations, they are reported in
+    /// the "post-reservation-refinement" format. The purpose is to allow
+    /// downgrading of an agent as well as communication with a pre-1.4.0
+    /// master until the reservation refinement feature is actually used.
+    ///
+    /// See the 'Resource Format' section for more details.
+    /// ```
+    ///
+    /// (2) The format of the resources reported by the HTTP endpoints.
+    ///
+    /// ```text
+    /// For resources reported by agent endpoints, the
+    /// "pre-reservation-refinement" format is "injected" if possible.
+    /// That is, resources without refined reservations will have the
+    /// `Resource.role` and `Resource.reservation` set, whereas
+    /// resources with refined reservations will not.
+    ///
+    /// See the 'Resource Format' section for more details.
+    /// ```
+    ///
+    /// (3) The ability for the agent to launch tasks, reserve resources, and
+    ///     create volumes using resources that have refined reservations.
+    ///
+    /// ```text
+    /// See `ReservationInfo.reservations` section for more details.
+    /// ```
+    ///
+    /// NOTE: Resources are said to have refined reservations if it uses the
+    /// `Resource.reservations` field, and `Resource.reservations_size() > 1`.
+    RESERVATION_REFINEMENT = 3,
+    /// This expresses the ability for the agent to handle resource
+    /// provider related operations. This includes the following:
+    ///
+    /// (1) The ability to report resources that are provided by some
+    ///     local resource providers through the resource provider API.
+    ///
+    /// (2) The ability to provide operation feedback.
+    RESOURCE_PROVIDER = 4,
+    /// This expresses the capability for the agent to handle persistent volume
+    /// resize operations safely. This capability is turned on by default.
+    RESIZE_VOLUME = 5,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::MULTI_ROLE,
+            2 => Type::HIERARCHICAL_ROLE,
+            3 => Type::RESERVATION_REFINEMENT,
+            4 => Type::RESOURCE_PROVIDER,
+            5 => Type::RESIZE_VOLUME,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "MULTI_ROLE" => Type::MULTI_ROLE,
+            "HIERARCHICAL_ROLE" => Type::HIERARCHICAL_ROLE,
+            "RESERVATION_REFINEMENT" => Type::RESERVATION_REFINEMENT,
+            "RESOURCE_PROVIDER" => Type::RESOURCE_PROVIDER,
+            "RESIZE_VOLUME" => Type::RESIZE_VOLUME,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// Describes the container configuration to run a CSI plugin component.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CSIPluginContainerInfo<'a> {
+    pub services: Vec<mesos::v1::mod_CSIPluginContainerInfo::Service>,
+    pub command: Option<mesos::v1::CommandInfo<'a>>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub container: Option<mesos::v1::ContainerInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for CSIPluginContainerInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.services.clear();
+        self.command = Default::default();
+        self.resources.clear();
+        self.container = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.services.push(r.read_enum(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(26) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(34) => r.merge_message(bytes, self.container.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CSIPluginContainerInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.services.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.container.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.services { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.resources { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.container { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CSIPluginContainerInfo`
+pub mod mod_CSIPluginContainerInfo {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Service {
+    UNKNOWN = 0,
+    CONTROLLER_SERVICE = 1,
+    NODE_SERVICE = 2,
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Service::UNKNOWN
+    }
+}
+
+impl From<i32> for Service {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Service::UNKNOWN,
+            1 => Service::CONTROLLER_SERVICE,
+            2 => Service::NODE_SERVICE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Service {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Service::UNKNOWN,
+            "CONTROLLER_SERVICE" => Service::CONTROLLER_SERVICE,
+            "NODE_SERVICE" => Service::NODE_SERVICE,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a CSI plugin.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CSIPluginInfo<'a> {
+    /// The type of the CSI service. This uniquely identifies a CSI
+    /// implementation. For instance:
+    ///     org.apache.mesos.csi.test
+    ///
+    /// Please follow to Java package naming convention
+    /// (https://en.wikipedia.org/wiki/Java_package#Package_naming_conventions)
+    /// to avoid conflicts on type names.
+    pub type_pb: Cow<'a, str>,
+    /// The name of the CSI service. There could be mutliple instances of a
+    /// type of CSI service. The name field is used to distinguish these
+    /// instances. It should be a legal Java identifier
+    /// (https://docs.oracle.com/javase/tutorial/java/nutsandbolts/variables.html)
+    /// to avoid conflicts on concatenation of type and name.
+    pub name: Cow<'a, str>,
+    /// A list of container configurations to run CSI plugin components.
+    /// The controller service will be served by the first configuration
+    /// that contains `CONTROLLER_SERVICE`, and the node service will be
+    /// served by the first configuration that contains `NODE_SERVICE`.
+    pub containers: Vec<mesos::v1::CSIPluginContainerInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for CSIPluginInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.name = Default::default();
+        self.containers.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.type_pb = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => self.containers.push(r.read_message::<mesos::v1::CSIPluginContainerInfo>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CSIPluginInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.type_pb).len())
+        + 1 + sizeof_len((&self.name).len())
+        + self.containers.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.type_pb))?;
+        w.write_with_tag(18, |w| w.write_string(&**&self.name))?;
+        for s in &self.containers { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a resource provider. Note that the 'id' field is only available
+/// after a resource provider is registered with the master, and is made
+/// available here to facilitate re-registration.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ResourceProviderInfo<'a> {
+    pub id: Option<mesos::v1::ResourceProviderID<'a>>,
+    pub attributes: Vec<mesos::v1::Attribute<'a>>,
+    /// The type of the resource provider. This uniquely identifies a
+    /// resource provider implementation. For instance:
+    ///     org.apache.mesos.rp.local.storage
+    ///
+    /// Please follow to Java package naming convention
+    /// (https://en.wikipedia.org/wiki/Java_package#Package_naming_conventions)
+    /// to avoid conflicts on type names.
+    pub type_pb: Cow<'a, str>,
+    /// The name of the resource provider. There could be multiple
+    /// instances of a type of resource provider. The name field is used
+    /// to distinguish these instances. It should be a legal Java identifier
+    /// (https://docs.oracle.com/javase/tutorial/java/nutsandbolts/variables.html)
+    /// to avoid conflicts on concatenation of type and name.
+    pub name: Cow<'a, str>,
+    /// The stack of default reservations. If this field is not empty, it
+    /// indicates that resources from this resource provider are reserved
+    /// by default, except for the resources that have been reserved or
+    /// unreserved through operations. The first `ReservationInfo`
+    /// may have type `STATIC` or `DYNAMIC`, but the rest must have
+    /// `DYNAMIC`. One can create a new reservation on top of an existing
+    /// one by pushing a new `ReservationInfo` to the back. The last
+    /// `ReservationInfo` in this stack is the "current" reservation. The
+    /// new reservation's role must be a child of the current one.
+    ///
+    /// EXPERIMENTAL.
+    pub default_reservations: Vec<mesos::v1::mod_Resource::ReservationInfo<'a>>,
+    /// EXPERIMENTAL.
+    pub storage: Option<mesos::v1::mod_ResourceProviderInfo::Storage<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ResourceProviderInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.attributes.clear();
+        self.type_pb = Default::default();
+        self.name = Default::default();
+        self.default_reservations.clear();
+        self.storage = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.id.get_or_insert_with(Default::default))?,
+                Ok(18) => self.attributes.push(r.read_message::<mesos::v1::Attribute>(bytes)?),
+                Ok(26) => self.type_pb = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(34) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(42) => self.default_reservations.push(r.read_message::<mesos::v1::mod_Resource::ReservationInfo>(bytes)?),
+                Ok(50) => r.merge_message(bytes, self.storage.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ResourceProviderInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.attributes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + 1 + sizeof_len((&self.type_pb).len())
+        + 1 + sizeof_len((&self.name).len())
+        + self.default_reservations.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.storage.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.id { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.attributes { w.write_with_tag(18, |w| w.write_message(s))?; }
+        w.write_with_tag(26, |w| w.write_string(&**&self.type_pb))?;
+        w.write_with_tag(34, |w| w.write_string(&**&self.name))?;
+        for s in &self.default_reservations { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.storage { w.write_with_tag(50, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ResourceProviderInfo`
+pub mod mod_ResourceProviderInfo {
+
+use super::*;
+
+/// Storage resource provider related information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Storage<'a> {
+    pub plugin: mesos::v1::CSIPluginInfo<'a>,
+}
+
+impl<'a> MessageRead<'a> for Storage<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.plugin = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.plugin)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Storage<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.plugin).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.plugin))?;
+        Ok(())
+    }
+}
+
+}
+
+/// Describes an Attribute or Resource "value". A value is described
+/// using the standard protocol buffer "union" trick.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Value<'a> {
+    pub type_pb: mesos::v1::mod_Value::Type,
+    pub scalar: Option<mesos::v1::mod_Value::Scalar>,
+    pub ranges: Option<mesos::v1::mod_Value::Ranges>,
+    pub set: Option<mesos::v1::mod_Value::Set<'a>>,
+    pub text: Option<mesos::v1::mod_Value::Text<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Value<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.scalar = Default::default();
+        self.ranges = Default::default();
+        self.set = Default::default();
+        self.text = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = r.read_enum(bytes)?,
+                Ok(18) => r.merge_message(bytes, self.scalar.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.ranges.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.set.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.text.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Value<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.scalar.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.ranges.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.set.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.text.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.type_pb as i32))?;
+        if let Some(ref s) = self.scalar { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.ranges { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.set { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.text { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Value`
+pub mod mod_Value {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Scalar {
+    /// Scalar values are represented using floating point. To reduce
+    /// the chance of unpredictable floating point behavior due to
+    /// roundoff error, Mesos only supports three decimal digits of
+    /// precision for scalar resource values. That is, floating point
+    /// values are converted to a fixed point format that supports
+    /// three decimal digits of precision, and then converted back to
+    /// floating point on output. Any additional precision in scalar
+    /// resource values is discarded (via rounding).
+    pub value: f64,
+}
+
+impl<'a> MessageRead<'a> for Scalar {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.value = r.read_double(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Scalar {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + 8
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(9, |w| w.write_double(*&self.value))?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Range {
+    pub begin: u64,
+    pub end: u64,
+}
+
+impl<'a> MessageRead<'a> for Range {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.begin = Default::default();
+        self.end = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.begin = r.read_uint64(bytes)?,
+                Ok(16) => self.end = r.read_uint64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Range {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.begin) as u64)
+        + 1 + sizeof_varint(*(&self.end) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint64(*&self.begin))?;
+        w.write_with_tag(16, |w| w.write_uint64(*&self.end))?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Ranges {
+    pub range: Vec<mesos::v1::mod_Value::Range>,
+}
+
+impl<'a> MessageRead<'a> for Ranges {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.range.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.range.push(r.read_message::<mesos::v1::mod_Value::Range>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Ranges {
+    fn get_size(&self) -> usize {
+        0
+        + self.range.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.range { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Set<'a> {
+    pub item: Vec<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Set<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.item.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.item.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Set<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.item.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.item { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Text<'a> {
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Text<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Text<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    SCALAR = 0,
+    RANGES = 1,
+    SET = 2,
+    TEXT = 3,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::SCALAR
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::SCALAR,
+            1 => Type::RANGES,
+            2 => Type::SET,
+            3 => Type::TEXT,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "SCALAR" => Type::SCALAR,
+            "RANGES" => Type::RANGES,
+            "SET" => Type::SET,
+            "TEXT" => Type::TEXT,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes an attribute that can be set on a machine. For now,
+/// attributes and resources share the same "value" type, but this may
+/// change in the future and attributes may only be string based.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Attribute<'a> {
+    pub name: Cow<'a, str>,
+    pub type_pb: mesos::v1::mod_Value::Type,
+    pub scalar: Option<mesos::v1::mod_Value::Scalar>,
+    pub ranges: Option<mesos::v1::mod_Value::Ranges>,
+    pub set: Option<mesos::v1::mod_Value::Set<'a>>,
+    pub text: Option<mesos::v1::mod_Value::Text<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Attribute<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.type_pb = Default::default();
+        self.scalar = Default::default();
+        self.ranges = Default::default();
+        self.set = Default::default();
+        self.text = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.type_pb = r.read_enum(bytes)?,
+                Ok(26) => r.merge_message(bytes, self.scalar.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.ranges.get_or_insert_with(Default::default))?,
+                Ok(50) => r.merge_message(bytes, self.set.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.text.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Attribute<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.scalar.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.ranges.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.set.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.text.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(16, |w| w.write_enum(*&self.type_pb as i32))?;
+        if let Some(ref s) = self.scalar { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.ranges { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.set { w.write_with_tag(50, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.text { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a resource from a resource provider. The `name` field is
+/// a string like "cpus" or "mem" that indicates which kind of resource
+/// this is; the rest of the fields describe the properties of the
+/// resource. A resource can take on one of three types: scalar
+/// (double), a list of finite and discrete ranges (e.g., [1-10,
+/// 20-30]), or a set of items. A resource is described using the
+/// standard protocol buffer "union" trick.
+///
+/// Note that "disk" and "mem" resources are scalar values expressed in
+/// megabytes. Fractional "cpus" values are allowed (e.g., "0.5"),
+/// which correspond to partial shares of a CPU.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Resource<'a> {
+    pub provider_id: Option<mesos::v1::ResourceProviderID<'a>>,
+    pub name: Cow<'a, str>,
+    pub type_pb: mesos::v1::mod_Value::Type,
+    pub scalar: Option<mesos::v1::mod_Value::Scalar>,
+    pub ranges: Option<mesos::v1::mod_Value::Ranges>,
+    pub set: Option<mesos::v1::mod_Value::Set<'a>>,
+    pub allocation_info: Option<mesos::v1::mod_Resource::AllocationInfo<'a>>,
+    /// If this is set, this resource was dynamically reserved by an
+    /// operator or a framework. Otherwise, this resource is either unreserved
+    /// or statically reserved by an operator via the --resources flag.
+    ///
+    /// NOTE: Frameworks must not set this field if `reservations` is set.
+    ///       See the 'Resource Format' section for more details.
+    ///
+    /// TODO(mpark): Deprecate once `reservations` is no longer experimental.
+    pub reservation: Option<mesos::v1::mod_Resource::ReservationInfo<'a>>,
+    /// The stack of reservations. If this field is empty, it indicates that this
+    /// resource is unreserved. Otherwise, the resource is reserved. The first
+    /// `ReservationInfo` may have type `STATIC` or `DYNAMIC`, but the rest must
+    /// have `DYNAMIC`. One can create a new reservation on top of an existing
+    /// one by pushing a new `ReservationInfo` to the back. The last
+    /// `ReservationInfo` in this stack is the "current" reservation. The new
+    /// reservation's role must be a child of the current reservation's role.
+    ///
+    /// NOTE: Frameworks must not set this field if `reservation` is set.
+    ///       See the 'Resource Format' section for more details.
+    ///
+    /// TODO(mpark): Deprecate `role` and `reservation` once this is stable.
+    ///
+    /// EXPERIMENTAL.
+    pub reservations: Vec<mesos::v1::mod_Resource::ReservationInfo<'a>>,
+    pub disk: Option<mesos::v1::mod_Resource::DiskInfo<'a>>,
+    /// If this is set, the resources are revocable, i.e., any tasks or
+    /// executors launched using these resources could get preempted or
+    /// throttled at any time. This could be used by frameworks to run
+    /// best effort tasks that do not need strict uptime or performance
+    /// guarantees. Note that if this is set, 'disk' or 'reservation'
+    /// cannot be set.
+    pub revocable: Option<mesos::v1::mod_Resource::RevocableInfo>,
+    /// If this is set, the resources are shared, i.e. multiple tasks
+    /// can be launched using this resource and all of them shall refer
+    /// to the same physical resource on the cluster. Note that only
+    /// persistent volumes can be shared currently.
+    pub shared: Option<mesos::v1::mod_Resource::SharedInfo>,
+}
+
+impl<'a> MessageRead<'a> for Resource<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Resource {
+            role: Cow::Borrowed("*"),
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.provider_id = Default::default();
+        self.name = Default::default();
+        self.type_pb = Default::default();
+        self.scalar = Default::default();
+        self.ranges = Default::default();
+        self.set = Default::default();
+        self.allocation_info = Default::default();
+        self.reservation = Default::default();
+        self.reservations.clear();
+        self.disk = Default::default();
+        self.revocable = Default::default();
+        self.shared = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(98) => r.merge_message(bytes, self.provider_id.get_or_insert_with(Default::default))?,
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.type_pb = r.read_enum(bytes)?,
+                Ok(26) => r.merge_message(bytes, self.scalar.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.ranges.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.set.get_or_insert_with(Default::default))?,
+                Ok(90) => r.merge_message(bytes, self.allocation_info.get_or_insert_with(Default::default))?,
+                Ok(66) => r.merge_message(bytes, self.reservation.get_or_insert_with(Default::default))?,
+                Ok(106) => self.reservations.push(r.read_message::<mesos::v1::mod_Resource::ReservationInfo>(bytes)?),
+                Ok(58) => r.merge_message(bytes, self.disk.get_or_insert_with(Default::default))?,
+                Ok(74) => r.merge_message(bytes, self.revocable.get_or_insert_with(Default::default))?,
+                Ok(82) => r.merge_message(bytes, self.shared.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Resource<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.provider_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.scalar.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.ranges.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.set.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.allocation_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.reservation.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.reservations.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.disk.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.revocable.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.shared.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.provider_id { w.write_with_tag(98, |w| w.write_message(s))?; }
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(16, |w| w.write_enum(*&self.type_pb as i32))?;
+        if let Some(ref s) = self.scalar { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.ranges { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.set { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.allocation_info { w.write_with_tag(90, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.reservation { w.write_with_tag(66, |w| w.write_message(s))?; }
+        for s in &self.reservations { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.disk { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.revocable { w.write_with_tag(74, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.shared { w.write_with_tag(82, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Resource`
+pub mod mod_Resource {
+
+use std::borrow::Cow;
+use super::*;
+
+/// This was initially introduced to support MULTI_ROLE capable
+/// frameworks. Frameworks that are not MULTI_ROLE capable can
+/// continue to assume that the offered resources are allocated
+/// to their role.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct AllocationInfo<'a> {
+    /// If set, this resource is allocated to a role. Note that in the
+    /// future, this may be unset and the scheduler may be responsible
+    /// for allocating to one of its roles.
+    pub role: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for AllocationInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.role = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.role = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for AllocationInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.role.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.role { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ReservationInfo<'a> {
+    /// The type of this reservation.
+    ///
+    /// NOTE: This field must not be set for `Resource.reservation`.
+    ///       See the 'Resource Format' section for more details.
+    pub type_pb: Option<mesos::v1::mod_Resource::mod_ReservationInfo::Type>,
+    /// The role to which this reservation is made for.
+    ///
+    /// NOTE: This field must not be set for `Resource.reservation`.
+    ///       See the 'Resource Format' section for more details.
+    pub role: Option<Cow<'a, str>>,
+    /// Indicates the principal, if any, of the framework or operator
+    /// that reserved this resource. If reserved by a framework, the
+    /// field should match the `FrameworkInfo.principal`. It is used in
+    /// conjunction with the `UnreserveResources` ACL to determine
+    /// whether the entity attempting to unreserve this resource is
+    /// permitted to do so.
+    pub principal: Option<Cow<'a, str>>,
+    /// Labels are free-form key value pairs that can be used to
+    /// associate arbitrary metadata with a reserved resource.  For
+    /// example, frameworks can use labels to identify the intended
+    /// purpose for a portion of the resources the framework has
+    /// reserved at a given agent. Labels should not contain duplicate
+    /// key-value pairs.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ReservationInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.role = Default::default();
+        self.principal = Default::default();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(32) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(26) => self.role = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(10) => self.principal = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ReservationInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.role.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.principal.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(32, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.role { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.principal { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ReservationInfo`
+pub mod mod_ReservationInfo {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    STATIC = 1,
+    DYNAMIC = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::STATIC,
+            2 => Type::DYNAMIC,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "STATIC" => Type::STATIC,
+            "DYNAMIC" => Type::DYNAMIC,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DiskInfo<'a> {
+    pub persistence: Option<mesos::v1::mod_Resource::mod_DiskInfo::Persistence<'a>>,
+    /// Describes how this disk resource will be mounted in the
+    /// container. If not set, the disk resource will be used as the
+    /// sandbox. Otherwise, it will be mounted according to the
+    /// 'container_path' inside 'volume'. The 'host_path' inside
+    /// 'volume' is ignored.
+    /// NOTE: If 'volume' is set but 'persistence' is not set, the
+    /// volume will be automatically garbage collected after
+    /// task/executor terminates. Currently, if 'persistence' is set,
+    /// 'volume' must be set.
+    pub volume: Option<mesos::v1::Volume<'a>>,
+    pub source: Option<mesos::v1::mod_Resource::mod_DiskInfo::Source<'a>>,
+}
+
+impl<'a> MessageRead<'a> for DiskInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.persistence = Default::default();
+        self.volume = Default::default();
+        self.source = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.persistence.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.volume.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.source.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DiskInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.persistence.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.volume.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.source.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.persistence { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.volume { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.source { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DiskInfo`
+pub mod mod_DiskInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Describes a persistent disk volume.
+///
+/// A persistent disk volume will not be automatically garbage
+/// collected if the task/executor/agent terminates, but will be
+/// re-offered to the framework(s) belonging to the 'role'.
+///
+/// NOTE: Currently, we do not allow persistent disk volumes
+/// without a reservation (i.e., 'role' cannot be '*').
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Persistence<'a> {
+    /// A unique ID for the persistent disk volume. This ID must be
+    /// unique per role on each agent. Although it is possible to use
+    /// the same ID on different agents in the cluster and to reuse
+    /// IDs after a volume with that ID has been destroyed, both
+    /// practices are discouraged.
+    pub id: Cow<'a, str>,
+    /// This field indicates the principal of the operator or
+    /// framework that created this volume. It is used in conjunction
+    /// with the "destroy" ACL to determine whether an entity
+    /// attempting to destroy the volume is permitted to do so.
+    ///
+    /// NOTE: This field should match the FrameworkInfo.principal of
+    /// the framework that created the volume.
+    pub principal: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Persistence<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.principal = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.id = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.principal = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Persistence<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).len())
+        + self.principal.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.id))?;
+        if let Some(ref s) = self.principal { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes where a disk originates from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Source<'a> {
+    pub type_pb: mesos::v1::mod_Resource::mod_DiskInfo::mod_Source::Type,
+    pub path: Option<mesos::v1::mod_Resource::mod_DiskInfo::mod_Source::Path<'a>>,
+    pub mount: Option<mesos::v1::mod_Resource::mod_DiskInfo::mod_Source::Mount<'a>>,
+    /// An identifier for this source. This field maps onto CSI
+    /// volume IDs and is not expected to be set by frameworks.
+    ///
+    /// EXPERIMENTAL.
+    pub id: Option<Cow<'a, str>>,
+    /// Additional metadata for this source. This field maps onto CSI
+    /// volume metadata and is not expected to be set by frameworks.
+    ///
+    /// EXPERIMENTAL.
+    pub metadata: Option<mesos::v1::Labels<'a>>,
+    /// This field serves as an indirection to a set of storage
+    /// vendor specific disk parameters which describe the properties
+    /// of the disk. The operator will setup mappings between a
+    /// profile name to a set of vendor specific disk parameters. And
+    /// the framework will do disk selection based on profile names,
+    /// instead of vendor specific disk parameters.
+    ///
+    /// Also see the DiskProfileAdaptor module.
+    ///
+    /// EXPERIMENTAL.
+    pub profile: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Source<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.path = Default::default();
+        self.mount = Default::default();
+        self.id = Default::default();
+        self.metadata = Default::default();
+        self.profile = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = r.read_enum(bytes)?,
+                Ok(18) => r.merge_message(bytes, self.path.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.mount.get_or_insert_with(Default::default))?,
+                Ok(34) => self.id = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => r.merge_message(bytes, self.metadata.get_or_insert_with(Default::default))?,
+                Ok(50) => self.profile = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Source<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.path.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.mount.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.metadata.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.profile.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.type_pb as i32))?;
+        if let Some(ref s) = self.path { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.mount { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.id { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.metadata { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.profile { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Source`
+pub mod mod_Source {
+
+use std::borrow::Cow;
+use super::*;
+
+/// A folder that can be located on a separate disk device. This
+/// can be shared and carved up as necessary between frameworks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Path<'a> {
+    /// Path to the folder (e.g., /mnt/raid/disk0). If the path is a
+    /// relative path, it is relative to the agent work directory.
+    pub root: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Path<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.root = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.root = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Path<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.root.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.root { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// A mounted file-system set up by the Agent administrator. This
+/// can only be used exclusively: a framework cannot accept a
+/// partial amount of this disk.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Mount<'a> {
+    /// Path to mount point (e.g., /mnt/raid/disk0). If the path is a
+    /// relative path, it is relative to the agent work directory.
+    pub root: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Mount<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.root = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.root = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Mount<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.root.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.root { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    PATH = 1,
+    MOUNT = 2,
+    BLOCK = 3,
+    RAW = 4,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::PATH,
+            2 => Type::MOUNT,
+            3 => Type::BLOCK,
+            4 => Type::RAW,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "PATH" => Type::PATH,
+            "MOUNT" => Type::MOUNT,
+            "BLOCK" => Type::BLOCK,
+            "RAW" => Type::RAW,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RevocableInfo { }
+
+impl<'a> MessageRead<'a> for RevocableInfo {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for RevocableInfo { }
+
+/// Allow the resource to be shared across tasks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SharedInfo { }
+
+impl<'a> MessageRead<'a> for SharedInfo {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for SharedInfo { }
+
+}
+
+/// When the network bandwidth caps are enabled and the container
+/// is over its limit, outbound packets may be either delayed or
+/// dropped completely either because it exceeds the maximum bandwidth
+/// allocation for a single container (the cap) or because the combined
+/// network traffic of multiple containers on the host exceeds the
+/// transmit capacity of the host (the share). We can report the
+/// following statistics for each of these conditions exported directly
+/// from the Linux Traffic Control Queueing Discipline.
+///
+/// id         : name of the limiter, e.g. 'tx_bw_cap'
+/// backlog    : number of packets currently delayed
+/// bytes      : total bytes seen
+/// drops      : number of packets dropped in total
+/// overlimits : number of packets which exceeded allocation
+/// packets    : total packets seen
+/// qlen       : number of packets currently queued
+/// rate_bps   : throughput in bytes/sec
+/// rate_pps   : throughput in packets/sec
+/// requeues   : number of times a packet has been delayed due to
+///              locking or device contention issues
+///
+/// More information on the operation of Linux Traffic Control can be
+/// found at http://www.lartc.org/lartc.html.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TrafficControlStatistics<'a> {
+    pub id: Cow<'a, str>,
+    pub backlog: Option<u64>,
+    pub bytes: Option<u64>,
+    pub drops: Option<u64>,
+    pub overlimits: Option<u64>,
+    pub packets: Option<u64>,
+    pub qlen: Option<u64>,
+    pub ratebps: Option<u64>,
+    pub ratepps: Option<u64>,
+    pub requeues: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for TrafficControlStatistics<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.backlog = Default::default();
+        self.bytes = Default::default();
+        self.drops = Default::default();
+        self.overlimits = Default::default();
+        self.packets = Default::default();
+        self.qlen = Default::default();
+        self.ratebps = Default::default();
+        self.ratepps = Default::default();
+        self.requeues = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.id = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.backlog = Some(r.read_uint64(bytes)?),
+                Ok(24) => self.bytes = Some(r.read_uint64(bytes)?),
+                Ok(32) => self.drops = Some(r.read_uint64(bytes)?),
+                Ok(40) => self.overlimits = Some(r.read_uint64(bytes)?),
+                Ok(48) => self.packets = Some(r.read_uint64(bytes)?),
+                Ok(56) => self.qlen = Some(r.read_uint64(bytes)?),
+                Ok(64) => self.ratebps = Some(r.read_uint64(bytes)?),
+                Ok(72) => self.ratepps = Some(r.read_uint64(bytes)?),
+                Ok(80) => self.requeues = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TrafficControlStatistics<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).len())
+        + self.backlog.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.drops.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.overlimits.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.packets.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.qlen.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ratebps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ratepps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.requeues.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.id))?;
+        if let Some(ref s) = self.backlog { w.write_with_tag(16, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.bytes { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.drops { w.write_with_tag(32, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.overlimits { w.write_with_tag(40, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.packets { w.write_with_tag(48, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.qlen { w.write_with_tag(56, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.ratebps { w.write_with_tag(64, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.ratepps { w.write_with_tag(72, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.requeues { w.write_with_tag(80, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IpStatistics {
+    pub Forwarding: Option<i64>,
+    pub DefaultTTL: Option<i64>,
+    pub InReceives: Option<i64>,
+    pub InHdrErrors: Option<i64>,
+    pub InAddrErrors: Option<i64>,
+    pub ForwDatagrams: Option<i64>,
+    pub InUnknownProtos: Option<i64>,
+    pub InDiscards: Option<i64>,
+    pub InDelivers: Option<i64>,
+    pub OutRequests: Option<i64>,
+    pub OutDiscards: Option<i64>,
+    pub OutNoRoutes: Option<i64>,
+    pub ReasmTimeout: Option<i64>,
+    pub ReasmReqds: Option<i64>,
+    pub ReasmOKs: Option<i64>,
+    pub ReasmFails: Option<i64>,
+    pub FragOKs: Option<i64>,
+    pub FragFails: Option<i64>,
+    pub FragCreates: Option<i64>,
+}
+
+impl<'a> MessageRead<'a> for IpStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.Forwarding = Default::default();
+        self.DefaultTTL = Default::default();
+        self.InReceives = Default::default();
+        self.InHdrErrors = Default::default();
+        self.InAddrErrors = Default::default();
+        self.ForwDatagrams = Default::default();
+        self.InUnknownProtos = Default::default();
+        self.InDiscards = Default::default();
+        self.InDelivers = Default::default();
+        self.OutRequests = Default::default();
+        self.OutDiscards = Default::default();
+        self.OutNoRoutes = Default::default();
+        self.ReasmTimeout = Default::default();
+        self.ReasmReqds = Default::default();
+        self.ReasmOKs = Default::default();
+        self.ReasmFails = Default::default();
+        self.FragOKs = Default::default();
+        self.FragFails = Default::default();
+        self.FragCreates = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.Forwarding = Some(r.read_int64(bytes)?),
+                Ok(16) => self.DefaultTTL = Some(r.read_int64(bytes)?),
+                Ok(24) => self.InReceives = Some(r.read_int64(bytes)?),
+                Ok(32) => self.InHdrErrors = Some(r.read_int64(bytes)?),
+                Ok(40) => self.InAddrErrors = Some(r.read_int64(bytes)?),
+                Ok(48) => self.ForwDatagrams = Some(r.read_int64(bytes)?),
+                Ok(56) => self.InUnknownProtos = Some(r.read_int64(bytes)?),
+                Ok(64) => self.InDiscards = Some(r.read_int64(bytes)?),
+                Ok(72) => self.InDelivers = Some(r.read_int64(bytes)?),
+                Ok(80) => self.OutRequests = Some(r.read_int64(bytes)?),
+                Ok(88) => self.OutDiscards = Some(r.read_int64(bytes)?),
+                Ok(96) => self.OutNoRoutes = Some(r.read_int64(bytes)?),
+                Ok(104) => self.ReasmTimeout = Some(r.read_int64(bytes)?),
+                Ok(112) => self.ReasmReqds = Some(r.read_int64(bytes)?),
+                Ok(120) => self.ReasmOKs = Some(r.read_int64(bytes)?),
+                Ok(128) => self.ReasmFails = Some(r.read_int64(bytes)?),
+                Ok(136) => self.FragOKs = Some(r.read_int64(bytes)?),
+                Ok(144) => self.FragFails = Some(r.read_int64(bytes)?),
+                Ok(152) => self.FragCreates = Some(r.read_int64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for IpStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.Forwarding.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.DefaultTTL.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InReceives.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InHdrErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InAddrErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ForwDatagrams.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InUnknownProtos.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InDiscards.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InDelivers.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutRequests.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutDiscards.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutNoRoutes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ReasmTimeout.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ReasmReqds.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ReasmOKs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ReasmFails.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.FragOKs.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.FragFails.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.FragCreates.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.Forwarding { w.write_with_tag(8, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.DefaultTTL { w.write_with_tag(16, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InReceives { w.write_with_tag(24, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InHdrErrors { w.write_with_tag(32, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InAddrErrors { w.write_with_tag(40, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ForwDatagrams { w.write_with_tag(48, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InUnknownProtos { w.write_with_tag(56, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InDiscards { w.write_with_tag(64, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InDelivers { w.write_with_tag(72, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutRequests { w.write_with_tag(80, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutDiscards { w.write_with_tag(88, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutNoRoutes { w.write_with_tag(96, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ReasmTimeout { w.write_with_tag(104, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ReasmReqds { w.write_with_tag(112, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ReasmOKs { w.write_with_tag(120, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ReasmFails { w.write_with_tag(128, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.FragOKs { w.write_with_tag(136, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.FragFails { w.write_with_tag(144, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.FragCreates { w.write_with_tag(152, |w| w.write_int64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IcmpStatistics {
+    pub InMsgs: Option<i64>,
+    pub InErrors: Option<i64>,
+    pub InCsumErrors: Option<i64>,
+    pub InDestUnreachs: Option<i64>,
+    pub InTimeExcds: Option<i64>,
+    pub InParmProbs: Option<i64>,
+    pub InSrcQuenchs: Option<i64>,
+    pub InRedirects: Option<i64>,
+    pub InEchos: Option<i64>,
+    pub InEchoReps: Option<i64>,
+    pub InTimestamps: Option<i64>,
+    pub InTimestampReps: Option<i64>,
+    pub InAddrMasks: Option<i64>,
+    pub InAddrMaskReps: Option<i64>,
+    pub OutMsgs: Option<i64>,
+    pub OutErrors: Option<i64>,
+    pub OutDestUnreachs: Option<i64>,
+    pub OutTimeExcds: Option<i64>,
+    pub OutParmProbs: Option<i64>,
+    pub OutSrcQuenchs: Option<i64>,
+    pub OutRedirects: Option<i64>,
+    pub OutEchos: Option<i64>,
+    pub OutEchoReps: Option<i64>,
+    pub OutTimestamps: Option<i64>,
+    pub OutTimestampReps: Option<i64>,
+    pub OutAddrMasks: Option<i64>,
+    pub OutAddrMaskReps: Option<i64>,
+}
+
+impl<'a> MessageRead<'a> for IcmpStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.InMsgs = Default::default();
+        self.InErrors = Default::default();
+        self.InCsumErrors = Default::default();
+        self.InDestUnreachs = Default::default();
+        self.InTimeExcds = Default::default();
+        self.InParmProbs = Default::default();
+        self.InSrcQuenchs = Default::default();
+        self.InRedirects = Default::default();
+        self.InEchos = Default::default();
+        self.InEchoReps = Default::default();
+        self.InTimestamps = Default::default();
+        self.InTimestampReps = Default::default();
+        self.InAddrMasks = Default::default();
+        self.InAddrMaskReps = Default::default();
+        self.OutMsgs = Default::default();
+        self.OutErrors = Default::default();
+        self.OutDestUnreachs = Default::default();
+        self.OutTimeExcds = Default::default();
+        self.OutParmProbs = Default::default();
+        self.OutSrcQuenchs = Default::default();
+        self.OutRedirects = Default::default();
+        self.OutEchos = Default::default();
+        self.OutEchoReps = Default::default();
+        self.OutTimestamps = Default::default();
+        self.OutTimestampReps = Default::default();
+        self.OutAddrMasks = Default::default();
+        self.OutAddrMaskReps = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.InMsgs = Some(r.read_int64(bytes)?),
+                Ok(16) => self.InErrors = Some(r.read_int64(bytes)?),
+                Ok(24) => self.InCsumErrors = Some(r.read_int64(bytes)?),
+                Ok(32) => self.InDestUnreachs = Some(r.read_int64(bytes)?),
+                Ok(40) => self.InTimeExcds = Some(r.read_int64(bytes)?),
+                Ok(48) => self.InParmProbs = Some(r.read_int64(bytes)?),
+                Ok(56) => self.InSrcQuenchs = Some(r.read_int64(bytes)?),
+                Ok(64) => self.InRedirects = Some(r.read_int64(bytes)?),
+                Ok(72) => self.InEchos = Some(r.read_int64(bytes)?),
+                Ok(80) => self.InEchoReps = Some(r.read_int64(bytes)?),
+                Ok(88) => self.InTimestamps = Some(r.read_int64(bytes)?),
+                Ok(96) => self.InTimestampReps = Some(r.read_int64(bytes)?),
+                Ok(104) => self.InAddrMasks = Some(r.read_int64(bytes)?),
+                Ok(112) => self.InAddrMaskReps = Some(r.read_int64(bytes)?),
+                Ok(120) => self.OutMsgs = Some(r.read_int64(bytes)?),
+                Ok(128) => self.OutErrors = Some(r.read_int64(bytes)?),
+                Ok(136) => self.OutDestUnreachs = Some(r.read_int64(bytes)?),
+                Ok(144) => self.OutTimeExcds = Some(r.read_int64(bytes)?),
+                Ok(152) => self.OutParmProbs = Some(r.read_int64(bytes)?),
+                Ok(160) => self.OutSrcQuenchs = Some(r.read_int64(bytes)?),
+                Ok(168) => self.OutRedirects = Some(r.read_int64(bytes)?),
+                Ok(176) => self.OutEchos = Some(r.read_int64(bytes)?),
+                Ok(184) => self.OutEchoReps = Some(r.read_int64(bytes)?),
+                Ok(192) => self.OutTimestamps = Some(r.read_int64(bytes)?),
+                Ok(200) => self.OutTimestampReps = Some(r.read_int64(bytes)?),
+                Ok(208) => self.OutAddrMasks = Some(r.read_int64(bytes)?),
+                Ok(216) => self.OutAddrMaskReps = Some(r.read_int64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for IcmpStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.InMsgs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InCsumErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InDestUnreachs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InTimeExcds.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InParmProbs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InSrcQuenchs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InRedirects.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InEchos.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InEchoReps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InTimestamps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InTimestampReps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InAddrMasks.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InAddrMaskReps.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutMsgs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutErrors.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutDestUnreachs.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutTimeExcds.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutParmProbs.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutSrcQuenchs.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutRedirects.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutEchos.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutEchoReps.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutTimestamps.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutTimestampReps.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutAddrMasks.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.OutAddrMaskReps.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.InMsgs { w.write_with_tag(8, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InErrors { w.write_with_tag(16, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InCsumErrors { w.write_with_tag(24, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InDestUnreachs { w.write_with_tag(32, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InTimeExcds { w.write_with_tag(40, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InParmProbs { w.write_with_tag(48, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InSrcQuenchs { w.write_with_tag(56, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InRedirects { w.write_with_tag(64, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InEchos { w.write_with_tag(72, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InEchoReps { w.write_with_tag(80, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InTimestamps { w.write_with_tag(88, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InTimestampReps { w.write_with_tag(96, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InAddrMasks { w.write_with_tag(104, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InAddrMaskReps { w.write_with_tag(112, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutMsgs { w.write_with_tag(120, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutErrors { w.write_with_tag(128, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutDestUnreachs { w.write_with_tag(136, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutTimeExcds { w.write_with_tag(144, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutParmProbs { w.write_with_tag(152, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutSrcQuenchs { w.write_with_tag(160, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutRedirects { w.write_with_tag(168, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutEchos { w.write_with_tag(176, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutEchoReps { w.write_with_tag(184, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutTimestamps { w.write_with_tag(192, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutTimestampReps { w.write_with_tag(200, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutAddrMasks { w.write_with_tag(208, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutAddrMaskReps { w.write_with_tag(216, |w| w.write_int64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TcpStatistics {
+    pub RtoAlgorithm: Option<i64>,
+    pub RtoMin: Option<i64>,
+    pub RtoMax: Option<i64>,
+    pub MaxConn: Option<i64>,
+    pub ActiveOpens: Option<i64>,
+    pub PassiveOpens: Option<i64>,
+    pub AttemptFails: Option<i64>,
+    pub EstabResets: Option<i64>,
+    pub CurrEstab: Option<i64>,
+    pub InSegs: Option<i64>,
+    pub OutSegs: Option<i64>,
+    pub RetransSegs: Option<i64>,
+    pub InErrs: Option<i64>,
+    pub OutRsts: Option<i64>,
+    pub InCsumErrors: Option<i64>,
+}
+
+impl<'a> MessageRead<'a> for TcpStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.RtoAlgorithm = Default::default();
+        self.RtoMin = Default::default();
+        self.RtoMax = Default::default();
+        self.MaxConn = Default::default();
+        self.ActiveOpens = Default::default();
+        self.PassiveOpens = Default::default();
+        self.AttemptFails = Default::default();
+        self.EstabResets = Default::default();
+        self.CurrEstab = Default::default();
+        self.InSegs = Default::default();
+        self.OutSegs = Default::default();
+        self.RetransSegs = Default::default();
+        self.InErrs = Default::default();
+        self.OutRsts = Default::default();
+        self.InCsumErrors = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.RtoAlgorithm = Some(r.read_int64(bytes)?),
+                Ok(16) => self.RtoMin = Some(r.read_int64(bytes)?),
+                Ok(24) => self.RtoMax = Some(r.read_int64(bytes)?),
+                Ok(32) => self.MaxConn = Some(r.read_int64(bytes)?),
+                Ok(40) => self.ActiveOpens = Some(r.read_int64(bytes)?),
+                Ok(48) => self.PassiveOpens = Some(r.read_int64(bytes)?),
+                Ok(56) => self.AttemptFails = Some(r.read_int64(bytes)?),
+                Ok(64) => self.EstabResets = Some(r.read_int64(bytes)?),
+                Ok(72) => self.CurrEstab = Some(r.read_int64(bytes)?),
+                Ok(80) => self.InSegs = Some(r.read_int64(bytes)?),
+                Ok(88) => self.OutSegs = Some(r.read_int64(bytes)?),
+                Ok(96) => self.RetransSegs = Some(r.read_int64(bytes)?),
+                Ok(104) => self.InErrs = Some(r.read_int64(bytes)?),
+                Ok(112) => self.OutRsts = Some(r.read_int64(bytes)?),
+                Ok(120) => self.InCsumErrors = Some(r.read_int64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TcpStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.RtoAlgorithm.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.RtoMin.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.RtoMax.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.MaxConn.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ActiveOpens.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.PassiveOpens.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.AttemptFails.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.EstabResets.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.CurrEstab.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InSegs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutSegs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.RetransSegs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InErrs.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutRsts.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InCsumErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.RtoAlgorithm { w.write_with_tag(8, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.RtoMin { w.write_with_tag(16, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.RtoMax { w.write_with_tag(24, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.MaxConn { w.write_with_tag(32, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.ActiveOpens { w.write_with_tag(40, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.PassiveOpens { w.write_with_tag(48, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.AttemptFails { w.write_with_tag(56, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.EstabResets { w.write_with_tag(64, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.CurrEstab { w.write_with_tag(72, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InSegs { w.write_with_tag(80, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutSegs { w.write_with_tag(88, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.RetransSegs { w.write_with_tag(96, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InErrs { w.write_with_tag(104, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutRsts { w.write_with_tag(112, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InCsumErrors { w.write_with_tag(120, |w| w.write_int64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct UdpStatistics {
+    pub InDatagrams: Option<i64>,
+    pub NoPorts: Option<i64>,
+    pub InErrors: Option<i64>,
+    pub OutDatagrams: Option<i64>,
+    pub RcvbufErrors: Option<i64>,
+    pub SndbufErrors: Option<i64>,
+    pub InCsumErrors: Option<i64>,
+    pub IgnoredMulti: Option<i64>,
+}
+
+impl<'a> MessageRead<'a> for UdpStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.InDatagrams = Default::default();
+        self.NoPorts = Default::default();
+        self.InErrors = Default::default();
+        self.OutDatagrams = Default::default();
+        self.RcvbufErrors = Default::default();
+        self.SndbufErrors = Default::default();
+        self.InCsumErrors = Default::default();
+        self.IgnoredMulti = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.InDatagrams = Some(r.read_int64(bytes)?),
+                Ok(16) => self.NoPorts = Some(r.read_int64(bytes)?),
+                Ok(24) => self.InErrors = Some(r.read_int64(bytes)?),
+                Ok(32) => self.OutDatagrams = Some(r.read_int64(bytes)?),
+                Ok(40) => self.RcvbufErrors = Some(r.read_int64(bytes)?),
+                Ok(48) => self.SndbufErrors = Some(r.read_int64(bytes)?),
+                Ok(56) => self.InCsumErrors = Some(r.read_int64(bytes)?),
+                Ok(64) => self.IgnoredMulti = Some(r.read_int64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for UdpStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.InDatagrams.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.NoPorts.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OutDatagrams.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.RcvbufErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.SndbufErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.InCsumErrors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.IgnoredMulti.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.InDatagrams { w.write_with_tag(8, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.NoPorts { w.write_with_tag(16, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InErrors { w.write_with_tag(24, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.OutDatagrams { w.write_with_tag(32, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.RcvbufErrors { w.write_with_tag(40, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.SndbufErrors { w.write_with_tag(48, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.InCsumErrors { w.write_with_tag(56, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.IgnoredMulti { w.write_with_tag(64, |w| w.write_int64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SNMPStatistics {
+    pub ip_stats: Option<mesos::v1::IpStatistics>,
+    pub icmp_stats: Option<mesos::v1::IcmpStatistics>,
+    pub tcp_stats: Option<mesos::v1::TcpStatistics>,
+    pub udp_stats: Option<mesos::v1::UdpStatistics>,
+}
+
+impl<'a> MessageRead<'a> for SNMPStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.ip_stats = Default::default();
+        self.icmp_stats = Default::default();
+        self.tcp_stats = Default::default();
+        self.udp_stats = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.ip_stats.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.icmp_stats.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.tcp_stats.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.udp_stats.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for SNMPStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.ip_stats.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.icmp_stats.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tcp_stats.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.udp_stats.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.ip_stats { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.icmp_stats { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.tcp_stats { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.udp_stats { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DiskStatistics<'a> {
+    pub source: Option<mesos::v1::mod_Resource::mod_DiskInfo::Source<'a>>,
+    pub persistence: Option<mesos::v1::mod_Resource::mod_DiskInfo::Persistence<'a>>,
+    pub limit_bytes: Option<u64>,
+    pub used_bytes: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for DiskStatistics<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.source = Default::default();
+        self.persistence = Default::default();
+        self.limit_bytes = Default::default();
+        self.used_bytes = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.source.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.persistence.get_or_insert_with(Default::default))?,
+                Ok(24) => self.limit_bytes = Some(r.read_uint64(bytes)?),
+                Ok(32) => self.used_bytes = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DiskStatistics<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.source.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.persistence.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.limit_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.used_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.source { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.persistence { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.limit_bytes { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.used_bytes { w.write_with_tag(32, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+/// A snapshot of resource usage statistics.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ResourceStatistics<'a> {
+    /// Snapshot time, in seconds since the Epoch.
+    pub timestamp: f64,
+    pub processes: Option<u32>,
+    pub threads: Option<u32>,
+    /// CPU Usage Information:
+    /// Total CPU time spent in user mode, and kernel mode.
+    pub cpus_user_time_secs: Option<f64>,
+    pub cpus_system_time_secs: Option<f64>,
+    /// Number of CPUs allocated.
+    pub cpus_limit: Option<f64>,
+    /// cpu.stat on process throttling (for contention issues).
+    pub cpus_nr_periods: Option<u32>,
+    pub cpus_nr_throttled: Option<u32>,
+    pub cpus_throttled_time_secs: Option<f64>,
+    /// mem_total_bytes was added in 0.23.0 to represent the total memory
+    /// of a process in RAM (as opposed to in Swap). This was previously
+    /// reported as mem_rss_bytes, which was also changed in 0.23.0 to
+    /// represent only the anonymous memory usage, to keep in sync with
+    /// Linux kernel's (arguably erroneous) use of terminology.
+    pub mem_total_bytes: Option<u64>,
+    /// Total memory + swap usage. This is set if swap is enabled.
+    pub mem_total_memsw_bytes: Option<u64>,
+    /// Hard memory limit for a container.
+    pub mem_limit_bytes: Option<u64>,
+    /// Soft memory limit for a container.
+    pub mem_soft_limit_bytes: Option<u64>,
+    /// TODO(chzhcn) mem_file_bytes and mem_anon_bytes are deprecated in
+    /// 0.23.0 and will be removed in 0.24.0.
+    pub mem_file_bytes: Option<u64>,
+    pub mem_anon_bytes: Option<u64>,
+    /// mem_cache_bytes is added in 0.23.0 to represent page cache usage.
+    pub mem_cache_bytes: Option<u64>,
+    /// Since 0.23.0, mem_rss_bytes is changed to represent only
+    /// anonymous memory usage. Note that neither its requiredness, type,
+    /// name nor numeric tag has been changed.
+    pub mem_rss_bytes: Option<u64>,
+    pub mem_mapped_file_bytes: Option<u64>,
+    /// This is only set if swap is enabled.
+    pub mem_swap_bytes: Option<u64>,
+    pub mem_unevictable_bytes: Option<u64>,
+    /// Number of occurrences of different levels of memory pressure
+    /// events reported by memory cgroup. Pressure listening (re)starts
+    /// with these values set to 0 when agent (re)starts. See
+    /// https://www.kernel.org/doc/Documentation/cgroups/memory.txt for
+    /// more details.
+    pub mem_low_pressure_counter: Option<u64>,
+    pub mem_medium_pressure_counter: Option<u64>,
+    pub mem_critical_pressure_counter: Option<u64>,
+    /// Disk Usage Information for executor working directory.
+    pub disk_limit_bytes: Option<u64>,
+    pub disk_used_bytes: Option<u64>,
+    /// Per disk (resource) statistics.
+    pub disk_statistics: Vec<mesos::v1::DiskStatistics<'a>>,
+    /// Cgroups blkio statistics.
+    pub blkio_statistics: Option<mesos::v1::mod_CgroupInfo::mod_Blkio::Statistics>,
+    /// Perf statistics.
+    pub perf: Option<mesos::v1::PerfStatistics>,
+    /// Network Usage Information:
+    pub net_rx_packets: Option<u64>,
+    pub net_rx_bytes: Option<u64>,
+    pub net_rx_errors: Option<u64>,
+    pub net_rx_dropped: Option<u64>,
+    pub net_tx_packets: Option<u64>,
+    pub net_tx_bytes: Option<u64>,
+    pub net_tx_errors: Option<u64>,
+    pub net_tx_dropped: Option<u64>,
+    /// The kernel keeps track of RTT (round-trip time) for its TCP
+    /// sockets. RTT is a way to tell the latency of a container.
+    pub net_tcp_rtt_microsecs_p50: Option<f64>,
+    pub net_tcp_rtt_microsecs_p90: Option<f64>,
+    pub net_tcp_rtt_microsecs_p95: Option<f64>,
+    pub net_tcp_rtt_microsecs_p99: Option<f64>,
+    pub net_tcp_active_connections: Option<f64>,
+    pub net_tcp_time_wait_connections: Option<f64>,
+    /// Network traffic flowing into or out of a container can be delayed
+    /// or dropped due to congestion or policy inside and outside the
+    /// container.
+    pub net_traffic_control_statistics: Vec<mesos::v1::TrafficControlStatistics<'a>>,
+    /// Network SNMP statistics for each container.
+    pub net_snmp_statistics: Option<mesos::v1::SNMPStatistics>,
+}
+
+impl<'a> MessageRead<'a> for ResourceStatistics<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.timestamp = Default::default();
+        self.processes = Default::default();
+        self.threads = Default::default();
+        self.cpus_user_time_secs = Default::default();
+        self.cpus_system_time_secs = Default::default();
+        self.cpus_limit = Default::default();
+        self.cpus_nr_periods = Default::default();
+        self.cpus_nr_throttled = Default::default();
+        self.cpus_throttled_time_secs = Default::default();
+        self.mem_total_bytes = Default::default();
+        self.mem_total_memsw_bytes = Default::default();
+        self.mem_limit_bytes = Default::default();
+        self.mem_soft_limit_bytes = Default::default();
+        self.mem_file_bytes = Default::default();
+        self.mem_anon_bytes = Default::default();
+        self.mem_cache_bytes = Default::default();
+        self.mem_rss_bytes = Default::default();
+        self.mem_mapped_file_bytes = Default::default();
+        self.mem_swap_bytes = Default::default();
+        self.mem_unevictable_bytes = Default::default();
+        self.mem_low_pressure_counter = Default::default();
+        self.mem_medium_pressure_counter = Default::default();
+        self.mem_critical_pressure_counter = Default::default();
+        self.disk_limit_bytes = Default::default();
+        self.disk_used_bytes = Default::default();
+        self.disk_statistics.clear();
+        self.blkio_statistics = Default::default();
+        self.perf = Default::default();
+        self.net_rx_packets = Default::default();
+        self.net_rx_bytes = Default::default();
+        self.net_rx_errors = Default::default();
+        self.net_rx_dropped = Default::default();
+        self.net_tx_packets = Default::default();
+        self.net_tx_bytes = Default::default();
+        self.net_tx_errors = Default::default();
+        self.net_tx_dropped = Default::default();
+        self.net_tcp_rtt_microsecs_p50 = Default::default();
+        self.net_tcp_rtt_microsecs_p90 = Default::default();
+        self.net_tcp_rtt_microsecs_p95 = Default::default();
+        self.net_tcp_rtt_microsecs_p99 = Default::default();
+        self.net_tcp_active_connections = Default::default();
+        self.net_tcp_time_wait_connections = Default::default();
+        self.net_traffic_control_statistics.clear();
+        self.net_snmp_statistics = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.timestamp = r.read_double(bytes)?,
+                Ok(240) => self.processes = Some(r.read_uint32(bytes)?),
+                Ok(248) => self.threads = Some(r.read_uint32(bytes)?),
+                Ok(17) => self.cpus_user_time_secs = Some(r.read_double(bytes)?),
+                Ok(25) => self.cpus_system_time_secs = Some(r.read_double(bytes)?),
+                Ok(33) => self.cpus_limit = Some(r.read_double(bytes)?),
+                Ok(56) => self.cpus_nr_periods = Some(r.read_uint32(bytes)?),
+                Ok(64) => self.cpus_nr_throttled = Some(r.read_uint32(bytes)?),
+                Ok(73) => self.cpus_throttled_time_secs = Some(r.read_double(bytes)?),
+                Ok(288) => self.mem_total_bytes = Some(r.read_uint64(bytes)?),
+                Ok(296) => self.mem_total_memsw_bytes = Some(r.read_uint64(bytes)?),
+                Ok(48) => self.mem_limit_bytes = Some(r.read_uint64(bytes)?),
+                Ok(304) => self.mem_soft_limit_bytes = Some(r.read_uint64(bytes)?),
+                Ok(80) => self.mem_file_bytes = Some(r.read_uint64(bytes)?),
+                Ok(88) => self.mem_anon_bytes = Some(r.read_uint64(bytes)?),
+                Ok(312) => self.mem_cache_bytes = Some(r.read_uint64(bytes)?),
+                Ok(40) => self.mem_rss_bytes = Some(r.read_uint64(bytes)?),
+                Ok(96) => self.mem_mapped_file_bytes = Some(r.read_uint64(bytes)?),
+                Ok(320) => self.mem_swap_bytes = Some(r.read_uint64(bytes)?),
+                Ok(328) => self.mem_unevictable_bytes = Some(r.read_uint64(bytes)?),
+                Ok(256) => self.mem_low_pressure_counter = Some(r.read_uint64(bytes)?),
+                Ok(264) => self.mem_medium_pressure_counter = Some(r.read_uint64(bytes)?),
+                Ok(272) => self.mem_critical_pressure_counter = Some(r.read_uint64(bytes)?),
+                Ok(208) => self.disk_limit_bytes = Some(r.read_uint64(bytes)?),
+                Ok(216) => self.disk_used_bytes = Some(r.read_uint64(bytes)?),
+                Ok(346) => self.disk_statistics.push(r.read_message::<mesos::v1::DiskStatistics>(bytes)?),
+                Ok(354) => r.merge_message(bytes, self.blkio_statistics.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.perf.get_or_insert_with(Default::default))?,
+                Ok(112) => self.net_rx_packets = Some(r.read_uint64(bytes)?),
+                Ok(120) => self.net_rx_bytes = Some(r.read_uint64(bytes)?),
+                Ok(128) => self.net_rx_errors = Some(r.read_uint64(bytes)?),
+                Ok(136) => self.net_rx_dropped = Some(r.read_uint64(bytes)?),
+                Ok(144) => self.net_tx_packets = Some(r.read_uint64(bytes)?),
+                Ok(152) => self.net_tx_bytes = Some(r.read_uint64(bytes)?),
+                Ok(160) => self.net_tx_errors = Some(r.read_uint64(bytes)?),
+                Ok(168) => self.net_tx_dropped = Some(r.read_uint64(bytes)?),
+                Ok(177) => self.net_tcp_rtt_microsecs_p50 = Some(r.read_double(bytes)?),
+                Ok(185) => self.net_tcp_rtt_microsecs_p90 = Some(r.read_double(bytes)?),
+                Ok(193) => self.net_tcp_rtt_microsecs_p95 = Some(r.read_double(bytes)?),
+                Ok(201) => self.net_tcp_rtt_microsecs_p99 = Some(r.read_double(bytes)?),
+                Ok(225) => self.net_tcp_active_connections = Some(r.read_double(bytes)?),
+                Ok(233) => self.net_tcp_time_wait_connections = Some(r.read_double(bytes)?),
+                Ok(282) => self.net_traffic_control_statistics.push(r.read_message::<mesos::v1::TrafficControlStatistics>(bytes)?),
+                Ok(338) => r.merge_message(bytes, self.net_snmp_statistics.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ResourceStatistics<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + 8
+        + self.processes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.threads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.cpus_user_time_secs.as_ref().map_or(0, |_| 1 + 8)
+        + self.cpus_system_time_secs.as_ref().map_or(0, |_| 1 + 8)
+        + self.cpus_limit.as_ref().map_or(0, |_| 1 + 8)
+        + self.cpus_nr_periods.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.cpus_nr_throttled.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.cpus_throttled_time_secs.as_ref().map_or(0, |_| 1 + 8)
+        + self.mem_total_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_total_memsw_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_limit_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mem_soft_limit_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_file_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mem_anon_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mem_cache_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_rss_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mem_mapped_file_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mem_swap_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_unevictable_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_low_pressure_counter.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_medium_pressure_counter.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.mem_critical_pressure_counter.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.disk_limit_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.disk_used_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.disk_statistics.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.blkio_statistics.as_ref().map_or(0, |m| 2 + sizeof_len((m).get_size()))
+        + self.perf.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.net_rx_packets.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.net_rx_bytes.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.net_rx_errors.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_rx_dropped.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_tx_packets.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_tx_bytes.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_tx_errors.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_tx_dropped.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.net_tcp_rtt_microsecs_p50.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_tcp_rtt_microsecs_p90.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_tcp_rtt_microsecs_p95.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_tcp_rtt_microsecs_p99.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_tcp_active_connections.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_tcp_time_wait_connections.as_ref().map_or(0, |_| 2 + 8)
+        + self.net_traffic_control_statistics.iter().map(|s| 2 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.net_snmp_statistics.as_ref().map_or(0, |m| 2 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(9, |w| w.write_double(*&self.timestamp))?;
+        if let Some(ref s) = self.processes { w.write_with_tag(240, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.threads { w.write_with_tag(248, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.cpus_user_time_secs { w.write_with_tag(17, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.cpus_system_time_secs { w.write_with_tag(25, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.cpus_limit { w.write_with_tag(33, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.cpus_nr_periods { w.write_with_tag(56, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.cpus_nr_throttled { w.write_with_tag(64, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.cpus_throttled_time_secs { w.write_with_tag(73, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.mem_total_bytes { w.write_with_tag(288, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_total_memsw_bytes { w.write_with_tag(296, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_limit_bytes { w.write_with_tag(48, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_soft_limit_bytes { w.write_with_tag(304, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_file_bytes { w.write_with_tag(80, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_anon_bytes { w.write_with_tag(88, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_cache_bytes { w.write_with_tag(312, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_rss_bytes { w.write_with_tag(40, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_mapped_file_bytes { w.write_with_tag(96, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_swap_bytes { w.write_with_tag(320, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_unevictable_bytes { w.write_with_tag(328, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_low_pressure_counter { w.write_with_tag(256, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_medium_pressure_counter { w.write_with_tag(264, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mem_critical_pressure_counter { w.write_with_tag(272, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.disk_limit_bytes { w.write_with_tag(208, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.disk_used_bytes { w.write_with_tag(216, |w| w.write_uint64(*s))?; }
+        for s in &self.disk_statistics { w.write_with_tag(346, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.blkio_statistics { w.write_with_tag(354, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.perf { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.net_rx_packets { w.write_with_tag(112, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_rx_bytes { w.write_with_tag(120, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_rx_errors { w.write_with_tag(128, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_rx_dropped { w.write_with_tag(136, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_tx_packets { w.write_with_tag(144, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_tx_bytes { w.write_with_tag(152, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_tx_errors { w.write_with_tag(160, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_tx_dropped { w.write_with_tag(168, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.net_tcp_rtt_microsecs_p50 { w.write_with_tag(177, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.net_tcp_rtt_microsecs_p90 { w.write_with_tag(185, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.net_tcp_rtt_microsecs_p95 { w.write_with_tag(193, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.net_tcp_rtt_microsecs_p99 { w.write_with_tag(201, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.net_tcp_active_connections { w.write_with_tag(225, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.net_tcp_time_wait_connections { w.write_with_tag(233, |w| w.write_double(*s))?; }
+        for s in &self.net_traffic_control_statistics { w.write_with_tag(282, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.net_snmp_statistics { w.write_with_tag(338, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a snapshot of the resource usage for executors.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ResourceUsage<'a> {
+    pub executors: Vec<mesos::v1::mod_ResourceUsage::Executor<'a>>,
+    /// Agent's total resources including checkpointed dynamic
+    /// reservations and persistent volumes.
+    pub total: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for ResourceUsage<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.executors.clear();
+        self.total.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.executors.push(r.read_message::<mesos::v1::mod_ResourceUsage::Executor>(bytes)?),
+                Ok(18) => self.total.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ResourceUsage<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.executors.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.total.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.executors { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.total { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ResourceUsage`
+pub mod mod_ResourceUsage {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Executor<'a> {
+    pub executor_info: mesos::v1::ExecutorInfo<'a>,
+    /// This includes resources used by the executor itself
+    /// as well as its active tasks.
+    pub allocated: Vec<mesos::v1::Resource<'a>>,
+    /// Current resource usage. If absent, the containerizer
+    /// cannot provide resource usage.
+    pub statistics: Option<mesos::v1::ResourceStatistics<'a>>,
+    /// The container id for the executor specified in the executor_info field.
+    pub container_id: mesos::v1::ContainerID<'a>,
+    /// Non-terminal tasks.
+    pub tasks: Vec<mesos::v1::mod_ResourceUsage::mod_Executor::Task<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Executor<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.executor_info = Default::default();
+        self.allocated.clear();
+        self.statistics = Default::default();
+        self.container_id = Default::default();
+        self.tasks.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.executor_info)?,
+                Ok(18) => self.allocated.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(26) => r.merge_message(bytes, self.statistics.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, &mut self.container_id)?,
+                Ok(42) => self.tasks.push(r.read_message::<mesos::v1::mod_ResourceUsage::mod_Executor::Task>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Executor<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.executor_info).get_size())
+        + self.allocated.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.statistics.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.container_id).get_size())
+        + self.tasks.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.executor_info))?;
+        for s in &self.allocated { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.statistics { w.write_with_tag(26, |w| w.write_message(s))?; }
+        w.write_with_tag(34, |w| w.write_message(&self.container_id))?;
+        for s in &self.tasks { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Executor`
+pub mod mod_Executor {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Task<'a> {
+    pub name: Cow<'a, str>,
+    pub id: mesos::v1::TaskID<'a>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Task<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.id = Default::default();
+        self.resources.clear();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, &mut self.id)?,
+                Ok(26) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(34) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Task<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_len((&self.id).get_size())
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(18, |w| w.write_message(&self.id))?;
+        for s in &self.resources { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+}
+
+}
+
+/// Describes a sample of events from "perf stat". Only available on
+/// Linux.
+///
+/// NOTE: Each optional field matches the name of a perf event (see
+/// "perf list") with the following changes:
+/// 1. Names are downcased.
+/// 2. Hyphens ('-') are replaced with underscores ('_').
+/// 3. Events with alternate names use the name "perf stat" returns,
+///    e.g., for the event "cycles OR cpu-cycles" perf always returns
+///    cycles.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PerfStatistics {
+    /// Start of sample interval, in seconds since the Epoch.
+    pub timestamp: f64,
+    /// Duration of sample interval, in seconds.
+    pub duration: f64,
+    /// Hardware event.
+    pub cycles: Option<u64>,
+    pub stalled_cycles_frontend: Option<u64>,
+    pub stalled_cycles_backend: Option<u64>,
+    pub instructions: Option<u64>,
+    pub cache_references: Option<u64>,
+    pub cache_misses: Option<u64>,
+    pub branches: Option<u64>,
+    pub branch_misses: Option<u64>,
+    pub bus_cycles: Option<u64>,
+    pub ref_cycles: Option<u64>,
+    /// Software event.
+    pub cpu_clock: Option<f64>,
+    pub task_clock: Option<f64>,
+    pub page_faults: Option<u64>,
+    pub minor_faults: Option<u64>,
+    pub major_faults: Option<u64>,
+    pub context_switches: Option<u64>,
+    pub cpu_migrations: Option<u64>,
+    pub alignment_faults: Option<u64>,
+    pub emulation_faults: Option<u64>,
+    /// Hardware cache event.
+    pub l1_dcache_loads: Option<u64>,
+    pub l1_dcache_load_misses: Option<u64>,
+    pub l1_dcache_stores: Option<u64>,
+    pub l1_dcache_store_misses: Option<u64>,
+    pub l1_dcache_prefetches: Option<u64>,
+    pub l1_dcache_prefetch_misses: Option<u64>,
+    pub l1_icache_loads: Option<u64>,
+    pub l1_icache_load_misses: Option<u64>,
+    pub l1_icache_prefetches: Option<u64>,
+    pub l1_icache_prefetch_misses: Option<u64>,
+    pub llc_loads: Option<u64>,
+    pub llc_load_misses: Option<u64>,
+    pub llc_stores: Option<u64>,
+    pub llc_store_misses: Option<u64>,
+    pub llc_prefetches: Option<u64>,
+    pub llc_prefetch_misses: Option<u64>,
+    pub dtlb_loads: Option<u64>,
+    pub dtlb_load_misses: Option<u64>,
+    pub dtlb_stores: Option<u64>,
+    pub dtlb_store_misses: Option<u64>,
+    pub dtlb_prefetches: Option<u64>,
+    pub dtlb_prefetch_misses: Option<u64>,
+    pub itlb_loads: Option<u64>,
+    pub itlb_load_misses: Option<u64>,
+    pub branch_loads: Option<u64>,
+    pub branch_load_misses: Option<u64>,
+    pub node_loads: Option<u64>,
+    pub node_load_misses: Option<u64>,
+    pub node_stores: Option<u64>,
+    pub node_store_misses: Option<u64>,
+    pub node_prefetches: Option<u64>,
+    pub node_prefetch_misses: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for PerfStatistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.timestamp = Default::default();
+        self.duration = Default::default();
+        self.cycles = Default::default();
+        self.stalled_cycles_frontend = Default::default();
+        self.stalled_cycles_backend = Default::default();
+        self.instructions = Default::default();
+        self.cache_references = Default::default();
+        self.cache_misses = Default::default();
+        self.branches = Default::default();
+        self.branch_misses = Default::default();
+        self.bus_cycles = Default::default();
+        self.ref_cycles = Default::default();
+        self.cpu_clock = Default::default();
+        self.task_clock = Default::default();
+        self.page_faults = Default::default();
+        self.minor_faults = Default::default();
+        self.major_faults = Default::default();
+        self.context_switches = Default::default();
+        self.cpu_migrations = Default::default();
+        self.alignment_faults = Default::default();
+        self.emulation_faults = Default::default();
+        self.l1_dcache_loads = Default::default();
+        self.l1_dcache_load_misses = Default::default();
+        self.l1_dcache_stores = Default::default();
+        self.l1_dcache_store_misses = Default::default();
+        self.l1_dcache_prefetches = Default::default();
+        self.l1_dcache_prefetch_misses = Default::default();
+        self.l1_icache_loads = Default::default();
+        self.l1_icache_load_misses = Default::default();
+        self.l1_icache_prefetches = Default::default();
+        self.l1_icache_prefetch_misses = Default::default();
+        self.llc_loads = Default::default();
+        self.llc_load_misses = Default::default();
+        self.llc_stores = Default::default();
+        self.llc_store_misses = Default::default();
+        self.llc_prefetches = Default::default();
+        self.llc_prefetch_misses = Default::default();
+        self.dtlb_loads = Default::default();
+        self.dtlb_load_misses = Default::default();
+        self.dtlb_stores = Default::default();
+        self.dtlb_store_misses = Default::default();
+        self.dtlb_prefetches = Default::default();
+        self.dtlb_prefetch_misses = Default::default();
+        self.itlb_loads = Default::default();
+        self.itlb_load_misses = Default::default();
+        self.branch_loads = Default::default();
+        self.branch_load_misses = Default::default();
+        self.node_loads = Default::default();
+        self.node_load_misses = Default::default();
+        self.node_stores = Default::default();
+        self.node_store_misses = Default::default();
+        self.node_prefetches = Default::default();
+        self.node_prefetch_misses = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.timestamp = r.read_double(bytes)?,
+                Ok(17) => self.duration = r.read_double(bytes)?,
+                Ok(24) => self.cycles = Some(r.read_uint64(bytes)?),
+                Ok(32) => self.stalled_cycles_frontend = Some(r.read_uint64(bytes)?),
+                Ok(40) => self.stalled_cycles_backend = Some(r.read_uint64(bytes)?),
+                Ok(48) => self.instructions = Some(r.read_uint64(bytes)?),
+                Ok(56) => self.cache_references = Some(r.read_uint64(bytes)?),
+                Ok(64) => self.cache_misses = Some(r.read_uint64(bytes)?),
+                Ok(72) => self.branches = Some(r.read_uint64(bytes)?),
+                Ok(80) => self.branch_misses = Some(r.read_uint64(bytes)?),
+                Ok(88) => self.bus_cycles = Some(r.read_uint64(bytes)?),
+                Ok(96) => self.ref_cycles = Some(r.read_uint64(bytes)?),
+                Ok(105) => self.cpu_clock = Some(r.read_double(bytes)?),
+                Ok(113) => self.task_clock = Some(r.read_double(bytes)?),
+                Ok(120) => self.page_faults = Some(r.read_uint64(bytes)?),
+                Ok(128) => self.minor_faults = Some(r.read_uint64(bytes)?),
+                Ok(136) => self.major_faults = Some(r.read_uint64(bytes)?),
+                Ok(144) => self.context_switches = Some(r.read_uint64(bytes)?),
+                Ok(152) => self.cpu_migrations = Some(r.read_uint64(bytes)?),
+                Ok(160) => self.alignment_faults = Some(r.read_uint64(bytes)?),
+                Ok(168) => self.emulation_faults = Some(r.read_uint64(bytes)?),
+                Ok(176) => self.l1_dcache_loads = Some(r.read_uint64(bytes)?),
+                Ok(184) => self.l1_dcache_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(192) => self.l1_dcache_stores = Some(r.read_uint64(bytes)?),
+                Ok(200) => self.l1_dcache_store_misses = Some(r.read_uint64(bytes)?),
+                Ok(208) => self.l1_dcache_prefetches = Some(r.read_uint64(bytes)?),
+                Ok(216) => self.l1_dcache_prefetch_misses = Some(r.read_uint64(bytes)?),
+                Ok(224) => self.l1_icache_loads = Some(r.read_uint64(bytes)?),
+                Ok(232) => self.l1_icache_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(240) => self.l1_icache_prefetches = Some(r.read_uint64(bytes)?),
+                Ok(248) => self.l1_icache_prefetch_misses = Some(r.read_uint64(bytes)?),
+                Ok(256) => self.llc_loads = Some(r.read_uint64(bytes)?),
+                Ok(264) => self.llc_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(272) => self.llc_stores = Some(r.read_uint64(bytes)?),
+                Ok(280) => self.llc_store_misses = Some(r.read_uint64(bytes)?),
+                Ok(288) => self.llc_prefetches = Some(r.read_uint64(bytes)?),
+                Ok(296) => self.llc_prefetch_misses = Some(r.read_uint64(bytes)?),
+                Ok(304) => self.dtlb_loads = Some(r.read_uint64(bytes)?),
+                Ok(312) => self.dtlb_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(320) => self.dtlb_stores = Some(r.read_uint64(bytes)?),
+                Ok(328) => self.dtlb_store_misses = Some(r.read_uint64(bytes)?),
+                Ok(336) => self.dtlb_prefetches = Some(r.read_uint64(bytes)?),
+                Ok(344) => self.dtlb_prefetch_misses = Some(r.read_uint64(bytes)?),
+                Ok(352) => self.itlb_loads = Some(r.read_uint64(bytes)?),
+                Ok(360) => self.itlb_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(368) => self.branch_loads = Some(r.read_uint64(bytes)?),
+                Ok(376) => self.branch_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(384) => self.node_loads = Some(r.read_uint64(bytes)?),
+                Ok(392) => self.node_load_misses = Some(r.read_uint64(bytes)?),
+                Ok(400) => self.node_stores = Some(r.read_uint64(bytes)?),
+                Ok(408) => self.node_store_misses = Some(r.read_uint64(bytes)?),
+                Ok(416) => self.node_prefetches = Some(r.read_uint64(bytes)?),
+                Ok(424) => self.node_prefetch_misses = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for PerfStatistics {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + 8
+        + 1 + 8
+        + self.cycles.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.stalled_cycles_frontend.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.stalled_cycles_backend.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.instructions.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.cache_references.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.cache_misses.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.branches.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.branch_misses.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.bus_cycles.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.ref_cycles.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.cpu_clock.as_ref().map_or(0, |_| 1 + 8)
+        + self.task_clock.as_ref().map_or(0, |_| 1 + 8)
+        + self.page_faults.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.minor_faults.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.major_faults.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.context_switches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.cpu_migrations.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.alignment_faults.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.emulation_faults.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_stores.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_store_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_prefetches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_dcache_prefetch_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_icache_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_icache_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_icache_prefetches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.l1_icache_prefetch_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_stores.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_store_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_prefetches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.llc_prefetch_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_stores.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_store_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_prefetches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.dtlb_prefetch_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.itlb_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.itlb_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.branch_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.branch_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_loads.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_load_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_stores.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_store_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_prefetches.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+        + self.node_prefetch_misses.as_ref().map_or(0, |m| 2 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(9, |w| w.write_double(*&self.timestamp))?;
+        w.write_with_tag(17, |w| w.write_double(*&self.duration))?;
+        if let Some(ref s) = self.cycles { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.stalled_cycles_frontend { w.write_with_tag(32, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.stalled_cycles_backend { w.write_with_tag(40, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.instructions { w.write_with_tag(48, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.cache_references { w.write_with_tag(56, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.cache_misses { w.write_with_tag(64, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.branches { w.write_with_tag(72, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.branch_misses { w.write_with_tag(80, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.bus_cycles { w.write_with_tag(88, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.ref_cycles { w.write_with_tag(96, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.cpu_clock { w.write_with_tag(105, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.task_clock { w.write_with_tag(113, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.page_faults { w.write_with_tag(120, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.minor_faults { w.write_with_tag(128, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.major_faults { w.write_with_tag(136, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.context_switches { w.write_with_tag(144, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.cpu_migrations { w.write_with_tag(152, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.alignment_faults { w.write_with_tag(160, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.emulation_faults { w.write_with_tag(168, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_loads { w.write_with_tag(176, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_load_misses { w.write_with_tag(184, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_stores { w.write_with_tag(192, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_store_misses { w.write_with_tag(200, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_prefetches { w.write_with_tag(208, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_dcache_prefetch_misses { w.write_with_tag(216, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_icache_loads { w.write_with_tag(224, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_icache_load_misses { w.write_with_tag(232, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_icache_prefetches { w.write_with_tag(240, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.l1_icache_prefetch_misses { w.write_with_tag(248, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_loads { w.write_with_tag(256, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_load_misses { w.write_with_tag(264, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_stores { w.write_with_tag(272, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_store_misses { w.write_with_tag(280, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_prefetches { w.write_with_tag(288, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.llc_prefetch_misses { w.write_with_tag(296, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_loads { w.write_with_tag(304, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_load_misses { w.write_with_tag(312, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_stores { w.write_with_tag(320, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_store_misses { w.write_with_tag(328, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_prefetches { w.write_with_tag(336, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.dtlb_prefetch_misses { w.write_with_tag(344, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.itlb_loads { w.write_with_tag(352, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.itlb_load_misses { w.write_with_tag(360, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.branch_loads { w.write_with_tag(368, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.branch_load_misses { w.write_with_tag(376, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_loads { w.write_with_tag(384, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_load_misses { w.write_with_tag(392, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_stores { w.write_with_tag(400, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_store_misses { w.write_with_tag(408, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_prefetches { w.write_with_tag(416, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.node_prefetch_misses { w.write_with_tag(424, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a request for resources that can be used by a framework
+/// to proactively influence the allocator.  If 'agent_id' is provided
+/// then this request is assumed to only apply to resources on that
+/// agent.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Request<'a> {
+    pub agent_id: Option<mesos::v1::AgentID<'a>>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Request<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.agent_id = Default::default();
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.agent_id.get_or_insert_with(Default::default))?,
+                Ok(18) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Request<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.agent_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.agent_id { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.resources { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes some resources available on an agent. An offer only
+/// contains resources from a single agent.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Offer<'a> {
+    pub id: mesos::v1::OfferID<'a>,
+    pub framework_id: mesos::v1::FrameworkID<'a>,
+    pub agent_id: mesos::v1::AgentID<'a>,
+    pub hostname: Cow<'a, str>,
+    /// URL for reaching the agent running on the host.
+    pub url: Option<mesos::v1::URL<'a>>,
+    /// The domain of the agent.
+    pub domain: Option<mesos::v1::DomainInfo<'a>>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub attributes: Vec<mesos::v1::Attribute<'a>>,
+    /// Executors of the same framework running on this agent.
+    pub executor_ids: Vec<mesos::v1::ExecutorID<'a>>,
+    /// Signifies that the resources in this Offer may be unavailable during
+    /// the given interval.  Any tasks launched using these resources may be
+    /// killed when the interval arrives.  For example, these resources may be
+    /// part of a planned maintenance schedule.
+    ///
+    /// This field only provides information about a planned unavailability.
+    /// The unavailability interval may not necessarily start at exactly this
+    /// interval, nor last for exactly the duration of this interval.
+    /// The unavailability may also be forever!  See comments in
+    /// `Unavailability` for more details.
+    pub unavailability: Option<mesos::v1::Unavailability>,
+    /// An offer represents resources allocated to *one* of the
+    /// roles managed by the scheduler. (Therefore, each
+    /// `Offer.resources[i].allocation_info` will match the
+    /// top level `Offer.allocation_info`).
+    pub allocation_info: Option<mesos::v1::mod_Resource::AllocationInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Offer<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.framework_id = Default::default();
+        self.agent_id = Default::default();
+        self.hostname = Default::default();
+        self.url = Default::default();
+        self.domain = Default::default();
+        self.resources.clear();
+        self.attributes.clear();
+        self.executor_ids.clear();
+        self.unavailability = Default::default();
+        self.allocation_info = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.id)?,
+                Ok(18) => r.merge_message(bytes, &mut self.framework_id)?,
+                Ok(26) => r.merge_message(bytes, &mut self.agent_id)?,
+                Ok(34) => self.hostname = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(66) => r.merge_message(bytes, self.url.get_or_insert_with(Default::default))?,
+                Ok(90) => r.merge_message(bytes, self.domain.get_or_insert_with(Default::default))?,
+                Ok(42) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(58) => self.attributes.push(r.read_message::<mesos::v1::Attribute>(bytes)?),
+                Ok(50) => self.executor_ids.push(r.read_message::<mesos::v1::ExecutorID>(bytes)?),
+                Ok(74) => r.merge_message(bytes, self.unavailability.get_or_insert_with(Default::default))?,
+                Ok(82) => r.merge_message(bytes, self.allocation_info.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Offer<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).get_size())
+        + 1 + sizeof_len((&self.framework_id).get_size())
+        + 1 + sizeof_len((&self.agent_id).get_size())
+        + 1 + sizeof_len((&self.hostname).len())
+        + self.url.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.domain.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.attributes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.executor_ids.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.unavailability.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.allocation_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.id))?;
+        w.write_with_tag(18, |w| w.write_message(&self.framework_id))?;
+        w.write_with_tag(26, |w| w.write_message(&self.agent_id))?;
+        w.write_with_tag(34, |w| w.write_string(&**&self.hostname))?;
+        if let Some(ref s) = self.url { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.domain { w.write_with_tag(90, |w| w.write_message(s))?; }
+        for s in &self.resources { w.write_with_tag(42, |w| w.write_message(s))?; }
+        for s in &self.attributes { w.write_with_tag(58, |w| w.write_message(s))?; }
+        for s in &self.executor_ids { w.write_with_tag(50, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.unavailability { w.write_with_tag(74, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.allocation_info { w.write_with_tag(82, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Offer`
+pub mod mod_Offer {
+
+use super::*;
+
+/// Defines an operation that can be performed against offers.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Operation<'a> {
+    pub type_pb: Option<mesos::v1::mod_Offer::mod_Operation::Type>,
+    /// NOTE: The `id` field will allow frameworks to indicate that they wish to
+    /// receive feedback about an operation. Since this feature is not yet
+    /// implemented, the `id` field should NOT be set at present. See MESOS-8054.
+    ///
+    /// EXPERIMENTAL.
+    pub id: Option<mesos::v1::OperationID<'a>>,
+    pub launch: Option<mesos::v1::mod_Offer::mod_Operation::Launch<'a>>,
+    pub launch_group: Option<mesos::v1::mod_Offer::mod_Operation::LaunchGroup<'a>>,
+    pub reserve: Option<mesos::v1::mod_Offer::mod_Operation::Reserve<'a>>,
+    pub unreserve: Option<mesos::v1::mod_Offer::mod_Operation::Unreserve<'a>>,
+    pub create: Option<mesos::v1::mod_Offer::mod_Operation::Create<'a>>,
+    pub destroy: Option<mesos::v1::mod_Offer::mod_Operation::Destroy<'a>>,
+    /// EXPERIMENTAL.
+    pub grow_volume: Option<mesos::v1::mod_Offer::mod_Operation::GrowVolume<'a>>,
+    /// EXPERIMENTAL.
+    pub shrink_volume: Option<mesos::v1::mod_Offer::mod_Operation::ShrinkVolume<'a>>,
+    /// EXPERIMENTAL.
+    pub create_disk: Option<mesos::v1::mod_Offer::mod_Operation::CreateDisk<'a>>,
+    /// EXPERIMENTAL.
+    pub destroy_disk: Option<mesos::v1::mod_Offer::mod_Operation::DestroyDisk<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Operation<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.id = Default::default();
+        self.launch = Default::default();
+        self.launch_group = Default::default();
+        self.reserve = Default::default();
+        self.unreserve = Default::default();
+        self.create = Default::default();
+        self.destroy = Default::default();
+        self.grow_volume = Default::default();
+        self.shrink_volume = Default::default();
+        self.create_disk = Default::default();
+        self.destroy_disk = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(98) => r.merge_message(bytes, self.id.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.launch.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.launch_group.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.reserve.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.unreserve.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.create.get_or_insert_with(Default::default))?,
+                Ok(50) => r.merge_message(bytes, self.destroy.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.grow_volume.get_or_insert_with(Default::default))?,
+                Ok(114) => r.merge_message(bytes, self.shrink_volume.get_or_insert_with(Default::default))?,
+                Ok(122) => r.merge_message(bytes, self.create_disk.get_or_insert_with(Default::default))?,
+                Ok(130) => r.merge_message(bytes, self.destroy_disk.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Operation<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.launch.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.launch_group.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.reserve.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.unreserve.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.create.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.destroy.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.grow_volume.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.shrink_volume.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.create_disk.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.destroy_disk.as_ref().map_or(0, |m| 2 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.id { w.write_with_tag(98, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.launch { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.launch_group { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.reserve { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.unreserve { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.create { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.destroy { w.write_with_tag(50, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.grow_volume { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.shrink_volume { w.write_with_tag(114, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.create_disk { w.write_with_tag(122, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.destroy_disk { w.write_with_tag(130, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Operation`
+pub mod mod_Operation {
+
+use super::*;
+
+/// TODO(vinod): Deprecate this in favor of `LaunchGroup` below.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Launch<'a> {
+    pub task_infos: Vec<mesos::v1::TaskInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Launch<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.task_infos.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.task_infos.push(r.read_message::<mesos::v1::TaskInfo>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Launch<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.task_infos.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.task_infos { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Unlike `Launch` above, all the tasks in a `task_group` are
+/// atomically delivered to an executor.
+///
+/// `NetworkInfo` set on executor will be shared by all tasks in
+/// the task group.
+///
+/// TODO(vinod): Any volumes set on executor could be used by a
+/// task by explicitly setting `Volume.source` in its resources.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct LaunchGroup<'a> {
+    pub executor: mesos::v1::ExecutorInfo<'a>,
+    pub task_group: mesos::v1::TaskGroupInfo<'a>,
+}
+
+impl<'a> MessageRead<'a> for LaunchGroup<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.executor = Default::default();
+        self.task_group = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.executor)?,
+                Ok(18) => r.merge_message(bytes, &mut self.task_group)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for LaunchGroup<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.executor).get_size())
+        + 1 + sizeof_len((&self.task_group).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.executor))?;
+        w.write_with_tag(18, |w| w.write_message(&self.task_group))?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Reserve<'a> {
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Reserve<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Reserve<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.resources { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Unreserve<'a> {
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Unreserve<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Unreserve<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.resources { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Create<'a> {
+    pub volumes: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Create<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.volumes.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.volumes.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Create<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.volumes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.volumes { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Destroy<'a> {
+    pub volumes: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Destroy<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.volumes.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.volumes.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Destroy<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.volumes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.volumes { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Grow a volume by an additional disk resource.
+/// NOTE: This is currently experimental and only for persistent volumes
+/// created on ROOT/PATH disk.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct GrowVolume<'a> {
+    pub volume: mesos::v1::Resource<'a>,
+    pub addition: mesos::v1::Resource<'a>,
+}
+
+impl<'a> MessageRead<'a> for GrowVolume<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.volume = Default::default();
+        self.addition = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.volume)?,
+                Ok(18) => r.merge_message(bytes, &mut self.addition)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for GrowVolume<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.volume).get_size())
+        + 1 + sizeof_len((&self.addition).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.volume))?;
+        w.write_with_tag(18, |w| w.write_message(&self.addition))?;
+        Ok(())
+    }
+}
+
+/// Shrink a volume by the size specified in the `subtract` field.
+/// NOTE: This is currently experimental and only for persistent volumes
+/// created on ROOT/PATH disk.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ShrinkVolume<'a> {
+    pub volume: mesos::v1::Resource<'a>,
+    /// See comments in `Value.Scalar` for maximum precision supported.
+    pub subtract: mesos::v1::mod_Value::Scalar,
+}
+
+impl<'a> MessageRead<'a> for ShrinkVolume<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.volume = Default::default();
+        self.subtract = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.volume)?,
+                Ok(18) => r.merge_message(bytes, &mut self.subtract)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ShrinkVolume<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.volume).get_size())
+        + 1 + sizeof_len((&self.subtract).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.volume))?;
+        w.write_with_tag(18, |w| w.write_message(&self.subtract))?;
+        Ok(())
+    }
+}
+
+/// Create a `MOUNT` or `BLOCK` disk resource from a `RAW` disk resource.
+/// NOTE: For the time being, this API is subject to change and the related
+/// feature is experimental.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CreateDisk<'a> {
+    pub source: mesos::v1::Resource<'a>,
+    /// NOTE: Only `MOUNT` or `BLOCK` is allowed in the `target_type` field.
+    pub target_type: mesos::v1::mod_Resource::mod_DiskInfo::mod_Source::Type,
+}
+
+impl<'a> MessageRead<'a> for CreateDisk<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.source = Default::default();
+        self.target_type = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.source)?,
+                Ok(16) => self.target_type = r.read_enum(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for CreateDisk<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.source).get_size())
+        + 1 + sizeof_varint(*(&self.target_type) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.source))?;
+        w.write_with_tag(16, |w| w.write_enum(*&self.target_type as i32))?;
+        Ok(())
+    }
+}
+
+/// Destroy a `MOUNT` or `BLOCK` disk resource. This will result in a `RAW`
+/// disk resource.
+/// NOTE: For the time being, this API is subject to change and the related
+/// feature is experimental.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DestroyDisk<'a> {
+    /// NOTE: Only a `MOUNT` or `BLOCK` disk is allowed in the `source` field.
+    pub source: mesos::v1::Resource<'a>,
+}
+
+impl<'a> MessageRead<'a> for DestroyDisk<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.source = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.source)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DestroyDisk<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.source).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.source))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    LAUNCH = 1,
+    LAUNCH_GROUP = 6,
+    RESERVE = 2,
+    UNRESERVE = 3,
+    CREATE = 4,
+    DESTROY = 5,
+    /// EXPERIMENTAL.
+    GROW_VOLUME = 11,
+    /// EXPERIMENTAL.
+    SHRINK_VOLUME = 12,
+    /// EXPERIMENTAL.
+    CREATE_DISK = 13,
+    /// EXPERIMENTAL.
+    DESTROY_DISK = 14,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::LAUNCH,
+            6 => Type::LAUNCH_GROUP,
+            2 => Type::RESERVE,
+            3 => Type::UNRESERVE,
+            4 => Type::CREATE,
+            5 => Type::DESTROY,
+            11 => Type::GROW_VOLUME,
+            12 => Type::SHRINK_VOLUME,
+            13 => Type::CREATE_DISK,
+            14 => Type::DESTROY_DISK,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "LAUNCH" => Type::LAUNCH,
+            "LAUNCH_GROUP" => Type::LAUNCH_GROUP,
+            "RESERVE" => Type::RESERVE,
+            "UNRESERVE" => Type::UNRESERVE,
+            "CREATE" => Type::CREATE,
+            "DESTROY" => Type::DESTROY,
+            "GROW_VOLUME" => Type::GROW_VOLUME,
+            "SHRINK_VOLUME" => Type::SHRINK_VOLUME,
+            "CREATE_DISK" => Type::CREATE_DISK,
+            "DESTROY_DISK" => Type::DESTROY_DISK,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// A request to return some resources occupied by a framework.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct InverseOffer<'a> {
+    /// This is the same OfferID as found in normal offers, which allows
+    /// re-use of some of the OfferID-only messages.
+    pub id: mesos::v1::OfferID<'a>,
+    /// URL for reaching the agent running on the host.  This enables some
+    /// optimizations as described in MESOS-3012, such as allowing the
+    /// scheduler driver to bypass the master and talk directly with an agent.
+    pub url: Option<mesos::v1::URL<'a>>,
+    /// The framework that should release its resources.
+    /// If no specifics are provided (i.e. which agent), all the framework's
+    /// resources are requested back.
+    pub framework_id: mesos::v1::FrameworkID<'a>,
+    /// Specified if the resources need to be released from a particular agent.
+    /// All the framework's resources on this agent are requested back,
+    /// unless further qualified by the `resources` field.
+    pub agent_id: Option<mesos::v1::AgentID<'a>>,
+    /// This InverseOffer represents a planned unavailability event in the
+    /// specified interval.  Any tasks running on the given framework or agent
+    /// may be killed when the interval arrives.  Therefore, frameworks should
+    /// aim to gracefully terminate tasks prior to the arrival of the interval.
+    ///
+    /// For reserved resources, the resources are expected to be returned to the
+    /// framework after the unavailability interval.  This is an expectation,
+    /// not a guarantee.  For example, if the unavailability duration is not set,
+    /// the resources may be removed permanently.
+    ///
+    /// For other resources, there is no guarantee that requested resources will
+    /// be returned after the unavailability interval.  The allocator has no
+    /// obligation to re-offer these resources to the prior framework after
+    /// the unavailability.
+    pub unavailability: mesos::v1::Unavailability,
+    /// A list of resources being requested back from the framework,
+    /// on the agent identified by `agent_id`.  If no resources are specified
+    /// then all resources are being requested back.  For the purpose of
+    /// maintenance, this field is always empty (maintenance always requests
+    /// all resources back).
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for InverseOffer<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.url = Default::default();
+        self.framework_id = Default::default();
+        self.agent_id = Default::default();
+        self.unavailability = Default::default();
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.id)?,
+                Ok(18) => r.merge_message(bytes, self.url.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, &mut self.framework_id)?,
+                Ok(34) => r.merge_message(bytes, self.agent_id.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, &mut self.unavailability)?,
+                Ok(50) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for InverseOffer<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.id).get_size())
+        + self.url.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.framework_id).get_size())
+        + self.agent_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.unavailability).get_size())
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.id))?;
+        if let Some(ref s) = self.url { w.write_with_tag(18, |w| w.write_message(s))?; }
+        w.write_with_tag(26, |w| w.write_message(&self.framework_id))?;
+        if let Some(ref s) = self.agent_id { w.write_with_tag(34, |w| w.write_message(s))?; }
+        w.write_with_tag(42, |w| w.write_message(&self.unavailability))?;
+        for s in &self.resources { w.write_with_tag(50, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a task. Passed from the scheduler all the way to an
+/// executor (see SchedulerDriver::launchTasks and
+/// Executor::launchTask). Either ExecutorInfo or CommandInfo should be set.
+/// A different executor can be used to launch this task, and subsequent tasks
+/// meant for the same executor can reuse the same ExecutorInfo struct.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskInfo<'a> {
+    pub name: Cow<'a, str>,
+    pub task_id: mesos::v1::TaskID<'a>,
+    pub agent_id: mesos::v1::AgentID<'a>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub executor: Option<mesos::v1::ExecutorInfo<'a>>,
+    pub command: Option<mesos::v1::CommandInfo<'a>>,
+    /// Task provided with a container will launch the container as part
+    /// of this task paired with the task's CommandInfo.
+    pub container: Option<mesos::v1::ContainerInfo<'a>>,
+    /// A health check for the task. Implemented for executor-less
+    /// command-based tasks. For tasks that specify an executor, it is
+    /// the executor's responsibility to implement the health checking.
+    pub health_check: Option<mesos::v1::HealthCheck<'a>>,
+    /// A general check for the task. Implemented for all built-in executors.
+    /// For tasks that specify an executor, it is the executor's responsibility
+    /// to implement checking support. Executors should (all built-in executors
+    /// will) neither interpret nor act on the check's result.
+    ///
+    /// NOTE: Check support in built-in executors is experimental.
+    ///
+    /// TODO(alexr): Consider supporting multiple checks per task.
+    pub check: Option<mesos::v1::CheckInfo<'a>>,
+    /// A kill policy for the task. Implemented for executor-less
+    /// command-based and docker tasks. For tasks that specify an
+    /// executor, it is the executor's responsibility to implement
+    /// the kill policy.
+    pub kill_policy: Option<mesos::v1::KillPolicy>,
+    pub data: Option<Cow<'a, [u8]>>,
+    /// Labels are free-form key value pairs which are exposed through
+    /// master and agent endpoints. Labels will not be interpreted or
+    /// acted upon by Mesos itself. As opposed to the data field, labels
+    /// will be kept in memory on master and agent processes. Therefore,
+    /// labels should be used to tag tasks with light-weight meta-data.
+    /// Labels should not contain duplicate key-value pairs.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+    /// Service discovery information for the task. It is not interpreted
+    /// or acted upon by Mesos. It is up to a service discovery system
+    /// to use this information as needed and to handle tasks without
+    /// service discovery information.
+    pub discovery: Option<mesos::v1::DiscoveryInfo<'a>>,
+    /// Maximum duration for task completion. If the task is non-terminal at the
+    /// end of this duration, it will fail with the reason
+    /// `REASON_MAX_COMPLETION_TIME_REACHED`. Mesos supports this field for
+    /// executor-less tasks, and tasks that use Docker or default executors.
+    /// It is the executor's responsibility to implement this, so it might not be
+    /// supported by all custom executors.
+    pub max_completion_time: Option<mesos::v1::DurationInfo>,
+}
+
+impl<'a> MessageRead<'a> for TaskInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.task_id = Default::default();
+        self.agent_id = Default::default();
+        self.resources.clear();
+        self.executor = Default::default();
+        self.command = Default::default();
+        self.container = Default::default();
+        self.health_check = Default::default();
+        self.check = Default::default();
+        self.kill_policy = Default::default();
+        self.data = Default::default();
+        self.labels = Default::default();
+        self.discovery = Default::default();
+        self.max_completion_time = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, &mut self.task_id)?,
+                Ok(26) => r.merge_message(bytes, &mut self.agent_id)?,
+                Ok(34) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(42) => r.merge_message(bytes, self.executor.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(74) => r.merge_message(bytes, self.container.get_or_insert_with(Default::default))?,
+                Ok(66) => r.merge_message(bytes, self.health_check.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.check.get_or_insert_with(Default::default))?,
+                Ok(98) => r.merge_message(bytes, self.kill_policy.get_or_insert_with(Default::default))?,
+                Ok(50) => self.data = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(82) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(90) => r.merge_message(bytes, self.discovery.get_or_insert_with(Default::default))?,
+                Ok(114) => r.merge_message(bytes, self.max_completion_time.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TaskInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_len((&self.task_id).get_size())
+        + 1 + sizeof_len((&self.agent_id).get_size())
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.executor.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.container.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.health_check.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.check.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.kill_policy.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.discovery.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.max_completion_time.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(18, |w| w.write_message(&self.task_id))?;
+        w.write_with_tag(26, |w| w.write_message(&self.agent_id))?;
+        for s in &self.resources { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.executor { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.container { w.write_with_tag(74, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.health_check { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.check { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.kill_policy { w.write_with_tag(98, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.data { w.write_with_tag(50, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(82, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.discovery { w.write_with_tag(90, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.max_completion_time { w.write_with_tag(114, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a group of tasks that belong to an executor. The
+/// executor will receive the task group in a single message to
+/// allow the group to be launched "atomically".
+///
+/// NOTES:
+/// 1) `NetworkInfo` must not be set inside task's `ContainerInfo`.
+/// 2) `TaskInfo.executor` doesn't need to set. If set, it should match
+///    `LaunchGroup.executor`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskGroupInfo<'a> {
+    pub tasks: Vec<mesos::v1::TaskInfo<'a>>,
+}
+
+impl<'a> MessageRead<'a> for TaskGroupInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.tasks.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.tasks.push(r.read_message::<mesos::v1::TaskInfo>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TaskGroupInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.tasks.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.tasks { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// TODO(bmahler): Add executor_uuid here, and send it to the master. This will
+/// allow us to expose executor work directories for tasks in the webui when
+/// looking from the master level. Currently only the agent knows which run the
+/// task belongs to.
+/// Describes a task, similar to `TaskInfo`.
+///
+/// `Task` is used in some of the Mesos messages found below.
+/// `Task` is used instead of `TaskInfo` if:
+///   1) we need additional IDs, such as a specific
+///      framework, executor, or agent; or
+///   2) we do not need the additional data, such as the command run by the
+///      task or the health checks.  These additional fields may be large and
+///      unnecessary for some Mesos messages.
+///
+/// `Task` is generally constructed from a `TaskInfo`.  See protobuf::createTask.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Task<'a> {
+    pub name: Cow<'a, str>,
+    pub task_id: mesos::v1::TaskID<'a>,
+    pub framework_id: mesos::v1::FrameworkID<'a>,
+    pub executor_id: Option<mesos::v1::ExecutorID<'a>>,
+    pub agent_id: mesos::v1::AgentID<'a>,
+    /// Latest state of the task.
+    pub state: mesos::v1::TaskState,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+    pub statuses: Vec<mesos::v1::TaskStatus<'a>>,
+    /// These fields correspond to the state and uuid of the latest
+    /// status update forwarded to the master.
+    /// NOTE: Either both the fields must be set or both must be unset.
+    pub status_update_state: Option<mesos::v1::TaskState>,
+    pub status_update_uuid: Option<Cow<'a, [u8]>>,
+    pub labels: Option<mesos::v1::Labels<'a>>,
+    /// Service discovery information for the task. It is not interpreted
+    /// or acted upon by Mesos. It is up to a service discovery system
+    /// to use this information as needed and to handle tasks without
+    /// service discovery information.
+    pub discovery: Option<mesos::v1::DiscoveryInfo<'a>>,
+    /// Container information for the task.
+    pub container: Option<mesos::v1::ContainerInfo<'a>>,
+    /// Specific user under which task is running.
+    pub user: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Task<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.task_id = Default::default();
+        self.framework_id = Default::default();
+        self.executor_id = Default::default();
+        self.agent_id = Default::default();
+        self.state = Default::default();
+        self.resources.clear();
+        self.statuses.clear();
+        self.status_update_state = Default::default();
+        self.status_update_uuid = Default::default();
+        self.labels = Default::default();
+        self.discovery = Default::default();
+        self.container = Default::default();
+        self.user = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, &mut self.task_id)?,
+                Ok(26) => r.merge_message(bytes, &mut self.framework_id)?,
+                Ok(34) => r.merge_message(bytes, self.executor_id.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, &mut self.agent_id)?,
+                Ok(48) => self.state = r.read_enum(bytes)?,
+                Ok(58) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(66) => self.statuses.push(r.read_message::<mesos::v1::TaskStatus>(bytes)?),
+                Ok(72) => self.status_update_state = Some(r.read_enum(bytes)?),
+                Ok(82) => self.status_update_uuid = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(90) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(98) => r.merge_message(bytes, self.discovery.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.container.get_or_insert_with(Default::default))?,
+                Ok(114) => self.user = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Task<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_len((&self.task_id).get_size())
+        + 1 + sizeof_len((&self.framework_id).get_size())
+        + self.executor_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.agent_id).get_size())
+        + 1 + sizeof_varint(*(&self.state) as u64)
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.statuses.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.status_update_state.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.status_update_uuid.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.discovery.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.container.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.user.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(18, |w| w.write_message(&self.task_id))?;
+        w.write_with_tag(26, |w| w.write_message(&self.framework_id))?;
+        if let Some(ref s) = self.executor_id { w.write_with_tag(34, |w| w.write_message(s))?; }
+        w.write_with_tag(42, |w| w.write_message(&self.agent_id))?;
+        w.write_with_tag(48, |w| w.write_enum(*&self.state as i32))?;
+        for s in &self.resources { w.write_with_tag(58, |w| w.write_message(s))?; }
+        for s in &self.statuses { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.status_update_state { w.write_with_tag(72, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.status_update_uuid { w.write_with_tag(82, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(90, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.discovery { w.write_with_tag(98, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.container { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.user { w.write_with_tag(114, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a resource limitation that caused a task failure.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskResourceLimitation<'a> {
+    /// This field contains the resource whose limits were violated.
+    ///
+    /// NOTE: 'Resources' is used here because the resource may span
+    /// multiple roles (e.g. `"mem(*):1;mem(role):2"`).
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for TaskResourceLimitation<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TaskResourceLimitation<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.resources { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a UUID.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct UUID<'a> {
+    pub value: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for UUID<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.value = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for UUID<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_bytes(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// Describes an operation, similar to `Offer.Operation`, with
+/// some additional information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Operation<'a> {
+    pub framework_id: Option<mesos::v1::FrameworkID<'a>>,
+    pub agent_id: Option<mesos::v1::AgentID<'a>>,
+    pub info: mesos::v1::mod_Offer::Operation<'a>,
+    pub latest_status: mesos::v1::OperationStatus<'a>,
+    /// All the statuses known to this operation. Some of the statuses in this
+    /// list might not have been acknowledged yet. The statuses are ordered.
+    pub statuses: Vec<mesos::v1::OperationStatus<'a>>,
+    /// This is the internal UUID for the operation, which is kept independently
+    /// from the framework-specified operation ID, which is optional.
+    pub uuid: mesos::v1::UUID<'a>,
+}
+
+impl<'a> MessageRead<'a> for Operation<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.framework_id = Default::default();
+        self.agent_id = Default::default();
+        self.info = Default::default();
+        self.latest_status = Default::default();
+        self.statuses.clear();
+        self.uuid = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.framework_id.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.agent_id.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, &mut self.info)?,
+                Ok(34) => r.merge_message(bytes, &mut self.latest_status)?,
+                Ok(42) => self.statuses.push(r.read_message::<mesos::v1::OperationStatus>(bytes)?),
+                Ok(50) => r.merge_message(bytes, &mut self.uuid)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Operation<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.framework_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.agent_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_len((&self.info).get_size())
+        + 1 + sizeof_len((&self.latest_status).get_size())
+        + self.statuses.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + 1 + sizeof_len((&self.uuid).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.framework_id { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.agent_id { w.write_with_tag(18, |w| w.write_message(s))?; }
+        w.write_with_tag(26, |w| w.write_message(&self.info))?;
+        w.write_with_tag(34, |w| w.write_message(&self.latest_status))?;
+        for s in &self.statuses { w.write_with_tag(42, |w| w.write_message(s))?; }
+        w.write_with_tag(50, |w| w.write_message(&self.uuid))?;
+        Ok(())
+    }
+}
+
+/// Describes the current status of an operation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OperationStatus<'a> {
+    /// While frameworks will only receive status updates for operations on which
+    /// they have set an ID, this field is optional because this message is also
+    /// used internally by Mesos components when the operation's ID has not been
+    /// set.
+    pub operation_id: Option<mesos::v1::OperationID<'a>>,
+    pub state: mesos::v1::OperationState,
+    pub message: Option<Cow<'a, str>>,
+    /// Converted resources after applying the operation. This only
+    /// applies if the `state` is `OPERATION_FINISHED`.
+    pub converted_resources: Vec<mesos::v1::Resource<'a>>,
+    /// Statuses that are delivered reliably to the scheduler will
+    /// include a `uuid`. The status is considered delivered once
+    /// it is acknowledged by the scheduler.
+    pub uuid: Option<mesos::v1::UUID<'a>>,
+}
+
+impl<'a> MessageRead<'a> for OperationStatus<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.operation_id = Default::default();
+        self.state = Default::default();
+        self.message = Default::default();
+        self.converted_resources.clear();
+        self.uuid = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.operation_id.get_or_insert_with(Default::default))?,
+                Ok(16) => self.state = r.read_enum(bytes)?,
+                Ok(26) => self.message = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.converted_resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(42) => r.merge_message(bytes, self.uuid.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for OperationStatus<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.operation_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_varint(*(&self.state) as u64)
+        + self.message.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.converted_resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.uuid.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.operation_id { w.write_with_tag(10, |w| w.write_message(s))?; }
+        w.write_with_tag(16, |w| w.write_enum(*&self.state as i32))?;
+        if let Some(ref s) = self.message { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.converted_resources { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.uuid { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describes the status of a check. Type and the corresponding field, i.e.,
+/// `command` or `http` must be set. If the result of the check is not available
+/// (e.g., the check timed out), these fields must contain empty messages, i.e.,
+/// `exit_code` or `status_code` will be unset.
+///
+/// NOTE: This API is subject to change and the related feature is experimental.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CheckStatusInfo {
+    /// The type of the check this status corresponds to.
+    pub type_pb: Option<mesos::v1::mod_CheckInfo::Type>,
+    /// Status of a command check.
+    pub command: Option<mesos::v1::mod_CheckStatusInfo::Command>,
+    /// Status of an HTTP check.
+    pub http: Option<mesos::v1::mod_CheckStatusInfo::Http>,
+    /// Status of a TCP check.
+    pub tcp: Option<mesos::v1::mod_CheckStatusInfo::Tcp>,
+}
+
+impl<'a> MessageRead<'a> for CheckStatusInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.command = Default::default();
+        self.http = Default::default();
+        self.tcp = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.command.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.http.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.tcp.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for CheckStatusInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.command.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.http.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tcp.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.command { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.http { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.tcp { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CheckStatusInfo`
+pub mod mod_CheckStatusInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Command {
+    /// Exit code of a command check. It is the result of calling
+    /// `WEXITSTATUS()` on `waitpid()` termination information on
+    /// Posix and calling `GetExitCodeProcess()` on Windows.
+    pub exit_code: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for Command {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.exit_code = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.exit_code = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Command {
+    fn get_size(&self) -> usize {
+        0
+        + self.exit_code.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.exit_code { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Http {
+    /// HTTP status code of an HTTP check.
+    pub status_code: Option<u32>,
+}
+
+impl<'a> MessageRead<'a> for Http {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.status_code = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.status_code = Some(r.read_uint32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Http {
+    fn get_size(&self) -> usize {
+        0
+        + self.status_code.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.status_code { w.write_with_tag(8, |w| w.write_uint32(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Tcp {
+    /// Whether a TCP connection succeeded.
+    pub succeeded: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for Tcp {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.succeeded = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.succeeded = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Tcp {
+    fn get_size(&self) -> usize {
+        0
+        + self.succeeded.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.succeeded { w.write_with_tag(8, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
+/// Describes the current status of a task.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TaskStatus<'a> {
+    pub task_id: mesos::v1::TaskID<'a>,
+    pub state: mesos::v1::TaskState,
+    /// Possible message explaining state.
+    pub message: Option<Cow<'a, str>>,
+    pub source: Option<mesos::v1::mod_TaskStatus::Source>,
+    pub reason: Option<mesos::v1::mod_TaskStatus::Reason>,
+    pub data: Option<Cow<'a, [u8]>>,
+    pub agent_id: Option<mesos::v1::AgentID<'a>>,
+    /// TODO(benh): Use in master/agent.
+    pub executor_id: Option<mesos::v1::ExecutorID<'a>>,
+    pub timestamp: Option<f64>,
+    /// Statuses that are delivered reliably to the scheduler will
+    /// include a 'uuid'. The status is considered delivered once
+    /// it is acknowledged by the scheduler. Schedulers can choose
+    /// to either explicitly acknowledge statuses or let the scheduler
+    /// driver implicitly acknowledge (default).
+    ///
+    /// TODO(bmahler): This is currently overwritten in the scheduler
+    /// driver and executor driver, but executors will need to set this
+    /// to a valid RFC-4122 UUID if using the HTTP API.
+    pub uuid: Option<Cow<'a, [u8]>>,
+    /// Describes whether the task has been determined to be healthy (true) or
+    /// unhealthy (false) according to the `health_check` field in `TaskInfo`.
+    pub healthy: Option<bool>,
+    /// Contains check status for the check specified in the corresponding
+    /// `TaskInfo`. If no check has been specified, this field must be
+    /// absent, otherwise it must be present even if the check status is
+    /// not available yet. If the status update is triggered for a different
+    /// reason than `REASON_TASK_CHECK_STATUS_UPDATED`, this field will contain
+    /// the last known value.
+    ///
+    /// NOTE: A check-related task status update is triggered if and only if
+    /// the value or presence of any field in `CheckStatusInfo` changes.
+    ///
+    /// NOTE: Check support in built-in executors is experimental.
+    pub check_status: Option<mesos::v1::CheckStatusInfo>,
+    /// Labels are free-form key value pairs which are exposed through
+    /// master and agent endpoints. Labels will not be interpreted or
+    /// acted upon by Mesos itself. As opposed to the data field, labels
+    /// will be kept in memory on master and agent processes. Therefore,
+    /// labels should be used to tag TaskStatus message with light-weight
+    /// meta-data. Labels should not contain duplicate key-value pairs.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+    /// Container related information that is resolved dynamically such as
+    /// network address.
+    pub container_status: Option<mesos::v1::ContainerStatus<'a>>,
+    /// The time (according to the master's clock) when the agent where
+    /// this task was running became unreachable. This is only set on
+    /// status updates for tasks running on agents that are unreachable
+    /// (e.g., partitioned away from the master).
+    pub unreachable_time: Option<mesos::v1::TimeInfo>,
+    /// If the reason field indicates a container resource limitation,
+    /// this field optionally contains additional information.
+    pub limitation: Option<mesos::v1::TaskResourceLimitation<'a>>,
+}
+
+impl<'a> MessageRead<'a> for TaskStatus<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.task_id = Default::default();
+        self.state = Default::default();
+        self.message = Default::default();
+        self.source = Default::default();
+        self.reason = Default::default();
+        self.data = Default::default();
+        self.agent_id = Default::default();
+        self.executor_id = Default::default();
+        self.timestamp = Default::default();
+        self.uuid = Default::default();
+        self.healthy = Default::default();
+        self.check_status = Default::default();
+        self.labels = Default::default();
+        self.container_status = Default::default();
+        self.unreachable_time = Default::default();
+        self.limitation = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.task_id)?,
+                Ok(16) => self.state = r.read_enum(bytes)?,
+                Ok(34) => self.message = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(72) => self.source = Some(r.read_enum(bytes)?),
+                Ok(80) => self.reason = Some(r.read_enum(bytes)?),
+                Ok(26) => self.data = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(42) => r.merge_message(bytes, self.agent_id.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.executor_id.get_or_insert_with(Default::default))?,
+                Ok(49) => self.timestamp = Some(r.read_double(bytes)?),
+                Ok(90) => self.uuid = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(64) => self.healthy = Some(r.read_bool(bytes)?),
+                Ok(122) => r.merge_message(bytes, self.check_status.get_or_insert_with(Default::default))?,
+                Ok(98) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(106) => r.merge_message(bytes, self.container_status.get_or_insert_with(Default::default))?,
+                Ok(114) => r.merge_message(bytes, self.unreachable_time.get_or_insert_with(Default::default))?,
+                Ok(130) => r.merge_message(bytes, self.limitation.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TaskStatus<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.task_id).get_size())
+        + 1 + sizeof_varint(*(&self.state) as u64)
+        + self.message.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.source.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.reason.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.agent_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.executor_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.timestamp.as_ref().map_or(0, |_| 1 + 8)
+        + self.uuid.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.healthy.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.check_status.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.container_status.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.unreachable_time.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.limitation.as_ref().map_or(0, |m| 2 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.task_id))?;
+        w.write_with_tag(16, |w| w.write_enum(*&self.state as i32))?;
+        if let Some(ref s) = self.message { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.source { w.write_with_tag(72, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.reason { w.write_with_tag(80, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.data { w.write_with_tag(26, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.agent_id { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.executor_id { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.timestamp { w.write_with_tag(49, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.uuid { w.write_with_tag(90, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.healthy { w.write_with_tag(64, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.check_status { w.write_with_tag(122, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(98, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.container_status { w.write_with_tag(106, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.unreachable_time { w.write_with_tag(114, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.limitation { w.write_with_tag(130, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `TaskStatus`
+pub mod mod_TaskStatus {
+
+
+/// Describes the source of the task status update.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Source {
+    SOURCE_MASTER = 0,
+    SOURCE_AGENT = 1,
+    SOURCE_EXECUTOR = 2,
+}
+
+impl Default for Source {
+    fn default() -> Self {
+        Source::SOURCE_MASTER
+    }
+}
+
+impl From<i32> for Source {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Source::SOURCE_MASTER,
+            1 => Source::SOURCE_AGENT,
+            2 => Source::SOURCE_EXECUTOR,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Source {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "SOURCE_MASTER" => Source::SOURCE_MASTER,
+            "SOURCE_AGENT" => Source::SOURCE_AGENT,
+            "SOURCE_EXECUTOR" => Source::SOURCE_EXECUTOR,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Detailed reason for the task status update.
+/// Refer to docs/task-state-reasons.md for additional explanation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Reason {
+    /// TODO(jieyu): The default value when a caller doesn't check for
+    /// presence is 0 and so ideally the 0 reason is not a valid one.
+    /// Since this is not used anywhere, consider removing this reason.
+    REASON_COMMAND_EXECUTOR_FAILED = 0,
+    REASON_CONTAINER_LAUNCH_FAILED = 21,
+    REASON_CONTAINER_LIMITATION = 19,
+    REASON_CONTAINER_LIMITATION_DISK = 20,
+    REASON_CONTAINER_LIMITATION_MEMORY = 8,
+    REASON_CONTAINER_PREEMPTED = 17,
+    REASON_CONTAINER_UPDATE_FAILED = 22,
+    REASON_MAX_COMPLETION_TIME_REACHED = 33,
+    REASON_EXECUTOR_REGISTRATION_TIMEOUT = 23,
+    REASON_EXECUTOR_REREGISTRATION_TIMEOUT = 24,
+    REASON_EXECUTOR_TERMINATED = 1,
+    /// No longer used.
+    REASON_EXECUTOR_UNREGISTERED = 2,
+    REASON_FRAMEWORK_REMOVED = 3,
+    REASON_GC_ERROR = 4,
+    REASON_INVALID_FRAMEWORKID = 5,
+    REASON_INVALID_OFFERS = 6,
+    REASON_IO_SWITCHBOARD_EXITED = 27,
+    REASON_MASTER_DISCONNECTED = 7,
+    REASON_RECONCILIATION = 9,
+    REASON_RESOURCES_UNKNOWN = 18,
+    REASON_AGENT_DISCONNECTED = 10,
+    REASON_AGENT_REMOVED = 11,
+    REASON_AGENT_REMOVED_BY_OPERATOR = 31,
+    REASON_AGENT_REREGISTERED = 32,
+    REASON_AGENT_RESTARTED = 12,
+    REASON_AGENT_UNKNOWN = 13,
+    REASON_TASK_KILLED_DURING_LAUNCH = 30,
+    REASON_TASK_CHECK_STATUS_UPDATED = 28,
+    REASON_TASK_HEALTH_CHECK_STATUS_UPDATED = 29,
+    REASON_TASK_GROUP_INVALID = 25,
+    REASON_TASK_GROUP_UNAUTHORIZED = 26,
+    REASON_TASK_INVALID = 14,
+    REASON_TASK_UNAUTHORIZED = 15,
+    REASON_TASK_UNKNOWN = 16,
+}
+
+impl Default for Reason {
+    fn default() -> Self {
+        Reason::REASON_COMMAND_EXECUTOR_FAILED
+    }
+}
+
+impl From<i32> for Reason {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Reason::REASON_COMMAND_EXECUTOR_FAILED,
+            21 => Reason::REASON_CONTAINER_LAUNCH_FAILED,
+            19 => Reason::REASON_CONTAINER_LIMITATION,
+            20 => Reason::REASON_CONTAINER_LIMITATION_DISK,
+            8 => Reason::REASON_CONTAINER_LIMITATION_MEMORY,
+            17 => Reason::REASON_CONTAINER_PREEMPTED,
+            22 => Reason::REASON_CONTAINER_UPDATE_FAILED,
+            33 => Reason::REASON_MAX_COMPLETION_TIME_REACHED,
+            23 => Reason::REASON_EXECUTOR_REGISTRATION_TIMEOUT,
+            24 => Reason::REASON_EXECUTOR_REREGISTRATION_TIMEOUT,
+            1 => Reason::REASON_EXECUTOR_TERMINATED,
+            2 => Reason::REASON_EXECUTOR_UNREGISTERED,
+            3 => Reason::REASON_FRAMEWORK_REMOVED,
+            4 => Reason::REASON_GC_ERROR,
+            5 => Reason::REASON_INVALID_FRAMEWORKID,
+            6 => Reason::REASON_INVALID_OFFERS,
+            27 => Reason::REASON_IO_SWITCHBOARD_EXITED,
+            7 => Reason::REASON_MASTER_DISCONNECTED,
+            9 => Reason::REASON_RECONCILIATION,
+            18 => Reason::REASON_RESOURCES_UNKNOWN,
+            10 => Reason::REASON_AGENT_DISCONNECTED,
+            11 => Reason::REASON_AGENT_REMOVED,
+            31 => Reason::REASON_AGENT_REMOVED_BY_OPERATOR,
+            32 => Reason::REASON_AGENT_REREGISTERED,
+            12 => Reason::REASON_AGENT_RESTARTED,
+            13 => Reason::REASON_AGENT_UNKNOWN,
+            30 => Reason::REASON_TASK_KILLED_DURING_LAUNCH,
+            28 => Reason::REASON_TASK_CHECK_STATUS_UPDATED,
+            29 => Reason::REASON_TASK_HEALTH_CHECK_STATUS_UPDATED,
+            25 => Reason::REASON_TASK_GROUP_INVALID,
+            26 => Reason::REASON_TASK_GROUP_UNAUTHORIZED,
+            14 => Reason::REASON_TASK_INVALID,
+            15 => Reason::REASON_TASK_UNAUTHORIZED,
+            16 => Reason::REASON_TASK_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Reason {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "REASON_COMMAND_EXECUTOR_FAILED" => Reason::REASON_COMMAND_EXECUTOR_FAILED,
+            "REASON_CONTAINER_LAUNCH_FAILED" => Reason::REASON_CONTAINER_LAUNCH_FAILED,
+            "REASON_CONTAINER_LIMITATION" => Reason::REASON_CONTAINER_LIMITATION,
+            "REASON_CONTAINER_LIMITATION_DISK" => Reason::REASON_CONTAINER_LIMITATION_DISK,
+            "REASON_CONTAINER_LIMITATION_MEMORY" => Reason::REASON_CONTAINER_LIMITATION_MEMORY,
+            "REASON_CONTAINER_PREEMPTED" => Reason::REASON_CONTAINER_PREEMPTED,
+            "REASON_CONTAINER_UPDATE_FAILED" => Reason::REASON_CONTAINER_UPDATE_FAILED,
+            "REASON_MAX_COMPLETION_TIME_REACHED" => Reason::REASON_MAX_COMPLETION_TIME_REACHED,
+            "REASON_EXECUTOR_REGISTRATION_TIMEOUT" => Reason::REASON_EXECUTOR_REGISTRATION_TIMEOUT,
+            "REASON_EXECUTOR_REREGISTRATION_TIMEOUT" => Reason::REASON_EXECUTOR_REREGISTRATION_TIMEOUT,
+            "REASON_EXECUTOR_TERMINATED" => Reason::REASON_EXECUTOR_TERMINATED,
+            "REASON_EXECUTOR_UNREGISTERED" => Reason::REASON_EXECUTOR_UNREGISTERED,
+            "REASON_FRAMEWORK_REMOVED" => Reason::REASON_FRAMEWORK_REMOVED,
+            "REASON_GC_ERROR" => Reason::REASON_GC_ERROR,
+            "REASON_INVALID_FRAMEWORKID" => Reason::REASON_INVALID_FRAMEWORKID,
+            "REASON_INVALID_OFFERS" => Reason::REASON_INVALID_OFFERS,
+            "REASON_IO_SWITCHBOARD_EXITED" => Reason::REASON_IO_SWITCHBOARD_EXITED,
+            "REASON_MASTER_DISCONNECTED" => Reason::REASON_MASTER_DISCONNECTED,
+            "REASON_RECONCILIATION" => Reason::REASON_RECONCILIATION,
+            "REASON_RESOURCES_UNKNOWN" => Reason::REASON_RESOURCES_UNKNOWN,
+            "REASON_AGENT_DISCONNECTED" => Reason::REASON_AGENT_DISCONNECTED,
+            "REASON_AGENT_REMOVED" => Reason::REASON_AGENT_REMOVED,
+            "REASON_AGENT_REMOVED_BY_OPERATOR" => Reason::REASON_AGENT_REMOVED_BY_OPERATOR,
+            "REASON_AGENT_REREGISTERED" => Reason::REASON_AGENT_REREGISTERED,
+            "REASON_AGENT_RESTARTED" => Reason::REASON_AGENT_RESTARTED,
+            "REASON_AGENT_UNKNOWN" => Reason::REASON_AGENT_UNKNOWN,
+            "REASON_TASK_KILLED_DURING_LAUNCH" => Reason::REASON_TASK_KILLED_DURING_LAUNCH,
+            "REASON_TASK_CHECK_STATUS_UPDATED" => Reason::REASON_TASK_CHECK_STATUS_UPDATED,
+            "REASON_TASK_HEALTH_CHECK_STATUS_UPDATED" => Reason::REASON_TASK_HEALTH_CHECK_STATUS_UPDATED,
+            "REASON_TASK_GROUP_INVALID" => Reason::REASON_TASK_GROUP_INVALID,
+            "REASON_TASK_GROUP_UNAUTHORIZED" => Reason::REASON_TASK_GROUP_UNAUTHORIZED,
+            "REASON_TASK_INVALID" => Reason::REASON_TASK_INVALID,
+            "REASON_TASK_UNAUTHORIZED" => Reason::REASON_TASK_UNAUTHORIZED,
+            "REASON_TASK_UNKNOWN" => Reason::REASON_TASK_UNKNOWN,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes possible filters that can be applied to unused resources
+/// (see SchedulerDriver::launchTasks) to influence the allocator.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Filters {
+    /// Time to consider unused resources refused. Note that all unused
+    /// resources will be considered refused and use the default value
+    /// (below) regardless of whether Filters was passed to
+    /// SchedulerDriver::launchTasks. You MUST pass Filters with this
+    /// field set to change this behavior (i.e., get another offer which
+    /// includes unused resources sooner or later than the default).
+    ///
+    /// If this field is set to a number of seconds greater than 31536000
+    /// (365 days), then the resources will be considered refused for 365
+    /// days. If it is set to a negative number, then the default value
+    /// will be used.
+    pub refuse_seconds: f64,
+}
+
+impl<'a> MessageRead<'a> for Filters {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Filters {
+            refuse_seconds: 5.0f64,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.refuse_seconds = 5.0f64;
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.refuse_seconds = r.read_double(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Filters {
+    fn get_size(&self) -> usize {
+        0
+        + if self.refuse_seconds == 5.0f64 { 0 } else { 1 + 8 }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.refuse_seconds != 5.0f64 { w.write_with_tag(9, |w| w.write_double(*&self.refuse_seconds))?; }
+        Ok(())
+    }
+}
+
+/// Describes a collection of environment variables. This is used with
+/// CommandInfo in order to set environment variables before running a
+/// command. The contents of each variable may be specified as a string
+/// or a Secret; only one of `value` and `secret` must be set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Environment<'a> {
+    pub variables: Vec<mesos::v1::mod_Environment::Variable<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Environment<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.variables.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.variables.push(r.read_message::<mesos::v1::mod_Environment::Variable>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Environment<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.variables.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.variables { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Environment`
+pub mod mod_Environment {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Variable<'a> {
+    pub name: Cow<'a, str>,
+    /// In Mesos 1.2, the `Environment.variables.value` message was made
+    /// optional. The default type for `Environment.variables.type` is now VALUE,
+    /// which requires `value` to be set, maintaining backward compatibility.
+    ///
+    /// TODO(greggomann): The default can be removed in Mesos 2.1 (MESOS-7134).
+    pub type_pb: mesos::v1::mod_Environment::mod_Variable::Type,
+    /// Only one of `value` and `secret` must be set.
+    pub value: Option<Cow<'a, str>>,
+    pub secret: Option<mesos::v1::Secret<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Variable<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Variable {
+            type_pb: mesos::v1::mod_Environment::mod_Variable::Type::VALUE,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.type_pb = mesos::v1::mod_Environment::mod_Variable::Type::VALUE;
+        self.value = Default::default();
+        self.secret = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => self.type_pb = r.read_enum(bytes)?,
+                Ok(18) => self.value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => r.merge_message(bytes, self.secret.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Variable<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + if self.type_pb == mesos::v1::mod_Environment::mod_Variable::Type::VALUE { 0 } else { 1 + sizeof_varint(*(&self.type_pb) as u64) }
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.secret.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if self.type_pb != mesos::v1::mod_Environment::mod_Variable::Type::VALUE { w.write_with_tag(24, |w| w.write_enum(*&self.type_pb as i32))?; }
+        if let Some(ref s) = self.value { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.secret { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Variable`
+pub mod mod_Variable {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    VALUE = 1,
+    SECRET = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::VALUE,
+            2 => Type::SECRET,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "VALUE" => Type::VALUE,
+            "SECRET" => Type::SECRET,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// A generic (key, value) pair used in various places for parameters.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Parameter<'a> {
+    pub key: Cow<'a, str>,
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Parameter<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.key = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.key = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.value = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Parameter<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.key).len())
+        + 1 + sizeof_len((&self.value).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.key))?;
+        w.write_with_tag(18, |w| w.write_string(&**&self.value))?;
+        Ok(())
+    }
+}
+
+/// Collection of Parameter.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Parameters<'a> {
+    pub parameter: Vec<mesos::v1::Parameter<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Parameters<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.parameter.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.parameter.push(r.read_message::<mesos::v1::Parameter>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Parameters<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.parameter.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.parameter { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Credential used in various places for authentication and
+/// authorization.
+///
+/// NOTE: A 'principal' is different from 'FrameworkInfo.user'. The
+/// former is used for authentication and authorization while the
+/// latter is used to determine the default user under which the
+/// framework's executors/tasks are run.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Credential<'a> {
+    pub principal: Cow<'a, str>,
+    pub secret: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Credential<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.principal = Default::default();
+        self.secret = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.principal = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.secret = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Credential<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.principal).len())
+        + self.secret.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.principal))?;
+        if let Some(ref s) = self.secret { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Credentials used for framework authentication, HTTP authentication
+/// (where the common 'username' and 'password' are captured as
+/// 'principal' and 'secret' respectively), etc.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Credentials<'a> {
+    pub credentials: Vec<mesos::v1::Credential<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Credentials<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.credentials.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.credentials.push(r.read_message::<mesos::v1::Credential>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Credentials<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.credentials.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.credentials { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Secret used to pass privileged information. It is designed to provide
+/// pass-by-value or pass-by-reference semantics, where the REFERENCE type can be
+/// used by custom modules which interact with a secure back-end.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Secret<'a> {
+    pub type_pb: Option<mesos::v1::mod_Secret::Type>,
+    /// Only one of `reference` and `value` must be set.
+    pub reference: Option<mesos::v1::mod_Secret::Reference<'a>>,
+    pub value: Option<mesos::v1::mod_Secret::Value<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Secret<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.reference = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.reference.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.value.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Secret<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.reference.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.reference { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.value { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Secret`
+pub mod mod_Secret {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Can be used by modules to refer to a secret stored in a secure back-end.
+/// The `key` field is provided to permit reference to a single value within a
+/// secret containing arbitrary key-value pairs.
+///
+/// For example, given a back-end secret store with a secret named
+/// "my-secret" containing the following key-value pairs:
+///
+///   {
+///     "username": "my-user",
+///     "password": "my-password
+///   }
+///
+/// the username could be referred to in a `Secret` by specifying
+/// "my-secret" for the `name` and "username" for the `key`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Reference<'a> {
+    pub name: Cow<'a, str>,
+    pub key: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Reference<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.key = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.key = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Reference<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + self.key.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.key { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Used to pass the value of a secret.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Value<'a> {
+    pub data: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for Value<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.data = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.data = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Value<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.data).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_bytes(&**&self.data))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    REFERENCE = 1,
+    VALUE = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::REFERENCE,
+            2 => Type::VALUE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "REFERENCE" => Type::REFERENCE,
+            "VALUE" => Type::VALUE,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Rate (queries per second, QPS) limit for messages from a framework to master.
+/// Strictly speaking they are the combined rate from all frameworks of the same
+/// principal.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RateLimit<'a> {
+    /// Leaving QPS unset gives it unlimited rate (i.e., not throttled),
+    /// which also implies unlimited capacity.
+    pub qps: Option<f64>,
+    /// Principal of framework(s) to be throttled. Should match
+    /// FrameworkInfo.principal and Credential.principal (if using authentication).
+    pub principal: Cow<'a, str>,
+    /// Max number of outstanding messages from frameworks of this principal
+    /// allowed by master before the next message is dropped and an error is sent
+    /// back to the sender. Messages received before the capacity is reached are
+    /// still going to be processed after the error is sent.
+    /// If unspecified, this principal is assigned unlimited capacity.
+    /// NOTE: This value is ignored if 'qps' is not set.
+    pub capacity: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for RateLimit<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.qps = Default::default();
+        self.principal = Default::default();
+        self.capacity = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.qps = Some(r.read_double(bytes)?),
+                Ok(18) => self.principal = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => self.capacity = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for RateLimit<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.qps.as_ref().map_or(0, |_| 1 + 8)
+        + 1 + sizeof_len((&self.principal).len())
+        + self.capacity.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.qps { w.write_with_tag(9, |w| w.write_double(*s))?; }
+        w.write_with_tag(18, |w| w.write_string(&**&self.principal))?;
+        if let Some(ref s) = self.capacity { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+/// Collection of RateLimit.
+/// Frameworks without rate limits defined here are not throttled unless
+/// 'aggregate_default_qps' is specified.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RateLimits<'a> {
+    /// Items should have unique principals.
+    pub limits: Vec<mesos::v1::RateLimit<'a>>,
+    /// All the frameworks not specified in 'limits' get this default rate.
+    /// This rate is an aggregate rate for all of them, i.e., their combined
+    /// traffic is throttled together at this rate.
+    pub aggregate_default_qps: Option<f64>,
+    /// All the frameworks not specified in 'limits' get this default capacity.
+    /// This is an aggregate value similar to 'aggregate_default_qps'.
+    pub aggregate_default_capacity: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for RateLimits<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.limits.clear();
+        self.aggregate_default_qps = Default::default();
+        self.aggregate_default_capacity = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.limits.push(r.read_message::<mesos::v1::RateLimit>(bytes)?),
+                Ok(17) => self.aggregate_default_qps = Some(r.read_double(bytes)?),
+                Ok(24) => self.aggregate_default_capacity = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for RateLimits<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.limits.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.aggregate_default_qps.as_ref().map_or(0, |_| 1 + 8)
+        + self.aggregate_default_capacity.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.limits { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.aggregate_default_qps { w.write_with_tag(17, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.aggregate_default_capacity { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+/// Describe an image used by tasks or executors. Note that it's only
+/// for tasks or executors launched by MesosContainerizer currently.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Image<'a> {
+    pub type_pb: mesos::v1::mod_Image::Type,
+    /// Only one of the following image messages should be set to match
+    /// the type.
+    pub appc: Option<mesos::v1::mod_Image::Appc<'a>>,
+    pub docker: Option<mesos::v1::mod_Image::Docker<'a>>,
+    /// With this flag set to false, the mesos containerizer will pull
+    /// the docker/appc image from the registry even if the image is
+    /// already downloaded on the agent.
+    pub cached: bool,
+}
+
+impl<'a> MessageRead<'a> for Image<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Image {
+            cached: true,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.appc = Default::default();
+        self.docker = Default::default();
+        self.cached = true;
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = r.read_enum(bytes)?,
+                Ok(18) => r.merge_message(bytes, self.appc.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.docker.get_or_insert_with(Default::default))?,
+                Ok(32) => self.cached = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Image<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.appc.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.docker.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.cached == true { 0 } else { 1 + sizeof_varint(*(&self.cached) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.type_pb as i32))?;
+        if let Some(ref s) = self.appc { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.docker { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if self.cached != true { w.write_with_tag(32, |w| w.write_bool(*&self.cached))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Image`
+pub mod mod_Image {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Protobuf for specifying an Appc container image. See:
+/// https://github.com/appc/spec/blob/master/spec/aci.md
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Appc<'a> {
+    /// The name of the image.
+    pub name: Cow<'a, str>,
+    /// An image ID is a string of the format "hash-value", where
+    /// "hash" is the hash algorithm used and "value" is the hex
+    /// encoded string of the digest. Currently the only permitted
+    /// hash algorithm is sha512.
+    pub id: Option<Cow<'a, str>>,
+    /// Optional labels. Suggested labels: "version", "os", and "arch".
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Appc<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.id = Default::default();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.id = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Appc<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.id { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Docker<'a> {
+    /// The name of the image. Expected format:
+    ///   [REGISTRY_HOST[:REGISTRY_PORT]/]REPOSITORY[:TAG|@TYPE:DIGEST]
+    ///
+    /// See: https://docs.docker.com/reference/commandline/pull/
+    pub name: Cow<'a, str>,
+    /// Docker config containing credentials to authenticate with
+    /// docker registry. The secret is expected to be a docker
+    /// config file in JSON format with UTF-8 character encoding.
+    pub config: Option<mesos::v1::Secret<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Docker<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.config = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => r.merge_message(bytes, self.config.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Docker<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + self.config.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.config { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    APPC = 1,
+    DOCKER = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::APPC
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Type::APPC,
+            2 => Type::DOCKER,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "APPC" => Type::APPC,
+            "DOCKER" => Type::DOCKER,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes how the mount will be propagated for a volume. See the
+/// following doc for more details about mount propagation:
+/// https://www.kernel.org/doc/Documentation/filesystems/sharedsubtree.txt
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MountPropagation {
+    pub mode: Option<mesos::v1::mod_MountPropagation::Mode>,
+}
+
+impl<'a> MessageRead<'a> for MountPropagation {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.mode = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.mode = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for MountPropagation {
+    fn get_size(&self) -> usize {
+        0
+        + self.mode.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.mode { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `MountPropagation`
+pub mod mod_MountPropagation {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    UNKNOWN = 0,
+    /// The volume in a container will receive new mounts from the host
+    /// or other containers, but filesystems mounted inside the
+    /// container won't be propagated to the host or other containers.
+    /// This is currently the default behavior for all volumes.
+    HOST_TO_CONTAINER = 1,
+    /// The volume in a container will receive new mounts from the host
+    /// or other containers, and its own mounts will be propagated from
+    /// the container to the host or other containers.
+    BIDIRECTIONAL = 2,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::UNKNOWN
+    }
+}
+
+impl From<i32> for Mode {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Mode::UNKNOWN,
+            1 => Mode::HOST_TO_CONTAINER,
+            2 => Mode::BIDIRECTIONAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Mode {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Mode::UNKNOWN,
+            "HOST_TO_CONTAINER" => Mode::HOST_TO_CONTAINER,
+            "BIDIRECTIONAL" => Mode::BIDIRECTIONAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a volume mapping either from host to container or vice
+/// versa. Both paths can either refer to a directory or a file.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Volume<'a> {
+    /// TODO(gyliu513): Make this as `optional` after deprecation cycle of 1.0.
+    pub mode: mesos::v1::mod_Volume::Mode,
+    /// Path pointing to a directory or file in the container. If the
+    /// path is a relative path, it is relative to the container work
+    /// directory. If the path is an absolute path, that path must
+    /// already exist.
+    pub container_path: Cow<'a, str>,
+    /// Absolute path pointing to a directory or file on the host or a
+    /// path relative to the container work directory.
+    pub host_path: Option<Cow<'a, str>>,
+    /// The source of the volume is an Image which describes a root
+    /// filesystem which will be provisioned by Mesos.
+    pub image: Option<mesos::v1::Image<'a>>,
+    pub source: Option<mesos::v1::mod_Volume::Source<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Volume<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.mode = Default::default();
+        self.container_path = Default::default();
+        self.host_path = Default::default();
+        self.image = Default::default();
+        self.source = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(24) => self.mode = r.read_enum(bytes)?,
+                Ok(10) => self.container_path = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.host_path = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => r.merge_message(bytes, self.image.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.source.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Volume<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.mode) as u64)
+        + 1 + sizeof_len((&self.container_path).len())
+        + self.host_path.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.image.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.source.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(24, |w| w.write_enum(*&self.mode as i32))?;
+        w.write_with_tag(10, |w| w.write_string(&**&self.container_path))?;
+        if let Some(ref s) = self.host_path { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.image { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.source { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Volume`
+pub mod mod_Volume {
+
+use super::*;
+
+/// Describes where a volume originates from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Source<'a> {
+    /// Enum fields should be optional, see: MESOS-4997.
+    pub type_pb: Option<mesos::v1::mod_Volume::mod_Source::Type>,
+    /// The source of the volume created by docker volume driver.
+    pub docker_volume: Option<mesos::v1::mod_Volume::mod_Source::DockerVolume<'a>>,
+    pub host_path: Option<mesos::v1::mod_Volume::mod_Source::HostPath<'a>>,
+    pub sandbox_path: Option<mesos::v1::mod_Volume::mod_Source::SandboxPath<'a>>,
+    /// The volume/secret isolator uses the secret-fetcher module (third-party or
+    /// internal) downloads the secret and makes it available at container_path.
+    pub secret: Option<mesos::v1::Secret<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Source<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.docker_volume = Default::default();
+        self.host_path = Default::default();
+        self.sandbox_path = Default::default();
+        self.secret = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.docker_volume.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.host_path.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.sandbox_path.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.secret.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Source<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.docker_volume.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.host_path.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.sandbox_path.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.secret.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.docker_volume { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.host_path { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.sandbox_path { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.secret { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Source`
+pub mod mod_Source {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DockerVolume<'a> {
+    /// Driver of the volume, it can be flocker, convoy, raxrey etc.
+    pub driver: Option<Cow<'a, str>>,
+    /// Name of the volume.
+    pub name: Cow<'a, str>,
+    /// Volume driver specific options.
+    pub driver_options: Option<mesos::v1::Parameters<'a>>,
+}
+
+impl<'a> MessageRead<'a> for DockerVolume<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.driver = Default::default();
+        self.name = Default::default();
+        self.driver_options = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.driver = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => r.merge_message(bytes, self.driver_options.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DockerVolume<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.driver.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + 1 + sizeof_len((&self.name).len())
+        + self.driver_options.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.driver { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        w.write_with_tag(18, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.driver_options { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Absolute path pointing to a directory or file on the host.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HostPath<'a> {
+    pub path: Cow<'a, str>,
+    pub mount_propagation: Option<mesos::v1::MountPropagation>,
+}
+
+impl<'a> MessageRead<'a> for HostPath<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.path = Default::default();
+        self.mount_propagation = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.path = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => r.merge_message(bytes, self.mount_propagation.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for HostPath<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.path).len())
+        + self.mount_propagation.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.path))?;
+        if let Some(ref s) = self.mount_propagation { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Describe a path from a container's sandbox. The container can
+/// be the current container (SELF), or its parent container
+/// (PARENT). PARENT allows all child containers to share a volume
+/// from their parent container's sandbox. It'll be an error if
+/// the current container is a top level container.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SandboxPath<'a> {
+    pub type_pb: Option<mesos::v1::mod_Volume::mod_Source::mod_SandboxPath::Type>,
+    /// A path relative to the corresponding container's sandbox.
+    /// Note that upwards traversal (i.e. ../../abc) is not allowed.
+    pub path: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for SandboxPath<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.path = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(18) => self.path = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for SandboxPath<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + 1 + sizeof_len((&self.path).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        w.write_with_tag(18, |w| w.write_string(&**&self.path))?;
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `SandboxPath`
+pub mod mod_SandboxPath {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    SELF = 1,
+    PARENT = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::SELF,
+            2 => Type::PARENT,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "SELF" => Type::SELF,
+            "PARENT" => Type::PARENT,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    /// This must be the first enum value in this list, to
+    /// ensure that if 'type' is not set, the default value
+    /// is UNKNOWN. This enables enum values to be added
+    /// in a backwards-compatible way. See: MESOS-4997.
+    UNKNOWN = 0,
+    /// TODO(gyliu513): Add IMAGE as volume source type.
+    DOCKER_VOLUME = 1,
+    HOST_PATH = 4,
+    SANDBOX_PATH = 2,
+    SECRET = 3,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::DOCKER_VOLUME,
+            4 => Type::HOST_PATH,
+            2 => Type::SANDBOX_PATH,
+            3 => Type::SECRET,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "DOCKER_VOLUME" => Type::DOCKER_VOLUME,
+            "HOST_PATH" => Type::HOST_PATH,
+            "SANDBOX_PATH" => Type::SANDBOX_PATH,
+            "SECRET" => Type::SECRET,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    /// read-write.
+    RW = 1,
+    /// read-only.
+    RO = 2,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::RW
+    }
+}
+
+impl From<i32> for Mode {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Mode::RW,
+            2 => Mode::RO,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Mode {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "RW" => Mode::RW,
+            "RO" => Mode::RO,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Describes a network request from a framework as well as network resolution
+/// provided by Mesos.
+///
+/// A framework may request the network isolator on the Agent to isolate the
+/// container in a network namespace and create a virtual network interface.
+/// The `NetworkInfo` message describes the properties of that virtual
+/// interface, including the IP addresses and network isolation policy
+/// (network group membership).
+///
+/// The NetworkInfo message is not interpreted by the Master or Agent and is
+/// intended to be used by Agent and Master modules implementing network
+/// isolation. If the modules are missing, the message is simply ignored. In
+/// future, the task launch will fail if there is no module providing the
+/// network isolation capabilities (MESOS-3390).
+///
+/// An executor, Agent, or an Agent module may append NetworkInfos inside
+/// TaskStatus::container_status to provide information such as the container IP
+/// address and isolation groups.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NetworkInfo<'a> {
+    /// When included in a ContainerInfo, each of these represent a
+    /// request for an IP address. Each request can specify an explicit address
+    /// or the IP protocol to use.
+    ///
+    /// When included in a TaskStatus message, these inform the framework
+    /// scheduler about the IP addresses that are bound to the container
+    /// interface. When there are no custom network isolator modules installed,
+    /// this field is filled in automatically with the Agent IP address.
+    pub ip_addresses: Vec<mesos::v1::mod_NetworkInfo::IPAddress<'a>>,
+    /// Name of the network which will be used by network isolator to determine
+    /// the network that the container joins. It's up to the network isolator
+    /// to decide how to interpret this field.
+    pub name: Option<Cow<'a, str>>,
+    /// A group is the name given to a set of logically-related interfaces that
+    /// are allowed to communicate among themselves. Network traffic is allowed
+    /// between two container interfaces that share at least one network group.
+    /// For example, one might want to create separate groups for isolating dev,
+    /// testing, qa and prod deployment environments.
+    pub groups: Vec<Cow<'a, str>>,
+    /// To tag certain metadata to be used by Isolator/IPAM, e.g., rack, etc.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+    pub port_mappings: Vec<mesos::v1::mod_NetworkInfo::PortMapping<'a>>,
+}
+
+impl<'a> MessageRead<'a> for NetworkInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.ip_addresses.clear();
+        self.name = Default::default();
+        self.groups.clear();
+        self.labels = Default::default();
+        self.port_mappings.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(42) => self.ip_addresses.push(r.read_message::<mesos::v1::mod_NetworkInfo::IPAddress>(bytes)?),
+                Ok(50) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => self.groups.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(58) => self.port_mappings.push(r.read_message::<mesos::v1::mod_NetworkInfo::PortMapping>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for NetworkInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.ip_addresses.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.groups.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.port_mappings.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.ip_addresses { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.name { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        for s in &self.groups { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.port_mappings { w.write_with_tag(58, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `NetworkInfo`
+pub mod mod_NetworkInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Specifies a request for an IP address, or reports the assigned container
+/// IP address.
+///
+/// Users can request an automatically assigned IP (for example, via an
+/// IPAM service) or a specific IP by adding a NetworkInfo to the
+/// ContainerInfo for a task.  On a request, specifying neither `protocol`
+/// nor `ip_address` means that any available address may be assigned.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct IPAddress<'a> {
+    /// Specify IP address requirement. Set protocol to the desired value to
+    /// request the network isolator on the Agent to assign an IP address to the
+    /// container being launched. If a specific IP address is specified in
+    /// ip_address, this field should not be set.
+    pub protocol: mesos::v1::mod_NetworkInfo::Protocol,
+    /// Statically assigned IP provided by the Framework. This IP will be
+    /// assigned to the container by the network isolator module on the Agent.
+    /// This field should not be used with the protocol field above.
+    ///
+    /// If an explicit address is requested but is unavailable, the network
+    /// isolator should fail the task.
+    pub ip_address: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for IPAddress<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.protocol = Default::default();
+        self.ip_address = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.protocol = r.read_enum(bytes)?,
+                Ok(18) => self.ip_address = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for IPAddress<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.protocol == mesos::v1::mod_NetworkInfo::Protocol::IPv4 { 0 } else { 1 + sizeof_varint(*(&self.protocol) as u64) }
+        + self.ip_address.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.protocol != mesos::v1::mod_NetworkInfo::Protocol::IPv4 { w.write_with_tag(8, |w| w.write_enum(*&self.protocol as i32))?; }
+        if let Some(ref s) = self.ip_address { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Specifies a port mapping request for the task on this network.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PortMapping<'a> {
+    pub host_port: u32,
+    pub container_port: u32,
+    /// Protocol to expose as (ie: tcp, udp).
+    pub protocol: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for PortMapping<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.host_port = Default::default();
+        self.container_port = Default::default();
+        self.protocol = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.host_port = r.read_uint32(bytes)?,
+                Ok(16) => self.container_port = r.read_uint32(bytes)?,
+                Ok(26) => self.protocol = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for PortMapping<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.host_port) as u64)
+        + 1 + sizeof_varint(*(&self.container_port) as u64)
+        + self.protocol.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.host_port))?;
+        w.write_with_tag(16, |w| w.write_uint32(*&self.container_port))?;
+        if let Some(ref s) = self.protocol { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Protocol {
+    IPv4 = 1,
+    IPv6 = 2,
+}
+
+impl Default for Protocol {
+    fn default() -> Self {
+        Protocol::IPv4
+    }
+}
+
+impl From<i32> for Protocol {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Protocol::IPv4,
+            2 => Protocol::IPv6,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Protocol {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "IPv4" => Protocol::IPv4,
+            "IPv6" => Protocol::IPv6,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Encapsulation of `Capabilities` supported by Linux.
+/// Reference: http://linux.die.net/man/7/capabilities.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CapabilityInfo {
+    pub capabilities: Vec<mesos::v1::mod_CapabilityInfo::Capability>,
+}
+
+impl<'a> MessageRead<'a> for CapabilityInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.capabilities.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.capabilities.push(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for CapabilityInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.capabilities.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.capabilities { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CapabilityInfo`
+pub mod mod_CapabilityInfo {
+
+
+/// We start the actual values at an offset(1000) because Protobuf 2
+/// uses the first value as the default one. Separating the default
+/// value from the real first value helps to disambiguate them. This
+/// is especially valuable for backward compatibility.
+/// See: MESOS-4997.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Capability {
+    UNKNOWN = 0,
+    CHOWN = 1000,
+    DAC_OVERRIDE = 1001,
+    DAC_READ_SEARCH = 1002,
+    FOWNER = 1003,
+    FSETID = 1004,
+    KILL = 1005,
+    SETGID = 1006,
+    SETUID = 1007,
+    SETPCAP = 1008,
+    LINUX_IMMUTABLE = 1009,
+    NET_BIND_SERVICE = 1010,
+    NET_BROADCAST = 1011,
+    NET_ADMIN = 1012,
+    NET_RAW = 1013,
+    IPC_LOCK = 1014,
+    IPC_OWNER = 1015,
+    SYS_MODULE = 1016,
+    SYS_RAWIO = 1017,
+    SYS_CHROOT = 1018,
+    SYS_PTRACE = 1019,
+    SYS_PACCT = 1020,
+    SYS_ADMIN = 1021,
+    SYS_BOOT = 1022,
+    SYS_NICE = 1023,
+    SYS_RESOURCE = 1024,
+    SYS_TIME = 1025,
+    SYS_TTY_CONFIG = 1026,
+    MKNOD = 1027,
+    LEASE = 1028,
+    AUDIT_WRITE = 1029,
+    AUDIT_CONTROL = 1030,
+    SETFCAP = 1031,
+    MAC_OVERRIDE = 1032,
+    MAC_ADMIN = 1033,
+    SYSLOG = 1034,
+    WAKE_ALARM = 1035,
+    BLOCK_SUSPEND = 1036,
+    AUDIT_READ = 1037,
+}
+
+impl Default for Capability {
+    fn default() -> Self {
+        Capability::UNKNOWN
+    }
+}
+
+impl From<i32> for Capability {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Capability::UNKNOWN,
+            1000 => Capability::CHOWN,
+            1001 => Capability::DAC_OVERRIDE,
+            1002 => Capability::DAC_READ_SEARCH,
+            1003 => Capability::FOWNER,
+            1004 => Capability::FSETID,
+            1005 => Capability::KILL,
+            1006 => Capability::SETGID,
+            1007 => Capability::SETUID,
+            1008 => Capability::SETPCAP,
+            1009 => Capability::LINUX_IMMUTABLE,
+            1010 => Capability::NET_BIND_SERVICE,
+            1011 => Capability::NET_BROADCAST,
+            1012 => Capability::NET_ADMIN,
+            1013 => Capability::NET_RAW,
+            1014 => Capability::IPC_LOCK,
+            1015 => Capability::IPC_OWNER,
+            1016 => Capability::SYS_MODULE,
+            1017 => Capability::SYS_RAWIO,
+            1018 => Capability::SYS_CHROOT,
+            1019 => Capability::SYS_PTRACE,
+            1020 => Capability::SYS_PACCT,
+            1021 => Capability::SYS_ADMIN,
+            1022 => Capability::SYS_BOOT,
+            1023 => Capability::SYS_NICE,
+            1024 => Capability::SYS_RESOURCE,
+            1025 => Capability::SYS_TIME,
+            1026 => Capability::SYS_TTY_CONFIG,
+            1027 => Capability::MKNOD,
+            1028 => Capability::LEASE,
+            1029 => Capability::AUDIT_WRITE,
+            1030 => Capability::AUDIT_CONTROL,
+            1031 => Capability::SETFCAP,
+            1032 => Capability::MAC_OVERRIDE,
+            1033 => Capability::MAC_ADMIN,
+            1034 => Capability::SYSLOG,
+            1035 => Capability::WAKE_ALARM,
+            1036 => Capability::BLOCK_SUSPEND,
+            1037 => Capability::AUDIT_READ,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Capability {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Capability::UNKNOWN,
+            "CHOWN" => Capability::CHOWN,
+            "DAC_OVERRIDE" => Capability::DAC_OVERRIDE,
+            "DAC_READ_SEARCH" => Capability::DAC_READ_SEARCH,
+            "FOWNER" => Capability::FOWNER,
+            "FSETID" => Capability::FSETID,
+            "KILL" => Capability::KILL,
+            "SETGID" => Capability::SETGID,
+            "SETUID" => Capability::SETUID,
+            "SETPCAP" => Capability::SETPCAP,
+            "LINUX_IMMUTABLE" => Capability::LINUX_IMMUTABLE,
+            "NET_BIND_SERVICE" => Capability::NET_BIND_SERVICE,
+            "NET_BROADCAST" => Capability::NET_BROADCAST,
+            "NET_ADMIN" => Capability::NET_ADMIN,
+            "NET_RAW" => Capability::NET_RAW,
+            "IPC_LOCK" => Capability::IPC_LOCK,
+            "IPC_OWNER" => Capability::IPC_OWNER,
+            "SYS_MODULE" => Capability::SYS_MODULE,
+            "SYS_RAWIO" => Capability::SYS_RAWIO,
+            "SYS_CHROOT" => Capability::SYS_CHROOT,
+            "SYS_PTRACE" => Capability::SYS_PTRACE,
+            "SYS_PACCT" => Capability::SYS_PACCT,
+            "SYS_ADMIN" => Capability::SYS_ADMIN,
+            "SYS_BOOT" => Capability::SYS_BOOT,
+            "SYS_NICE" => Capability::SYS_NICE,
+            "SYS_RESOURCE" => Capability::SYS_RESOURCE,
+            "SYS_TIME" => Capability::SYS_TIME,
+            "SYS_TTY_CONFIG" => Capability::SYS_TTY_CONFIG,
+            "MKNOD" => Capability::MKNOD,
+            "LEASE" => Capability::LEASE,
+            "AUDIT_WRITE" => Capability::AUDIT_WRITE,
+            "AUDIT_CONTROL" => Capability::AUDIT_CONTROL,
+            "SETFCAP" => Capability::SETFCAP,
+            "MAC_OVERRIDE" => Capability::MAC_OVERRIDE,
+            "MAC_ADMIN" => Capability::MAC_ADMIN,
+            "SYSLOG" => Capability::SYSLOG,
+            "WAKE_ALARM" => Capability::WAKE_ALARM,
+            "BLOCK_SUSPEND" => Capability::BLOCK_SUSPEND,
+            "AUDIT_READ" => Capability::AUDIT_READ,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Encapsulation for Linux specific configuration.
+/// E.g, capabilities, limits etc.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct LinuxInfo {
+    /// The set of capabilities that are allowed but not initially
+    /// granted to tasks.
+    pub bounding_capabilities: Option<mesos::v1::CapabilityInfo>,
+    /// Represents the set of capabilities that the task will
+    /// be executed with.
+    pub effective_capabilities: Option<mesos::v1::CapabilityInfo>,
+    /// If set as 'true', the container shares the pid namespace with
+    /// its parent. If the container is a top level container, it will
+    /// share the pid namespace with the agent. If the container is a
+    /// nested container, it will share the pid namespace with its
+    /// parent container. This field will be ignored if 'namespaces/pid'
+    /// isolator is not enabled.
+    pub share_pid_namespace: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for LinuxInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.bounding_capabilities = Default::default();
+        self.effective_capabilities = Default::default();
+        self.share_pid_namespace = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(18) => r.merge_message(bytes, self.bounding_capabilities.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.effective_capabilities.get_or_insert_with(Default::default))?,
+                Ok(32) => self.share_pid_namespace = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for LinuxInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.bounding_capabilities.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.effective_capabilities.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.share_pid_namespace.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.bounding_capabilities { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.effective_capabilities { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.share_pid_namespace { w.write_with_tag(32, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+/// Encapsulation for POSIX rlimits, see
+/// http://pubs.opengroup.org/onlinepubs/009695399/functions/getrlimit.html.
+/// Note that some types might only be defined for Linux.
+/// We use a custom prefix to avoid conflict with existing system macros
+/// (e.g., `RLIMIT_CPU` or `NOFILE`).
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RLimitInfo {
+    pub rlimits: Vec<mesos::v1::mod_RLimitInfo::RLimit>,
+}
+
+impl<'a> MessageRead<'a> for RLimitInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.rlimits.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.rlimits.push(r.read_message::<mesos::v1::mod_RLimitInfo::RLimit>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for RLimitInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.rlimits.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.rlimits { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `RLimitInfo`
+pub mod mod_RLimitInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct RLimit {
+    pub type_pb: Option<mesos::v1::mod_RLimitInfo::mod_RLimit::Type>,
+    /// Either both are set or both are not set.
+    /// If both are not set, it represents unlimited.
+    /// If both are set, we require `soft` <= `hard`.
+    pub hard: Option<u64>,
+    pub soft: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for RLimit {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.hard = Default::default();
+        self.soft = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = Some(r.read_enum(bytes)?),
+                Ok(16) => self.hard = Some(r.read_uint64(bytes)?),
+                Ok(24) => self.soft = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for RLimit {
+    fn get_size(&self) -> usize {
+        0
+        + self.type_pb.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.hard.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.soft.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.type_pb { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.hard { w.write_with_tag(16, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.soft { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `RLimit`
+pub mod mod_RLimit {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    UNKNOWN = 0,
+    RLMT_AS = 1,
+    RLMT_CORE = 2,
+    RLMT_CPU = 3,
+    RLMT_DATA = 4,
+    RLMT_FSIZE = 5,
+    RLMT_LOCKS = 6,
+    RLMT_MEMLOCK = 7,
+    RLMT_MSGQUEUE = 8,
+    RLMT_NICE = 9,
+    RLMT_NOFILE = 10,
+    RLMT_NPROC = 11,
+    RLMT_RSS = 12,
+    RLMT_RTPRIO = 13,
+    RLMT_RTTIME = 14,
+    RLMT_SIGPENDING = 15,
+    RLMT_STACK = 16,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::UNKNOWN
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Type::UNKNOWN,
+            1 => Type::RLMT_AS,
+            2 => Type::RLMT_CORE,
+            3 => Type::RLMT_CPU,
+            4 => Type::RLMT_DATA,
+            5 => Type::RLMT_FSIZE,
+            6 => Type::RLMT_LOCKS,
+            7 => Type::RLMT_MEMLOCK,
+            8 => Type::RLMT_MSGQUEUE,
+            9 => Type::RLMT_NICE,
+            10 => Type::RLMT_NOFILE,
+            11 => Type::RLMT_NPROC,
+            12 => Type::RLMT_RSS,
+            13 => Type::RLMT_RTPRIO,
+            14 => Type::RLMT_RTTIME,
+            15 => Type::RLMT_SIGPENDING,
+            16 => Type::RLMT_STACK,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Type::UNKNOWN,
+            "RLMT_AS" => Type::RLMT_AS,
+            "RLMT_CORE" => Type::RLMT_CORE,
+            "RLMT_CPU" => Type::RLMT_CPU,
+            "RLMT_DATA" => Type::RLMT_DATA,
+            "RLMT_FSIZE" => Type::RLMT_FSIZE,
+            "RLMT_LOCKS" => Type::RLMT_LOCKS,
+            "RLMT_MEMLOCK" => Type::RLMT_MEMLOCK,
+            "RLMT_MSGQUEUE" => Type::RLMT_MSGQUEUE,
+            "RLMT_NICE" => Type::RLMT_NICE,
+            "RLMT_NOFILE" => Type::RLMT_NOFILE,
+            "RLMT_NPROC" => Type::RLMT_NPROC,
+            "RLMT_RSS" => Type::RLMT_RSS,
+            "RLMT_RTPRIO" => Type::RLMT_RTPRIO,
+            "RLMT_RTTIME" => Type::RLMT_RTTIME,
+            "RLMT_SIGPENDING" => Type::RLMT_SIGPENDING,
+            "RLMT_STACK" => Type::RLMT_STACK,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+}
+
+/// Describes the information about (pseudo) TTY that can
+/// be attached to a process running in a container.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TTYInfo {
+    pub window_size: Option<mesos::v1::mod_TTYInfo::WindowSize>,
+}
+
+impl<'a> MessageRead<'a> for TTYInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.window_size = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.window_size.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TTYInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.window_size.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.window_size { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `TTYInfo`
+pub mod mod_TTYInfo {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct WindowSize {
+    pub rows: u32,
+    pub columns: u32,
+}
+
+impl<'a> MessageRead<'a> for WindowSize {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.rows = Default::default();
+        self.columns = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.rows = r.read_uint32(bytes)?,
+                Ok(16) => self.columns = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for WindowSize {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.rows) as u64)
+        + 1 + sizeof_varint(*(&self.columns) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.rows))?;
+        w.write_with_tag(16, |w| w.write_uint32(*&self.columns))?;
+        Ok(())
+    }
+}
+
+}
+
+/// Describes a container configuration and allows extensible
+/// configurations for different container implementations.
+///
+/// NOTE: `ContainerInfo` may be specified, e.g., by a task, even if no
+/// container image is provided. In this case neither `MesosInfo` nor
+/// `DockerInfo` is set, the required `type` must be `MESOS`. This is to
+/// address a case when a task without an image, e.g., a shell script
+/// with URIs, wants to use features originally designed for containers,
+/// for example custom network isolation via `NetworkInfo`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ContainerInfo<'a> {
+    pub type_pb: mesos::v1::mod_ContainerInfo::Type,
+    pub volumes: Vec<mesos::v1::Volume<'a>>,
+    pub hostname: Option<Cow<'a, str>>,
+    /// Only one of the following *Info messages should be set to match
+    /// the type.
+    pub docker: Option<mesos::v1::mod_ContainerInfo::DockerInfo<'a>>,
+    pub mesos: Option<mesos::v1::mod_ContainerInfo::MesosInfo<'a>>,
+    /// A list of network requests. A framework can request multiple IP addresses
+    /// for the container.
+    pub network_infos: Vec<mesos::v1::NetworkInfo<'a>>,
+    /// Linux specific information for the container.
+    pub linux_info: Option<mesos::v1::LinuxInfo>,
+    /// (POSIX only) rlimits of the container.
+    pub rlimit_info: Option<mesos::v1::RLimitInfo>,
+    /// If specified a tty will be attached to the container entrypoint.
+    pub tty_info: Option<mesos::v1::TTYInfo>,
+}
+
+impl<'a> MessageRead<'a> for ContainerInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.type_pb = Default::default();
+        self.volumes.clear();
+        self.hostname = Default::default();
+        self.docker = Default::default();
+        self.mesos = Default::default();
+        self.network_infos.clear();
+        self.linux_info = Default::default();
+        self.rlimit_info = Default::default();
+        self.tty_info = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.type_pb = r.read_enum(bytes)?,
+                Ok(18) => self.volumes.push(r.read_message::<mesos::v1::Volume>(bytes)?),
+                Ok(34) => self.hostname = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => r.merge_message(bytes, self.docker.get_or_insert_with(Default::default))?,
+                Ok(42) => r.merge_message(bytes, self.mesos.get_or_insert_with(Default::default))?,
+                Ok(58) => self.network_infos.push(r.read_message::<mesos::v1::NetworkInfo>(bytes)?),
+                Ok(66) => r.merge_message(bytes, self.linux_info.get_or_insert_with(Default::default))?,
+                Ok(74) => r.merge_message(bytes, self.rlimit_info.get_or_insert_with(Default::default))?,
+                Ok(82) => r.merge_message(bytes, self.tty_info.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ContainerInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + self.volumes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.hostname.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.docker.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.mesos.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.network_infos.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.linux_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.rlimit_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tty_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.type_pb as i32))?;
+        for s in &self.volumes { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.hostname { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.docker { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.mesos { w.write_with_tag(42, |w| w.write_message(s))?; }
+        for s in &self.network_infos { w.write_with_tag(58, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.linux_info { w.write_with_tag(66, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.rlimit_info { w.write_with_tag(74, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.tty_info { w.write_with_tag(82, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ContainerInfo`
+pub mod mod_ContainerInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DockerInfo<'a> {
+    /// The docker image that is going to be passed to the registry.
+    pub image: Cow<'a, str>,
+    pub network: mesos::v1::mod_ContainerInfo::mod_DockerInfo::Network,
+    pub port_mappings: Vec<mesos::v1::mod_ContainerInfo::mod_DockerInfo::PortMapping<'a>>,
+    pub privileged: bool,
+    /// Allowing arbitrary parameters to be passed to docker CLI.
+    /// Note that anything passed to this field is not guaranteed
+    /// to be supported moving forward, as we might move away from
+    /// the docker CLI.
+    pub parameters: Vec<mesos::v1::Parameter<'a>>,
+    /// With this flag set to true, the docker containerizer will
+    /// pull the docker image from the registry even if the image
+    /// is already downloaded on the agent.
+    pub force_pull_image: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for DockerInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.image = Default::default();
+        self.network = Default::default();
+        self.port_mappings.clear();
+        self.privileged = Default::default();
+        self.parameters.clear();
+        self.force_pull_image = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.image = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.network = r.read_enum(bytes)?,
+                Ok(26) => self.port_mappings.push(r.read_message::<mesos::v1::mod_ContainerInfo::mod_DockerInfo::PortMapping>(bytes)?),
+                Ok(32) => self.privileged = r.read_bool(bytes)?,
+                Ok(42) => self.parameters.push(r.read_message::<mesos::v1::Parameter>(bytes)?),
+                Ok(48) => self.force_pull_image = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DockerInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.image).len())
+        + if self.network == mesos::v1::mod_ContainerInfo::mod_DockerInfo::Network::HOST { 0 } else { 1 + sizeof_varint(*(&self.network) as u64) }
+        + self.port_mappings.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + if self.privileged == false { 0 } else { 1 + sizeof_varint(*(&self.privileged) as u64) }
+        + self.parameters.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.force_pull_image.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.image))?;
+        if self.network != mesos::v1::mod_ContainerInfo::mod_DockerInfo::Network::HOST { w.write_with_tag(16, |w| w.write_enum(*&self.network as i32))?; }
+        for s in &self.port_mappings { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if self.privileged != false { w.write_with_tag(32, |w| w.write_bool(*&self.privileged))?; }
+        for s in &self.parameters { w.write_with_tag(42, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.force_pull_image { w.write_with_tag(48, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DockerInfo`
+pub mod mod_DockerInfo {
+
+use std::borrow::Cow;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct PortMapping<'a> {
+    pub host_port: u32,
+    pub container_port: u32,
+    /// Protocol to expose as (ie: tcp, udp).
+    pub protocol: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for PortMapping<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.host_port = Default::default();
+        self.container_port = Default::default();
+        self.protocol = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.host_port = r.read_uint32(bytes)?,
+                Ok(16) => self.container_port = r.read_uint32(bytes)?,
+                Ok(26) => self.protocol = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for PortMapping<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.host_port) as u64)
+        + 1 + sizeof_varint(*(&self.container_port) as u64)
+        + self.protocol.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.host_port))?;
+        w.write_with_tag(16, |w| w.write_uint32(*&self.container_port))?;
+        if let Some(ref s) = self.protocol { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Network options.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Network {
+    HOST = 1,
+    BRIDGE = 2,
+    NONE = 3,
+    USER = 4,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Network::HOST
+    }
+}
+
+impl From<i32> for Network {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Network::HOST,
+            2 => Network::BRIDGE,
+            3 => Network::NONE,
+            4 => Network::USER,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Network {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "HOST" => Network::HOST,
+            "BRIDGE" => Network::BRIDGE,
+            "NONE" => Network::NONE,
+            "USER" => Network::USER,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MesosInfo<'a> {
+    pub image: Option<mesos::v1::Image<'a>>,
+}
+
+impl<'a> MessageRead<'a> for MesosInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.image = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.image.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for MesosInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.image.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.image { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// All container implementation types.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    DOCKER = 1,
+    MESOS = 2,
+}
+
+impl Default for Type {
+    fn default() -> Self {
+        Type::DOCKER
+    }
+}
+
+impl From<i32> for Type {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Type::DOCKER,
+            2 => Type::MESOS,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "DOCKER" => Type::DOCKER,
+            "MESOS" => Type::MESOS,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Container related information that is resolved during container
+/// setup. The information is sent back to the framework as part of the
+/// TaskStatus message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ContainerStatus<'a> {
+    pub container_id: Option<mesos::v1::ContainerID<'a>>,
+    /// This field can be reliably used to identify the container IP address.
+    pub network_infos: Vec<mesos::v1::NetworkInfo<'a>>,
+    /// Information about Linux control group (cgroup).
+    pub cgroup_info: Option<mesos::v1::CgroupInfo>,
+    /// Information about Executor PID.
+    pub executor_pid: Option<u32>,
+}
+
+impl<'a> MessageRead<'a> for ContainerStatus<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.container_id = Default::default();
+        self.network_infos.clear();
+        self.cgroup_info = Default::default();
+        self.executor_pid = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(34) => r.merge_message(bytes, self.container_id.get_or_insert_with(Default::default))?,
+                Ok(10) => self.network_infos.push(r.read_message::<mesos::v1::NetworkInfo>(bytes)?),
+                Ok(18) => r.merge_message(bytes, self.cgroup_info.get_or_insert_with(Default::default))?,
+                Ok(24) => self.executor_pid = Some(r.read_uint32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ContainerStatus<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.container_id.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.network_infos.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.cgroup_info.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.executor_pid.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.container_id { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.network_infos { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.cgroup_info { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.executor_pid { w.write_with_tag(24, |w| w.write_uint32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Linux control group (cgroup) information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CgroupInfo {
+    pub net_cls: Option<mesos::v1::mod_CgroupInfo::NetCls>,
+}
+
+impl<'a> MessageRead<'a> for CgroupInfo {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.net_cls = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.net_cls.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for CgroupInfo {
+    fn get_size(&self) -> usize {
+        0
+        + self.net_cls.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.net_cls { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CgroupInfo`
+pub mod mod_CgroupInfo {
+
+use super::*;
+
+/// Configuration of a blkio cgroup subsystem.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Blkio {
+}
+
+impl<'a> MessageRead<'a> for Blkio {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Blkio {
+    fn get_size(&self) -> usize {
+        0
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Blkio`
+pub mod mod_Blkio {
+
+use super::*;
+
+/// Describes a stat value without the device descriptor part.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Value {
+    /// Required.
+    pub op: Option<mesos::v1::mod_CgroupInfo::mod_Blkio::Operation>,
+    /// Required.
+    pub value: Option<u64>,
+}
+
+impl<'a> MessageRead<'a> for Value {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.op = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.op = Some(r.read_enum(bytes)?),
+                Ok(16) => self.value = Some(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Value {
+    fn get_size(&self) -> usize {
+        0
+        + self.op.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.op { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.value { w.write_with_tag(16, |w| w.write_uint64(*s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CFQ {
+}
+
+impl<'a> MessageRead<'a> for CFQ {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for CFQ {
+    fn get_size(&self) -> usize {
+        0
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `CFQ`
+pub mod mod_CFQ {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Statistics {
+    /// Stats are grouped by block devices. If `device` is not
+    /// set, it represents `Total`.
+    pub device: Option<mesos::v1::mod_Device::Number>,
+    /// blkio.sectors
+    pub sectors: Option<u64>,
+    /// blkio.time
+    pub time: Option<u64>,
+    /// blkio.io_serviced
+    pub io_serviced: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.io_service_bytes
+    pub io_service_bytes: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.io_service_time
+    pub io_service_time: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.io_wait_time
+    pub io_wait_time: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.io_merged
+    pub io_merged: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.io_queued
+    pub io_queued: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+}
+
+impl<'a> MessageRead<'a> for Statistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.device = Default::default();
+        self.sectors = Default::default();
+        self.time = Default::default();
+        self.io_serviced.clear();
+        self.io_service_bytes.clear();
+        self.io_service_time.clear();
+        self.io_wait_time.clear();
+        self.io_merged.clear();
+        self.io_queued.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.device.get_or_insert_with(Default::default))?,
+                Ok(16) => self.sectors = Some(r.read_uint64(bytes)?),
+                Ok(24) => self.time = Some(r.read_uint64(bytes)?),
+                Ok(34) => self.io_serviced.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(42) => self.io_service_bytes.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(50) => self.io_service_time.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(58) => self.io_wait_time.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(66) => self.io_merged.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(74) => self.io_queued.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Statistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.device.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.sectors.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.time.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.io_serviced.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_service_bytes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_service_time.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_wait_time.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_merged.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_queued.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.device { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.sectors { w.write_with_tag(16, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.time { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        for s in &self.io_serviced { w.write_with_tag(34, |w| w.write_message(s))?; }
+        for s in &self.io_service_bytes { w.write_with_tag(42, |w| w.write_message(s))?; }
+        for s in &self.io_service_time { w.write_with_tag(50, |w| w.write_message(s))?; }
+        for s in &self.io_wait_time { w.write_with_tag(58, |w| w.write_message(s))?; }
+        for s in &self.io_merged { w.write_with_tag(66, |w| w.write_message(s))?; }
+        for s in &self.io_queued { w.write_with_tag(74, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Throttling {
+}
+
+impl<'a> MessageRead<'a> for Throttling {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Throttling {
+    fn get_size(&self) -> usize {
+        0
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Throttling`
+pub mod mod_Throttling {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Statistics {
+    /// Stats are grouped by block devices. If `device` is not
+    /// set, it represents `Total`.
+    pub device: Option<mesos::v1::mod_Device::Number>,
+    /// blkio.throttle.io_serviced
+    pub io_serviced: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+    /// blkio.throttle.io_service_bytes
+    pub io_service_bytes: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>,
+}
+
+impl<'a> MessageRead<'a> for Statistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.device = Default::default();
+        self.io_serviced.clear();
+        self.io_service_bytes.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.device.get_or_insert_with(Default::default))?,
+                Ok(18) => self.io_serviced.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(26) => self.io_service_bytes.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::Value>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Statistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.device.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.io_serviced.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.io_service_bytes.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.device { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.io_serviced { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.io_service_bytes { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Statistics {
+    pub cfq: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_CFQ::Statistics>,
+    pub cfq_recursive: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_CFQ::Statistics>,
+    pub throttling: Vec<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_Throttling::Statistics>,
+}
+
+impl<'a> MessageRead<'a> for Statistics {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.cfq.clear();
+        self.cfq_recursive.clear();
+        self.throttling.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.cfq.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_CFQ::Statistics>(bytes)?),
+                Ok(18) => self.cfq_recursive.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_CFQ::Statistics>(bytes)?),
+                Ok(26) => self.throttling.push(r.read_message::<mesos::v1::mod_CgroupInfo::mod_Blkio::mod_Throttling::Statistics>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Statistics {
+    fn get_size(&self) -> usize {
+        0
+        + self.cfq.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.cfq_recursive.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.throttling.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.cfq { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.cfq_recursive { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.throttling { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    UNKNOWN = 0,
+    TOTAL = 1,
+    READ = 2,
+    WRITE = 3,
+    SYNC = 4,
+    ASYNC = 5,
+}
+
+impl Default for Operation {
+    fn default() -> Self {
+        Operation::UNKNOWN
+    }
+}
+
+impl From<i32> for Operation {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Operation::UNKNOWN,
+            1 => Operation::TOTAL,
+            2 => Operation::READ,
+            3 => Operation::WRITE,
+            4 => Operation::SYNC,
+            5 => Operation::ASYNC,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Operation {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Operation::UNKNOWN,
+            "TOTAL" => Operation::TOTAL,
+            "READ" => Operation::READ,
+            "WRITE" => Operation::WRITE,
+            "SYNC" => Operation::SYNC,
+            "ASYNC" => Operation::ASYNC,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Configuration of a net_cls cgroup subsystem.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NetCls {
+    /// The 32-bit classid consists of two parts, a 16 bit major handle
+    /// and a 16-bit minor handle. The major and minor handle are
+    /// represented using the format 0xAAAABBBB, where 0xAAAA is the
+    /// 16-bit major handle and 0xBBBB is the 16-bit minor handle.
+    pub classid: Option<u32>,
+}
+
+impl<'a> MessageRead<'a> for NetCls {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.classid = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.classid = Some(r.read_uint32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for NetCls {
+    fn get_size(&self) -> usize {
+        0
+        + self.classid.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.classid { w.write_with_tag(8, |w| w.write_uint32(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
+/// Collection of labels. Labels should not contain duplicate key-value
+/// pairs.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Labels<'a> {
+    pub labels: Vec<mesos::v1::Label<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Labels<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.labels.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.labels.push(r.read_message::<mesos::v1::Label>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Labels<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.labels.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.labels { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Key, value pair used to store free form user-data.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Label<'a> {
+    pub key: Cow<'a, str>,
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Label<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.key = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.key = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Label<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.key).len())
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.key))?;
+        if let Some(ref s) = self.value { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Named port used for service discovery.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Port<'a> {
+    /// Port number on which the framework exposes a service.
+    pub number: u32,
+    /// Name of the service hosted on this port.
+    pub name: Option<Cow<'a, str>>,
+    /// Layer 4-7 protocol on which the framework exposes its services.
+    pub protocol: Option<Cow<'a, str>>,
+    /// This field restricts discovery within a framework (FRAMEWORK),
+    /// within a Mesos cluster (CLUSTER), or places no restrictions (EXTERNAL).
+    /// The visibility setting for a Port overrides the general visibility setting
+    /// in the DiscoveryInfo.
+    pub visibility: Option<mesos::v1::mod_DiscoveryInfo::Visibility>,
+    /// This can be used to decorate the message with metadata to be
+    /// interpreted by external applications such as firewalls.
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Port<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.number = Default::default();
+        self.name = Default::default();
+        self.protocol = Default::default();
+        self.visibility = Default::default();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.number = r.read_uint32(bytes)?,
+                Ok(18) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => self.protocol = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(32) => self.visibility = Some(r.read_enum(bytes)?),
+                Ok(42) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Port<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.number) as u64)
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.protocol.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.visibility.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint32(*&self.number))?;
+        if let Some(ref s) = self.name { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.protocol { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.visibility { w.write_with_tag(32, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(42, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Collection of ports.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Ports<'a> {
+    pub ports: Vec<mesos::v1::Port<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Ports<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.ports.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.ports.push(r.read_message::<mesos::v1::Port>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Ports<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.ports.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.ports { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Service discovery information.
+/// The visibility field restricts discovery within a framework (FRAMEWORK),
+/// within a Mesos cluster (CLUSTER), or places no restrictions (EXTERNAL).
+/// Each port in the ports field also has an optional visibility field.
+/// If visibility is specified for a port, it overrides the default service-wide
+/// DiscoveryInfo.visibility for that port.
+/// The environment, location, and version fields provide first class support for
+/// common attributes used to differentiate between similar services. The
+/// environment may receive values such as PROD/QA/DEV, the location field may
+/// receive values like EAST-US/WEST-US/EUROPE/AMEA, and the version field may
+/// receive values like v2.0/v0.9. The exact use of these fields is up to each
+/// service discovery system.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DiscoveryInfo<'a> {
+    pub visibility: mesos::v1::mod_DiscoveryInfo::Visibility,
+    pub name: Option<Cow<'a, str>>,
+    pub environment: Option<Cow<'a, str>>,
+    pub location: Option<Cow<'a, str>>,
+    pub version: Option<Cow<'a, str>>,
+    pub ports: Option<mesos::v1::Ports<'a>>,
+    pub labels: Option<mesos::v1::Labels<'a>>,
+}
+
+impl<'a> MessageRead<'a> for DiscoveryInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.visibility = Default::default();
+        self.name = Default::default();
+        self.environment = Default::default();
+        self.location = Default::default();
+        self.version = Default::default();
+        self.ports = Default::default();
+        self.labels = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.visibility = r.read_enum(bytes)?,
+                Ok(18) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => self.environment = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.location = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => self.version = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => r.merge_message(bytes, self.ports.get_or_insert_with(Default::default))?,
+                Ok(58) => r.merge_message(bytes, self.labels.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DiscoveryInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.visibility) as u64)
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.environment.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.location.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.version.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.ports.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.labels.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.visibility as i32))?;
+        if let Some(ref s) = self.name { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.environment { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.location { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.version { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.ports { w.write_with_tag(50, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.labels { w.write_with_tag(58, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DiscoveryInfo`
+pub mod mod_DiscoveryInfo {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Visibility {
+    FRAMEWORK = 0,
+    CLUSTER = 1,
+    EXTERNAL = 2,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Visibility::FRAMEWORK
+    }
+}
+
+impl From<i32> for Visibility {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Visibility::FRAMEWORK,
+            1 => Visibility::CLUSTER,
+            2 => Visibility::EXTERNAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Visibility {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "FRAMEWORK" => Visibility::FRAMEWORK,
+            "CLUSTER" => Visibility::CLUSTER,
+            "EXTERNAL" => Visibility::EXTERNAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+/// Named WeightInfo to indicate resource allocation
+/// priority between the different roles.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct WeightInfo<'a> {
+    pub weight: f64,
+    /// Related role name.
+    pub role: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for WeightInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.weight = Default::default();
+        self.role = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(9) => self.weight = r.read_double(bytes)?,
+                Ok(18) => self.role = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for WeightInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + 8
+        + self.role.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(9, |w| w.write_double(*&self.weight))?;
+        if let Some(ref s) = self.role { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Version information of a component.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct VersionInfo<'a> {
+    pub version: Cow<'a, str>,
+    pub build_date: Option<Cow<'a, str>>,
+    pub build_time: Option<f64>,
+    pub build_user: Option<Cow<'a, str>>,
+    pub git_sha: Option<Cow<'a, str>>,
+    pub git_branch: Option<Cow<'a, str>>,
+    pub git_tag: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for VersionInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.version = Default::default();
+        self.build_date = Default::default();
+        self.build_time = Default::default();
+        self.build_user = Default::default();
+        self.git_sha = Default::default();
+        self.git_branch = Default::default();
+        self.git_tag = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.version = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.build_date = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(25) => self.build_time = Some(r.read_double(bytes)?),
+                Ok(34) => self.build_user = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => self.git_sha = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => self.git_branch = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => self.git_tag = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for VersionInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.version).len())
+        + self.build_date.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.build_time.as_ref().map_or(0, |_| 1 + 8)
+        + self.build_user.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.git_sha.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.git_branch.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.git_tag.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.version))?;
+        if let Some(ref s) = self.build_date { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.build_time { w.write_with_tag(25, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.build_user { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.git_sha { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.git_branch { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.git_tag { w.write_with_tag(58, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Flag consists of a name and optionally its value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Flag<'a> {
+    pub name: Cow<'a, str>,
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Flag<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Flag<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.value { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a Role. Roles can be used to specify that certain resources are
+/// reserved for the use of one or more frameworks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Role<'a> {
+    pub name: Cow<'a, str>,
+    pub weight: f64,
+    pub frameworks: Vec<mesos::v1::FrameworkID<'a>>,
+    pub resources: Vec<mesos::v1::Resource<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Role<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.weight = Default::default();
+        self.frameworks.clear();
+        self.resources.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(17) => self.weight = r.read_double(bytes)?,
+                Ok(26) => self.frameworks.push(r.read_message::<mesos::v1::FrameworkID>(bytes)?),
+                Ok(34) => self.resources.push(r.read_message::<mesos::v1::Resource>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Role<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + 8
+        + self.frameworks.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.resources.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(17, |w| w.write_double(*&self.weight))?;
+        for s in &self.frameworks { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.resources { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Metric consists of a name and optionally its value.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Metric<'a> {
+    pub name: Cow<'a, str>,
+    pub value: Option<f64>,
+}
+
+impl<'a> MessageRead<'a> for Metric<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(17) => self.value = Some(r.read_double(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Metric<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + self.value.as_ref().map_or(0, |_| 1 + 8)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        if let Some(ref s) = self.value { w.write_with_tag(17, |w| w.write_double(*s))?; }
+        Ok(())
+    }
+}
+
+/// Describes a File.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FileInfo<'a> {
+    /// Absolute path to the file.
+    pub path: Cow<'a, str>,
+    /// Number of hard links.
+    pub nlink: Option<i32>,
+    /// Total size in bytes.
+    pub size: Option<u64>,
+    /// Last modification time.
+    pub mtime: Option<mesos::v1::TimeInfo>,
+    /// Represents a file's mode and permission bits. The bits have the same
+    /// definition on all systems and is portable.
+    pub mode: Option<u32>,
+    /// User ID of owner.
+    pub uid: Option<Cow<'a, str>>,
+    /// Group ID of owner.
+    pub gid: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for FileInfo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.path = Default::default();
+        self.nlink = Default::default();
+        self.size = Default::default();
+        self.mtime = Default::default();
+        self.mode = Default::default();
+        self.uid = Default::default();
+        self.gid = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.path = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.nlink = Some(r.read_int32(bytes)?),
+                Ok(24) => self.size = Some(r.read_uint64(bytes)?),
+                Ok(34) => r.merge_message(bytes, self.mtime.get_or_insert_with(Default::default))?,
+                Ok(40) => self.mode = Some(r.read_uint32(bytes)?),
+                Ok(50) => self.uid = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(58) => self.gid = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for FileInfo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.path).len())
+        + self.nlink.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.size.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mtime.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.mode.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.uid.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.gid.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.path))?;
+        if let Some(ref s) = self.nlink { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.size { w.write_with_tag(24, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.mtime { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.mode { w.write_with_tag(40, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.uid { w.write_with_tag(50, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.gid { w.write_with_tag(58, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Describes information about a device.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Device<'a> {
+    pub path: Option<Cow<'a, str>>,
+    pub number: Option<mesos::v1::mod_Device::Number>,
+}
+
+impl<'a> MessageRead<'a> for Device<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.path = Default::default();
+        self.number = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.path = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(18) => r.merge_message(bytes, self.number.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Device<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.path.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.number.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.path { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.number { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Device`
+pub mod mod_Device {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Number {
+    pub major_number: u64,
+    pub minor_number: u64,
+}
+
+impl<'a> MessageRead<'a> for Number {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.major_number = Default::default();
+        self.minor_number = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.major_number = r.read_uint64(bytes)?,
+                Ok(16) => self.minor_number = r.read_uint64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Number {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.major_number) as u64)
+        + 1 + sizeof_varint(*(&self.minor_number) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_uint64(*&self.major_number))?;
+        w.write_with_tag(16, |w| w.write_uint64(*&self.minor_number))?;
+        Ok(())
+    }
+}
+
+}
+
+/// Describes a device whitelist entry that expose from host to container.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DeviceAccess<'a> {
+    pub device: mesos::v1::Device<'a>,
+    pub access: mesos::v1::mod_DeviceAccess::Access,
+}
+
+impl<'a> MessageRead<'a> for DeviceAccess<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.device = Default::default();
+        self.access = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut self.device)?,
+                Ok(18) => r.merge_message(bytes, &mut self.access)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DeviceAccess<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.device).get_size())
+        + 1 + sizeof_len((&self.access).get_size())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_message(&self.device))?;
+        w.write_with_tag(18, |w| w.write_message(&self.access))?;
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `DeviceAccess`
+pub mod mod_DeviceAccess {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Access {
+    pub read: Option<bool>,
+    pub write: Option<bool>,
+    pub mknod: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for Access {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.read = Default::default();
+        self.write = Default::default();
+        self.mknod = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.read = Some(r.read_bool(bytes)?),
+                Ok(16) => self.write = Some(r.read_bool(bytes)?),
+                Ok(24) => self.mknod = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Access {
+    fn get_size(&self) -> usize {
+        0
+        + self.read.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.write.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.mknod.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.read { w.write_with_tag(8, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.write { w.write_with_tag(16, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.mknod { w.write_with_tag(24, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DeviceWhitelist<'a> {
+    pub allowed_devices: Vec<mesos::v1::DeviceAccess<'a>>,
+}
+
+impl<'a> MessageRead<'a> for DeviceWhitelist<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.allowed_devices.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.allowed_devices.push(r.read_message::<mesos::v1::DeviceAccess>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for DeviceWhitelist<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.allowed_devices.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.allowed_devices { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
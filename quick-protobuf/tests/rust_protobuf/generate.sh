@@ -8,16 +8,20 @@
 have_failures=""
 
 # Expected codegen failures are marked in the associative array `must_fail`
-# with the relative path as the key and reason as value.
+# with the relative path as the key and the expected error message (or a part of it) as value.
 # When adding new, remember not to add any whitespace around `=`.
 declare -A must_fail
 
-must_fail["v2/test_group_pb.proto"]="expected failure (empty read)"
-must_fail["v3/test_enum_alias_pb.proto"]="enum alias not implemented"
-must_fail["v2/test_enum_alias_pb.proto"]="enum alias not implemented"
-must_fail["v2/test_expose_oneof_pb.proto"]="missing file"
-must_fail["v2/test_enum_invalid_default.proto"]="enum variant does not exist"
-must_fail["v3/test_enum_invalid_default.proto"]="enum variant does not exist"
+must_fail["v2/test_group_pb.proto"]='expected `;`, found `{`'
+must_fail["v2/test_expose_oneof_pb.proto"]="rustproto.proto not found"
+must_fail["v2/test_enum_invalid_default.proto"]="this variant does not exist"
+must_fail["v3/test_enum_invalid_default.proto"]="this variant does not exist"
+must_fail["v2/test_field_number_duplicate.proto"]="Fields a and b share the number 1"
+must_fail["v3/test_field_number_duplicate.proto"]="Fields a and b share the number 1"
+must_fail["v2/test_field_number_zero.proto"]="Field a number 0 is not between 1 and"
+must_fail["v3/test_field_number_zero.proto"]="Field a number 0 is not between 1 and"
+must_fail["v2/test_field_number_reserved.proto"]="conflict with reserved fields"
+must_fail["v3/test_field_number_reserved.proto"]="conflict with reserved fields"
 
 # Custom arguments to pass to `pb-rs` for generating files used in testing
 declare -A custom_pbrs_args
@@ -26,6 +30,20 @@ custom_pbrs_args["v2/test_owned_pb.proto"]="--owned"
 custom_pbrs_args["v3/test_owned_pb.proto"]="--owned"
 custom_pbrs_args["v2/test_deprecated_lifetime_can_compile.proto"]="--add-deprecated-fields"
 custom_pbrs_args["v3/test_deprecated_lifetime_can_compile.proto"]="--add-deprecated-fields"
+custom_pbrs_args["v3/test_basic_strict_pb.proto"]="--strict"
+custom_pbrs_args["v3/test_services_pb.proto"]="--generate_services"
+custom_pbrs_args["v2/test_builders_pb.proto"]="--gen-builders"
+custom_pbrs_args["v2/test_accessors_pb.proto"]="--gen-accessors"
+custom_pbrs_args["v3/test_heapless_pb.proto"]="--heapless"
+custom_pbrs_args["v3/test_dont_use_cow_pb.proto"]="--dont_use_cow"
+custom_pbrs_args["v3/test_field_types_pb.proto"]="\
+--custom_field_type Record.hash=[u8;32] \
+--custom_field_type Record.name=std::sync::Arc<str> \
+--custom_field_type Record.created=crate::rust_protobuf::v3::test_rust_options::UnixMillis \
+--custom_field_type Record.tags=String \
+--custom_field_type Record.label=String \
+--custom_field_type Detached.name=String \
+--custom_field_type Detached.data=Vec<u8>"
 
 # Combined stdout and stderr for codegen of unexpectedly failed file.
 declare -A outs
@@ -55,6 +73,10 @@ for f in v[23]/*.proto; do
 		outs["$f"]="$out"
 		have_failures="true"
 		echo "$f: unexpected success"
+	elif expecting_failure "$f" && ! grep -qF -- "${must_fail["$f"]}" <<< "$out"; then
+		outs["$f"]="$out"
+		have_failures="true"
+		echo "$f: unexpected error, expecting '${must_fail["$f"]}'"
 	elif expecting_success "$f" && [ "$ret" -ne 0 ]; then
 		have_failures="true"
 		outs["$f"]="$out"
@@ -73,6 +95,10 @@ for f in common/*.proto; do
 		outs["$f"]="$out"
 		have_failures="true"
 		echo "$f: unexpected success"
+	elif expecting_failure "$f" && ! grep -qF -- "${must_fail["$f"]}" <<< "$out"; then
+		outs["$f"]="$out"
+		have_failures="true"
+		echo "$f: unexpected error, expecting '${must_fail["$f"]}'"
 	elif expecting_success "$f" && [ "$ret" -ne 0 ]; then
 		have_failures="true"
 		outs["$f"]="$out"
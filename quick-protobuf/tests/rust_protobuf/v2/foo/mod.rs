@@ -0,0 +1,3 @@
+// Automatically generated mod.rs
+pub mod baz;
+pub mod bar;
@@ -0,0 +1,213 @@
+// Automatically generated rust module for 'issue118.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ViaOneOf {
+    pub Alternatives: mod_ViaOneOf::OneOfAlternatives,
+}
+
+impl<'a> MessageRead<'a> for ViaOneOf {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.Alternatives = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.Alternatives = mod_ViaOneOf::OneOfAlternatives::present(Box::new(r.read_message::<mod_ViaOneOf::Data>(bytes)?)),
+                Ok(16) => self.Alternatives = mod_ViaOneOf::OneOfAlternatives::absent(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for ViaOneOf {
+    fn get_size(&self) -> usize {
+        0
+        + match self.Alternatives {
+            mod_ViaOneOf::OneOfAlternatives::present(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_ViaOneOf::OneOfAlternatives::absent(ref m) => 1 + sizeof_varint(*(m) as u64),
+            mod_ViaOneOf::OneOfAlternatives::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        match self.Alternatives {            mod_ViaOneOf::OneOfAlternatives::present(ref m) => { w.write_with_tag(10, |w| w.write_message(&**m))? },
+            mod_ViaOneOf::OneOfAlternatives::absent(ref m) => { w.write_with_tag(16, |w| w.write_bool(*m))? },
+            mod_ViaOneOf::OneOfAlternatives::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ViaOneOf`
+pub mod mod_ViaOneOf {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Data {
+    pub self_reference: Option<Box<ViaOneOf>>,
+}
+
+impl<'a> MessageRead<'a> for Data {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.self_reference = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut **self.self_reference.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Data {
+    fn get_size(&self) -> usize {
+        0
+        + self.self_reference.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.self_reference { w.write_with_tag(10, |w| w.write_message(&**s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfAlternatives {
+    present(Box<mod_ViaOneOf::Data>),
+    absent(bool),
+    /// None of the fields is set
+    None,
+}
+
+impl Default for OneOfAlternatives {
+    fn default() -> Self {
+        OneOfAlternatives::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ViaOptional {
+    pub data: Option<Box<mod_ViaOptional::Data>>,
+}
+
+impl<'a> MessageRead<'a> for ViaOptional {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.data = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut **self.data.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for ViaOptional {
+    fn get_size(&self) -> usize {
+        0
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.data { w.write_with_tag(10, |w| w.write_message(&**s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `ViaOptional`
+pub mod mod_ViaOptional {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Data {
+    pub self_reference: Option<Box<ViaOptional>>,
+}
+
+impl<'a> MessageRead<'a> for Data {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.self_reference = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, &mut **self.self_reference.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Data {
+    fn get_size(&self) -> usize {
+        0
+        + self.self_reference.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.self_reference { w.write_with_tag(10, |w| w.write_message(&**s))?; }
+        Ok(())
+    }
+}
+
+}
+
@@ -0,0 +1,71 @@
+// Automatically generated rust module for 'issue_170_d.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct D {
+    pub common: Option<issue_170_common::Common>,
+    pub b: Option<issue_170_b::B>,
+    pub a: Option<issue_170_a::A>,
+    pub c: Option<issue_170_c::C>,
+}
+
+impl<'a> MessageRead<'a> for D {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.common = Default::default();
+        self.b = Default::default();
+        self.a = Default::default();
+        self.c = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.common.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.b.get_or_insert_with(Default::default))?,
+                Ok(26) => r.merge_message(bytes, self.a.get_or_insert_with(Default::default))?,
+                Ok(34) => r.merge_message(bytes, self.c.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for D {
+    fn get_size(&self) -> usize {
+        0
+        + self.common.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.b.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.a.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.c.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.common { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.b { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.a { w.write_with_tag(26, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.c { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
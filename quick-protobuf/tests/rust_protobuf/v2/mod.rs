@@ -17,12 +17,18 @@ pub mod nonunique_2;
 pub mod shared0;
 pub mod special;
 pub mod struct_pb;
+pub mod test_accessors;
+pub mod test_accessors_test;
 pub mod test_basic;
+pub mod test_builders;
+pub mod test_builders_test;
 pub mod test_default_values;
 pub mod test_default_values_test;
 pub mod test_deprecated_lifetime;
 pub mod test_deprecated_lifetime_can_compile;
 pub mod test_enum_values_pb;
+pub mod test_extensions;
+pub mod test_extensions_pb;
 pub mod test_ident_pb;
 pub mod test_import_nested_imported_pb;
 pub mod test_import_nested_pb;
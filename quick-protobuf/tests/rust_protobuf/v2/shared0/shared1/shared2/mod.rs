@@ -0,0 +1,3 @@
+// Automatically generated mod.rs
+pub mod first0;
+pub mod second0;
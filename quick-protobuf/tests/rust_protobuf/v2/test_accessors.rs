@@ -0,0 +1,443 @@
+// Automatically generated rust module for 'test_accessors_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Color {
+    RED = 0,
+    GREEN = 1,
+    BLUE = 2,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::RED
+    }
+}
+
+impl From<i32> for Color {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Color::RED,
+            1 => Color::GREEN,
+            2 => Color::BLUE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Color {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "RED" => Color::RED,
+            "GREEN" => Color::GREEN,
+            "BLUE" => Color::BLUE,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Inner {
+    pub value: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for Inner {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.value = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Inner {
+    fn get_size(&self) -> usize {
+        0
+        + self.value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.value { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+impl Inner {
+    pub fn value(&self) -> i32 {
+        self.value.unwrap_or_default()
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    pub fn clear_value(&mut self) {
+        self.value = None;
+    }
+
+    pub fn set_value(&mut self, v: i32) {
+        self.value = Some(v);
+    }
+
+    pub fn mut_value(&mut self) -> &mut i32 {
+        self.value.get_or_insert_with(Default::default)
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Outer<'a> {
+    pub plain: Option<i32>,
+    pub answer: Option<i32>,
+    pub name: Option<Cow<'a, str>>,
+    pub greeting: Option<Cow<'a, str>>,
+    pub data: Option<Cow<'a, [u8]>>,
+    pub color: Option<test_accessors::Color>,
+    pub shade: Option<test_accessors::Color>,
+    pub inner: Option<test_accessors::Inner>,
+    pub id: i32,
+    pub values: Vec<i32>,
+    pub choice: test_accessors::mod_Outer::OneOfchoice<'a>,
+}
+
+impl<'a> MessageRead<'a> for Outer<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.plain = Default::default();
+        self.answer = Default::default();
+        self.name = Default::default();
+        self.greeting = Default::default();
+        self.data = Default::default();
+        self.color = Default::default();
+        self.shade = Default::default();
+        self.inner = Default::default();
+        self.id = Default::default();
+        self.values.clear();
+        self.choice = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.plain = Some(r.read_int32(bytes)?),
+                Ok(16) => self.answer = Some(r.read_int32(bytes)?),
+                Ok(26) => self.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.greeting = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(42) => self.data = Some(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(48) => self.color = Some(r.read_enum(bytes)?),
+                Ok(56) => self.shade = Some(r.read_enum(bytes)?),
+                Ok(66) => r.merge_message(bytes, self.inner.get_or_insert_with(Default::default))?,
+                Ok(72) => self.id = r.read_int32(bytes)?,
+                Ok(80) => self.values.push(r.read_int32(bytes)?),
+                Ok(88) => self.choice = test_accessors::mod_Outer::OneOfchoice::number(r.read_int32(bytes)?),
+                Ok(98) => self.choice = test_accessors::mod_Outer::OneOfchoice::text(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(106) => self.choice = test_accessors::mod_Outer::OneOfchoice::nested(r.read_message::<test_accessors::Inner>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Outer<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.plain.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.answer.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.greeting.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.color.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.shade.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.inner.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + 1 + sizeof_varint(*(&self.id) as u64)
+        + self.values.iter().map(|s| 1 + sizeof_varint(*(s) as u64)).sum::<usize>()
+        + match self.choice {
+            test_accessors::mod_Outer::OneOfchoice::number(ref m) => 1 + sizeof_varint(*(m) as u64),
+            test_accessors::mod_Outer::OneOfchoice::text(ref m) => 1 + sizeof_len((m).len()),
+            test_accessors::mod_Outer::OneOfchoice::nested(ref m) => 1 + sizeof_len((m).get_size()),
+            test_accessors::mod_Outer::OneOfchoice::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.plain { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.answer { w.write_with_tag(16, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.name { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.greeting { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.data { w.write_with_tag(42, |w| w.write_bytes(&**s))?; }
+        if let Some(ref s) = self.color { w.write_with_tag(48, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.shade { w.write_with_tag(56, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.inner { w.write_with_tag(66, |w| w.write_message(s))?; }
+        w.write_with_tag(72, |w| w.write_int32(*&self.id))?;
+        for s in &self.values { w.write_with_tag(80, |w| w.write_int32(*s))?; }
+        match self.choice {            test_accessors::mod_Outer::OneOfchoice::number(ref m) => { w.write_with_tag(88, |w| w.write_int32(*m))? },
+            test_accessors::mod_Outer::OneOfchoice::text(ref m) => { w.write_with_tag(98, |w| w.write_string(&**m))? },
+            test_accessors::mod_Outer::OneOfchoice::nested(ref m) => { w.write_with_tag(106, |w| w.write_message(m))? },
+            test_accessors::mod_Outer::OneOfchoice::None => {},
+    }        Ok(())
+    }
+}
+
+impl<'a> Outer<'a> {
+    pub fn plain(&self) -> i32 {
+        self.plain.unwrap_or_default()
+    }
+
+    pub fn has_plain(&self) -> bool {
+        self.plain.is_some()
+    }
+
+    pub fn clear_plain(&mut self) {
+        self.plain = None;
+    }
+
+    pub fn set_plain(&mut self, v: i32) {
+        self.plain = Some(v);
+    }
+
+    pub fn mut_plain(&mut self) -> &mut i32 {
+        self.plain.get_or_insert_with(Default::default)
+    }
+
+    pub fn answer(&self) -> i32 {
+        self.answer.unwrap_or(42i32)
+    }
+
+    pub fn has_answer(&self) -> bool {
+        self.answer.is_some()
+    }
+
+    pub fn clear_answer(&mut self) {
+        self.answer = None;
+    }
+
+    pub fn set_answer(&mut self, v: i32) {
+        self.answer = Some(v);
+    }
+
+    pub fn mut_answer(&mut self) -> &mut i32 {
+        self.answer.get_or_insert_with(Default::default)
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or_default()
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    pub fn clear_name(&mut self) {
+        self.name = None;
+    }
+
+    pub fn set_name(&mut self, v: impl Into<Cow<'a, str>>) {
+        self.name = Some(v.into());
+    }
+
+    pub fn mut_name(&mut self) -> &mut Cow<'a, str> {
+        self.name.get_or_insert_with(Default::default)
+    }
+
+    pub fn greeting(&self) -> &str {
+        self.greeting.as_deref().unwrap_or("hello")
+    }
+
+    pub fn has_greeting(&self) -> bool {
+        self.greeting.is_some()
+    }
+
+    pub fn clear_greeting(&mut self) {
+        self.greeting = None;
+    }
+
+    pub fn set_greeting(&mut self, v: impl Into<Cow<'a, str>>) {
+        self.greeting = Some(v.into());
+    }
+
+    pub fn mut_greeting(&mut self) -> &mut Cow<'a, str> {
+        self.greeting.get_or_insert_with(Default::default)
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data.as_deref().unwrap_or_default()
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
+    }
+
+    pub fn clear_data(&mut self) {
+        self.data = None;
+    }
+
+    pub fn set_data(&mut self, v: impl Into<Cow<'a, [u8]>>) {
+        self.data = Some(v.into());
+    }
+
+    pub fn mut_data(&mut self) -> &mut Cow<'a, [u8]> {
+        self.data.get_or_insert_with(Default::default)
+    }
+
+    pub fn color(&self) -> test_accessors::Color {
+        self.color.unwrap_or(test_accessors::Color::BLUE)
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    pub fn clear_color(&mut self) {
+        self.color = None;
+    }
+
+    pub fn set_color(&mut self, v: test_accessors::Color) {
+        self.color = Some(v);
+    }
+
+    pub fn mut_color(&mut self) -> &mut test_accessors::Color {
+        self.color.get_or_insert_with(Default::default)
+    }
+
+    pub fn shade(&self) -> test_accessors::Color {
+        self.shade.unwrap_or_default()
+    }
+
+    pub fn has_shade(&self) -> bool {
+        self.shade.is_some()
+    }
+
+    pub fn clear_shade(&mut self) {
+        self.shade = None;
+    }
+
+    pub fn set_shade(&mut self, v: test_accessors::Color) {
+        self.shade = Some(v);
+    }
+
+    pub fn mut_shade(&mut self) -> &mut test_accessors::Color {
+        self.shade.get_or_insert_with(Default::default)
+    }
+
+    pub fn has_inner(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    pub fn clear_inner(&mut self) {
+        self.inner = None;
+    }
+
+    pub fn set_inner(&mut self, v: test_accessors::Inner) {
+        self.inner = Some(v);
+    }
+
+    pub fn mut_inner(&mut self) -> &mut test_accessors::Inner {
+        self.inner.get_or_insert_with(Default::default)
+    }
+
+    pub fn number(&self) -> Option<i32> {
+        match self.choice {
+            test_accessors::mod_Outer::OneOfchoice::number(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn has_number(&self) -> bool {
+        matches!(self.choice, test_accessors::mod_Outer::OneOfchoice::number(_))
+    }
+
+    pub fn set_number(&mut self, v: i32) {
+        self.choice = test_accessors::mod_Outer::OneOfchoice::number(v);
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        match self.choice {
+            test_accessors::mod_Outer::OneOfchoice::text(ref v) => Some(&**v),
+            _ => None,
+        }
+    }
+
+    pub fn has_text(&self) -> bool {
+        matches!(self.choice, test_accessors::mod_Outer::OneOfchoice::text(_))
+    }
+
+    pub fn set_text(&mut self, v: impl Into<Cow<'a, str>>) {
+        self.choice = test_accessors::mod_Outer::OneOfchoice::text(v.into());
+    }
+
+    pub fn nested(&self) -> Option<&test_accessors::Inner> {
+        match self.choice {
+            test_accessors::mod_Outer::OneOfchoice::nested(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn has_nested(&self) -> bool {
+        matches!(self.choice, test_accessors::mod_Outer::OneOfchoice::nested(_))
+    }
+
+    pub fn set_nested(&mut self, v: test_accessors::Inner) {
+        self.choice = test_accessors::mod_Outer::OneOfchoice::nested(v);
+    }
+
+    pub fn clear_choice(&mut self) {
+        self.choice = test_accessors::mod_Outer::OneOfchoice::None;
+    }
+}
+
+/// Nested messages, enums and oneofs of `Outer`
+pub mod mod_Outer {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfchoice<'a> {
+    number(i32),
+    text(Cow<'a, str>),
+    nested(test_accessors::Inner),
+    /// None of the fields is set
+    None,
+}
+
+impl<'a> Default for OneOfchoice<'a> {
+    fn default() -> Self {
+        OneOfchoice::None
+    }
+}
+
+}
+
@@ -0,0 +1,65 @@
+use quick_protobuf::*;
+
+use super::test_accessors::*;
+
+#[test]
+fn test_accessors_defaults() {
+    let outer = Outer::default();
+    assert_eq!(0, outer.plain());
+    assert!(!outer.has_plain());
+    assert_eq!(42, outer.answer());
+    assert!(!outer.has_answer());
+    assert!(!outer.has_greeting());
+    assert_eq!("", outer.name());
+    assert_eq!("hello", outer.greeting());
+    assert_eq!(&[] as &[u8], outer.data());
+    assert_eq!(Color::BLUE, outer.color());
+    assert_eq!(Color::RED, outer.shade());
+    assert!(!outer.has_inner());
+    assert_eq!(None, outer.number());
+    assert!(!outer.has_text());
+}
+
+#[test]
+fn test_accessors_presence() {
+    let mut outer = Outer::default();
+    outer.set_plain(0);
+    outer.set_answer(1);
+    outer.set_name("world");
+    outer.set_greeting("hello");
+    *outer.mut_greeting() += " you";
+    outer.set_color(Color::BLUE);
+    outer.mut_inner().set_value(3);
+    outer.set_text("some text");
+    assert!(outer.has_plain());
+    assert_eq!(0, outer.plain());
+    assert!(outer.has_answer());
+    assert_eq!("world", outer.name());
+    assert_eq!("hello you", outer.greeting());
+    // set to its default value
+    assert!(outer.has_color());
+    assert_eq!(Some(3), outer.inner.as_ref().map(|i| i.value()));
+    assert!(outer.has_text());
+    assert_eq!(Some("some text"), outer.text());
+    assert_eq!(None, outer.nested());
+
+    // presence survives a round trip
+    let bytes = serialize_into_vec(&outer).unwrap();
+    let mut read: Outer = deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(outer, read);
+    assert!(read.has_plain());
+
+    read.clear_plain();
+    read.clear_answer();
+    read.clear_inner();
+    read.clear_choice();
+    assert!(!read.has_plain());
+    assert!(!read.has_answer());
+    assert_eq!(42, read.answer());
+    assert!(!read.has_inner());
+    assert_eq!(None, read.text());
+
+    read.set_nested(Inner::default());
+    assert!(read.has_nested());
+    assert!(!read.has_text());
+}
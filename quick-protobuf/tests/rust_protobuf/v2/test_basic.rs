@@ -225,6 +225,19 @@ fn test_truncated_repeated_packed() {
     assert!(TestTruncated::from_reader(&mut reader, &bytes).is_err());
 }
 
+#[test]
+fn test_repeated_packed_fixed_chunks() {
+    // field 2 packed in two chunks, the values of both are kept
+    let test_truncated = TestTruncated {
+        ints: vec![1u32, 2, 3].into(),
+    };
+    test_deserialize!(
+        "12 04 01 00 00 00 12 08 02 00 00 00 03 00 00 00",
+        &test_truncated,
+        TestTruncated
+    );
+}
+
 #[test]
 fn test_bug_sint() {
     {
@@ -0,0 +1,360 @@
+// Automatically generated rust module for 'test_builders_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+type KVMap<K, V> = HashMap<K, V>;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    KIND_UNSPECIFIED = 0,
+    KIND_USER = 1,
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::KIND_UNSPECIFIED
+    }
+}
+
+impl From<i32> for Kind {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Kind::KIND_UNSPECIFIED,
+            1 => Kind::KIND_USER,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Kind {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "KIND_UNSPECIFIED" => Kind::KIND_UNSPECIFIED,
+            "KIND_USER" => Kind::KIND_USER,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Address<'a> {
+    pub city: Cow<'a, str>,
+    pub street: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Address<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.city = Default::default();
+        self.street = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.city = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.street = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Address<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.city).len())
+        + self.street.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.city))?;
+        if let Some(ref s) = self.street { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+impl<'a> Address<'a> {
+    /// A builder of `Address`
+    pub fn builder() -> AddressBuilder<'a> {
+        let msg = Self::default();
+        AddressBuilder { msg, has_city: false }
+    }
+}
+
+/// Builder of [`Address`], see `Address::builder`
+#[derive(Debug, Clone)]
+pub struct AddressBuilder<'a> {
+    msg: Address<'a>,
+    has_city: bool,
+}
+
+impl<'a> AddressBuilder<'a> {
+    pub fn city(mut self, v: impl Into<Cow<'a, str>>) -> Self {
+        self.msg.city = v.into();
+        self.has_city = true;
+        self
+    }
+
+    pub fn street(mut self, v: impl Into<Cow<'a, str>>) -> Self {
+        self.msg.street = Some(v.into());
+        self
+    }
+
+    /// Builds the `Address`, fails if a required field is not set
+    pub fn build(self) -> Result<Address<'a>> {
+        if !self.has_city {
+            return Err(quick_protobuf::Error::MissingField("Address.city"));
+        }
+        Ok(self.msg)
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Person<'a> {
+    pub name: Cow<'a, str>,
+    pub id: i32,
+    pub age: i32,
+    pub kind: Option<test_builders::Kind>,
+    pub emails: Vec<Cow<'a, str>>,
+    pub scores: Cow<'a, [u32]>,
+    pub counters: KVMap<Cow<'a, str>, i64>,
+    pub address: Option<test_builders::Address<'a>>,
+    pub previous: Vec<test_builders::Address<'a>>,
+    pub contact: test_builders::mod_Person::OneOfcontact<'a>,
+}
+
+impl<'a> MessageRead<'a> for Person<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Person {
+            age: 18i32,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.id = Default::default();
+        self.age = 18i32;
+        self.kind = Default::default();
+        self.emails.clear();
+        self.scores = Default::default();
+        self.counters.clear();
+        self.address = Default::default();
+        self.previous.clear();
+        self.contact = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.id = r.read_int32(bytes)?,
+                Ok(24) => self.age = r.read_int32(bytes)?,
+                Ok(32) => self.kind = Some(r.read_enum(bytes)?),
+                Ok(42) => self.emails.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => if self.scores.is_empty() { self.scores = r.read_packed_fixed(bytes)? } else { self.scores.to_mut().extend_from_slice(&r.read_packed_fixed(bytes)?) },
+                Ok(58) => {
+                    let (key, value) = r.read_map_entry(bytes, 10, |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?), 16, |r, bytes| Ok(r.read_int64(bytes)?))?;
+                    self.counters.insert(key, value);
+                }
+                Ok(66) => r.merge_message(bytes, self.address.get_or_insert_with(Default::default))?,
+                Ok(74) => self.previous.push(r.read_message::<test_builders::Address>(bytes)?),
+                Ok(82) => self.contact = test_builders::mod_Person::OneOfcontact::phone(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(90) => self.contact = test_builders::mod_Person::OneOfcontact::mail(r.read_message::<test_builders::Address>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Person<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.name).len())
+        + 1 + sizeof_varint(*(&self.id) as u64)
+        + if self.age == 18i32 { 0 } else { 1 + sizeof_varint(*(&self.age) as u64) }
+        + self.kind.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.emails.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + if self.scores.is_empty() { 0 } else { 1 + sizeof_len(self.scores.len() * 4) }
+        + self.counters.iter().map(|(k, v)| 1 + sizeof_len(2 + sizeof_len((k).len()) + sizeof_varint(*(v) as u64))).sum::<usize>()
+        + self.address.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.previous.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + match self.contact {
+            test_builders::mod_Person::OneOfcontact::phone(ref m) => 1 + sizeof_len((m).len()),
+            test_builders::mod_Person::OneOfcontact::mail(ref m) => 1 + sizeof_len((m).get_size()),
+            test_builders::mod_Person::OneOfcontact::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        w.write_with_tag(16, |w| w.write_int32(*&self.id))?;
+        if self.age != 18i32 { w.write_with_tag(24, |w| w.write_int32(*&self.age))?; }
+        if let Some(ref s) = self.kind { w.write_with_tag(32, |w| w.write_enum(*s as i32))?; }
+        for s in &self.emails { w.write_with_tag(42, |w| w.write_string(&**s))?; }
+        w.write_packed_fixed_with_tag(50, &self.scores)?;
+        for (k, v) in self.counters.iter() { w.write_with_tag(58, |w| w.write_map(2 + sizeof_len((k).len()) + sizeof_varint(*(v) as u64), 10, |w| w.write_string(&**k), 16, |w| w.write_int64(*v)))?; }
+        if let Some(ref s) = self.address { w.write_with_tag(66, |w| w.write_message(s))?; }
+        for s in &self.previous { w.write_with_tag(74, |w| w.write_message(s))?; }
+        match self.contact {            test_builders::mod_Person::OneOfcontact::phone(ref m) => { w.write_with_tag(82, |w| w.write_string(&**m))? },
+            test_builders::mod_Person::OneOfcontact::mail(ref m) => { w.write_with_tag(90, |w| w.write_message(m))? },
+            test_builders::mod_Person::OneOfcontact::None => {},
+    }        Ok(())
+    }
+}
+
+impl<'a> Person<'a> {
+    /// A builder of `Person`
+    pub fn builder() -> PersonBuilder<'a> {
+        let msg = Person {
+            age: 18i32,
+            ..Self::default()
+        };
+        PersonBuilder { msg, has_name: false, has_id: false }
+    }
+}
+
+/// Builder of [`Person`], see `Person::builder`
+#[derive(Debug, Clone)]
+pub struct PersonBuilder<'a> {
+    msg: Person<'a>,
+    has_name: bool,
+    has_id: bool,
+}
+
+impl<'a> PersonBuilder<'a> {
+    pub fn name(mut self, v: impl Into<Cow<'a, str>>) -> Self {
+        self.msg.name = v.into();
+        self.has_name = true;
+        self
+    }
+
+    pub fn id(mut self, v: i32) -> Self {
+        self.msg.id = v;
+        self.has_id = true;
+        self
+    }
+
+    pub fn age(mut self, v: i32) -> Self {
+        self.msg.age = v;
+        self
+    }
+
+    pub fn kind(mut self, v: test_builders::Kind) -> Self {
+        self.msg.kind = Some(v);
+        self
+    }
+
+    pub fn emails(mut self, v: Vec<Cow<'a, str>>) -> Self {
+        self.msg.emails = v;
+        self
+    }
+
+    pub fn push_emails(mut self, v: impl Into<Cow<'a, str>>) -> Self {
+        self.msg.emails.push(v.into());
+        self
+    }
+
+    pub fn scores(mut self, v: impl Into<Cow<'a, [u32]>>) -> Self {
+        self.msg.scores = v.into();
+        self
+    }
+
+    pub fn push_scores(mut self, v: u32) -> Self {
+        self.msg.scores.to_mut().push(v);
+        self
+    }
+
+    pub fn counters(mut self, v: KVMap<Cow<'a, str>, i64>) -> Self {
+        self.msg.counters = v;
+        self
+    }
+
+    pub fn insert_counters(mut self, k: impl Into<Cow<'a, str>>, v: i64) -> Self {
+        self.msg.counters.insert(k.into(), v);
+        self
+    }
+
+    pub fn address(mut self, v: test_builders::Address<'a>) -> Self {
+        self.msg.address = Some(v);
+        self
+    }
+
+    pub fn previous(mut self, v: Vec<test_builders::Address<'a>>) -> Self {
+        self.msg.previous = v;
+        self
+    }
+
+    pub fn push_previous(mut self, v: test_builders::Address<'a>) -> Self {
+        self.msg.previous.push(v);
+        self
+    }
+
+    pub fn phone(mut self, v: impl Into<Cow<'a, str>>) -> Self {
+        self.msg.contact = test_builders::mod_Person::OneOfcontact::phone(v.into());
+        self
+    }
+
+    pub fn mail(mut self, v: test_builders::Address<'a>) -> Self {
+        self.msg.contact = test_builders::mod_Person::OneOfcontact::mail(v);
+        self
+    }
+
+    /// Builds the `Person`, fails if a required field is not set
+    pub fn build(self) -> Result<Person<'a>> {
+        if !self.has_name {
+            return Err(quick_protobuf::Error::MissingField("Person.name"));
+        }
+        if !self.has_id {
+            return Err(quick_protobuf::Error::MissingField("Person.id"));
+        }
+        Ok(self.msg)
+    }
+}
+
+/// Nested messages, enums and oneofs of `Person`
+pub mod mod_Person {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfcontact<'a> {
+    phone(Cow<'a, str>),
+    mail(test_builders::Address<'a>),
+    /// None of the fields is set
+    None,
+}
+
+impl<'a> Default for OneOfcontact<'a> {
+    fn default() -> Self {
+        OneOfcontact::None
+    }
+}
+
+}
+
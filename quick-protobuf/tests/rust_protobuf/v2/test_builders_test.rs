@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use quick_protobuf::*;
+
+use super::test_builders::mod_Person::OneOfcontact;
+use super::test_builders::*;
+
+#[test]
+fn test_builder() {
+    let person = Person::builder()
+        .name("Ada")
+        .id(1)
+        .kind(Kind::KIND_USER)
+        .push_emails("ada@example.com")
+        .push_emails(String::from("ada@example.org"))
+        .push_scores(3)
+        .insert_counters("visits", 2)
+        .address(Address::builder().city("London").build().unwrap())
+        .mail(Address::builder().city("Paris").street("Rue").build().unwrap())
+        .build()
+        .unwrap();
+
+    assert_eq!("Ada", person.name);
+    assert_eq!(1, person.id);
+    // declared default
+    assert_eq!(18, person.age);
+    assert_eq!(Some(Kind::KIND_USER), person.kind);
+    assert_eq!(vec!["ada@example.com", "ada@example.org"], person.emails);
+    assert_eq!(&[3][..], &*person.scores);
+    assert_eq!(Some(&2), person.counters.get("visits"));
+    assert_eq!(Some("London"), person.address.as_ref().map(|a| &*a.city));
+    match person.contact {
+        OneOfcontact::mail(ref a) => assert_eq!(Some(Cow::Borrowed("Rue")), a.street),
+        ref c => panic!("unexpected contact {:?}", c),
+    }
+
+    // same as decoded
+    let bytes = serialize_into_vec(&person).unwrap();
+    assert_eq!(person, deserialize_from_slice(&bytes).unwrap());
+}
+
+#[test]
+fn test_builder_missing_required() {
+    match Person::builder().name("Ada").build() {
+        Err(Error::MissingField(field)) => assert_eq!("Person.id", field),
+        r => panic!("unexpected result {:?}", r),
+    }
+    assert!(Address::builder().street("Rue").build().is_err());
+}
@@ -0,0 +1,215 @@
+// Automatically generated rust module for 'test_comments.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+/// BOOM
+/// BOOM
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct A<'a> {
+    pub a: Cow<'a, str>,
+    pub Thing: mod_A::OneOfThing,
+}
+
+impl<'a> MessageRead<'a> for A<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.a = Default::default();
+        self.Thing = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.a = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.Thing = mod_A::OneOfThing::c(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for A<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_len((&self.a).len())
+        + match self.Thing {
+            mod_A::OneOfThing::c(ref m) => 1 + sizeof_varint(*(m) as u64),
+            mod_A::OneOfThing::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(10, |w| w.write_string(&**&self.a))?;
+        match self.Thing {            mod_A::OneOfThing::c(ref m) => { w.write_with_tag(16, |w| w.write_int32(*m))? },
+            mod_A::OneOfThing::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `A`
+pub mod mod_A {
+
+use std::borrow::Cow;
+use super::*;
+
+/// BOOM
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct B<'a> {
+    /// BOOM
+    /// BOOM
+    pub b: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for B<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.b = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.b = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for B<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.b.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.b { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct C { }
+
+impl<'a> MessageRead<'a> for C {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for C { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct D { }
+
+impl<'a> MessageRead<'a> for D {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for D { }
+
+/// BOOM
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum E1 {
+    /// BOOM
+    /// BOOM
+    V1 = 1,
+    /// BOOM
+    v2 = 2,
+}
+
+impl Default for E1 {
+    fn default() -> Self {
+        E1::V1
+    }
+}
+
+impl From<i32> for E1 {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => E1::V1,
+            2 => E1::v2,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for E1 {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "V1" => E1::V1,
+            "v2" => E1::v2,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// BOOM
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum E2 {
+}
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum E3 {
+}
+
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfThing {
+    c(i32),
+    /// None of the fields is set
+    None,
+}
+
+impl Default for OneOfThing {
+    fn default() -> Self {
+        OneOfThing::None
+    }
+}
+
+}
+
@@ -0,0 +1,62 @@
+use quick_protobuf::*;
+
+use super::test_extensions_pb::mod_Scope::nested_ext;
+use super::test_extensions_pb::*;
+use crate::rust_protobuf::hex::{decode_hex, encode_hex};
+
+#[test]
+fn test_extensions_roundtrip() {
+    let mut msg = Extendable {
+        a: Some(1),
+        ..Default::default()
+    };
+    msg.set_extension(&int32_ext, &150).unwrap();
+    msg.set_extension(&string_ext, &"foo").unwrap();
+    msg.add_extension(&sint64_ext, &-1).unwrap();
+    msg.add_extension(&sint64_ext, &1).unwrap();
+    msg.set_extension(&color_ext, &Color::GREEN).unwrap();
+    msg.set_extension(&nested_ext, &7).unwrap();
+    test_serialize_deserialize!(
+        "08 01 a0 06 96 01 aa 06 03 66 6f 6f b0 06 01 b0 06 02 b8 06 02 c5 3e 07 00 00 00",
+        &msg,
+        Extendable
+    );
+
+    assert_eq!(msg.get_extension(&int32_ext).unwrap(), Some(150));
+    assert_eq!(msg.get_extension(&string_ext).unwrap(), Some("foo"));
+    assert_eq!(msg.get_repeated_extension(&sint64_ext).unwrap(), [-1, 1]);
+    assert_eq!(msg.get_extension(&color_ext).unwrap(), Some(Color::GREEN));
+    assert_eq!(msg.get_extension(&nested_ext).unwrap(), Some(7));
+    assert_eq!(msg.get_extension(&message_ext).unwrap(), None);
+}
+
+#[test]
+fn test_extensions_message() {
+    let mut msg = Extendable::default();
+    let value = ExtensionValue {
+        s: Some("bar".into()),
+    };
+    msg.set_extension(&message_ext, &value).unwrap();
+    test_serialize_deserialize!("c2 06 05 0a 03 62 61 72", &msg, Extendable);
+
+    let bytes = decode_hex("c2 06 05 0a 03 62 61 72");
+    let mut reader = BytesReader::from_bytes(&bytes);
+    let parsed = Extendable::from_reader(&mut reader, &bytes).unwrap();
+    assert_eq!(parsed.get_extension(&message_ext).unwrap(), Some(value));
+
+    let mut parsed = parsed;
+    parsed.clear_extension(&message_ext);
+    assert!(!parsed.has_extension(&message_ext));
+    assert_eq!(parsed.get_size(), 0);
+}
+
+#[test]
+fn test_extensions_borrowed_message() {
+    let mut msg = Labeled {
+        label: Some("a".into()),
+        ..Default::default()
+    };
+    msg.set_extension(&label_ext, &"b").unwrap();
+    test_serialize_deserialize!("0a 01 61 a2 06 01 62", &msg, Labeled);
+    assert_eq!(msg.get_extension(&label_ext).unwrap(), Some("b"));
+}
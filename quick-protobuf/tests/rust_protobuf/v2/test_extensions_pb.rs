@@ -0,0 +1,282 @@
+// Automatically generated rust module for 'test_extensions_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Color {
+    RED = 1,
+    GREEN = 2,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::RED
+    }
+}
+
+impl From<i32> for Color {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => Color::RED,
+            2 => Color::GREEN,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Color {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "RED" => Color::RED,
+            "GREEN" => Color::GREEN,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Extendable {
+    pub a: Option<i32>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for Extendable {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.a = Default::default();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.a = Some(r.read_int32(bytes)?),
+                Ok(t) if matches!(t >> 3, 100..=199 | 1000..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Extendable {
+    fn get_size(&self) -> usize {
+        0
+        + self.a.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.a { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl quick_protobuf::extensions::ExtendableMessage for Extendable {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ExtensionValue<'a> {
+    pub s: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for ExtensionValue<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.s = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.s = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for ExtensionValue<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.s.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.s { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Labeled<'a> {
+    pub label: Option<Cow<'a, str>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for Labeled<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.label = Default::default();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.label = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) if matches!(t >> 3, 100..=199) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Labeled<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.label.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.label { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for Labeled<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Scope { }
+
+impl<'a> MessageRead<'a> for Scope {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for Scope { }
+
+/// Nested messages, enums and oneofs of `Scope`
+pub mod mod_Scope {
+
+use super::*;
+
+pub const nested_ext: quick_protobuf::extensions::Extension<Extendable, quick_protobuf::extensions::Fixed32> = quick_protobuf::extensions::Extension::new(1000);
+
+}
+
+pub const int32_ext: quick_protobuf::extensions::Extension<Extendable, quick_protobuf::extensions::Int32> = quick_protobuf::extensions::Extension::new(100);
+
+pub const string_ext: quick_protobuf::extensions::Extension<Extendable, quick_protobuf::extensions::Str> = quick_protobuf::extensions::Extension::new(101);
+
+pub const sint64_ext: quick_protobuf::extensions::Extension<Extendable, quick_protobuf::extensions::Sint64> = quick_protobuf::extensions::Extension::new(102);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ColorExtExtension;
+
+impl quick_protobuf::extensions::ExtensionType for ColorExtExtension {
+    type Value<'a> = Color;
+
+    const WIRE_TYPE: u32 = 0;
+
+    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self::Value<'a>> {
+        Ok(r.read_enum(bytes)?)
+    }
+
+    fn get_size(v: &Self::Value<'_>) -> usize {
+        sizeof_varint(*(v) as u64)
+    }
+
+    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &Self::Value<'_>) -> Result<()> {
+        w.write_enum(*v as i32)
+    }
+}
+
+pub const color_ext: quick_protobuf::extensions::Extension<Extendable, ColorExtExtension> = quick_protobuf::extensions::Extension::new(103);
+
+#[derive(Debug, Clone, Copy)]
+pub struct MessageExtExtension;
+
+impl quick_protobuf::extensions::ExtensionType for MessageExtExtension {
+    type Value<'a> = ExtensionValue<'a>;
+
+    const WIRE_TYPE: u32 = 2;
+
+    fn read<'a>(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self::Value<'a>> {
+        Ok(r.read_message::<ExtensionValue>(bytes)?)
+    }
+
+    fn get_size(v: &Self::Value<'_>) -> usize {
+        sizeof_len((v).get_size())
+    }
+
+    fn write<W: WriterBackend>(w: &mut Writer<W>, v: &Self::Value<'_>) -> Result<()> {
+        w.write_message(v)
+    }
+}
+
+pub const message_ext: quick_protobuf::extensions::Extension<Extendable, MessageExtExtension> = quick_protobuf::extensions::Extension::new(104);
+
+pub const label_ext: quick_protobuf::extensions::Extension<Labeled<'static>, quick_protobuf::extensions::Str> = quick_protobuf::extensions::Extension::new(100);
+
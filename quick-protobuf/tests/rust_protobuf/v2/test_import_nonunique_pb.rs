@@ -0,0 +1,61 @@
+// Automatically generated rust module for 'test_import_nonunique_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestImportNonunque {
+    pub n1: Option<nonunique_1::Nonunique>,
+    pub n2: Option<nonunique_2::Nonunique>,
+}
+
+impl<'a> MessageRead<'a> for TestImportNonunque {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.n1 = Default::default();
+        self.n2 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.n1.get_or_insert_with(Default::default))?,
+                Ok(18) => r.merge_message(bytes, self.n2.get_or_insert_with(Default::default))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TestImportNonunque {
+    fn get_size(&self) -> usize {
+        0
+        + self.n1.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.n2.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.n1 { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.n2 { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,61 @@
+// Automatically generated rust module for 'test_import_root_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ContainsImported {
+    pub imported_message: Option<test_import_root_imported_pb::ImportedMessage>,
+    pub imported_enum: Option<test_import_root_imported_pb::ImportedEnum>,
+}
+
+impl<'a> MessageRead<'a> for ContainsImported {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.imported_message = Default::default();
+        self.imported_enum = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => r.merge_message(bytes, self.imported_message.get_or_insert_with(Default::default))?,
+                Ok(16) => self.imported_enum = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for ContainsImported {
+    fn get_size(&self) -> usize {
+        0
+        + self.imported_message.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.imported_enum.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.imported_message { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.imported_enum { w.write_with_tag(16, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,88 @@
+// Automatically generated rust module for 'test_lite_runtime_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnumTestLiteRuntime {
+    ONE = 1,
+    TWO = 2,
+}
+
+impl Default for EnumTestLiteRuntime {
+    fn default() -> Self {
+        EnumTestLiteRuntime::ONE
+    }
+}
+
+impl From<i32> for EnumTestLiteRuntime {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => EnumTestLiteRuntime::ONE,
+            2 => EnumTestLiteRuntime::TWO,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnumTestLiteRuntime {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "ONE" => EnumTestLiteRuntime::ONE,
+            "TWO" => EnumTestLiteRuntime::TWO,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestLiteRuntime {
+    pub v: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for TestLiteRuntime {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.v = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.v = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TestLiteRuntime {
+    fn get_size(&self) -> usize {
+        0
+        + self.v.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.v { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,3 @@
+// Automatically generated mod.rs
+pub mod nested;
+pub mod remote;
@@ -0,0 +1,4 @@
+// Automatically generated mod.rs
+pub mod this;
+pub mod empty;
+pub mod basic;
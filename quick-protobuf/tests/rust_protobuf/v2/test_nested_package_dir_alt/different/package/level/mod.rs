@@ -0,0 +1,3 @@
+// Automatically generated mod.rs
+pub mod same;
+pub mod with;
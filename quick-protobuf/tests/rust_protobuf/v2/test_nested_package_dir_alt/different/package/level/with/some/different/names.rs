@@ -0,0 +1,458 @@
+// Automatically generated rust module for 'test_nested_p2.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::super::super::super::super::super::super::super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum unique_p2_OutermostEnum {
+    P2_OUTERMOST = 0,
+}
+
+impl Default for unique_p2_OutermostEnum {
+    fn default() -> Self {
+        unique_p2_OutermostEnum::P2_OUTERMOST
+    }
+}
+
+impl From<i32> for unique_p2_OutermostEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => unique_p2_OutermostEnum::P2_OUTERMOST,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for unique_p2_OutermostEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_OUTERMOST" => unique_p2_OutermostEnum::P2_OUTERMOST,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct unique_p2_Level1 {
+    pub P2_level1: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for unique_p2_Level1 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.P2_level1 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.P2_level1 = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for unique_p2_Level1 {
+    fn get_size(&self) -> usize {
+        0
+        + self.P2_level1.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.P2_level1 { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `unique_p2_Level1`
+pub mod mod_unique_p2_Level1 {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Level2 {
+    pub P2_level2: Option<i32>,
+    pub OM: Option<test_nested_package_dir_alt::different::package::level::same::names::OutermostEnum>,
+    pub L1: Option<test_nested_package_dir_alt::different::package::level::with::some::different::names::mod_unique_p2_Level1::Level1NativeEnum>,
+    pub L2: Option<test_nested_package_dir_alt::different::package::level::with::some::different::names::mod_unique_p2_Level1::mod_Level2::Level2NativeEnum>,
+    pub L3: Option<test_nested_package_dir_alt::different::package::level::same::names::mod_Level1::mod_Level2::mod_Level3::Level3NativeEnum>,
+    pub L4: Option<test_nested_package_dir_alt::different::package::level::with::some::different::names::mod_unique_p2_Level1::mod_Level2::mod_Level3::mod_Level4::Level4NativeEnum>,
+    pub L5: Option<test_nested_package_dir::this::is::a::nested::package::mod_Level1::mod_Level2::mod_Level3::mod_Level4::mod_Level5::Level5NativeEnum>,
+}
+
+impl<'a> MessageRead<'a> for Level2 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.P2_level2 = Default::default();
+        self.OM = Default::default();
+        self.L1 = Default::default();
+        self.L2 = Default::default();
+        self.L3 = Default::default();
+        self.L4 = Default::default();
+        self.L5 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.P2_level2 = Some(r.read_int32(bytes)?),
+                Ok(16) => self.OM = Some(r.read_enum(bytes)?),
+                Ok(24) => self.L1 = Some(r.read_enum(bytes)?),
+                Ok(32) => self.L2 = Some(r.read_enum(bytes)?),
+                Ok(40) => self.L3 = Some(r.read_enum(bytes)?),
+                Ok(48) => self.L4 = Some(r.read_enum(bytes)?),
+                Ok(56) => self.L5 = Some(r.read_enum(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Level2 {
+    fn get_size(&self) -> usize {
+        0
+        + self.P2_level2.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.OM.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.L1.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.L2.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.L3.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.L4.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.L5.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.P2_level2 { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.OM { w.write_with_tag(16, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.L1 { w.write_with_tag(24, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.L2 { w.write_with_tag(32, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.L3 { w.write_with_tag(40, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.L4 { w.write_with_tag(48, |w| w.write_enum(*s as i32))?; }
+        if let Some(ref s) = self.L5 { w.write_with_tag(56, |w| w.write_enum(*s as i32))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Level2`
+pub mod mod_Level2 {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Level3 {
+    pub P2_level3: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for Level3 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.P2_level3 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.P2_level3 = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Level3 {
+    fn get_size(&self) -> usize {
+        0
+        + self.P2_level3.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.P2_level3 { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Level3`
+pub mod mod_Level3 {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Level4 {
+    pub P2_level4: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for Level4 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.P2_level4 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.P2_level4 = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Level4 {
+    fn get_size(&self) -> usize {
+        0
+        + self.P2_level4.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.P2_level4 { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Level4`
+pub mod mod_Level4 {
+
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct unique_p2_Level5 {
+    pub P2_level5: Option<i32>,
+}
+
+impl<'a> MessageRead<'a> for unique_p2_Level5 {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.P2_level5 = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.P2_level5 = Some(r.read_int32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for unique_p2_Level5 {
+    fn get_size(&self) -> usize {
+        0
+        + self.P2_level5.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.P2_level5 { w.write_with_tag(8, |w| w.write_int32(*s))?; }
+        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `unique_p2_Level5`
+pub mod mod_unique_p2_Level5 {
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level5NativeEnum {
+    P2_LEVEL5 = 0,
+}
+
+impl Default for Level5NativeEnum {
+    fn default() -> Self {
+        Level5NativeEnum::P2_LEVEL5
+    }
+}
+
+impl From<i32> for Level5NativeEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level5NativeEnum::P2_LEVEL5,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level5NativeEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_LEVEL5" => Level5NativeEnum::P2_LEVEL5,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level4NativeEnum {
+    P2_LEVEL4 = 0,
+}
+
+impl Default for Level4NativeEnum {
+    fn default() -> Self {
+        Level4NativeEnum::P2_LEVEL4
+    }
+}
+
+impl From<i32> for Level4NativeEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level4NativeEnum::P2_LEVEL4,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level4NativeEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_LEVEL4" => Level4NativeEnum::P2_LEVEL4,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level3NativeEnum {
+    P2_LEVEL3 = 0,
+}
+
+impl Default for Level3NativeEnum {
+    fn default() -> Self {
+        Level3NativeEnum::P2_LEVEL3
+    }
+}
+
+impl From<i32> for Level3NativeEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level3NativeEnum::P2_LEVEL3,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level3NativeEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_LEVEL3" => Level3NativeEnum::P2_LEVEL3,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level2NativeEnum {
+    P2_LEVEL2 = 0,
+}
+
+impl Default for Level2NativeEnum {
+    fn default() -> Self {
+        Level2NativeEnum::P2_LEVEL2
+    }
+}
+
+impl From<i32> for Level2NativeEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level2NativeEnum::P2_LEVEL2,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level2NativeEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_LEVEL2" => Level2NativeEnum::P2_LEVEL2,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Level1NativeEnum {
+    P2_LEVEL1 = 0,
+}
+
+impl Default for Level1NativeEnum {
+    fn default() -> Self {
+        Level1NativeEnum::P2_LEVEL1
+    }
+}
+
+impl From<i32> for Level1NativeEnum {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level1NativeEnum::P2_LEVEL1,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level1NativeEnum {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "P2_LEVEL1" => Level1NativeEnum::P2_LEVEL1,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
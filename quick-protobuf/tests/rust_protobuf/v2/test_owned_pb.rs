@@ -0,0 +1,196 @@
+// Automatically generated rust module for 'test_owned_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use core::convert::{TryFrom, TryInto};
+use quick_protobuf::Owned;
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Foo<'a> {
+    pub s: Option<Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for Foo<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.s = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.s = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Foo<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.s.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.s { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        Ok(())
+    }
+}
+
+
+            // IMPORTANT: For any future changes, note that the lifetime parameter
+            // of the `proto` field is set to 'static!!!
+            //
+            // This means that the internals of `proto` should at no point create a
+            // mutable reference to something using that lifetime parameter, on pain
+            // of UB. This applies even though it may be transmuted to a smaller
+            // lifetime later (through `proto()` or `proto_mut()`).
+            //
+            // At the time of writing, the only possible thing that uses the
+            // lifetime parameter is `Cow<'a, T>`, which never does this, so it's
+            // not UB.
+            //
+            #[derive(Debug)]
+            struct FooOwnedInner {
+                buf: Vec<u8>,
+                proto: Option<Foo<'static>>,
+                _pin: core::marker::PhantomPinned,
+            }
+
+            impl FooOwnedInner {
+                fn new(buf: Vec<u8>) -> Result<core::pin::Pin<Box<Self>>> {
+                    let inner = Self {
+                        buf,
+                        proto: None,
+                        _pin: core::marker::PhantomPinned,
+                    };
+                    let mut pinned = Box::pin(inner);
+
+                    let mut reader = BytesReader::from_bytes(&pinned.buf);
+                    let proto = Foo::from_reader(&mut reader, &pinned.buf)?;
+
+                    unsafe {
+                        let proto = core::mem::transmute::<_, Foo<'_>>(proto);
+                        pinned.as_mut().get_unchecked_mut().proto = Some(proto);
+                    }
+                    Ok(pinned)
+                }
+            }
+
+            pub struct FooOwned {
+                inner: core::pin::Pin<Box<FooOwnedInner>>,
+            }
+
+            #[allow(dead_code)]
+            impl quick_protobuf::Owned for FooOwned {
+                type Inner<'a> = Foo<'a>;
+
+                unsafe fn try_owned_from<E>(
+                    buf: Vec<u8>,
+                    f: impl Fn(&[u8]) -> core::result::Result<Self::Inner<'_>, E>,
+                ) -> core::result::Result<Self, E>
+                where
+                    E: core::fmt::Debug + core::fmt::Display,
+                {
+                    let inner = FooOwnedInner {
+                        buf,
+                        proto: None,
+                        _pin: core::marker::PhantomPinned,
+                    };
+                    let mut inner = Box::pin(inner);
+
+                    let proto = f(&inner.buf)?;
+
+                    unsafe {
+                        let proto = core::mem::transmute::<_, Foo<'_>>(proto);
+                        inner.as_mut().get_unchecked_mut().proto = Some(proto);
+                    }
+                    Ok(Self { inner })
+                }
+
+                fn buf(&self) -> &[u8] {
+                    &self.inner.buf
+                }
+
+                fn proto<'a>(&'a self) -> &'a Foo<'a> {
+                    let proto = self.inner.proto.as_ref().unwrap();
+                    unsafe { core::mem::transmute::<&Foo<'static>, &Foo<'a>>(proto) }
+                }
+
+                fn proto_mut<'a>(&'a mut self) -> &'a mut Foo<'a> {
+                    let inner = self.inner.as_mut();
+                    let inner = unsafe { inner.get_unchecked_mut() };
+                    let proto = inner.proto.as_mut().unwrap();
+                    unsafe { core::mem::transmute::<_, &mut Foo<'a>>(proto) }
+                }
+            }
+
+            impl core::fmt::Debug for FooOwned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    self.inner.proto.as_ref().unwrap().fmt(f)
+                }
+            }
+
+            impl TryFrom<Vec<u8>> for FooOwned {
+                type Error=quick_protobuf::Error;
+
+                fn try_from(buf: Vec<u8>) -> Result<Self> {
+                    Ok(Self { inner: FooOwnedInner::new(buf)? })
+                }
+            }
+
+            impl TryInto<Vec<u8>> for FooOwned {
+                type Error=quick_protobuf::Error;
+
+                fn try_into(self) -> Result<Vec<u8>> {
+                    let mut buf = Vec::new();
+                    let mut writer = Writer::new(&mut buf);
+                    self.inner.proto.as_ref().unwrap().write_message(&mut writer)?;
+                    Ok(buf)
+                }
+            }
+
+            impl From<Foo<'static>> for FooOwned {
+                fn from(proto: Foo<'static>) -> Self {
+                    Self {
+                        inner: Box::pin(FooOwnedInner {
+                            buf: Vec::new(),
+                            proto: Some(proto),
+                            _pin: core::marker::PhantomPinned,
+                        })
+                    }
+                }
+            }
+
+            impl Default for FooOwned {
+                fn default() -> Self {
+                    Self {
+                        inner: Box::pin(FooOwnedInner {
+                            buf: Vec::new(),
+                            proto: Some(Foo::default()),
+                            _pin: core::marker::PhantomPinned,
+                        })
+                    }
+                }
+            }
+            
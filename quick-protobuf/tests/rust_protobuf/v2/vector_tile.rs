@@ -0,0 +1,367 @@
+// Automatically generated rust module for 'vector_tile.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Tile<'a> {
+    pub layers: Vec<vector_tile::mod_Tile::Layer<'a>>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for Tile<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.layers.clear();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(26) => self.layers.push(r.read_message::<vector_tile::mod_Tile::Layer>(bytes)?),
+                Ok(t) if matches!(t >> 3, 16..=8191) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Tile<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.layers.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.layers { w.write_with_tag(26, |w| w.write_message(s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for Tile<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Nested messages, enums and oneofs of `Tile`
+pub mod mod_Tile {
+
+use std::borrow::Cow;
+use super::*;
+
+/// Variant type encoding
+/// The use of values is described in section 4.1 of the specification
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Value<'a> {
+    /// Exactly one of these values must be present in a valid message
+    pub string_value: Option<Cow<'a, str>>,
+    pub float_value: Option<f32>,
+    pub double_value: Option<f64>,
+    pub int_value: Option<i64>,
+    pub uint_value: Option<u64>,
+    pub sint_value: Option<i64>,
+    pub bool_value: Option<bool>,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for Value<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.string_value = Default::default();
+        self.float_value = Default::default();
+        self.double_value = Default::default();
+        self.int_value = Default::default();
+        self.uint_value = Default::default();
+        self.sint_value = Default::default();
+        self.bool_value = Default::default();
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.string_value = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(21) => self.float_value = Some(r.read_float(bytes)?),
+                Ok(25) => self.double_value = Some(r.read_double(bytes)?),
+                Ok(32) => self.int_value = Some(r.read_int64(bytes)?),
+                Ok(40) => self.uint_value = Some(r.read_uint64(bytes)?),
+                Ok(48) => self.sint_value = Some(r.read_sint64(bytes)?),
+                Ok(56) => self.bool_value = Some(r.read_bool(bytes)?),
+                Ok(t) if matches!(t >> 3, 8..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Value<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.string_value.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.float_value.as_ref().map_or(0, |_| 1 + 4)
+        + self.double_value.as_ref().map_or(0, |_| 1 + 8)
+        + self.int_value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.uint_value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.sint_value.as_ref().map_or(0, |m| 1 + sizeof_sint64(*(m)))
+        + self.bool_value.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.string_value { w.write_with_tag(10, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.float_value { w.write_with_tag(21, |w| w.write_float(*s))?; }
+        if let Some(ref s) = self.double_value { w.write_with_tag(25, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.int_value { w.write_with_tag(32, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.uint_value { w.write_with_tag(40, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.sint_value { w.write_with_tag(48, |w| w.write_sint64(*s))?; }
+        if let Some(ref s) = self.bool_value { w.write_with_tag(56, |w| w.write_bool(*s))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for Value<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// Features are described in section 4.2 of the specification
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Feature {
+    pub id: u64,
+    /// Tags of this feature are encoded as repeated pairs of
+    /// integers.
+    /// A detailed description of tags is located in sections
+    /// 4.2 and 4.4 of the specification
+    pub tags: Vec<u32>,
+    /// The type of geometry stored in this feature.
+    pub type_pb: vector_tile::mod_Tile::GeomType,
+    /// Contains a stream of commands and parameters (vertices).
+    /// A detailed description on geometry encoding is located in
+    /// section 4.3 of the specification.
+    pub geometry: Vec<u32>,
+}
+
+impl<'a> MessageRead<'a> for Feature {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.id = Default::default();
+        self.tags.clear();
+        self.type_pb = Default::default();
+        self.geometry.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.id = r.read_uint64(bytes)?,
+                Ok(18) => r.read_packed_into(bytes, &mut self.tags, |r, bytes| Ok(r.read_uint32(bytes)?))?,
+                Ok(24) => self.type_pb = r.read_enum(bytes)?,
+                Ok(34) => r.read_packed_into(bytes, &mut self.geometry, |r, bytes| Ok(r.read_uint32(bytes)?))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Feature {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.tags.is_empty() { 0 } else { 1 + sizeof_len(self.tags.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + if self.type_pb == vector_tile::mod_Tile::GeomType::UNKNOWN { 0 } else { 1 + sizeof_varint(*(&self.type_pb) as u64) }
+        + if self.geometry.is_empty() { 0 } else { 1 + sizeof_len(self.geometry.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u64 { w.write_with_tag(8, |w| w.write_uint64(*&self.id))?; }
+        w.write_packed_with_tag(18, &self.tags, |w, m| w.write_uint32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        if self.type_pb != vector_tile::mod_Tile::GeomType::UNKNOWN { w.write_with_tag(24, |w| w.write_enum(*&self.type_pb as i32))?; }
+        w.write_packed_with_tag(34, &self.geometry, |w, m| w.write_uint32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        Ok(())
+    }
+}
+
+/// Layers are described in section 4.1 of the specification
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Layer<'a> {
+    /// Any compliant implementation must first read the version
+    /// number encoded in this message and choose the correct
+    /// implementation for this version number before proceeding to
+    /// decode other parts of this message.
+    pub version: u32,
+    pub name: Cow<'a, str>,
+    /// The actual features in this tile.
+    pub features: Vec<vector_tile::mod_Tile::Feature>,
+    /// Dictionary encoding for keys
+    pub keys: Vec<Cow<'a, str>>,
+    /// Dictionary encoding for values
+    pub values: Vec<vector_tile::mod_Tile::Value<'a>>,
+    /// Although this is an "optional" field it is required by the specification.
+    /// See https://github.com/mapbox/vector-tile-spec/issues/47
+    pub extent: u32,
+    pub extensions: quick_protobuf::extensions::Extensions,
+}
+
+impl<'a> MessageRead<'a> for Layer<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Layer {
+            version: 1u32,
+            extent: 4096u32,
+            ..Self::default()
+        };
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.version = 1u32;
+        self.name = Default::default();
+        self.features.clear();
+        self.keys.clear();
+        self.values.clear();
+        self.extent = 4096u32;
+        self.extensions.clear();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(120) => self.version = r.read_uint32(bytes)?,
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.features.push(r.read_message::<vector_tile::mod_Tile::Feature>(bytes)?),
+                Ok(26) => self.keys.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.values.push(r.read_message::<vector_tile::mod_Tile::Value>(bytes)?),
+                Ok(40) => self.extent = r.read_uint32(bytes)?,
+                Ok(t) if matches!(t >> 3, 16..=536870911) => self.extensions.read_field(r, bytes, t)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Layer<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.version) as u64)
+        + 1 + sizeof_len((&self.name).len())
+        + self.features.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.keys.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + self.values.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + if self.extent == 4096u32 { 0 } else { 1 + sizeof_varint(*(&self.extent) as u64) }
+        + self.extensions.get_size()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(120, |w| w.write_uint32(*&self.version))?;
+        w.write_with_tag(10, |w| w.write_string(&**&self.name))?;
+        for s in &self.features { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.keys { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.values { w.write_with_tag(34, |w| w.write_message(s))?; }
+        if self.extent != 4096u32 { w.write_with_tag(40, |w| w.write_uint32(*&self.extent))?; }
+        self.extensions.write(w)?;
+        Ok(())
+    }
+}
+
+impl<'a> quick_protobuf::extensions::ExtendableMessage for Layer<'a> {
+    fn extensions(&self) -> &quick_protobuf::extensions::Extensions {
+        &self.extensions
+    }
+
+    fn extensions_mut(&mut self) -> &mut quick_protobuf::extensions::Extensions {
+        &mut self.extensions
+    }
+}
+
+/// GeomType is described in section 4.3.4 of the specification
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GeomType {
+    UNKNOWN = 0,
+    POINT = 1,
+    LINESTRING = 2,
+    POLYGON = 3,
+}
+
+impl Default for GeomType {
+    fn default() -> Self {
+        GeomType::UNKNOWN
+    }
+}
+
+impl From<i32> for GeomType {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => GeomType::UNKNOWN,
+            1 => GeomType::POINT,
+            2 => GeomType::LINESTRING,
+            3 => GeomType::POLYGON,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GeomType {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => GeomType::UNKNOWN,
+            "POINT" => GeomType::POINT,
+            "LINESTRING" => GeomType::LINESTRING,
+            "POLYGON" => GeomType::POLYGON,
+            _ => Self::default(),
+        }
+    }
+}
+
+}
+
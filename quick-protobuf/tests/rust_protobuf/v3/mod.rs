@@ -1,20 +1,37 @@
 mod basic;
 mod test_basic;
 
+mod basic_strict;
+mod test_basic_strict;
+
 // mod test_zeros_are_not_written;
 
 mod test_map;
 mod test_map_pb;
 
-// mod test_enum_alias_pb;
-// mod test_enum_alias;
+mod test_dont_use_cow;
+mod test_dont_use_cow_pb;
+
+mod test_enum_alias;
+mod test_enum_alias_test;
+
+mod test_field_types;
+mod test_field_types_pb;
+mod test_heapless;
+mod test_heapless_pb;
 
 mod test_ident_pb;
 
 mod test_deprecated_lifetime;
 mod test_deprecated_lifetime_can_compile;
 mod test_deprecated_lifetime_pb;
+mod test_doc_comments;
+mod test_doc_comments_pb;
 mod test_oneof;
 mod test_oneof_pb;
 mod test_owned;
 mod test_owned_pb;
+mod test_rust_options;
+mod test_rust_options_pb;
+mod test_services;
+mod test_services_pb;
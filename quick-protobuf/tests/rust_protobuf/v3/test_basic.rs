@@ -239,3 +239,61 @@ fn test_bug_sint() {
         test_serialize_deserialize!("10 03", &x, TestBugSint);
     }
 }
+
+#[test]
+fn test_merge_from_reader() {
+    // packed repeated fields are appended
+    let bytes = decode_hex("22 02 03 04");
+    let mut test4 = Test4::default();
+    for _ in 0..2 {
+        let mut reader = BytesReader::from_bytes(&bytes);
+        test4.merge_from_reader(&mut reader, &bytes).unwrap();
+    }
+    assert_eq!(vec![3, 4, 3, 4], test4.d);
+
+    // clear keeps the capacity
+    let capacity = test4.d.capacity();
+    test4.clear();
+    assert!(test4.d.is_empty());
+    assert_eq!(capacity, test4.d.capacity());
+
+    // singular fields are overwritten
+    let bytes = decode_hex("12 02 61 62");
+    let mut test2 = Test2 {
+        b: "testing".into(),
+    };
+    let mut reader = BytesReader::from_bytes(&bytes);
+    test2.merge_from_reader(&mut reader, &bytes).unwrap();
+    assert_eq!("ab", test2.b);
+
+    // nested messages are merged
+    let bytes = decode_hex("1a 00");
+    let mut test3 = Test3 {
+        c: Some(Test1 { a: 150 }),
+    };
+    let mut reader = BytesReader::from_bytes(&bytes);
+    test3.merge_from_reader(&mut reader, &bytes).unwrap();
+    assert_eq!(Some(Test1 { a: 150 }), test3.c);
+}
+
+#[test]
+fn test_proto3_optional() {
+    // unset fields are not written
+    let msg = TestProto3Optional::default();
+    test_serialize_deserialize!("", &msg, TestProto3Optional);
+
+    // zero values are written when explicitly set
+    let msg = TestProto3Optional {
+        int32_field: Some(0),
+        string_field: Some("".into()),
+        enum_field: Some(TestEnumDescriptor::UNKNOWN),
+        implicit_field: 0,
+    };
+    test_serialize_deserialize!("08 00 12 00 18 00", &msg, TestProto3Optional);
+
+    let msg = TestProto3Optional {
+        int32_field: Some(150),
+        ..Default::default()
+    };
+    test_serialize_deserialize!("08 96 01", &msg, TestProto3Optional);
+}
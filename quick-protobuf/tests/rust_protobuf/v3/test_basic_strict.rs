@@ -0,0 +1,21 @@
+use quick_protobuf::*;
+
+use super::basic_strict::*;
+use crate::rust_protobuf::hex::decode_hex;
+
+#[test]
+fn test_strict_duplicate_singular() {
+    let bytes = decode_hex("08 01 08 02");
+    let mut reader = BytesReader::from_bytes(&bytes);
+    assert_eq!(2, Test1::from_reader(&mut reader, &bytes).unwrap().a);
+    let mut reader = BytesReader::from_bytes_strict(&bytes);
+    assert!(matches!(
+        Test1::from_reader(&mut reader, &bytes),
+        Err(Error::DuplicateField(1))
+    ));
+
+    // repeated fields may appear several times
+    let bytes = decode_hex("22 01 03 22 01 04");
+    let mut reader = BytesReader::from_bytes_strict(&bytes);
+    assert_eq!(vec![3, 4], Test4::from_reader(&mut reader, &bytes).unwrap().d);
+}
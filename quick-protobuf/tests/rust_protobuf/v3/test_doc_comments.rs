@@ -0,0 +1,16 @@
+use quick_protobuf::*;
+
+use super::test_doc_comments_pb::mod_Person::{OneOfcontact, Status};
+use super::test_doc_comments_pb::*;
+
+#[test]
+fn test_doc_comments() {
+    let msg = Person {
+        name: "name".into(),
+        id: 1,
+        contact: OneOfcontact::email("email".into()),
+        status: Status::ACTIVE,
+    };
+    let bytes = serialize_into_vec(&msg).unwrap();
+    assert_eq!(msg, deserialize_from_slice(&bytes).unwrap());
+}
@@ -0,0 +1,148 @@
+// Automatically generated rust module for 'test_doc_comments_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+/// A message with documented fields
+///
+/// ```text
+/// Person { name: "x" }
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Person<'a> {
+    /// Name of the person
+    ///
+    /// trailing comment
+    pub name: Cow<'a, str>,
+    /// Identifier
+    /// of the person
+    pub id: i32,
+    pub status: mod_Person::Status,
+    /// Kind of contact
+    pub contact: mod_Person::OneOfcontact<'a>,
+}
+
+impl<'a> MessageRead<'a> for Person<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.id = Default::default();
+        self.status = Default::default();
+        self.contact = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => self.id = r.read_int32(bytes)?,
+                Ok(40) => self.status = r.read_enum(bytes)?,
+                Ok(26) => self.contact = mod_Person::OneOfcontact::email(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(34) => self.contact = mod_Person::OneOfcontact::phone(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Person<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+        + if self.id == 0i32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.status == test_doc_comments_pb::mod_Person::Status::UNKNOWN { 0 } else { 1 + sizeof_varint(*(&self.status) as u64) }
+        + match self.contact {
+            mod_Person::OneOfcontact::email(ref m) => 1 + sizeof_len((m).len()),
+            mod_Person::OneOfcontact::phone(ref m) => 1 + sizeof_len((m).len()),
+            mod_Person::OneOfcontact::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.name != "" { w.write_with_tag(10, |w| w.write_string(&**&self.name))?; }
+        if self.id != 0i32 { w.write_with_tag(16, |w| w.write_int32(*&self.id))?; }
+        if self.status != test_doc_comments_pb::mod_Person::Status::UNKNOWN { w.write_with_tag(40, |w| w.write_enum(*&self.status as i32))?; }
+        match self.contact {            mod_Person::OneOfcontact::email(ref m) => { w.write_with_tag(26, |w| w.write_string(&**m))? },
+            mod_Person::OneOfcontact::phone(ref m) => { w.write_with_tag(34, |w| w.write_string(&**m))? },
+            mod_Person::OneOfcontact::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Person`
+pub mod mod_Person {
+
+use super::*;
+
+/// Status of the person
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Status {
+    /// Unknown status
+    UNKNOWN = 0,
+    /// Active status
+    ACTIVE = 1,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::UNKNOWN
+    }
+}
+
+impl From<i32> for Status {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Status::UNKNOWN,
+            1 => Status::ACTIVE,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => Status::UNKNOWN,
+            "ACTIVE" => Status::ACTIVE,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// Kind of contact
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfcontact<'a> {
+    /// Email address
+    email(Cow<'a, str>),
+    /// phone number
+    phone(Cow<'a, str>),
+    /// None of the fields is set
+    None,
+}
+
+impl<'a> Default for OneOfcontact<'a> {
+    fn default() -> Self {
+        OneOfcontact::None
+    }
+}
+
+}
+
@@ -0,0 +1,43 @@
+use quick_protobuf::*;
+
+use super::test_dont_use_cow_pb::mod_OwnedFields::OneOfvalue;
+use super::test_dont_use_cow_pb::*;
+use crate::rust_protobuf::hex::{decode_hex, encode_hex};
+
+#[test]
+fn test_dont_use_cow() {
+    let msg = OwnedFields {
+        name: "ab".into(),
+        data: vec![1],
+        label: Some("c".into()),
+        tags: vec!["d".into()],
+        samples: vec![2],
+        value: OneOfvalue::blob(vec![3]),
+        ..Default::default()
+    };
+    test_serialize_deserialize!(
+        "0a 02 61 62 12 01 01 1a 01 63 22 01 64 2a 04 02 00 00 00 42 01 03",
+        &msg,
+        OwnedFields
+    );
+}
+
+#[test]
+fn test_dont_use_cow_merge() {
+    let mut msg = OwnedFields {
+        name: "a long name".into(),
+        data: vec![1, 2, 3],
+        samples: vec![1],
+        ..Default::default()
+    };
+    let capacity = msg.name.capacity();
+
+    // strings and bytes are replaced in place, packed chunks are appended
+    let bytes = decode_hex("0a 01 78 12 01 04 2a 04 02 00 00 00");
+    let mut reader = BytesReader::from_bytes(&bytes);
+    msg.merge_from_reader(&mut reader, &bytes).unwrap();
+    assert_eq!("x", msg.name);
+    assert_eq!(capacity, msg.name.capacity());
+    assert_eq!(vec![4], msg.data);
+    assert_eq!(vec![1, 2], msg.samples);
+}
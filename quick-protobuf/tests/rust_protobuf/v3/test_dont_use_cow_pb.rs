@@ -0,0 +1,118 @@
+// Automatically generated rust module for 'test_dont_use_cow_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::collections::HashMap;
+type KVMap<K, V> = HashMap<K, V>;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OwnedFields {
+    pub name: String,
+    pub data: Vec<u8>,
+    pub label: Option<String>,
+    pub tags: Vec<String>,
+    pub samples: Vec<u32>,
+    pub attributes: KVMap<String, Vec<u8>>,
+    pub value: mod_OwnedFields::OneOfvalue,
+}
+
+impl<'a> MessageRead<'a> for OwnedFields {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.data.clear();
+        self.label = Default::default();
+        self.tags.clear();
+        self.samples.clear();
+        self.attributes.clear();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => { self.name.clear(); self.name.push_str(r.read_string(bytes)?); }
+                Ok(18) => { self.data.clear(); self.data.extend_from_slice(r.read_bytes(bytes)?); }
+                Ok(26) => self.label = Some(r.read_string(bytes)?.to_owned()),
+                Ok(34) => self.tags.push(r.read_string(bytes)?.to_owned()),
+                Ok(42) => self.samples.extend_from_slice(&r.read_packed_fixed(bytes)?),
+                Ok(50) => {
+                    let (key, value) = r.read_map_entry(bytes, 10, |r, bytes| Ok(r.read_string(bytes)?.to_owned()), 18, |r, bytes| Ok(r.read_bytes(bytes)?.to_owned()))?;
+                    self.attributes.insert(key, value);
+                }
+                Ok(58) => self.value = mod_OwnedFields::OneOfvalue::text(r.read_string(bytes)?.to_owned()),
+                Ok(66) => self.value = mod_OwnedFields::OneOfvalue::blob(r.read_bytes(bytes)?.to_owned()),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for OwnedFields {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name.is_empty() { 0 } else { 1 + sizeof_len((&self.name).len()) }
+        + if self.data.is_empty() { 0 } else { 1 + sizeof_len((&self.data).len()) }
+        + self.label.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.tags.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + if self.samples.is_empty() { 0 } else { 1 + sizeof_len(self.samples.len() * 4) }
+        + self.attributes.iter().map(|(k, v)| 1 + sizeof_len(2 + sizeof_len((k).len()) + sizeof_len((v).len()))).sum::<usize>()
+        + match self.value {
+            mod_OwnedFields::OneOfvalue::text(ref m) => 1 + sizeof_len((m).len()),
+            mod_OwnedFields::OneOfvalue::blob(ref m) => 1 + sizeof_len((m).len()),
+            mod_OwnedFields::OneOfvalue::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if !self.name.is_empty() { w.write_with_tag(10, |w| w.write_string(&**&self.name))?; }
+        if !self.data.is_empty() { w.write_with_tag(18, |w| w.write_bytes(&**&self.data))?; }
+        if let Some(ref s) = self.label { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.tags { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        w.write_packed_fixed_with_tag(42, &self.samples)?;
+        for (k, v) in self.attributes.iter() { w.write_with_tag(50, |w| w.write_map(2 + sizeof_len((k).len()) + sizeof_len((v).len()), 10, |w| w.write_string(&**k), 18, |w| w.write_bytes(&**v)))?; }
+        match self.value {            mod_OwnedFields::OneOfvalue::text(ref m) => { w.write_with_tag(58, |w| w.write_string(&**m))? },
+            mod_OwnedFields::OneOfvalue::blob(ref m) => { w.write_with_tag(66, |w| w.write_bytes(&**m))? },
+            mod_OwnedFields::OneOfvalue::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `OwnedFields`
+pub mod mod_OwnedFields {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfvalue {
+    text(String),
+    blob(Vec<u8>),
+    /// None of the fields is set
+    None,
+}
+
+impl Default for OneOfvalue {
+    fn default() -> Self {
+        OneOfvalue::None
+    }
+}
+
+}
+
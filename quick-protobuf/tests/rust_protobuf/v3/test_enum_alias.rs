@@ -0,0 +1,96 @@
+// Automatically generated rust module for 'test_enum_alias_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnumWithAlias {
+    UNKNOWN = 0,
+    A = 10,
+    B = 20,
+}
+
+impl EnumWithAlias {
+    pub const A_AGAIN: EnumWithAlias = EnumWithAlias::A;
+}
+
+impl Default for EnumWithAlias {
+    fn default() -> Self {
+        EnumWithAlias::UNKNOWN
+    }
+}
+
+impl From<i32> for EnumWithAlias {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => EnumWithAlias::UNKNOWN,
+            10 => EnumWithAlias::A,
+            20 => EnumWithAlias::B,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnumWithAlias {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "UNKNOWN" => EnumWithAlias::UNKNOWN,
+            "A" => EnumWithAlias::A,
+            "B" => EnumWithAlias::B,
+            "A_AGAIN" => EnumWithAlias::A_AGAIN,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestEnumWithAlias {
+    pub en: test_enum_alias::EnumWithAlias,
+}
+
+impl<'a> MessageRead<'a> for TestEnumWithAlias {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.en = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.en = r.read_enum(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for TestEnumWithAlias {
+    fn get_size(&self) -> usize {
+        0
+        + if self.en == test_enum_alias::EnumWithAlias::UNKNOWN { 0 } else { 1 + sizeof_varint(*(&self.en) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.en != test_enum_alias::EnumWithAlias::UNKNOWN { w.write_with_tag(8, |w| w.write_enum(*&self.en as i32))?; }
+        Ok(())
+    }
+}
+
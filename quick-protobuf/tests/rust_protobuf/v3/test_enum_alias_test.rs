@@ -0,0 +1,17 @@
+use quick_protobuf::*;
+
+use super::test_enum_alias::*;
+use crate::rust_protobuf::hex::{decode_hex, encode_hex};
+
+#[test]
+fn test_enum_alias() {
+    assert_eq!(EnumWithAlias::A, EnumWithAlias::A_AGAIN);
+    assert_eq!(EnumWithAlias::A, EnumWithAlias::from(10));
+    assert_eq!(EnumWithAlias::A, EnumWithAlias::from("A_AGAIN"));
+    assert_eq!(EnumWithAlias::B, EnumWithAlias::from("B"));
+
+    let msg = TestEnumWithAlias {
+        en: EnumWithAlias::A_AGAIN,
+    };
+    test_serialize_deserialize!("08 0a", &msg, TestEnumWithAlias);
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use quick_protobuf::*;
+
+use super::test_field_types_pb::*;
+use crate::rust_protobuf::v3::test_rust_options::UnixMillis;
+
+#[test]
+fn test_field_types() {
+    let record = Record {
+        hash: [7; 32],
+        name: Arc::from("name"),
+        created: UnixMillis(1000),
+        tags: vec!["a".to_string(), "b".to_string()],
+        label: "label".to_string(),
+        comment: "comment".into(),
+    };
+    let bytes = serialize_into_vec(&record).unwrap();
+    let read: Record = deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(record, read);
+}
+
+#[test]
+fn test_field_types_conversion_error() {
+    // a 3 bytes hash
+    let bytes = [0x0a, 0x03, 1, 2, 3];
+    let mut reader = BytesReader::from_bytes(&bytes);
+    let res = Record::from_reader(&mut reader, &bytes);
+    assert!(matches!(res, Err(Error::Conversion(1))));
+}
+
+#[test]
+fn test_field_types_detached() {
+    fn assert_static<T: 'static>(_: &T) {}
+
+    let bytes = serialize_into_vec(&Detached {
+        name: "name".to_string(),
+        data: vec![1, 2, 3],
+    })
+    .unwrap();
+    let owned: Detached = deserialize_from_slice(&bytes).unwrap();
+    drop(bytes);
+    assert_static(&owned);
+    assert_eq!("name", owned.name);
+    assert_eq!(vec![1, 2, 3], owned.data);
+}
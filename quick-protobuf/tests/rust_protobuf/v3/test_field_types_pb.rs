@@ -0,0 +1,128 @@
+// Automatically generated rust module for 'test_field_types_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Record<'a> {
+    pub hash: [u8;32],
+    pub name: std::sync::Arc<str>,
+    pub created: crate::rust_protobuf::v3::test_rust_options::UnixMillis,
+    pub tags: Vec<String>,
+    pub label: String,
+    pub comment: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Record<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.hash = Default::default();
+        self.name = Default::default();
+        self.created = Default::default();
+        self.tags.clear();
+        self.label = Default::default();
+        self.comment = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.hash = core::convert::TryFrom::try_from(r.read_bytes(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(1))?,
+                Ok(18) => self.name = core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(2))?,
+                Ok(24) => self.created = core::convert::TryFrom::try_from(r.read_int64(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(3))?,
+                Ok(34) => self.tags.push(core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(4))?),
+                Ok(42) => self.label = core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(5))?,
+                Ok(50) => self.comment = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Record<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.hash == <[u8;32] as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<[u8]>::as_ref(&self.hash)).len()) }
+        + if self.name == <std::sync::Arc<str> as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<str>::as_ref(&self.name)).len()) }
+        + if *&Into::<i64>::into(Clone::clone(&self.created)) == 0i64 { 0 } else { 1 + sizeof_varint(*(&Into::<i64>::into(Clone::clone(&self.created))) as u64) }
+        + self.tags.iter().map(|s| 1 + sizeof_len((&AsRef::<str>::as_ref(s)).len())).sum::<usize>()
+        + if self.label == <String as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<str>::as_ref(&self.label)).len()) }
+        + if self.comment == "" { 0 } else { 1 + sizeof_len((&self.comment).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if !(self.hash == <[u8;32] as Default>::default()) { w.write_with_tag(10, |w| w.write_bytes(&**&AsRef::<[u8]>::as_ref(&self.hash)))?; }
+        if !(self.name == <std::sync::Arc<str> as Default>::default()) { w.write_with_tag(18, |w| w.write_string(&**&AsRef::<str>::as_ref(&self.name)))?; }
+        if !(*&Into::<i64>::into(Clone::clone(&self.created)) == 0i64) { w.write_with_tag(24, |w| w.write_int64(*&Into::<i64>::into(Clone::clone(&self.created))))?; }
+        for s in &self.tags { w.write_with_tag(34, |w| w.write_string(&**&AsRef::<str>::as_ref(s)))?; }
+        if !(self.label == <String as Default>::default()) { w.write_with_tag(42, |w| w.write_string(&**&AsRef::<str>::as_ref(&self.label)))?; }
+        if self.comment != "" { w.write_with_tag(50, |w| w.write_string(&**&self.comment))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Detached {
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+impl<'a> MessageRead<'a> for Detached {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.data = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(1))?,
+                Ok(18) => self.data = core::convert::TryFrom::try_from(r.read_bytes(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(2))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Detached {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name == <String as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<str>::as_ref(&self.name)).len()) }
+        + if self.data == <Vec<u8> as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<[u8]>::as_ref(&self.data)).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if !(self.name == <String as Default>::default()) { w.write_with_tag(10, |w| w.write_string(&**&AsRef::<str>::as_ref(&self.name)))?; }
+        if !(self.data == <Vec<u8> as Default>::default()) { w.write_with_tag(18, |w| w.write_bytes(&**&AsRef::<[u8]>::as_ref(&self.data)))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,69 @@
+use std::convert::TryInto;
+
+use quick_protobuf::sizeofs::sizeof_varint;
+use quick_protobuf::*;
+
+use super::test_heapless_pb::mod_Telemetry::OneOfstatus;
+use super::test_heapless_pb::*;
+
+fn telemetry() -> Telemetry {
+    let mut telemetry = Telemetry {
+        device: "dev-1".try_into().unwrap(),
+        key: heapless::Vec::from_slice(&[1, 2, 3]).unwrap(),
+        label: Some("label".try_into().unwrap()),
+        samples: heapless::Vec::from_slice(&[1, 2, 3, 4]).unwrap(),
+        offsets: heapless::Vec::from_slice(&[-1, 0, 300]).unwrap(),
+        status: OneOfstatus::error("overheat".try_into().unwrap()),
+        ..Default::default()
+    };
+    telemetry.tags.push("a".try_into().unwrap()).unwrap();
+    telemetry.tags.push("b".try_into().unwrap()).unwrap();
+    telemetry
+        .readings
+        .push(Reading {
+            sensor: 7,
+            value: -42,
+        })
+        .unwrap();
+    telemetry.counters.insert("up".try_into().unwrap(), 3).unwrap();
+    telemetry
+}
+
+fn read(bytes: &[u8]) -> Result<Telemetry> {
+    let mut reader = BytesReader::from_bytes(bytes);
+    Telemetry::from_reader(&mut reader, bytes)
+}
+
+#[test]
+fn test_heapless_roundtrip() {
+    let telemetry = telemetry();
+    let mut buf = [0u8; 128];
+    serialize_into_slice(&telemetry, &mut buf).unwrap();
+    // skip the length prefix
+    let len = telemetry.get_size();
+    let bytes = &buf[sizeof_varint(len as u64)..][..len];
+    assert_eq!(telemetry, read(bytes).unwrap());
+}
+
+#[test]
+fn test_heapless_capacity() {
+    // device of 9 bytes, max_len is 8
+    let bytes = b"\x0a\x09dev-12345";
+    assert!(matches!(read(bytes), Err(Error::Capacity(1))));
+
+    // 3 tags, capacity is 2
+    let bytes = b"\x22\x01a\x22\x01b\x22\x01c";
+    assert!(matches!(read(bytes), Err(Error::Capacity(4))));
+
+    // 5 packed samples, capacity is 4
+    let bytes = [&[0x2a, 20][..], &[0; 20]].concat();
+    assert!(matches!(read(&bytes), Err(Error::Capacity(5))));
+
+    // 3 counters, capacity is 2
+    let bytes = b"\x42\x05\x0a\x01a\x10\x01\x42\x05\x0a\x01b\x10\x01\x42\x05\x0a\x01c\x10\x01";
+    assert!(matches!(read(bytes), Err(Error::Capacity(8))));
+
+    // error of 17 bytes in the oneof, max_len is 16
+    let bytes = [&[0x4a, 17][..], &[b'e'; 17]].concat();
+    assert!(matches!(read(&bytes), Err(Error::Capacity(9))));
+}
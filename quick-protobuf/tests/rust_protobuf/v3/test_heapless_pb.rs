@@ -0,0 +1,172 @@
+// Automatically generated rust module for 'test_heapless_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Reading {
+    pub sensor: u32,
+    pub value: i32,
+}
+
+impl<'a> MessageRead<'a> for Reading {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.sensor = Default::default();
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(13) => self.sensor = r.read_fixed32(bytes)?,
+                Ok(16) => self.value = r.read_sint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Reading {
+    fn get_size(&self) -> usize {
+        0
+        + if self.sensor == 0u32 { 0 } else { 1 + 4 }
+        + if self.value == 0i32 { 0 } else { 1 + sizeof_sint32(*(&self.value)) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.sensor != 0u32 { w.write_with_tag(13, |w| w.write_fixed32(*&self.sensor))?; }
+        if self.value != 0i32 { w.write_with_tag(16, |w| w.write_sint32(*&self.value))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Telemetry {
+    pub device: heapless::String<8>,
+    pub key: heapless::Vec<u8, 4>,
+    pub label: Option<heapless::String<8>>,
+    pub tags: heapless::Vec<heapless::String<4>, 2>,
+    pub samples: heapless::Vec<u32, 4>,
+    pub offsets: heapless::Vec<i32, 4>,
+    pub readings: heapless::Vec<Reading, 2>,
+    pub counters: heapless::FnvIndexMap<heapless::String<4>, i32, 2>,
+    pub status: mod_Telemetry::OneOfstatus,
+}
+
+impl<'a> MessageRead<'a> for Telemetry {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.device.clear();
+        self.key.clear();
+        self.label = Default::default();
+        self.tags.clear();
+        self.samples.clear();
+        self.offsets.clear();
+        self.readings.clear();
+        self.counters.clear();
+        self.status = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => { self.device = { let mut s = heapless::String::new(); s.push_str(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(1))?; s }; }
+                Ok(18) => { self.key = { let mut s = heapless::Vec::new(); s.extend_from_slice(r.read_bytes(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(2))?; s }; }
+                Ok(26) => { self.label = Some({ let mut s = heapless::String::new(); s.push_str(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(3))?; s }); }
+                Ok(34) => { self.tags.push({ let mut s = heapless::String::new(); s.push_str(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(4))?; s }).map_err(|_| quick_protobuf::Error::Capacity(4))?; }
+                Ok(42) => r.read_packed_with(bytes, |r, bytes| Ok(self.samples.push(r.read_fixed32(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(5))?))?,
+                Ok(50) => r.read_packed_with(bytes, |r, bytes| Ok(self.offsets.push(r.read_int32(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(6))?))?,
+                Ok(58) => self.readings.push(r.read_message::<Reading>(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(7))?,
+                Ok(66) => {
+                    let (key, value) = r.read_map_entry(bytes, 10, |r, bytes| Ok({ let mut s = heapless::String::new(); s.push_str(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(8))?; s }), 16, |r, bytes| Ok(r.read_int32(bytes)?))?;
+                    self.counters.insert(key, value).map_err(|_| quick_protobuf::Error::Capacity(8))?;
+                }
+                Ok(74) => { self.status = mod_Telemetry::OneOfstatus::error({ let mut s = heapless::String::new(); s.push_str(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Capacity(9))?; s }); }
+                Ok(80) => self.status = mod_Telemetry::OneOfstatus::code(r.read_uint32(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Telemetry {
+    fn get_size(&self) -> usize {
+        0
+        + if self.device.is_empty() { 0 } else { 1 + sizeof_len((&self.device).len()) }
+        + if self.key.is_empty() { 0 } else { 1 + sizeof_len((&self.key).len()) }
+        + self.label.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.tags.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + if self.samples.is_empty() { 0 } else { 1 + sizeof_len(self.samples.len() * 4) }
+        + if self.offsets.is_empty() { 0 } else { 1 + sizeof_len(self.offsets.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + self.readings.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.counters.iter().map(|(k, v)| 1 + sizeof_len(2 + sizeof_len((k).len()) + sizeof_varint(*(v) as u64))).sum::<usize>()
+        + match self.status {
+            mod_Telemetry::OneOfstatus::error(ref m) => 1 + sizeof_len((m).len()),
+            mod_Telemetry::OneOfstatus::code(ref m) => 1 + sizeof_varint(*(m) as u64),
+            mod_Telemetry::OneOfstatus::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if !self.device.is_empty() { w.write_with_tag(10, |w| w.write_string(&**&self.device))?; }
+        if !self.key.is_empty() { w.write_with_tag(18, |w| w.write_bytes(&**&self.key))?; }
+        if let Some(ref s) = self.label { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        for s in &self.tags { w.write_with_tag(34, |w| w.write_string(&**s))?; }
+        w.write_packed_fixed_with_tag(42, &self.samples)?;
+        w.write_packed_with_tag(50, &self.offsets, |w, m| w.write_int32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        for s in &self.readings { w.write_with_tag(58, |w| w.write_message(s))?; }
+        for (k, v) in self.counters.iter() { w.write_with_tag(66, |w| w.write_map(2 + sizeof_len((k).len()) + sizeof_varint(*(v) as u64), 10, |w| w.write_string(&**k), 16, |w| w.write_int32(*v)))?; }
+        match self.status {            mod_Telemetry::OneOfstatus::error(ref m) => { w.write_with_tag(74, |w| w.write_string(&**m))? },
+            mod_Telemetry::OneOfstatus::code(ref m) => { w.write_with_tag(80, |w| w.write_uint32(*m))? },
+            mod_Telemetry::OneOfstatus::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Telemetry`
+pub mod mod_Telemetry {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfstatus {
+    error(heapless::String<16>),
+    code(u32),
+    /// None of the fields is set
+    None,
+}
+
+impl Default for OneOfstatus {
+    fn default() -> Self {
+        OneOfstatus::None
+    }
+}
+
+}
+
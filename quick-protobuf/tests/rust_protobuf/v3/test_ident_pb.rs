@@ -0,0 +1,198 @@
+// Automatically generated rust module for 'test_ident_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Vec_pb { }
+
+impl<'a> MessageRead<'a> for Vec_pb {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for Vec_pb { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct String_pb { }
+
+impl<'a> MessageRead<'a> for String_pb {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for String_pb { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Option_pb { }
+
+impl<'a> MessageRead<'a> for Option_pb {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for Option_pb { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct None_pb { }
+
+impl<'a> MessageRead<'a> for None_pb {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for None_pb { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Some_pb { }
+
+impl<'a> MessageRead<'a> for Some_pb {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for Some_pb { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Message { }
+
+impl<'a> MessageRead<'a> for Message {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, _: &[u8]) -> Result<()> {
+        r.read_to_end();
+        Ok(())
+    }
+}
+
+impl MessageWrite for Message { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestType<'a> {
+    pub struct_pb: Vec<Cow<'a, str>>,
+    pub ref_pb: Vec<u32>,
+    pub type_pb: mod_TestType::OneOftype_pb<'a>,
+}
+
+impl<'a> MessageRead<'a> for TestType<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.struct_pb.clear();
+        self.ref_pb.clear();
+        self.type_pb = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(18) => self.struct_pb.push(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => r.read_packed_into(bytes, &mut self.ref_pb, |r, bytes| Ok(r.read_uint32(bytes)?))?,
+                Ok(10) => self.type_pb = mod_TestType::OneOftype_pb::s(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for TestType<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.struct_pb.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + if self.ref_pb.is_empty() { 0 } else { 1 + sizeof_len(self.ref_pb.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + match self.type_pb {
+            mod_TestType::OneOftype_pb::s(ref m) => 1 + sizeof_len((m).len()),
+            mod_TestType::OneOftype_pb::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.struct_pb { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        w.write_packed_with_tag(26, &self.ref_pb, |w, m| w.write_uint32(*m), &|m| sizeof_varint(*(m) as u64))?;
+        match self.type_pb {            mod_TestType::OneOftype_pb::s(ref m) => { w.write_with_tag(10, |w| w.write_string(&**m))? },
+            mod_TestType::OneOftype_pb::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `TestType`
+pub mod mod_TestType {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOftype_pb<'a> {
+    s(Cow<'a, str>),
+    /// None of the fields is set
+    None,
+}
+
+impl<'a> Default for OneOftype_pb<'a> {
+    fn default() -> Self {
+        OneOftype_pb::None
+    }
+}
+
+}
+
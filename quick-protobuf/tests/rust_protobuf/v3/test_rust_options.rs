@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use quick_protobuf::*;
+
+use super::test_rust_options_pb::*;
+use crate::rust_protobuf::hex::{decode_hex, encode_hex};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct UnixMillis(pub i64);
+
+impl From<i64> for UnixMillis {
+    fn from(ms: i64) -> Self {
+        UnixMillis(ms)
+    }
+}
+
+impl From<UnixMillis> for i64 {
+    fn from(ms: UnixMillis) -> Self {
+        ms.0
+    }
+}
+
+#[test]
+fn test_attributes() {
+    let levels: BTreeSet<_> = vec![Level::HIGH, Level::LOW].into_iter().collect();
+    assert_eq!(Some(&Level::LOW), levels.iter().next());
+
+    let a = Ordered {
+        a: 1,
+        b: "b".into(),
+    };
+    let b = Ordered {
+        a: 2,
+        b: "a".into(),
+    };
+    assert!(a < b);
+
+    fn assert_eq_impl<T: Eq>() {}
+    assert_eq_impl::<mod_Account::OneOfcontact>();
+}
+
+#[test]
+fn test_box() {
+    let account = Account {
+        boxed: Some(Box::new(Inner { value: 5 })),
+        ..Default::default()
+    };
+    test_serialize_deserialize!("22 02 08 05", &account, Account);
+}
+
+#[test]
+fn test_skip_debug() {
+    let account = Account {
+        name: "name".into(),
+        password: "secret".into(),
+        ..Default::default()
+    };
+    let debug = format!("{:?}", account);
+    assert!(debug.starts_with("Account { name: \"name\", level: LOW"));
+    assert!(debug.ends_with(", .. }"));
+    assert!(!debug.contains("secret"));
+}
+
+#[test]
+fn test_custom_types() {
+    // default values are skipped, including arrays
+    test_serialize_deserialize!("", &CustomTypes::default(), CustomTypes);
+
+    let msg = CustomTypes {
+        created: UnixMillis(1000),
+        deleted: Some(UnixMillis(0)),
+        updates: vec![UnixMillis(1), UnixMillis(2)],
+        label: "ab".into(),
+        tags: vec!["x".into()],
+        hash: [1, 2, 3, 4],
+        letters: vec!['a'],
+        inner: Some(Box::new(Inner { value: 5 })),
+    };
+    test_serialize_deserialize!(
+        "08 e8 07 10 00 1a 02 01 02 22 02 61 62 2a 01 78 32 04 01 02 03 04 3a 04 61 00 00 00 42 02 08 05",
+        &msg,
+        CustomTypes
+    );
+}
+
+#[test]
+fn test_custom_types_conversion_error() {
+    let bytes = decode_hex("32 03 01 02 03");
+    let mut reader = BytesReader::from_bytes(&bytes);
+    let res = CustomTypes::from_reader(&mut reader, &bytes);
+    assert!(matches!(res, Err(Error::Conversion(6))));
+}
@@ -0,0 +1,316 @@
+// Automatically generated rust module for 'test_rust_options_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, PartialOrd, Ord)]
+pub enum Level {
+    LOW = 0,
+    #[doc = "The highest level"]
+    HIGH = 1,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Level::LOW
+    }
+}
+
+impl From<i32> for Level {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Level::LOW,
+            1 => Level::HIGH,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Level {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "LOW" => Level::LOW,
+            "HIGH" => Level::HIGH,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Inner {
+    pub value: i32,
+}
+
+impl<'a> MessageRead<'a> for Inner {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.value = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.value = r.read_int32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Inner {
+    fn get_size(&self) -> usize {
+        0
+        + if self.value == 0i32 { 0 } else { 1 + sizeof_varint(*(&self.value) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.value != 0i32 { w.write_with_tag(8, |w| w.write_int32(*&self.value))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+#[derive(PartialOrd)]
+pub struct Ordered<'a> {
+#[doc(alias = "first")]
+    pub a: i32,
+    pub b: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Ordered<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.a = Default::default();
+        self.b = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.a = r.read_int32(bytes)?,
+                Ok(18) => self.b = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Ordered<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.a == 0i32 { 0 } else { 1 + sizeof_varint(*(&self.a) as u64) }
+        + if self.b == "" { 0 } else { 1 + sizeof_len((&self.b).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.a != 0i32 { w.write_with_tag(8, |w| w.write_int32(*&self.a))?; }
+        if self.b != "" { w.write_with_tag(18, |w| w.write_string(&**&self.b))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Default, PartialEq, Clone)]
+pub struct Account<'a> {
+    pub name: Cow<'a, str>,
+    pub password: Cow<'a, str>,
+    pub level: Level,
+    pub boxed: Option<Box<Inner>>,
+    #[doc = "How to reach the owner"]
+    pub contact: mod_Account::OneOfcontact<'a>,
+}
+
+impl<'a> core::fmt::Debug for Account<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Account")
+            .field("name", &self.name)
+            .field("level", &self.level)
+            .field("boxed", &self.boxed)
+            .field("contact", &self.contact)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> MessageRead<'a> for Account<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+        self.password = Default::default();
+        self.level = Default::default();
+        self.boxed = Default::default();
+        self.contact = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => self.password = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => self.level = r.read_enum(bytes)?,
+                Ok(34) => r.merge_message(bytes, &mut **self.boxed.get_or_insert_with(Default::default))?,
+                Ok(42) => self.contact = mod_Account::OneOfcontact::email(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(50) => self.contact = mod_Account::OneOfcontact::phone(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for Account<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+        + if self.password == "" { 0 } else { 1 + sizeof_len((&self.password).len()) }
+        + if self.level == test_rust_options_pb::Level::LOW { 0 } else { 1 + sizeof_varint(*(&self.level) as u64) }
+        + self.boxed.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + match self.contact {
+            mod_Account::OneOfcontact::email(ref m) => 1 + sizeof_len((m).len()),
+            mod_Account::OneOfcontact::phone(ref m) => 1 + sizeof_len((m).len()),
+            mod_Account::OneOfcontact::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.name != "" { w.write_with_tag(10, |w| w.write_string(&**&self.name))?; }
+        if self.password != "" { w.write_with_tag(18, |w| w.write_string(&**&self.password))?; }
+        if self.level != test_rust_options_pb::Level::LOW { w.write_with_tag(24, |w| w.write_enum(*&self.level as i32))?; }
+        if let Some(ref s) = self.boxed { w.write_with_tag(34, |w| w.write_message(&**s))?; }
+        match self.contact {            mod_Account::OneOfcontact::email(ref m) => { w.write_with_tag(42, |w| w.write_string(&**m))? },
+            mod_Account::OneOfcontact::phone(ref m) => { w.write_with_tag(50, |w| w.write_string(&**m))? },
+            mod_Account::OneOfcontact::None => {},
+    }        Ok(())
+    }
+}
+
+/// Nested messages, enums and oneofs of `Account`
+pub mod mod_Account {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+#[derive(Eq)]
+pub enum OneOfcontact<'a> {
+    email(Cow<'a, str>),
+    phone(Cow<'a, str>),
+    /// None of the fields is set
+    None,
+}
+
+impl<'a> Default for OneOfcontact<'a> {
+    fn default() -> Self {
+        OneOfcontact::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CustomTypes {
+    pub created: crate::rust_protobuf::v3::test_rust_options::UnixMillis,
+    pub deleted: Option<crate::rust_protobuf::v3::test_rust_options::UnixMillis>,
+    pub updates: Vec<crate::rust_protobuf::v3::test_rust_options::UnixMillis>,
+    pub label: Box<str>,
+    pub tags: Vec<Box<str>>,
+    pub hash: [u8; 4],
+    pub letters: Vec<char>,
+    pub inner: Option<Box<Inner>>,
+}
+
+impl<'a> MessageRead<'a> for CustomTypes {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.created = Default::default();
+        self.deleted = Default::default();
+        self.updates.clear();
+        self.label = Default::default();
+        self.tags.clear();
+        self.hash = Default::default();
+        self.letters.clear();
+        self.inner = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.created = core::convert::TryFrom::try_from(r.read_int64(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(1))?,
+                Ok(16) => self.deleted = Some(core::convert::TryFrom::try_from(r.read_int64(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(2))?),
+                Ok(26) => r.read_packed_into(bytes, &mut self.updates, |r, bytes| Ok(core::convert::TryFrom::try_from(r.read_int64(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(3))?))?,
+                Ok(34) => self.label = core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(4))?,
+                Ok(42) => self.tags.push(core::convert::TryFrom::try_from(r.read_string(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(5))?),
+                Ok(50) => self.hash = core::convert::TryFrom::try_from(r.read_bytes(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(6))?,
+                Ok(58) => r.read_packed_into(bytes, &mut self.letters, |r, bytes| Ok(core::convert::TryFrom::try_from(r.read_fixed32(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(7))?))?,
+                Ok(66) => self.inner = Some(core::convert::TryFrom::try_from(r.read_message::<Inner>(bytes)?).map_err(|_| quick_protobuf::Error::Conversion(8))?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for CustomTypes {
+    fn get_size(&self) -> usize {
+        0
+        + if *&Into::<i64>::into(Clone::clone(&self.created)) == 0i64 { 0 } else { 1 + sizeof_varint(*(&Into::<i64>::into(Clone::clone(&self.created))) as u64) }
+        + self.deleted.as_ref().map_or(0, |m| 1 + sizeof_varint(*(&Into::<i64>::into(Clone::clone(m))) as u64))
+        + if self.updates.is_empty() { 0 } else { 1 + sizeof_len(self.updates.iter().map(|s| sizeof_varint(*(&Into::<i64>::into(Clone::clone(s))) as u64)).sum::<usize>()) }
+        + if self.label == <Box<str> as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<str>::as_ref(&self.label)).len()) }
+        + self.tags.iter().map(|s| 1 + sizeof_len((&AsRef::<str>::as_ref(s)).len())).sum::<usize>()
+        + if self.hash == <[u8; 4] as Default>::default() { 0 } else { 1 + sizeof_len((&AsRef::<[u8]>::as_ref(&self.hash)).len()) }
+        + if self.letters.is_empty() { 0 } else { 1 + sizeof_len(self.letters.len() * 4) }
+        + self.inner.as_ref().map_or(0, |m| 1 + sizeof_len((AsRef::<Inner>::as_ref(m)).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if !(*&Into::<i64>::into(Clone::clone(&self.created)) == 0i64) { w.write_with_tag(8, |w| w.write_int64(*&Into::<i64>::into(Clone::clone(&self.created))))?; }
+        if let Some(ref s) = self.deleted { w.write_with_tag(16, |w| w.write_int64(*&Into::<i64>::into(Clone::clone(s))))?; }
+        w.write_packed_with_tag(26, &self.updates, |w, m| w.write_int64(*&Into::<i64>::into(Clone::clone(m))), &|m| sizeof_varint(*(&Into::<i64>::into(Clone::clone(m))) as u64))?;
+        if !(self.label == <Box<str> as Default>::default()) { w.write_with_tag(34, |w| w.write_string(&**&AsRef::<str>::as_ref(&self.label)))?; }
+        for s in &self.tags { w.write_with_tag(42, |w| w.write_string(&**&AsRef::<str>::as_ref(s)))?; }
+        if !(self.hash == <[u8; 4] as Default>::default()) { w.write_with_tag(50, |w| w.write_bytes(&**&AsRef::<[u8]>::as_ref(&self.hash)))?; }
+        w.write_packed_with_tag(58, &self.letters, |w, m| w.write_fixed32(*&Into::<u32>::into(Clone::clone(m))), &|m| 4)?;
+        if let Some(ref s) = self.inner { w.write_with_tag(66, |w| w.write_message(AsRef::<Inner>::as_ref(s)))?; }
+        Ok(())
+    }
+}
+
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use quick_protobuf::rpc::{Loopback, Transport};
+use quick_protobuf::{Error, Result};
+
+use super::test_services_pb::*;
+
+struct Server;
+
+impl Greeter for Server {
+    fn say_hello<'a>(&self, request: HelloRequest<'a>) -> Result<HelloReply<'a>> {
+        Ok(HelloReply {
+            message: Cow::Owned(format!("Hello {}", request.name)),
+        })
+    }
+
+    fn sum(&self, requests: Vec<Count>) -> Result<Count> {
+        Ok(Count {
+            n: requests.iter().map(|c| c.n).sum(),
+        })
+    }
+
+    fn repeat<'a>(&self, request: HelloRequest<'a>) -> Result<Vec<HelloReply<'a>>> {
+        if request.name.is_empty() {
+            return Err(Error::Rpc("no name".to_string()));
+        }
+        Ok(vec![
+            HelloReply {
+                message: request.name.clone(),
+            };
+            2
+        ])
+    }
+
+    fn running(&self, requests: Vec<Count>) -> Result<Vec<Count>> {
+        let mut n = 0;
+        Ok(requests
+            .iter()
+            .map(|c| {
+                n += c.n;
+                Count { n }
+            })
+            .collect())
+    }
+}
+
+#[test]
+fn test_loopback() {
+    let mut client = GreeterClient::new(Loopback(GreeterServer(Server)));
+
+    let request = HelloRequest { name: "a".into() };
+    assert_eq!("Hello a", client.say_hello(&request).unwrap().message);
+
+    let counts = [Count { n: 1 }, Count { n: 2 }, Count { n: 3 }];
+    assert_eq!(6, client.sum(&counts).unwrap().n);
+    assert_eq!(0, client.sum(&[]).unwrap().n);
+
+    let replies = client.repeat(&request).unwrap();
+    assert_eq!(
+        vec!["a", "a"],
+        replies.iter().map(|r| &*r.message).collect::<Vec<_>>()
+    );
+    let request = HelloRequest { name: "".into() };
+    assert!(matches!(client.repeat(&request), Err(Error::Rpc(_))));
+
+    let running = client.running(&counts).unwrap();
+    assert_eq!(
+        vec![1, 3, 6],
+        running.iter().map(|c| c.n).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_unknown_method() {
+    let mut transport = Loopback(GreeterServer(Server));
+    assert!(transport.call("/Greeter/SayHello", &[]).is_ok());
+    match transport.call("/Greeter/SayGoodbye", &[]) {
+        Err(Error::UnknownMethod(m)) => assert_eq!("/Greeter/SayGoodbye", m),
+        _ => panic!("expected an unknown method error"),
+    }
+}
@@ -0,0 +1,202 @@
+// Automatically generated rust module for 'test_services_pb.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HelloRequest<'a> {
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for HelloRequest<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.name = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for HelloRequest<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.name != "" { w.write_with_tag(10, |w| w.write_string(&**&self.name))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct HelloReply<'a> {
+    pub message: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for HelloReply<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.message = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => self.message = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> MessageWrite for HelloReply<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.message == "" { 0 } else { 1 + sizeof_len((&self.message).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.message != "" { w.write_with_tag(10, |w| w.write_string(&**&self.message))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Count {
+    pub n: i32,
+}
+
+impl<'a> MessageRead<'a> for Count {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        msg.merge_from_reader(r, bytes)?;
+        Ok(msg)
+    }
+
+    fn clear(&mut self) {
+        self.n = Default::default();
+    }
+
+    fn merge_from_reader(&mut self, r: &mut BytesReader, bytes: &'a [u8]) -> Result<()> {
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => self.n = r.read_int32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MessageWrite for Count {
+    fn get_size(&self) -> usize {
+        0
+        + if self.n == 0i32 { 0 } else { 1 + sizeof_varint(*(&self.n) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.n != 0i32 { w.write_with_tag(8, |w| w.write_int32(*&self.n))?; }
+        Ok(())
+    }
+}
+
+/// Greets people
+pub trait Greeter {
+    /// Greets one person
+    fn say_hello<'a>(&self, request: HelloRequest<'a>) -> quick_protobuf::Result<HelloReply<'a>>;
+    fn sum(&self, requests: Vec<Count>) -> quick_protobuf::Result<Count>;
+    fn repeat<'a>(&self, request: HelloRequest<'a>) -> quick_protobuf::Result<Vec<HelloReply<'a>>>;
+    fn running(&self, requests: Vec<Count>) -> quick_protobuf::Result<Vec<Count>>;
+}
+
+/// Server of the `Greeter` service, handling the encoded requests with `S`
+pub struct GreeterServer<S: Greeter>(pub S);
+
+impl<S: Greeter> quick_protobuf::rpc::Service for GreeterServer<S> {
+    fn call(&self, method: &str, request: &[u8]) -> quick_protobuf::Result<Vec<u8>> {
+        match method {
+            "/Greeter/SayHello" => quick_protobuf::rpc::encode(&self.0.say_hello(quick_protobuf::rpc::decode(request)?)?),
+            "/Greeter/Sum" => quick_protobuf::rpc::encode(&self.0.sum(quick_protobuf::rpc::decode_stream(request)?)?),
+            "/Greeter/Repeat" => quick_protobuf::rpc::encode_stream(&self.0.repeat(quick_protobuf::rpc::decode(request)?)?),
+            "/Greeter/Running" => quick_protobuf::rpc::encode_stream(&self.0.running(quick_protobuf::rpc::decode_stream(request)?)?),
+            _ => Err(quick_protobuf::Error::UnknownMethod(method.into())),
+        }
+    }
+}
+
+/// Client of the `Greeter` service, sending the encoded requests through `T`
+///
+/// Responses borrow the buffer of the client, until the next call.
+pub struct GreeterClient<T: quick_protobuf::rpc::Transport> {
+    pub transport: T,
+    response: Vec<u8>,
+}
+
+impl<T: quick_protobuf::rpc::Transport> GreeterClient<T> {
+    pub fn new(transport: T) -> Self {
+        GreeterClient { transport, response: Vec::new() }
+    }
+
+    /// Greets one person
+    pub fn say_hello(&mut self, request: &HelloRequest<'_>) -> quick_protobuf::Result<HelloReply<'_>> {
+        let request = quick_protobuf::rpc::encode(request)?;
+        self.response = self.transport.call("/Greeter/SayHello", &request)?;
+        quick_protobuf::rpc::decode(&self.response)
+    }
+
+    pub fn sum(&mut self, requests: &[Count]) -> quick_protobuf::Result<Count> {
+        let request = quick_protobuf::rpc::encode_stream(requests)?;
+        self.response = self.transport.call("/Greeter/Sum", &request)?;
+        quick_protobuf::rpc::decode(&self.response)
+    }
+
+    pub fn repeat(&mut self, request: &HelloRequest<'_>) -> quick_protobuf::Result<Vec<HelloReply<'_>>> {
+        let request = quick_protobuf::rpc::encode(request)?;
+        self.response = self.transport.call("/Greeter/Repeat", &request)?;
+        quick_protobuf::rpc::decode_stream(&self.response)
+    }
+
+    pub fn running(&mut self, requests: &[Count]) -> quick_protobuf::Result<Vec<Count>> {
+        let request = quick_protobuf::rpc::encode_stream(requests)?;
+        self.response = self.transport.call("/Greeter/Running", &request)?;
+        quick_protobuf::rpc::decode_stream(&self.response)
+    }
+}
+
@@ -1,9 +1,11 @@
 extern crate quick_protobuf;
 
+use quick_protobuf::grpc::{self, Compression};
 use quick_protobuf::sizeofs::*;
 use quick_protobuf::{deserialize_from_slice, serialize_into_slice, serialize_into_vec};
 use quick_protobuf::{
-    BytesReader, MessageRead, MessageWrite, Reader, Result, Writer, WriterBackend,
+    BytesReader, DynMessageInfo, DynMessageWrite, Error, MessageInfo, MessageRead, MessageWrite,
+    Reader, Result, Writer, WriterBackend,
 };
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -50,7 +52,7 @@ fn wr_bytes() {
         let mut w = Writer::new(&mut buf);
         w.write_bytes(v).unwrap();
     }
-    let mut r = BytesReader::from_bytes(&*buf);
+    let mut r = BytesReader::from_bytes(&buf);
     assert_eq!(v, r.read_bytes(&buf).unwrap());
 }
 
@@ -166,6 +168,45 @@ fn wr_message_slice() {
     assert_eq!(v, deserialize_from_slice(&buf).unwrap());
 }
 
+impl MessageInfo for TestMessage {
+    const PATH: &'static str = "write_read.TestMessage";
+}
+
+#[test]
+fn wr_dyn_message() {
+    let v = TestMessage {
+        id: Some(63),
+        val: vec![53, 5, 76, 743, 23, 753],
+    };
+    let b = TestMessageBorrow {
+        id: Some(1),
+        val: vec!["a", "bc"],
+    };
+    let messages: Vec<Box<dyn DynMessageWrite>> = vec![Box::new(v.clone()), Box::new(b.clone())];
+
+    let mut buf = Vec::new();
+    {
+        let mut w = Writer::new(&mut buf);
+        for m in &messages {
+            w.write_message(m).unwrap();
+        }
+    }
+    let mut r = BytesReader::from_bytes(&buf);
+    assert_eq!(v, r.read_message::<TestMessage>(&buf).unwrap());
+    assert_eq!(b, r.read_message::<TestMessageBorrow>(&buf).unwrap());
+    assert!(r.is_eof());
+
+    // without length prefix, into a `dyn WriterBackend`
+    let mut buf = Vec::new();
+    messages[1].dyn_write_message(&mut buf).unwrap();
+    assert_eq!(buf.len(), messages[1].dyn_get_size());
+    assert_eq!(b, TestMessageBorrow::from_reader(&mut BytesReader::from_bytes(&buf), &buf).unwrap());
+
+    let info: Box<dyn DynMessageInfo> = Box::new(v.clone());
+    assert_eq!("write_read.TestMessage", info.path());
+    assert_eq!(serialize_into_vec(&v).unwrap(), serialize_into_vec(&info).unwrap());
+}
+
 #[derive(PartialEq, Eq, Debug, Clone, Default)]
 struct TestMessageBorrow<'a> {
     id: Option<u32>,
@@ -205,7 +246,7 @@ impl<'a> MessageWrite for TestMessageBorrow<'a> {
             r.write_with_tag(10, |r| r.write_uint32(*s))?;
         }
         for s in &self.val {
-            r.write_with_tag(18, |r| r.write_string(*s))?;
+            r.write_with_tag(18, |r| r.write_string(s))?;
         }
         Ok(())
     }
@@ -312,7 +353,35 @@ fn wr_packed_float() {
         w.write_packed_fixed(&v).unwrap();
     }
     let mut r = BytesReader::from_bytes(&buf);
-    assert_eq!(v, r.read_packed_fixed(&buf).unwrap());
+    assert_eq!(v, &*r.read_packed_fixed(&buf).unwrap());
+}
+
+#[test]
+fn wr_packed_fixed_unaligned() {
+    let v = vec![1.5f64, -2.25, 1e300, 0.];
+    let mut buf = Vec::new();
+    {
+        let mut w = Writer::new(&mut buf);
+        w.write_packed_fixed(&v).unwrap();
+    }
+    // encoding is little endian whatever the host
+    assert_eq!(1 + 8 * v.len(), buf.len());
+    assert_eq!(&1.5f64.to_le_bytes(), &buf[1..9]);
+
+    for offset in 0..8 {
+        let mut shifted = vec![0u8; offset];
+        shifted.extend_from_slice(&buf);
+        let mut r = BytesReader::from_bytes(&shifted[offset..]);
+        let read = r.read_packed_fixed::<f64>(&shifted[offset..]).unwrap();
+        assert_eq!(v, &*read);
+        assert!(r.is_eof());
+
+        let aligned = shifted[offset + 1..].as_ptr() as usize % std::mem::align_of::<f64>() == 0;
+        match read {
+            Cow::Borrowed(_) => assert!(aligned && cfg!(target_endian = "little")),
+            Cow::Owned(_) => assert!(!aligned || cfg!(target_endian = "big")),
+        }
+    }
 }
 
 #[test]
@@ -330,7 +399,7 @@ fn wr_map() {
             w.write_map(
                 2 + sizeof_len(k.len()) + sizeof_varint(*v as u64),
                 10,
-                |w| w.write_string(&**k),
+                |w| w.write_string(k),
                 16,
                 |w| w.write_int32(*v),
             )
@@ -341,9 +410,11 @@ fn wr_map() {
     let mut read_back = HashMap::new();
     while !r.is_eof() {
         let (key, value) = r
-            .read_map(
+            .read_map_entry(
                 &buf,
+                10,
                 |r, bytes| r.read_string(bytes).map(Cow::Borrowed),
+                16,
                 |r, bytes| r.read_int32(bytes),
             )
             .unwrap();
@@ -351,3 +422,249 @@ fn wr_map() {
     }
     assert_eq!(v, read_back);
 }
+
+fn read_entry(buf: &[u8]) -> Result<(Cow<'_, str>, i32)> {
+    let mut r = BytesReader::from_bytes(buf);
+    r.read_map_entry(
+        buf,
+        10,
+        |r, bytes| r.read_string(bytes).map(Cow::Borrowed),
+        16,
+        |r, bytes| r.read_int32(bytes),
+    )
+}
+
+#[test]
+fn read_map_spec() {
+    // value before key
+    let buf = [7, 16, 5, 10, 3, b'f', b'o', b'o'];
+    assert_eq!((Cow::Borrowed("foo"), 5), read_entry(&buf).unwrap());
+
+    // missing key and value get their default
+    let buf = [2, 16, 5];
+    assert_eq!((Cow::Borrowed(""), 5), read_entry(&buf).unwrap());
+    let buf = [0];
+    assert_eq!((Cow::Borrowed(""), 0), read_entry(&buf).unwrap());
+
+    // unknown fields are skipped, including multi-byte tags
+    let buf = [
+        11, 24, 1, // field 3, varint
+        0x82, 0x01, 1, 0xff, // field 16, length delimited
+        10, 1, b'a', 16, 1,
+    ];
+    assert_eq!((Cow::Borrowed("a"), 1), read_entry(&buf).unwrap());
+
+    // unexpected wire type for the key or value
+    let buf = [2, 8, 1];
+    assert!(matches!(read_entry(&buf), Err(Error::Map(8))));
+    let buf = [3, 21, 1, 0];
+    assert!(read_entry(&buf).is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn read_map_deprecated() {
+    let buf = [9, 24, 1, 16, 5, 10, 3, b'f', b'o', b'o'];
+    let mut r = BytesReader::from_bytes(&buf);
+    let entry = r.read_map(
+        &buf,
+        |r, bytes| r.read_string(bytes).map(Cow::Borrowed),
+        |r, bytes| r.read_int32(bytes),
+    );
+    assert_eq!((Cow::Borrowed("foo"), 5), entry.unwrap());
+}
+
+/// Reverses the bytes, enough to check that frames are really compressed
+#[derive(Clone)]
+struct Reverse;
+
+impl Compression for Reverse {
+    fn name(&self) -> &str {
+        "reverse"
+    }
+
+    fn compress(&self, data: &[u8], out: &mut Vec<u8>) -> Result<()> {
+        out.extend(data.iter().rev());
+        Ok(())
+    }
+
+    fn decompress(&self, data: &[u8], out: &mut Vec<u8>, max_len: usize) -> Result<()> {
+        if data.len() > max_len {
+            return Err(Error::MessageTooLarge(data.len()));
+        }
+        out.extend(data.iter().rev());
+        Ok(())
+    }
+}
+
+/// Expands each byte into 1 GiB of zeros, stopping once more than `max_len` bytes are written
+struct Bomb;
+
+impl Compression for Bomb {
+    fn name(&self) -> &str {
+        "bomb"
+    }
+
+    fn compress(&self, data: &[u8], out: &mut Vec<u8>) -> Result<()> {
+        out.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn decompress(&self, data: &[u8], out: &mut Vec<u8>, max_len: usize) -> Result<()> {
+        for _ in 0..data.len() << 20 {
+            out.extend_from_slice(&[0; 1024]);
+            if out.len() > max_len {
+                return Err(Error::MessageTooLarge(out.len()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn grpc_frame() {
+    let v = TestMessage {
+        id: Some(63),
+        val: vec![53, 5, 6],
+    };
+    let mut codec = grpc::Codec::new();
+    let mut buf = Vec::new();
+    codec.encode(&v, &mut buf).unwrap();
+    assert_eq!(
+        &[0, 0, 0, 0, 8, 10, 63, 18, 106, 18, 10, 18, 12][..],
+        &buf[..]
+    );
+    codec.encode(&TestMessage::default(), &mut buf).unwrap();
+    assert_eq!(&[0, 0, 0, 0, 0][..], &buf[13..]);
+
+    // incomplete frames
+    for len in 0..13 {
+        assert!(codec.decode::<TestMessage>(&buf[..len]).unwrap().is_none());
+    }
+    let (m, len) = codec.decode::<TestMessage>(&buf).unwrap().unwrap();
+    assert_eq!((v, 13), (m, len));
+    let (m, len) = codec.decode::<TestMessage>(&buf[13..]).unwrap().unwrap();
+    assert_eq!((TestMessage::default(), 5), (m, len));
+
+    // compressed frames are rejected without compression
+    buf[0] = 1;
+    assert!(matches!(
+        codec.decode::<TestMessage>(&buf),
+        Err(Error::Compression(_))
+    ));
+    buf[0] = 2;
+    assert!(matches!(
+        codec.decode::<TestMessage>(&buf),
+        Err(Error::InvalidFrameFlag(2))
+    ));
+}
+
+#[test]
+fn grpc_frame_compression() {
+    let test = "eajhawbdkjblncljbdskjbclas";
+    let v = TestMessageBorrow {
+        id: Some(63),
+        val: vec![&test[0..2], &test[3..7]],
+    };
+    let mut codec = grpc::Codec::with_compression(Reverse);
+    assert_eq!("reverse", codec.compression().name());
+    let mut buf = Vec::new();
+    codec.encode(&v, &mut buf).unwrap();
+    assert_eq!(
+        &[1, 0, 0, 0, 12, b'b', b'w', b'a', b'h', 4, 18][..],
+        &buf[..11]
+    );
+
+    // the decoded message borrows the decompressed bytes of the codec
+    let (m, len) = codec.decode::<TestMessageBorrow>(&buf).unwrap().unwrap();
+    assert_eq!((&v, 17), (&m, len));
+
+    // identity frames are not compressed
+    let mut codec = grpc::Codec::with_compression(grpc::Identity);
+    let mut buf = Vec::new();
+    codec.encode(&v, &mut buf).unwrap();
+    assert_eq!(&[0, 0, 0, 0, 12, 10, 63][..], &buf[..7]);
+    let (m, len) = codec.decode::<TestMessageBorrow>(&buf).unwrap().unwrap();
+    assert_eq!((v, 17), (m, len));
+}
+
+#[test]
+fn grpc_frame_max_len() {
+    let v = TestMessage {
+        id: Some(63),
+        val: vec![53, 5, 6],
+    };
+    let mut buf = Vec::new();
+    grpc::Codec::new().encode(&v, &mut buf).unwrap();
+    let mut codec = grpc::Codec::new().max_message_len(7);
+    assert!(matches!(
+        codec.decode::<TestMessage>(&buf[..5]),
+        Err(Error::MessageTooLarge(8))
+    ));
+
+    let mut codec = grpc::Codec::new().max_message_len(8);
+    assert_eq!(v, codec.decode::<TestMessage>(&buf).unwrap().unwrap().0);
+
+    // the decompression stops at the maximum length
+    let mut codec = grpc::Codec::with_compression(Bomb).max_message_len(4096);
+    assert!(matches!(
+        codec.decode::<TestMessage>(&[1, 0, 0, 0, 1, 0]),
+        Err(Error::MessageTooLarge(5120))
+    ));
+}
+
+#[test]
+fn grpc_frame_reader_writer() {
+    let messages = vec![
+        TestMessage {
+            id: Some(63),
+            val: vec![53, 5, 6],
+        },
+        TestMessage::default(),
+        TestMessage {
+            id: None,
+            val: vec![-1],
+        },
+    ];
+    let mut writer = grpc::FrameWriter::new(Vec::new());
+    for m in &messages {
+        writer.write_message(m).unwrap();
+    }
+    let buf = writer.into_inner();
+    let mut reader = grpc::FrameReader::new(&buf[..]);
+    for m in &messages {
+        assert_eq!(
+            Some(m),
+            reader.read_message::<TestMessage>().unwrap().as_ref()
+        );
+    }
+    assert!(reader.read_message::<TestMessage>().unwrap().is_none());
+
+    // compressed
+    let codec = grpc::Codec::with_compression(Reverse);
+    let mut writer = grpc::FrameWriter::with_codec(Vec::new(), codec.clone());
+    for m in &messages {
+        writer.write_message(m).unwrap();
+    }
+    let buf = writer.into_inner();
+    let mut reader = grpc::FrameReader::with_codec(&buf[..], codec);
+    for m in &messages {
+        assert_eq!(
+            Some(m),
+            reader.read_message::<TestMessage>().unwrap().as_ref()
+        );
+    }
+    assert!(reader.read_message::<TestMessage>().unwrap().is_none());
+
+    // truncated header or message
+    let mut reader = grpc::FrameReader::new(&buf[..3]);
+    assert!(matches!(
+        reader.read_message::<TestMessage>(),
+        Err(Error::UnexpectedEndOfBuffer)
+    ));
+    let mut reader = grpc::FrameReader::new(&buf[..10]);
+    assert!(matches!(
+        reader.read_message::<TestMessage>(),
+        Err(Error::Io(_))
+    ));
+}
@@ -7,6 +7,7 @@ set -eux -o pipefail
 cargo run -p quick-protobuf --example pb_rs_example_v3_owned
 cargo run -p quick-protobuf --example pb_rs_example
 cargo run -p quick-protobuf --example pb_rs_example_v3
-cargo run -p quick-protobuf --example pb_rs_example_nostd --no-default-features
+cargo run -p quick-protobuf --example pb_rs_example_nostd --no-default-features --features alloc
+cargo run -p quick-protobuf --example pb_rs_example_heapless --no-default-features
 
 cargo test -p pb-rs -p quick-protobuf --examples --tests